@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use ampd::handlers::payload_validation::{self, ContractQuerier};
 use ampd::types::{PublicKey, TMAddress};
 use ampd_sdk::event::event_handler::{EventHandler, SubscriptionParams};
 use ampd_sdk::grpc::client::types::{Key, KeyAlgorithm};
@@ -16,7 +17,7 @@ use events::{try_from, AbciEventTypeFilter, EventType};
 use hex::encode;
 use multisig::msg::ExecuteMsg;
 use multisig::types::MsgToSign;
-use serde::de::Error as DeserializeError;
+use serde::de::{DeserializeOwned, Error as DeserializeError};
 use serde::{Deserialize, Deserializer};
 use tracing::info;
 use typed_builder::TypedBuilder;
@@ -29,6 +30,7 @@ pub type Result<T> = error_stack::Result<T, Error>;
 #[try_from("wasm-signing_started")]
 pub struct SigningStartedEvent {
     session_id: u64,
+    verifier_set_id: String,
     #[serde(deserialize_with = "deserialize_public_keys")]
     pub_keys: HashMap<TMAddress, PublicKey>,
     msg: MsgToSign,
@@ -56,6 +58,8 @@ pub struct Handler {
     pub verifier: TMAddress,
     pub multisig: TMAddress,
     pub chain: ChainName,
+    #[builder(default)]
+    pub payload_validation: Option<payload_validation::Config>,
 }
 
 impl Handler {
@@ -77,6 +81,35 @@ impl Handler {
     }
 }
 
+/// Lets the payload validation query contract state through the ampd gRPC client
+struct Querier<'a, HC>(&'a mut HC);
+
+#[async_trait]
+impl<HC> ContractQuerier for Querier<'_, HC>
+where
+    HC: EventHandlerClient + Send + 'static,
+{
+    async fn query<T>(
+        &mut self,
+        contract: &TMAddress,
+        query: Vec<u8>,
+    ) -> error_stack::Result<T, payload_validation::Error>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let contract = nonempty::String::try_from(contract.to_string())
+            .change_context(payload_validation::Error::ContractQuery)?;
+        let query = nonempty::Vec::try_from(query)
+            .change_context(payload_validation::Error::ContractQuery)?;
+
+        self.0
+            .contract_state(contract.clone(), query)
+            .await
+            .change_context(payload_validation::Error::ContractQuery)
+            .attach_printable_lazy(|| format!("{{ contract = {contract} }}"))
+    }
+}
+
 #[async_trait]
 impl EventHandler for Handler {
     type Err = Error;
@@ -89,6 +122,7 @@ impl EventHandler for Handler {
     ) -> Result<Vec<Any>> {
         let SigningStartedEvent {
             session_id,
+            verifier_set_id,
             pub_keys,
             msg,
             expires_at,
@@ -125,6 +159,21 @@ impl EventHandler for Handler {
 
         match pub_keys.get(&self.verifier) {
             Some(pub_key) => {
+                if let Some(config) = &self.payload_validation {
+                    payload_validation::validate_signing_session(
+                        config,
+                        &mut Querier(client),
+                        &self.multisig,
+                        self.chain.clone(),
+                        session_id,
+                        verifier_set_id,
+                        &msg,
+                    )
+                    .await
+                    .change_context(Error::PayloadValidation)
+                    .attach_printable_lazy(|| format!("{{ session_id = {session_id} }}"))?;
+                }
+
                 let key_type = match pub_key {
                     PublicKey::Secp256k1(_) => KeyAlgorithm::Ecdsa,
                     PublicKey::Ed25519(_) => KeyAlgorithm::Ed25519,
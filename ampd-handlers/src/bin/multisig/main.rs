@@ -1,6 +1,7 @@
 mod error;
 mod handler;
 
+use ampd::handlers::payload_validation;
 use ampd_handlers::tracing::init_tracing;
 use ampd_sdk::config;
 use ampd_sdk::runtime::HandlerRuntime;
@@ -8,17 +9,29 @@ use axelar_wasm_std::chain::ChainName;
 #[cfg(debug_assertions)]
 use dotenv_flow::dotenv_flow;
 use error_stack::{Result, ResultExt};
+use serde::{Deserialize, Serialize};
 use tokio_util::sync::CancellationToken;
 use tracing::Level;
 
 use crate::error::Error;
 use crate::handler::Handler;
 
-fn build_handler(runtime: &HandlerRuntime, chain_name: ChainName) -> Result<Handler, Error> {
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct MultisigHandlerConfig {
+    #[serde(default)]
+    payload_validation: Option<payload_validation::Config>,
+}
+
+fn build_handler(
+    runtime: &HandlerRuntime,
+    chain_name: ChainName,
+    config: MultisigHandlerConfig,
+) -> Result<Handler, Error> {
     let handler = Handler::builder()
         .verifier(runtime.verifier.clone().into())
         .multisig(runtime.contracts.multisig.clone().into())
         .chain(chain_name)
+        .payload_validation(config.payload_validation)
         .build();
 
     Ok(handler)
@@ -33,13 +46,19 @@ async fn main() -> Result<(), Error> {
 
     let base_config = config::Config::from_default_sources().change_context(Error::HandlerStart)?;
 
+    let handler_config = config::Config::builder()
+        .add_file_source("multisig-handler-config.toml")
+        .add_env_source("AMPD_MULTISIG_HANDLER")
+        .build::<MultisigHandlerConfig>()
+        .change_context(Error::HandlerStart)?;
+
     let token = CancellationToken::new();
 
     let runtime = HandlerRuntime::start(&base_config, token.clone())
         .await
         .change_context(Error::HandlerStart)?;
 
-    let handler = build_handler(&runtime, base_config.chain_name.clone())?;
+    let handler = build_handler(&runtime, base_config.chain_name.clone(), handler_config)?;
 
     runtime
         .run_handler(handler, base_config, token)
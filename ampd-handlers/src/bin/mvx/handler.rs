@@ -0,0 +1,543 @@
+use std::collections::{HashMap, HashSet};
+
+use ampd::handlers::mvx_verify_msg::Message;
+use ampd::handlers::mvx_verify_verifier_set::VerifierSetConfirmation;
+use ampd::monitoring;
+use ampd::mvx::proxy::MvxProxy;
+use ampd::mvx::verifier::{verify_message, verify_verifier_set};
+use ampd::types::Hash;
+use ampd_handlers::voting::{self, Error, PollEventData as _, VotingHandler};
+use ampd_sdk::event::event_handler::{EventHandler, SubscriptionParams};
+use ampd_sdk::grpc::client::EventHandlerClient;
+use async_trait::async_trait;
+use axelar_wasm_std::chain::ChainName;
+use axelar_wasm_std::msg_id::HexTxHashAndEventIndex;
+use axelar_wasm_std::voting::{PollId, Vote};
+use cosmrs::{AccountId, Any};
+use error_stack::Report;
+use events::{try_from, AbciEventTypeFilter, Event, EventType};
+use multiversx_sdk::data::address::Address;
+use multiversx_sdk::data::transaction::TransactionOnNetwork;
+use serde::Deserialize;
+use typed_builder::TypedBuilder;
+
+pub type Result<T> = error_stack::Result<T, Error>;
+
+#[derive(Clone, Debug, Deserialize)]
+#[try_from("wasm-messages_poll_started")]
+pub struct MessagesPollStarted {
+    poll_id: PollId,
+    source_chain: ChainName,
+    source_gateway_address: Address,
+    confirmation_height: u64,
+    expires_at: u64,
+    messages: Vec<Message>,
+    participants: Vec<AccountId>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[try_from("wasm-verifier_set_poll_started")]
+pub struct VerifierSetPollStarted {
+    verifier_set: VerifierSetConfirmation,
+    poll_id: PollId,
+    source_chain: ChainName,
+    source_gateway_address: Address,
+    expires_at: u64,
+    confirmation_height: u64,
+    participants: Vec<AccountId>,
+}
+
+#[derive(Clone, Debug)]
+pub enum PollStartedEvent {
+    Messages(MessagesPollStarted),
+    VerifierSet(VerifierSetPollStarted),
+}
+
+impl TryFrom<Event> for PollStartedEvent {
+    type Error = Report<events::Error>;
+
+    fn try_from(event: Event) -> std::result::Result<Self, Self::Error> {
+        if let Ok(event) = MessagesPollStarted::try_from(event.clone()) {
+            Ok(PollStartedEvent::Messages(event))
+        } else if let Ok(event) = VerifierSetPollStarted::try_from(event.clone()) {
+            Ok(PollStartedEvent::VerifierSet(event))
+        } else {
+            Err(Report::new(events::Error::EventTypeMismatch(format!(
+                "{}/{}",
+                MessagesPollStarted::event_type(),
+                VerifierSetPollStarted::event_type()
+            )))
+            .attach_printable(format!("{{ event = {event:?} }}")))
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum PollEventData {
+    Message(Message),
+    VerifierSet(VerifierSetConfirmation),
+}
+
+impl voting::PollEventData for PollEventData {
+    type Digest = Hash;
+    type MessageId = HexTxHashAndEventIndex;
+    type ChainAddress = Address;
+    type Receipt = TransactionOnNetwork;
+
+    fn tx_hash(&self) -> Self::Digest {
+        match self {
+            PollEventData::Message(message) => message.message_id.tx_hash.into(),
+            PollEventData::VerifierSet(verifier_set) => verifier_set.message_id.tx_hash.into(),
+        }
+    }
+
+    fn message_id(&self) -> &Self::MessageId {
+        match self {
+            PollEventData::Message(message) => &message.message_id,
+            PollEventData::VerifierSet(verifier_set) => &verifier_set.message_id,
+        }
+    }
+
+    fn verify(
+        &self,
+        source_gateway_address: &Self::ChainAddress,
+        tx_receipt: &Self::Receipt,
+    ) -> Vote {
+        match self {
+            PollEventData::Message(message) => {
+                verify_message(source_gateway_address, tx_receipt, message)
+            }
+            PollEventData::VerifierSet(verifier_set) => {
+                verify_verifier_set(source_gateway_address, tx_receipt, verifier_set.clone())
+            }
+        }
+    }
+}
+
+impl From<PollStartedEvent> for voting::PollStartedEvent<PollEventData, Address> {
+    fn from(event: PollStartedEvent) -> Self {
+        match event {
+            PollStartedEvent::Messages(message_event) => voting::PollStartedEvent {
+                poll_data: message_event
+                    .messages
+                    .into_iter()
+                    .map(PollEventData::Message)
+                    .collect(),
+                poll_id: message_event.poll_id,
+                source_chain: message_event.source_chain,
+                source_gateway_address: message_event.source_gateway_address,
+                expires_at: message_event.expires_at,
+                confirmation_height: message_event.confirmation_height,
+                participants: message_event.participants,
+            },
+            PollStartedEvent::VerifierSet(verifier_set_event) => voting::PollStartedEvent {
+                poll_data: vec![PollEventData::VerifierSet(verifier_set_event.verifier_set)],
+                poll_id: verifier_set_event.poll_id,
+                source_chain: verifier_set_event.source_chain,
+                source_gateway_address: verifier_set_event.source_gateway_address,
+                expires_at: verifier_set_event.expires_at,
+                confirmation_height: verifier_set_event.confirmation_height,
+                participants: verifier_set_event.participants,
+            },
+        }
+    }
+}
+
+#[derive(Debug, TypedBuilder)]
+pub struct Handler<P>
+where
+    P: MvxProxy,
+{
+    pub verifier: AccountId,
+    pub voting_verifier_contract: AccountId,
+    pub chain: ChainName,
+    pub proxy: P,
+    pub monitoring_client: monitoring::Client,
+}
+
+#[async_trait]
+impl<P> VotingHandler for Handler<P>
+where
+    P: MvxProxy + Send + Sync,
+{
+    type Digest = Hash;
+    type Receipt = TransactionOnNetwork;
+    type ChainAddress = Address;
+    type EventData = PollEventData;
+
+    fn chain(&self) -> &ChainName {
+        &self.chain
+    }
+
+    fn verifier(&self) -> &AccountId {
+        &self.verifier
+    }
+
+    fn voting_verifier_contract(&self) -> &AccountId {
+        &self.voting_verifier_contract
+    }
+
+    fn monitoring_client(&self) -> &monitoring::Client {
+        &self.monitoring_client
+    }
+
+    async fn finalized_txs(
+        &self,
+        poll_data: &[Self::EventData],
+        _confirmation_height: Option<u64>,
+    ) -> Result<HashMap<Self::Digest, Self::Receipt>> {
+        let tx_hashes: HashSet<_> = poll_data.iter().map(|data| data.tx_hash()).collect();
+
+        Ok(self.proxy.transactions_info_with_results(tx_hashes).await)
+    }
+}
+
+#[async_trait]
+impl<P> EventHandler for Handler<P>
+where
+    P: MvxProxy + Send + Sync,
+{
+    type Err = Error;
+    type Event = PollStartedEvent;
+
+    async fn handle<HC: EventHandlerClient + Send + 'static>(
+        &self,
+        event: PollStartedEvent,
+        client: &mut HC,
+    ) -> Result<Vec<Any>> {
+        VotingHandler::handle(self, event.into(), client).await
+    }
+
+    fn subscription_params(&self) -> SubscriptionParams {
+        let attributes = HashMap::from([(
+            "source_chain".to_string(),
+            serde_json::Value::String(self.chain.to_string()),
+        )]);
+
+        SubscriptionParams::new(
+            vec![
+                AbciEventTypeFilter {
+                    event_type: MessagesPollStarted::event_type(),
+                    contract: self.voting_verifier_contract.clone(),
+                    attributes: attributes.clone(),
+                },
+                AbciEventTypeFilter {
+                    event_type: VerifierSetPollStarted::event_type(),
+                    contract: self.voting_verifier_contract.clone(),
+                    attributes,
+                },
+            ],
+            false,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::convert::TryInto;
+
+    use ampd::handlers::test_utils::{into_structured_event, participants};
+    use ampd::monitoring;
+    use ampd::mvx::proxy::MockMvxProxy;
+    use ampd::types::{Hash, TMAddress};
+    use ampd_sdk::event::event_handler::EventHandler;
+    use ampd_sdk::grpc::client::test_utils::MockHandlerTaskClient;
+    use axelar_wasm_std::chain_name;
+    use axelar_wasm_std::voting::Vote;
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use cosmrs::cosmwasm::MsgExecuteContract;
+    use cosmrs::tx::Msg;
+    use ethers_core::types::H160;
+    use events::Event;
+    use hex::ToHex;
+    use multisig::key::KeyType;
+    use multisig::test::common::{build_verifier_set, ed25519_test_data};
+    use multiversx_sdk::data::address::Address;
+    use multiversx_sdk::data::transaction::{ApiLogs, Events, LogData, TransactionOnNetwork};
+    use tokio::test as async_test;
+    use voting_verifier::events::{
+        PollMetadata, PollStarted, TxEventConfirmation, VerifierSetConfirmation,
+    };
+    use voting_verifier::msg::ExecuteMsg;
+
+    use super::{Handler, MessagesPollStarted, VerifierSetPollStarted};
+
+    const PREFIX: &str = "axelar";
+    const MULTIVERSX: &str = "multiversx";
+    const GATEWAY_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgqsvzyz88e8v8j6x3wquatxuztnxjwnw92kkls6rdtzx";
+    const TX_HASH: &str = "dfaf64de66510723f2efbacd7ead3c4f8c856aed1afc2cb30254552aeda47312";
+    const SOURCE_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgqzqvm5ywqqf524efwrhr039tjs29w0qltkklsa05pk7";
+
+    fn poll_metadata(participants: Vec<TMAddress>, expires_at: u64) -> PollMetadata {
+        PollMetadata {
+            poll_id: "100".parse().unwrap(),
+            source_chain: chain_name!(MULTIVERSX),
+            source_gateway_address: GATEWAY_ADDRESS.parse().unwrap(),
+            confirmation_height: 15,
+            expires_at,
+            participants: participants
+                .into_iter()
+                .map(|addr| cosmwasm_std::Addr::unchecked(addr.to_string()))
+                .collect(),
+        }
+    }
+
+    fn message_poll_started_event(participants: Vec<TMAddress>, expires_at: u64) -> PollStarted {
+        PollStarted::Messages {
+            metadata: poll_metadata(participants, expires_at),
+            messages: vec![TxEventConfirmation {
+                message_id: format!("0x{TX_HASH}-1").parse().unwrap(),
+                source_address: SOURCE_ADDRESS.parse().unwrap(),
+                destination_chain: chain_name!("ethereum"),
+                destination_address: format!("0x{:x}", H160::repeat_byte(2)).parse().unwrap(),
+                payload_hash: [1; 32],
+            }],
+        }
+    }
+
+    fn verifier_set_poll_started_event(
+        participants: Vec<TMAddress>,
+        expires_at: u64,
+    ) -> PollStarted {
+        PollStarted::VerifierSet {
+            #[allow(deprecated)]
+            verifier_set: VerifierSetConfirmation {
+                message_id: format!("0x{TX_HASH}-1").parse().unwrap(),
+                verifier_set: build_verifier_set(KeyType::Ed25519, &ed25519_test_data::signers()),
+            },
+            metadata: poll_metadata(participants, expires_at),
+        }
+    }
+
+    // a successful transaction whose second event is the gateway's contract call event for the polled message
+    fn contract_call_tx(payload_hash: [u8; 32]) -> TransactionOnNetwork {
+        let gateway_address = Address::from_bech32_string(GATEWAY_ADDRESS).unwrap();
+        let source_address = Address::from_bech32_string(SOURCE_ADDRESS).unwrap();
+
+        let contract_call_event = Events {
+            address: gateway_address.clone(),
+            identifier: "callContract".into(),
+            // on MultiversX, topics are base64 encoded
+            topics: Some(vec![
+                STANDARD.encode("contract_call_event"),
+                STANDARD.encode(source_address.to_bytes()),
+                STANDARD.encode("ethereum"),
+                STANDARD.encode(format!("0x{:x}", H160::repeat_byte(2))),
+                STANDARD.encode(payload_hash),
+            ]),
+            data: LogData::Empty,
+        };
+        let other_event = Events {
+            address: gateway_address,
+            identifier: "other".into(),
+            topics: None,
+            data: LogData::Empty,
+        };
+
+        TransactionOnNetwork {
+            hash: Some(TX_HASH.to_string()),
+            logs: Some(ApiLogs {
+                address: source_address.clone(),
+                events: vec![other_event, contract_call_event],
+            }),
+            status: "success".into(),
+            // the rest is irrelevant for verification but there is no default
+            kind: "".into(),
+            nonce: 1,
+            round: 1,
+            epoch: 1,
+            value: "".into(),
+            receiver: source_address.clone(),
+            sender: source_address,
+            gas_price: 0,
+            gas_limit: 0,
+            signature: "".into(),
+            source_shard: 1,
+            destination_shard: 1,
+            block_nonce: 1,
+            block_hash: "".into(),
+            notarized_at_source_in_meta_nonce: Some(0),
+            notarized_at_source_in_meta_hash: Some("".into()),
+            notarized_at_destination_in_meta_nonce: Some(0),
+            notarized_at_destination_in_meta_hash: Some("".into()),
+            miniblock_type: "".into(),
+            miniblock_hash: "".into(),
+            timestamp: 1,
+            data: None,
+            hyperblock_nonce: Some(1),
+            hyperblock_hash: Some("".into()),
+            smart_contract_results: vec![],
+            processing_type_on_destination: "".into(),
+        }
+    }
+
+    fn votes(result: &[cosmrs::Any]) -> Vec<Vote> {
+        let msg = MsgExecuteContract::from_any(result.first().unwrap()).unwrap();
+        match serde_json::from_slice(&msg.msg).unwrap() {
+            ExecuteMsg::Vote { votes, .. } => votes,
+            _ => panic!("expected vote msg"),
+        }
+    }
+
+    fn mock_handler_client(latest_block_height: u64) -> MockHandlerTaskClient {
+        let mut client = MockHandlerTaskClient::new();
+        client
+            .expect_latest_block_height()
+            .returning(move || Ok(latest_block_height));
+        client
+    }
+
+    fn handler(
+        verifier: &TMAddress,
+        voting_verifier_contract: &TMAddress,
+        proxy: MockMvxProxy,
+        monitoring_client: monitoring::Client,
+    ) -> Handler<MockMvxProxy> {
+        Handler::builder()
+            .verifier(verifier.as_ref().clone())
+            .voting_verifier_contract(voting_verifier_contract.as_ref().clone())
+            .chain(chain_name!(MULTIVERSX))
+            .proxy(proxy)
+            .monitoring_client(monitoring_client)
+            .build()
+    }
+
+    #[test]
+    fn mvx_verify_msg_should_deserialize_correct_event() {
+        let event: Event = into_structured_event(
+            message_poll_started_event(participants(5, None), 100),
+            &TMAddress::random(PREFIX),
+        );
+        let event: MessagesPollStarted = event.try_into().unwrap();
+
+        assert_eq!(event.poll_id, 100u64.into());
+        assert_eq!(
+            event.source_gateway_address.to_bech32_string().unwrap(),
+            GATEWAY_ADDRESS
+        );
+
+        let message = event.messages.first().unwrap();
+        assert_eq!(message.message_id.tx_hash.encode_hex::<String>(), TX_HASH);
+        assert_eq!(message.message_id.event_index, 1u64);
+        assert_eq!(message.destination_chain, "ethereum");
+    }
+
+    #[test]
+    fn mvx_verify_verifier_set_should_deserialize_correct_event() {
+        let event: Event = into_structured_event(
+            verifier_set_poll_started_event(participants(5, None), 100),
+            &TMAddress::random(PREFIX),
+        );
+        let event: VerifierSetPollStarted = event.try_into().unwrap();
+
+        assert_eq!(event.poll_id, 100u64.into());
+        assert_eq!(
+            event.verifier_set.verifier_set,
+            build_verifier_set(KeyType::Ed25519, &ed25519_test_data::signers())
+        );
+    }
+
+    #[async_test]
+    async fn should_skip_expired_poll() {
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let expiration = 100u64;
+        let event: Event = into_structured_event(
+            message_poll_started_event(participants(5, Some(verifier.clone())), expiration),
+            &voting_verifier_contract,
+        );
+
+        let (monitoring_client, _) = monitoring::test_utils::monitoring_client();
+        let handler = handler(
+            &verifier,
+            &voting_verifier_contract,
+            MockMvxProxy::new(),
+            monitoring_client,
+        );
+
+        let mut client = mock_handler_client(expiration + 1);
+        let result = handler.handle(event.try_into().unwrap(), &mut client).await;
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[async_test]
+    async fn should_vote_and_record_verification_vote_metric() {
+        let mut proxy = MockMvxProxy::new();
+        proxy
+            .expect_transactions_info_with_results()
+            .returning(|_| HashMap::new());
+
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event: Event = into_structured_event(
+            verifier_set_poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier_contract,
+        );
+
+        let (monitoring_client, mut receiver) = monitoring::test_utils::monitoring_client();
+        let handler = handler(
+            &verifier,
+            &voting_verifier_contract,
+            proxy,
+            monitoring_client,
+        );
+
+        let mut client = mock_handler_client(99);
+        let result = handler
+            .handle(event.try_into().unwrap(), &mut client)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        assert!(MsgExecuteContract::from_any(result.first().unwrap()).is_ok());
+
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            monitoring::metrics::Msg::VerificationVote {
+                vote_decision: Vote::NotFound,
+                chain_name: chain_name!(MULTIVERSX),
+            }
+        );
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[async_test]
+    async fn should_vote_on_message_by_its_gateway_event() {
+        for (payload_hash, expected_vote) in
+            [([1; 32], Vote::SucceededOnChain), ([2; 32], Vote::NotFound)]
+        {
+            let mut proxy = MockMvxProxy::new();
+            proxy
+                .expect_transactions_info_with_results()
+                .returning(move |_| {
+                    HashMap::from([(
+                        Hash::from_slice(&hex::decode(TX_HASH).unwrap()),
+                        contract_call_tx(payload_hash),
+                    )])
+                });
+
+            let voting_verifier_contract = TMAddress::random(PREFIX);
+            let verifier = TMAddress::random(PREFIX);
+            let event: Event = into_structured_event(
+                message_poll_started_event(participants(5, Some(verifier.clone())), 100),
+                &voting_verifier_contract,
+            );
+
+            let (monitoring_client, _) = monitoring::test_utils::monitoring_client();
+            let handler = handler(
+                &verifier,
+                &voting_verifier_contract,
+                proxy,
+                monitoring_client,
+            );
+
+            let mut client = mock_handler_client(99);
+            let result = handler
+                .handle(event.try_into().unwrap(), &mut client)
+                .await
+                .unwrap();
+            assert_eq!(votes(&result), vec![expected_vote]);
+        }
+    }
+}
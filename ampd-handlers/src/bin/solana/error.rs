@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("handler failed to start")]
+    HandlerStart,
+    #[error("handler task failed")]
+    HandlerTask,
+}
@@ -0,0 +1,537 @@
+use std::collections::{HashMap, HashSet};
+
+use ampd::handlers::solana_verify_msg::Message;
+use ampd::handlers::solana_verify_verifier_set::VerifierSetConfirmation;
+use ampd::monitoring;
+use ampd::solana::msg_verifier::verify_message;
+use ampd::solana::verifier_set_verifier::verify_verifier_set;
+use ampd::solana::{SolanaRpcClientProxy, SolanaTransaction};
+use ampd_handlers::voting::{self, Error, PollEventData as _, VotingHandler};
+use ampd_sdk::event::event_handler::{EventHandler, SubscriptionParams};
+use ampd_sdk::grpc::client::EventHandlerClient;
+use async_trait::async_trait;
+use axelar_wasm_std::chain::ChainName;
+use axelar_wasm_std::msg_id::Base58SolanaTxSignatureAndEventIndex;
+use axelar_wasm_std::voting::{PollId, Vote};
+use cosmrs::{AccountId, Any};
+use error_stack::Report;
+use events::{try_from, AbciEventTypeFilter, Event, EventType};
+use futures::future::join_all;
+use serde::Deserialize;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use typed_builder::TypedBuilder;
+
+pub type Result<T> = error_stack::Result<T, Error>;
+
+#[derive(Clone, Debug, Deserialize)]
+#[try_from("wasm-messages_poll_started")]
+pub struct MessagesPollStarted {
+    poll_id: PollId,
+    source_chain: ChainName,
+    #[serde(deserialize_with = "ampd::solana::deserialize_pubkey")]
+    source_gateway_address: Pubkey,
+    confirmation_height: u64,
+    expires_at: u64,
+    messages: Vec<Message>,
+    participants: Vec<AccountId>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[try_from("wasm-verifier_set_poll_started")]
+pub struct VerifierSetPollStarted {
+    verifier_set: VerifierSetConfirmation,
+    poll_id: PollId,
+    source_chain: ChainName,
+    #[serde(deserialize_with = "ampd::solana::deserialize_pubkey")]
+    source_gateway_address: Pubkey,
+    expires_at: u64,
+    confirmation_height: u64,
+    participants: Vec<AccountId>,
+}
+
+#[derive(Clone, Debug)]
+pub enum PollStartedEvent {
+    Messages(MessagesPollStarted),
+    VerifierSet(VerifierSetPollStarted),
+}
+
+impl TryFrom<Event> for PollStartedEvent {
+    type Error = Report<events::Error>;
+
+    fn try_from(event: Event) -> std::result::Result<Self, Self::Error> {
+        if let Ok(event) = MessagesPollStarted::try_from(event.clone()) {
+            Ok(PollStartedEvent::Messages(event))
+        } else if let Ok(event) = VerifierSetPollStarted::try_from(event.clone()) {
+            Ok(PollStartedEvent::VerifierSet(event))
+        } else {
+            Err(Report::new(events::Error::EventTypeMismatch(format!(
+                "{}/{}",
+                MessagesPollStarted::event_type(),
+                VerifierSetPollStarted::event_type()
+            )))
+            .attach_printable(format!("{{ event = {event:?} }}")))
+        }
+    }
+}
+
+/// A Solana transaction together with the domain separator of the gateway, which is needed to verify verifier set rotations
+#[derive(Clone, Debug)]
+pub struct TxReceipt {
+    tx: SolanaTransaction,
+    domain_separator: [u8; 32],
+}
+
+#[derive(Clone, Debug)]
+pub enum PollEventData {
+    Message(Message),
+    VerifierSet(VerifierSetConfirmation),
+}
+
+impl voting::PollEventData for PollEventData {
+    type Digest = Signature;
+    type MessageId = Base58SolanaTxSignatureAndEventIndex;
+    type ChainAddress = Pubkey;
+    type Receipt = TxReceipt;
+
+    fn tx_hash(&self) -> Self::Digest {
+        Signature::from(self.message_id().raw_signature)
+    }
+
+    fn message_id(&self) -> &Self::MessageId {
+        match self {
+            PollEventData::Message(message) => &message.message_id,
+            PollEventData::VerifierSet(verifier_set) => &verifier_set.message_id,
+        }
+    }
+
+    fn verify(
+        &self,
+        source_gateway_address: &Self::ChainAddress,
+        tx_receipt: &Self::Receipt,
+    ) -> Vote {
+        match self {
+            PollEventData::Message(message) => {
+                verify_message(&tx_receipt.tx, message, source_gateway_address)
+            }
+            PollEventData::VerifierSet(verifier_set) => verify_verifier_set(
+                &tx_receipt.tx,
+                verifier_set,
+                &tx_receipt.domain_separator,
+                source_gateway_address,
+            ),
+        }
+    }
+}
+
+impl From<PollStartedEvent> for voting::PollStartedEvent<PollEventData, Pubkey> {
+    fn from(event: PollStartedEvent) -> Self {
+        match event {
+            PollStartedEvent::Messages(message_event) => voting::PollStartedEvent {
+                poll_data: message_event
+                    .messages
+                    .into_iter()
+                    .map(PollEventData::Message)
+                    .collect(),
+                poll_id: message_event.poll_id,
+                source_chain: message_event.source_chain,
+                source_gateway_address: message_event.source_gateway_address,
+                expires_at: message_event.expires_at,
+                confirmation_height: message_event.confirmation_height,
+                participants: message_event.participants,
+            },
+            PollStartedEvent::VerifierSet(verifier_set_event) => voting::PollStartedEvent {
+                poll_data: vec![PollEventData::VerifierSet(verifier_set_event.verifier_set)],
+                poll_id: verifier_set_event.poll_id,
+                source_chain: verifier_set_event.source_chain,
+                source_gateway_address: verifier_set_event.source_gateway_address,
+                expires_at: verifier_set_event.expires_at,
+                confirmation_height: verifier_set_event.confirmation_height,
+                participants: verifier_set_event.participants,
+            },
+        }
+    }
+}
+
+#[derive(Debug, TypedBuilder)]
+pub struct Handler<C>
+where
+    C: SolanaRpcClientProxy,
+{
+    pub verifier: AccountId,
+    pub voting_verifier_contract: AccountId,
+    pub chain: ChainName,
+    pub rpc_client: C,
+    pub domain_separator: [u8; 32],
+    pub monitoring_client: monitoring::Client,
+}
+
+#[async_trait]
+impl<C> VotingHandler for Handler<C>
+where
+    C: SolanaRpcClientProxy,
+{
+    type Digest = Signature;
+    type Receipt = TxReceipt;
+    type ChainAddress = Pubkey;
+    type EventData = PollEventData;
+
+    fn chain(&self) -> &ChainName {
+        &self.chain
+    }
+
+    fn verifier(&self) -> &AccountId {
+        &self.verifier
+    }
+
+    fn voting_verifier_contract(&self) -> &AccountId {
+        &self.voting_verifier_contract
+    }
+
+    fn monitoring_client(&self) -> &monitoring::Client {
+        &self.monitoring_client
+    }
+
+    async fn finalized_txs(
+        &self,
+        poll_data: &[Self::EventData],
+        _confirmation_height: Option<u64>,
+    ) -> Result<HashMap<Self::Digest, Self::Receipt>> {
+        let signatures: HashSet<_> = poll_data.iter().map(|data| data.tx_hash()).collect();
+
+        // the rpc client is configured with the commitment level a transaction must have reached,
+        // so every transaction it returns is considered final
+        Ok(join_all(signatures.into_iter().map(|signature| async move {
+            self.rpc_client.tx(&signature).await.map(|tx| {
+                (
+                    signature,
+                    TxReceipt {
+                        tx,
+                        domain_separator: self.domain_separator,
+                    },
+                )
+            })
+        }))
+        .await
+        .into_iter()
+        .flatten()
+        .collect())
+    }
+}
+
+#[async_trait]
+impl<C> EventHandler for Handler<C>
+where
+    C: SolanaRpcClientProxy,
+{
+    type Err = Error;
+    type Event = PollStartedEvent;
+
+    async fn handle<HC: EventHandlerClient + Send + 'static>(
+        &self,
+        event: PollStartedEvent,
+        client: &mut HC,
+    ) -> Result<Vec<Any>> {
+        VotingHandler::handle(self, event.into(), client).await
+    }
+
+    fn subscription_params(&self) -> SubscriptionParams {
+        let attributes = HashMap::from([(
+            "source_chain".to_string(),
+            serde_json::Value::String(self.chain.to_string()),
+        )]);
+
+        SubscriptionParams::new(
+            vec![
+                AbciEventTypeFilter {
+                    event_type: MessagesPollStarted::event_type(),
+                    contract: self.voting_verifier_contract.clone(),
+                    attributes: attributes.clone(),
+                },
+                AbciEventTypeFilter {
+                    event_type: VerifierSetPollStarted::event_type(),
+                    contract: self.voting_verifier_contract.clone(),
+                    attributes,
+                },
+            ],
+            false,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use ampd::handlers::test_utils::{into_structured_event, participants};
+    use ampd::monitoring;
+    use ampd::solana::{MockSolanaRpcClientProxy, SolanaTransaction};
+    use ampd::types::TMAddress;
+    use ampd_sdk::event::event_handler::EventHandler;
+    use ampd_sdk::grpc::client::test_utils::MockHandlerTaskClient;
+    use axelar_solana_gateway::events::CallContractEvent;
+    use axelar_wasm_std::chain_name;
+    use axelar_wasm_std::msg_id::Base58SolanaTxSignatureAndEventIndex;
+    use axelar_wasm_std::voting::Vote;
+    use cosmrs::cosmwasm::MsgExecuteContract;
+    use cosmrs::tx::Msg;
+    use event_cpi::Discriminator;
+    use events::Event;
+    use multisig::key::KeyType;
+    use multisig::test::common::{build_verifier_set, ecdsa_test_data};
+    use solana_sdk::pubkey::Pubkey;
+    use solana_transaction_status::{UiCompiledInstruction, UiInnerInstructions, UiInstruction};
+    use tokio::test as async_test;
+    use voting_verifier::events::{
+        PollMetadata, PollStarted, TxEventConfirmation, VerifierSetConfirmation,
+    };
+    use voting_verifier::msg::ExecuteMsg;
+
+    use super::{Handler, MessagesPollStarted, VerifierSetPollStarted};
+
+    const PREFIX: &str = "axelar";
+    const SOLANA: &str = "solana";
+    const MESSAGE_ID: &str = "3GLo4z4siudHxW1BMHBbkTKy7kfbssNFaxLR5hTjhEXCUzp2Pi2VVwybc1s96pEKjRre7CcKKeLhni79zWTNUseP-1.10";
+    const SOURCE_ADDRESS: &str = "9Tp4XJZLQKdM82BHYfNAG6V3RWpLC7Y5mXo1UqKZFTJ3";
+    const DESTINATION_ADDRESS: &str = "0x3ad1f33ef5814e7adb43ed7fb39f9b45053ecab1";
+
+    fn poll_metadata(participants: Vec<TMAddress>, expires_at: u64) -> PollMetadata {
+        PollMetadata {
+            poll_id: "100".parse().unwrap(),
+            source_chain: chain_name!(SOLANA),
+            source_gateway_address: axelar_solana_gateway::ID.to_string().parse().unwrap(),
+            confirmation_height: 15,
+            expires_at,
+            participants: participants
+                .into_iter()
+                .map(|addr| cosmwasm_std::Addr::unchecked(addr.to_string()))
+                .collect(),
+        }
+    }
+
+    fn message_poll_started_event(participants: Vec<TMAddress>, expires_at: u64) -> PollStarted {
+        PollStarted::Messages {
+            metadata: poll_metadata(participants, expires_at),
+            #[allow(deprecated)]
+            messages: vec![TxEventConfirmation {
+                message_id: MESSAGE_ID.parse().unwrap(),
+                source_address: SOURCE_ADDRESS.parse().unwrap(),
+                destination_chain: chain_name!("ethereum"),
+                destination_address: DESTINATION_ADDRESS.parse().unwrap(),
+                payload_hash: [1; 32],
+            }],
+        }
+    }
+
+    fn verifier_set_poll_started_event(
+        participants: Vec<TMAddress>,
+        expires_at: u64,
+    ) -> PollStarted {
+        PollStarted::VerifierSet {
+            #[allow(deprecated)]
+            verifier_set: VerifierSetConfirmation {
+                message_id: MESSAGE_ID.parse().unwrap(),
+                verifier_set: build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers()),
+            },
+            metadata: poll_metadata(participants, expires_at),
+        }
+    }
+
+    // the message id points at the 10th inner instruction of the first instruction group,
+    // so the gateway's call contract event is emitted as the last of ten inner instructions
+    fn call_contract_tx(payload_hash: [u8; 32]) -> SolanaTransaction {
+        let event = CallContractEvent {
+            sender: SOURCE_ADDRESS.parse().unwrap(),
+            destination_chain: "ethereum".to_owned(),
+            destination_contract_address: DESTINATION_ADDRESS.to_owned(),
+            payload: vec![1, 2, 3],
+            payload_hash,
+        };
+
+        let mut event_data = Vec::new();
+        event_data.extend_from_slice(event_cpi::EVENT_IX_TAG_LE);
+        event_data.extend_from_slice(CallContractEvent::DISCRIMINATOR);
+        event_data.extend_from_slice(&borsh::to_vec(&event).unwrap());
+
+        let instruction = |data: &[u8]| {
+            UiInstruction::Compiled(UiCompiledInstruction {
+                program_id_index: 0,
+                accounts: vec![],
+                data: bs58::encode(data).into_string(),
+                stack_height: Some(2),
+            })
+        };
+
+        let mut instructions: Vec<_> = (0..9).map(|_| instruction(&[0; 8])).collect();
+        instructions.push(instruction(&event_data));
+
+        let message_id: Base58SolanaTxSignatureAndEventIndex = MESSAGE_ID.parse().unwrap();
+
+        SolanaTransaction {
+            signature: message_id.raw_signature.into(),
+            inner_instructions: vec![UiInnerInstructions {
+                index: 0,
+                instructions,
+            }],
+            err: None,
+            account_keys: vec![axelar_solana_gateway::ID],
+        }
+    }
+
+    fn votes(result: &[cosmrs::Any]) -> Vec<Vote> {
+        let msg = MsgExecuteContract::from_any(result.first().unwrap()).unwrap();
+        match serde_json::from_slice(&msg.msg).unwrap() {
+            ExecuteMsg::Vote { votes, .. } => votes,
+            _ => panic!("expected vote msg"),
+        }
+    }
+
+    fn mock_handler_client(latest_block_height: u64) -> MockHandlerTaskClient {
+        let mut client = MockHandlerTaskClient::new();
+        client
+            .expect_latest_block_height()
+            .returning(move || Ok(latest_block_height));
+        client
+    }
+
+    fn handler(
+        verifier: &TMAddress,
+        voting_verifier_contract: &TMAddress,
+        rpc_client: MockSolanaRpcClientProxy,
+        monitoring_client: monitoring::Client,
+    ) -> Handler<MockSolanaRpcClientProxy> {
+        Handler::builder()
+            .verifier(verifier.as_ref().clone())
+            .voting_verifier_contract(voting_verifier_contract.as_ref().clone())
+            .chain(chain_name!(SOLANA))
+            .rpc_client(rpc_client)
+            .domain_separator([42; 32])
+            .monitoring_client(monitoring_client)
+            .build()
+    }
+
+    #[test]
+    fn solana_verify_msg_should_deserialize_correct_event() {
+        let event: Event = into_structured_event(
+            message_poll_started_event(participants(5, None), 100),
+            &TMAddress::random(PREFIX),
+        );
+        let event: MessagesPollStarted = event.try_into().unwrap();
+
+        assert_eq!(event.poll_id, 100u64.into());
+        assert_eq!(event.source_gateway_address, axelar_solana_gateway::ID);
+
+        let message = event.messages.first().unwrap();
+        assert_eq!(message.message_id.to_string(), MESSAGE_ID);
+        assert_eq!(
+            message.source_address,
+            SOURCE_ADDRESS.parse::<Pubkey>().unwrap()
+        );
+    }
+
+    #[test]
+    fn solana_verify_verifier_set_should_deserialize_correct_event() {
+        let event: Event = into_structured_event(
+            verifier_set_poll_started_event(participants(5, None), 100),
+            &TMAddress::random(PREFIX),
+        );
+        let event: VerifierSetPollStarted = event.try_into().unwrap();
+
+        assert_eq!(event.poll_id, 100u64.into());
+        assert_eq!(event.verifier_set.message_id.to_string(), MESSAGE_ID);
+    }
+
+    #[async_test]
+    async fn should_skip_expired_poll() {
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let expiration = 100u64;
+        let event: Event = into_structured_event(
+            verifier_set_poll_started_event(participants(5, Some(verifier.clone())), expiration),
+            &voting_verifier_contract,
+        );
+
+        let (monitoring_client, _) = monitoring::test_utils::monitoring_client();
+        let handler = handler(
+            &verifier,
+            &voting_verifier_contract,
+            MockSolanaRpcClientProxy::new(),
+            monitoring_client,
+        );
+
+        let mut client = mock_handler_client(expiration + 1);
+        let result = handler.handle(event.try_into().unwrap(), &mut client).await;
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[async_test]
+    async fn should_vote_not_found_for_unknown_txs() {
+        let mut rpc_client = MockSolanaRpcClientProxy::new();
+        rpc_client.expect_tx().returning(|_| None);
+
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event: Event = into_structured_event(
+            message_poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier_contract,
+        );
+
+        let (monitoring_client, mut receiver) = monitoring::test_utils::monitoring_client();
+        let handler = handler(
+            &verifier,
+            &voting_verifier_contract,
+            rpc_client,
+            monitoring_client,
+        );
+
+        let mut client = mock_handler_client(99);
+        let result = handler
+            .handle(event.try_into().unwrap(), &mut client)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 1);
+
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            monitoring::metrics::Msg::VerificationVote {
+                vote_decision: Vote::NotFound,
+                chain_name: chain_name!(SOLANA),
+            }
+        );
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[async_test]
+    async fn should_vote_on_message_by_its_gateway_event() {
+        for (payload_hash, expected_vote) in
+            [([1; 32], Vote::SucceededOnChain), ([2; 32], Vote::NotFound)]
+        {
+            let mut rpc_client = MockSolanaRpcClientProxy::new();
+            rpc_client
+                .expect_tx()
+                .returning(move |_| Some(call_contract_tx(payload_hash)));
+
+            let voting_verifier_contract = TMAddress::random(PREFIX);
+            let verifier = TMAddress::random(PREFIX);
+            let event: Event = into_structured_event(
+                message_poll_started_event(participants(5, Some(verifier.clone())), 100),
+                &voting_verifier_contract,
+            );
+
+            let (monitoring_client, _) = monitoring::test_utils::monitoring_client();
+            let handler = handler(
+                &verifier,
+                &voting_verifier_contract,
+                rpc_client,
+                monitoring_client,
+            );
+
+            let mut client = mock_handler_client(99);
+            let result = handler
+                .handle(event.try_into().unwrap(), &mut client)
+                .await
+                .unwrap();
+            assert_eq!(votes(&result), vec![expected_vote]);
+        }
+    }
+}
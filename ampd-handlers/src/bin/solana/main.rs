@@ -0,0 +1,103 @@
+mod error;
+mod handler;
+
+use std::time::Duration;
+
+use ampd::solana::{deserialize_pubkey, Client, Commitment, SolanaRpcClientProxy};
+use ampd::url::Url;
+use ampd_handlers::tracing::init_tracing;
+use ampd_sdk::config;
+use ampd_sdk::runtime::HandlerRuntime;
+use axelar_wasm_std::chain::ChainName;
+#[cfg(debug_assertions)]
+use dotenv_flow::dotenv_flow;
+use error_stack::{report, Result, ResultExt};
+use serde::Deserialize;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use tokio_util::sync::CancellationToken;
+use tracing::Level;
+
+use crate::error::Error;
+use crate::handler::Handler;
+
+#[derive(Debug, Deserialize)]
+struct SolanaHandlerConfig {
+    #[serde(deserialize_with = "Url::deserialize_sensitive")]
+    rpc_url: Url,
+    #[serde(deserialize_with = "deserialize_pubkey")]
+    gateway_address: Pubkey,
+    #[serde(default)]
+    commitment: Commitment,
+    #[serde(with = "humantime_serde")]
+    #[serde(default = "default_rpc_timeout")]
+    rpc_timeout: Duration,
+}
+
+fn default_rpc_timeout() -> Duration {
+    Duration::from_secs(3)
+}
+
+async fn build_handler(
+    runtime: &HandlerRuntime,
+    chain_name: ChainName,
+    config: SolanaHandlerConfig,
+) -> Result<Handler<Client>, Error> {
+    let rpc_client = Client::new(
+        RpcClient::new_with_timeout_and_commitment(
+            config.rpc_url.as_str().to_string(),
+            config.rpc_timeout,
+            config.commitment.into(),
+        ),
+        runtime.monitoring_client.clone(),
+        chain_name.clone(),
+    );
+
+    let domain_separator = rpc_client
+        .domain_separator(&config.gateway_address)
+        .await
+        .ok_or_else(|| report!(Error::HandlerStart))
+        .attach_printable("failed to fetch the domain separator of the Solana gateway")?;
+
+    let handler = Handler::builder()
+        .verifier(runtime.verifier.clone())
+        .voting_verifier_contract(runtime.contracts.voting_verifier.clone())
+        .chain(chain_name)
+        .rpc_client(rpc_client)
+        .domain_separator(domain_separator)
+        .monitoring_client(runtime.monitoring_client.clone())
+        .build();
+
+    Ok(handler)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    #[cfg(debug_assertions)]
+    dotenv_flow().ok();
+
+    init_tracing(Level::INFO);
+
+    let base_config = config::Config::from_default_sources().change_context(Error::HandlerStart)?;
+
+    let handler_config = config::Config::builder()
+        .add_file_source("solana-handler-config.toml")
+        .add_env_source("AMPD_SOLANA_HANDLER")
+        .build::<SolanaHandlerConfig>()
+        .change_context(Error::HandlerStart)?;
+
+    let token = CancellationToken::new();
+
+    let runtime = HandlerRuntime::start(&base_config, token.clone())
+        .await
+        .change_context(Error::HandlerStart)?;
+
+    let handler = build_handler(&runtime, base_config.chain_name.clone(), handler_config).await?;
+
+    runtime
+        .run_handler(handler, base_config, token)
+        .await
+        .change_context(Error::HandlerTask)?;
+
+    Ok(())
+}
@@ -0,0 +1,563 @@
+use std::collections::{HashMap, HashSet};
+
+use ampd::handlers::stacks_verify_msg::Message;
+use ampd::handlers::stacks_verify_verifier_set::VerifierSetConfirmation;
+use ampd::monitoring;
+use ampd::stacks::finalizer::latest_finalized_block_height;
+use ampd::stacks::http_client::{Client, Transaction};
+use ampd::stacks::verifier::{
+    type_signature_contract_call, type_signature_signers_rotated, verify_message,
+    verify_verifier_set,
+};
+use ampd::types::Hash;
+use ampd_handlers::voting::{self, Error, PollEventData as _, VotingHandler};
+use ampd_sdk::event::event_handler::{EventHandler, SubscriptionParams};
+use ampd_sdk::grpc::client::EventHandlerClient;
+use async_trait::async_trait;
+use axelar_wasm_std::chain::ChainName;
+use axelar_wasm_std::msg_id::HexTxHashAndEventIndex;
+use axelar_wasm_std::voting::{PollId, Vote};
+use clarity_serialization::types::{PrincipalData, TypeSignature};
+use cosmrs::{AccountId, Any};
+use error_stack::{Report, ResultExt};
+use events::{try_from, AbciEventTypeFilter, Event, EventType};
+use lazy_static::lazy_static;
+use serde::Deserialize;
+use typed_builder::TypedBuilder;
+
+pub type Result<T> = error_stack::Result<T, Error>;
+
+lazy_static! {
+    static ref TYPE_SIGNATURE_CONTRACT_CALL: TypeSignature =
+        type_signature_contract_call().expect("contract call type signature should be valid");
+    static ref TYPE_SIGNATURE_SIGNERS_ROTATED: TypeSignature =
+        type_signature_signers_rotated().expect("signers rotated type signature should be valid");
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[try_from("wasm-messages_poll_started")]
+pub struct MessagesPollStarted {
+    poll_id: PollId,
+    source_chain: ChainName,
+    #[serde(with = "ampd::stacks::principal_data_serde")]
+    source_gateway_address: PrincipalData,
+    confirmation_height: u64,
+    expires_at: u64,
+    messages: Vec<Message>,
+    participants: Vec<AccountId>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[try_from("wasm-verifier_set_poll_started")]
+pub struct VerifierSetPollStarted {
+    verifier_set: VerifierSetConfirmation,
+    poll_id: PollId,
+    source_chain: ChainName,
+    #[serde(with = "ampd::stacks::principal_data_serde")]
+    source_gateway_address: PrincipalData,
+    expires_at: u64,
+    confirmation_height: u64,
+    participants: Vec<AccountId>,
+}
+
+#[derive(Clone, Debug)]
+pub enum PollStartedEvent {
+    Messages(MessagesPollStarted),
+    VerifierSet(VerifierSetPollStarted),
+}
+
+impl TryFrom<Event> for PollStartedEvent {
+    type Error = Report<events::Error>;
+
+    fn try_from(event: Event) -> std::result::Result<Self, Self::Error> {
+        if let Ok(event) = MessagesPollStarted::try_from(event.clone()) {
+            Ok(PollStartedEvent::Messages(event))
+        } else if let Ok(event) = VerifierSetPollStarted::try_from(event.clone()) {
+            Ok(PollStartedEvent::VerifierSet(event))
+        } else {
+            Err(Report::new(events::Error::EventTypeMismatch(format!(
+                "{}/{}",
+                MessagesPollStarted::event_type(),
+                VerifierSetPollStarted::event_type()
+            )))
+            .attach_printable(format!("{{ event = {event:?} }}")))
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum PollEventData {
+    Message(Message),
+    VerifierSet(VerifierSetConfirmation),
+}
+
+impl voting::PollEventData for PollEventData {
+    type Digest = Hash;
+    type MessageId = HexTxHashAndEventIndex;
+    type ChainAddress = PrincipalData;
+    type Receipt = Transaction;
+
+    fn tx_hash(&self) -> Self::Digest {
+        self.message_id().tx_hash.into()
+    }
+
+    fn message_id(&self) -> &Self::MessageId {
+        match self {
+            PollEventData::Message(message) => &message.message_id,
+            PollEventData::VerifierSet(verifier_set) => &verifier_set.message_id,
+        }
+    }
+
+    fn verify(
+        &self,
+        source_gateway_address: &Self::ChainAddress,
+        tx_receipt: &Self::Receipt,
+    ) -> Vote {
+        match self {
+            PollEventData::Message(message) => verify_message(
+                source_gateway_address,
+                tx_receipt,
+                message,
+                &TYPE_SIGNATURE_CONTRACT_CALL,
+            ),
+            PollEventData::VerifierSet(verifier_set) => verify_verifier_set(
+                source_gateway_address,
+                tx_receipt,
+                verifier_set.clone(),
+                &TYPE_SIGNATURE_SIGNERS_ROTATED,
+            ),
+        }
+    }
+}
+
+impl From<PollStartedEvent> for voting::PollStartedEvent<PollEventData, PrincipalData> {
+    fn from(event: PollStartedEvent) -> Self {
+        match event {
+            PollStartedEvent::Messages(message_event) => voting::PollStartedEvent {
+                poll_data: message_event
+                    .messages
+                    .into_iter()
+                    .map(PollEventData::Message)
+                    .collect(),
+                poll_id: message_event.poll_id,
+                source_chain: message_event.source_chain,
+                source_gateway_address: message_event.source_gateway_address,
+                expires_at: message_event.expires_at,
+                confirmation_height: message_event.confirmation_height,
+                participants: message_event.participants,
+            },
+            PollStartedEvent::VerifierSet(verifier_set_event) => voting::PollStartedEvent {
+                poll_data: vec![PollEventData::VerifierSet(verifier_set_event.verifier_set)],
+                poll_id: verifier_set_event.poll_id,
+                source_chain: verifier_set_event.source_chain,
+                source_gateway_address: verifier_set_event.source_gateway_address,
+                expires_at: verifier_set_event.expires_at,
+                confirmation_height: verifier_set_event.confirmation_height,
+                participants: verifier_set_event.participants,
+            },
+        }
+    }
+}
+
+#[derive(Debug, TypedBuilder)]
+pub struct Handler {
+    pub verifier: AccountId,
+    pub voting_verifier_contract: AccountId,
+    pub chain: ChainName,
+    pub http_client: Client,
+    pub monitoring_client: monitoring::Client,
+}
+
+#[async_trait]
+impl VotingHandler for Handler {
+    type Digest = Hash;
+    type Receipt = Transaction;
+    type ChainAddress = PrincipalData;
+    type EventData = PollEventData;
+
+    fn chain(&self) -> &ChainName {
+        &self.chain
+    }
+
+    fn verifier(&self) -> &AccountId {
+        &self.verifier
+    }
+
+    fn voting_verifier_contract(&self) -> &AccountId {
+        &self.voting_verifier_contract
+    }
+
+    fn monitoring_client(&self) -> &monitoring::Client {
+        &self.monitoring_client
+    }
+
+    async fn finalized_txs(
+        &self,
+        poll_data: &[Self::EventData],
+        confirmation_height: Option<u64>,
+    ) -> Result<HashMap<Self::Digest, Self::Receipt>> {
+        let confirmation_height = confirmation_height.expect("confirmation height is required");
+
+        let latest_finalized_block_height =
+            latest_finalized_block_height(&self.http_client, confirmation_height)
+                .await
+                .change_context(Error::FinalizedTxs)
+                .attach_printable("failed to get chain's latest finalized block height")?;
+
+        let tx_hashes: HashSet<_> = poll_data.iter().map(|data| data.tx_hash()).collect();
+
+        Ok(self
+            .http_client
+            .finalized_transactions(tx_hashes, latest_finalized_block_height)
+            .await)
+    }
+}
+
+#[async_trait]
+impl EventHandler for Handler {
+    type Err = Error;
+    type Event = PollStartedEvent;
+
+    async fn handle<HC: EventHandlerClient + Send + 'static>(
+        &self,
+        event: PollStartedEvent,
+        client: &mut HC,
+    ) -> Result<Vec<Any>> {
+        VotingHandler::handle(self, event.into(), client).await
+    }
+
+    fn subscription_params(&self) -> SubscriptionParams {
+        let attributes = HashMap::from([(
+            "source_chain".to_string(),
+            serde_json::Value::String(self.chain.to_string()),
+        )]);
+
+        SubscriptionParams::new(
+            vec![
+                AbciEventTypeFilter {
+                    event_type: MessagesPollStarted::event_type(),
+                    contract: self.voting_verifier_contract.clone(),
+                    attributes: attributes.clone(),
+                },
+                AbciEventTypeFilter {
+                    event_type: VerifierSetPollStarted::event_type(),
+                    contract: self.voting_verifier_contract.clone(),
+                    attributes,
+                },
+            ],
+            false,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::time::Duration;
+
+    use ampd::handlers::test_utils::{into_structured_event, participants};
+    use ampd::monitoring;
+    use ampd::stacks::http_client::Client;
+    use ampd::types::TMAddress;
+    use ampd::url::Url;
+    use ampd_sdk::event::event_handler::EventHandler;
+    use ampd_sdk::grpc::client::test_utils::MockHandlerTaskClient;
+    use axelar_wasm_std::chain_name;
+    use axelar_wasm_std::msg_id::HexTxHashAndEventIndex;
+    use axelar_wasm_std::voting::Vote;
+    use cosmrs::cosmwasm::MsgExecuteContract;
+    use cosmrs::tx::Msg;
+    use cosmwasm_std::HexBinary;
+    use ethers_core::types::H160;
+    use events::Event;
+    use multisig::key::KeyType;
+    use multisig::test::common::{build_verifier_set, ecdsa_test_data};
+    use tokio::test as async_test;
+    use voting_verifier::events::{
+        PollMetadata, PollStarted, TxEventConfirmation, VerifierSetConfirmation,
+    };
+    use voting_verifier::msg::ExecuteMsg;
+
+    use super::{
+        Handler, MessagesPollStarted, VerifierSetPollStarted, TYPE_SIGNATURE_CONTRACT_CALL,
+        TYPE_SIGNATURE_SIGNERS_ROTATED,
+    };
+
+    const PREFIX: &str = "axelar";
+    const STACKS: &str = "stacks";
+    const GATEWAY_ADDRESS: &str = "SP2N959SER36FZ5QT1CX9BR63W3E8X35WQCMBYYWC.axelar-gateway";
+
+    fn poll_metadata(participants: Vec<TMAddress>, expires_at: u64) -> PollMetadata {
+        PollMetadata {
+            poll_id: "100".parse().unwrap(),
+            source_chain: chain_name!(STACKS),
+            source_gateway_address: GATEWAY_ADDRESS.parse().unwrap(),
+            confirmation_height: 15,
+            expires_at,
+            participants: participants
+                .into_iter()
+                .map(|addr| cosmwasm_std::Addr::unchecked(addr.to_string()))
+                .collect(),
+        }
+    }
+
+    fn message_poll_started_event(participants: Vec<TMAddress>, expires_at: u64) -> PollStarted {
+        PollStarted::Messages {
+            metadata: poll_metadata(participants, expires_at),
+            messages: vec![TxEventConfirmation {
+                message_id: HexTxHashAndEventIndex::new([3; 32], 1u64)
+                    .to_string()
+                    .parse()
+                    .unwrap(),
+                source_address: "ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM".parse().unwrap(),
+                destination_chain: chain_name!("ethereum"),
+                destination_address: format!("0x{:x}", H160::repeat_byte(2)).parse().unwrap(),
+                payload_hash: [1; 32],
+            }],
+        }
+    }
+
+    fn verifier_set_poll_started_event(
+        participants: Vec<TMAddress>,
+        expires_at: u64,
+    ) -> PollStarted {
+        PollStarted::VerifierSet {
+            #[allow(deprecated)]
+            verifier_set: VerifierSetConfirmation {
+                message_id: HexTxHashAndEventIndex::new([3; 32], 1u64)
+                    .to_string()
+                    .parse()
+                    .unwrap(),
+                verifier_set: build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers()),
+            },
+            metadata: poll_metadata(participants, expires_at),
+        }
+    }
+
+    // contract call event of the gateway for `matching_message_poll_started_event`
+    const CONTRACT_CALL_EVENT_HEX: &str = "0x0c000000061164657374696e6174696f6e2d636861696e0d0000000b64657374696e6174696f6e1c64657374696e6174696f6e2d636f6e74726163742d616464726573730d000000083078313233616263077061796c6f61640200000029535431534a3344544535444e375835345944483544363452334243423641324147325a5138595044350c7061796c6f61642d6861736802000000209ed02951dbf029855b46b102cc960362732569e83d00a49a7575d7aed229890e0673656e646572051a99e2ec69ac5b6e67b4e26edd0e2c1c1a6b9bbd2304747970650d0000000d636f6e74726163742d63616c6c";
+
+    fn matching_message_poll_started_event(participants: Vec<TMAddress>) -> PollStarted {
+        PollStarted::Messages {
+            metadata: poll_metadata(participants, 100),
+            messages: vec![
+                TxEventConfirmation {
+                    message_id: HexTxHashAndEventIndex::new([3; 32], 1u64)
+                        .to_string()
+                        .parse()
+                        .unwrap(),
+                    source_address: "ST2CY5V39NHDPWSXMW9QDT3HC3GD6Q6XX4CFRK9AG".parse().unwrap(),
+                    destination_chain: chain_name!("destination"),
+                    destination_address: "0x123abc".parse().unwrap(),
+                    payload_hash: HexBinary::from_hex(
+                        "9ed02951dbf029855b46b102cc960362732569e83d00a49a7575d7aed229890e",
+                    )
+                    .unwrap()
+                    .to_array()
+                    .unwrap(),
+                },
+                TxEventConfirmation {
+                    message_id: HexTxHashAndEventIndex::new([3; 32], 1u64)
+                        .to_string()
+                        .parse()
+                        .unwrap(),
+                    source_address: "ST2CY5V39NHDPWSXMW9QDT3HC3GD6Q6XX4CFRK9AG".parse().unwrap(),
+                    destination_chain: chain_name!("destination"),
+                    destination_address: "0x123abc".parse().unwrap(),
+                    payload_hash: [1; 32],
+                },
+            ],
+        }
+    }
+
+    /// Serves the stacks API endpoints the handler needs: the latest block and a single successful transaction
+    /// that emitted the gateway's contract call event
+    fn serve_stacks_api() -> Url {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let tx = serde_json::json!({
+            "tx_id": format!("0x{}", hex::encode([3u8; 32])),
+            "tx_status": "success",
+            "burn_block_height": 10,
+            "events": [
+                { "event_index": 0, "contract_log": null },
+                {
+                    "event_index": 1,
+                    "contract_log": {
+                        "contract_id": GATEWAY_ADDRESS,
+                        "topic": "print",
+                        "value": { "hex": CONTRACT_CALL_EVENT_HEX },
+                    },
+                },
+            ],
+        });
+        let latest_block = serde_json::json!({ "burn_block_height": 100 });
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                // drain the headers, requests are bodiless GETs
+                let mut header = String::new();
+                while reader.read_line(&mut header).unwrap() > 2 {
+                    header.clear();
+                }
+
+                let body = if request_line.contains("/extended/v2/blocks/latest") {
+                    latest_block.to_string()
+                } else if request_line.contains(&hex::encode([3u8; 32])) {
+                    tx.to_string()
+                } else {
+                    "{}".to_string()
+                };
+
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        Url::new_non_sensitive(&format!("http://{addr}")).unwrap()
+    }
+
+    fn votes(result: &[cosmrs::Any]) -> Vec<Vote> {
+        let msg = MsgExecuteContract::from_any(result.first().unwrap()).unwrap();
+        match serde_json::from_slice(&msg.msg).unwrap() {
+            ExecuteMsg::Vote { votes, .. } => votes,
+            _ => panic!("expected vote msg"),
+        }
+    }
+
+    #[test]
+    fn type_signatures_should_be_valid() {
+        lazy_static::initialize(&TYPE_SIGNATURE_CONTRACT_CALL);
+        lazy_static::initialize(&TYPE_SIGNATURE_SIGNERS_ROTATED);
+    }
+
+    #[test]
+    fn stacks_verify_msg_should_deserialize_correct_event() {
+        let event: Event = into_structured_event(
+            message_poll_started_event(participants(5, None), 100),
+            &TMAddress::random(PREFIX),
+        );
+        let event: MessagesPollStarted = event.try_into().unwrap();
+
+        assert_eq!(event.poll_id, 100u64.into());
+        assert_eq!(event.source_gateway_address.to_string(), GATEWAY_ADDRESS);
+        assert_eq!(
+            event.messages.first().unwrap().source_address.to_string(),
+            "ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM"
+        );
+    }
+
+    #[test]
+    fn stacks_verify_verifier_set_should_deserialize_correct_event() {
+        let event: Event = into_structured_event(
+            verifier_set_poll_started_event(participants(5, None), 100),
+            &TMAddress::random(PREFIX),
+        );
+        let event: VerifierSetPollStarted = event.try_into().unwrap();
+
+        assert_eq!(event.poll_id, 100u64.into());
+        assert_eq!(
+            event.verifier_set.verifier_set,
+            build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers())
+        );
+    }
+
+    #[async_test]
+    async fn should_skip_expired_poll() {
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let expiration = 100u64;
+        let event: Event = into_structured_event(
+            message_poll_started_event(participants(5, Some(verifier.clone())), expiration),
+            &voting_verifier_contract,
+        );
+
+        let (monitoring_client, _) = monitoring::test_utils::monitoring_client();
+        // the http client must not be called for an expired poll, so any url will do
+        let http_client = Client::new_http(
+            Url::new_non_sensitive("http://localhost:3999").unwrap(),
+            Duration::from_secs(1),
+            monitoring_client.clone(),
+            chain_name!(STACKS),
+        )
+        .unwrap();
+        let handler = Handler::builder()
+            .verifier(verifier.as_ref().clone())
+            .voting_verifier_contract(voting_verifier_contract.as_ref().clone())
+            .chain(chain_name!(STACKS))
+            .http_client(http_client)
+            .monitoring_client(monitoring_client)
+            .build();
+
+        let mut client = MockHandlerTaskClient::new();
+        client
+            .expect_latest_block_height()
+            .returning(move || Ok(expiration + 1));
+
+        let result = handler.handle(event.try_into().unwrap(), &mut client).await;
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[async_test]
+    async fn should_vote_on_messages_by_their_gateway_events() {
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event: Event = into_structured_event(
+            matching_message_poll_started_event(participants(5, Some(verifier.clone()))),
+            &voting_verifier_contract,
+        );
+
+        let (monitoring_client, mut receiver) = monitoring::test_utils::monitoring_client();
+        let http_client = Client::new_http(
+            serve_stacks_api(),
+            Duration::from_secs(5),
+            monitoring_client.clone(),
+            chain_name!(STACKS),
+        )
+        .unwrap();
+        let handler = Handler::builder()
+            .verifier(verifier.as_ref().clone())
+            .voting_verifier_contract(voting_verifier_contract.as_ref().clone())
+            .chain(chain_name!(STACKS))
+            .http_client(http_client)
+            .monitoring_client(monitoring_client)
+            .build();
+
+        let mut client = MockHandlerTaskClient::new();
+        client.expect_latest_block_height().returning(|| Ok(99));
+
+        let result = handler
+            .handle(event.try_into().unwrap(), &mut client)
+            .await
+            .unwrap();
+        // the second message does not match the payload hash of the event
+        assert_eq!(votes(&result), vec![Vote::SucceededOnChain, Vote::NotFound]);
+
+        let vote_metrics: Vec<_> = std::iter::from_fn(|| receiver.try_recv().ok())
+            .filter(|msg| matches!(msg, monitoring::metrics::Msg::VerificationVote { .. }))
+            .collect();
+        assert_eq!(
+            vote_metrics,
+            [Vote::SucceededOnChain, Vote::NotFound]
+                .into_iter()
+                .map(|vote_decision| monitoring::metrics::Msg::VerificationVote {
+                    vote_decision,
+                    chain_name: chain_name!(STACKS),
+                })
+                .collect::<Vec<_>>()
+        );
+    }
+}
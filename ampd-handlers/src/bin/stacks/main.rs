@@ -0,0 +1,88 @@
+mod error;
+mod handler;
+
+use std::time::Duration;
+
+use ampd::stacks::http_client::Client;
+use ampd::url::Url;
+use ampd_handlers::tracing::init_tracing;
+use ampd_sdk::config;
+use ampd_sdk::runtime::HandlerRuntime;
+use axelar_wasm_std::chain::ChainName;
+#[cfg(debug_assertions)]
+use dotenv_flow::dotenv_flow;
+use error_stack::{Result, ResultExt};
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+use tracing::Level;
+
+use crate::error::Error;
+use crate::handler::Handler;
+
+#[derive(Debug, Deserialize, Serialize)]
+struct StacksHandlerConfig {
+    #[serde(deserialize_with = "Url::deserialize_non_sensitive")]
+    rpc_url: Url,
+    #[serde(with = "humantime_serde")]
+    #[serde(default = "default_rpc_timeout")]
+    rpc_timeout: Duration,
+}
+
+fn default_rpc_timeout() -> Duration {
+    Duration::from_secs(3)
+}
+
+fn build_handler(
+    runtime: &HandlerRuntime,
+    chain_name: ChainName,
+    config: StacksHandlerConfig,
+) -> Result<Handler, Error> {
+    let http_client = Client::new_http(
+        config.rpc_url,
+        config.rpc_timeout,
+        runtime.monitoring_client.clone(),
+        chain_name.clone(),
+    )
+    .change_context(Error::HandlerStart)?;
+
+    let handler = Handler::builder()
+        .verifier(runtime.verifier.clone())
+        .voting_verifier_contract(runtime.contracts.voting_verifier.clone())
+        .chain(chain_name)
+        .http_client(http_client)
+        .monitoring_client(runtime.monitoring_client.clone())
+        .build();
+
+    Ok(handler)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    #[cfg(debug_assertions)]
+    dotenv_flow().ok();
+
+    init_tracing(Level::INFO);
+
+    let base_config = config::Config::from_default_sources().change_context(Error::HandlerStart)?;
+
+    let handler_config = config::Config::builder()
+        .add_file_source("stacks-handler-config.toml")
+        .add_env_source("AMPD_STACKS_HANDLER")
+        .build::<StacksHandlerConfig>()
+        .change_context(Error::HandlerStart)?;
+
+    let token = CancellationToken::new();
+
+    let runtime = HandlerRuntime::start(&base_config, token.clone())
+        .await
+        .change_context(Error::HandlerStart)?;
+
+    let handler = build_handler(&runtime, base_config.chain_name.clone(), handler_config)?;
+
+    runtime
+        .run_handler(handler, base_config, token)
+        .await
+        .change_context(Error::HandlerTask)?;
+
+    Ok(())
+}
@@ -0,0 +1,495 @@
+use std::collections::HashMap;
+
+use ampd::handlers::starknet_verify_msg::Message;
+use ampd::handlers::starknet_verify_verifier_set::VerifierSetConfirmation;
+use ampd::monitoring;
+use ampd::starknet::json_rpc::StarknetClient;
+use ampd::starknet::verifier::{verify_msg, verify_verifier_set};
+use ampd::types::starknet::events::contract_call::ContractCallEvent;
+use ampd::types::starknet::events::signers_rotated::SignersRotatedEvent;
+use ampd_handlers::voting::{self, Error, PollEventData as _, VotingHandler};
+use ampd_sdk::event::event_handler::{EventHandler, SubscriptionParams};
+use ampd_sdk::grpc::client::EventHandlerClient;
+use async_trait::async_trait;
+use axelar_wasm_std::chain::ChainName;
+use axelar_wasm_std::msg_id::FieldElementAndEventIndex;
+use axelar_wasm_std::voting::{PollId, Vote};
+use cosmrs::{AccountId, Any};
+use error_stack::Report;
+use events::{try_from, AbciEventTypeFilter, Event, EventType};
+use futures::future::join_all;
+use serde::Deserialize;
+use typed_builder::TypedBuilder;
+
+pub type Result<T> = error_stack::Result<T, Error>;
+
+#[derive(Clone, Debug, Deserialize)]
+#[try_from("wasm-messages_poll_started")]
+pub struct MessagesPollStarted {
+    poll_id: PollId,
+    source_chain: ChainName,
+    source_gateway_address: String,
+    confirmation_height: u64,
+    expires_at: u64,
+    messages: Vec<Message>,
+    participants: Vec<AccountId>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[try_from("wasm-verifier_set_poll_started")]
+pub struct VerifierSetPollStarted {
+    verifier_set: VerifierSetConfirmation,
+    poll_id: PollId,
+    source_chain: ChainName,
+    source_gateway_address: String,
+    expires_at: u64,
+    confirmation_height: u64,
+    participants: Vec<AccountId>,
+}
+
+#[derive(Clone, Debug)]
+pub enum PollStartedEvent {
+    Messages(MessagesPollStarted),
+    VerifierSet(VerifierSetPollStarted),
+}
+
+impl TryFrom<Event> for PollStartedEvent {
+    type Error = Report<events::Error>;
+
+    fn try_from(event: Event) -> std::result::Result<Self, Self::Error> {
+        if let Ok(event) = MessagesPollStarted::try_from(event.clone()) {
+            Ok(PollStartedEvent::Messages(event))
+        } else if let Ok(event) = VerifierSetPollStarted::try_from(event.clone()) {
+            Ok(PollStartedEvent::VerifierSet(event))
+        } else {
+            Err(Report::new(events::Error::EventTypeMismatch(format!(
+                "{}/{}",
+                MessagesPollStarted::event_type(),
+                VerifierSetPollStarted::event_type()
+            )))
+            .attach_printable(format!("{{ event = {event:?} }}")))
+        }
+    }
+}
+
+/// Starknet gateway event that was emitted at the position a message id points to
+#[derive(Clone, Debug)]
+pub enum GatewayEvent {
+    ContractCall(ContractCallEvent),
+    SignersRotated(SignersRotatedEvent),
+}
+
+#[derive(Clone, Debug)]
+pub enum PollEventData {
+    Message(Message),
+    VerifierSet(VerifierSetConfirmation),
+}
+
+impl voting::PollEventData for PollEventData {
+    // Starknet events are fetched by message id rather than by transaction hash
+    type Digest = FieldElementAndEventIndex;
+    type MessageId = FieldElementAndEventIndex;
+    type ChainAddress = String;
+    type Receipt = GatewayEvent;
+
+    fn tx_hash(&self) -> Self::Digest {
+        self.message_id().clone()
+    }
+
+    fn message_id(&self) -> &Self::MessageId {
+        match self {
+            PollEventData::Message(message) => &message.message_id,
+            PollEventData::VerifierSet(verifier_set) => &verifier_set.message_id,
+        }
+    }
+
+    fn verify(
+        &self,
+        source_gateway_address: &Self::ChainAddress,
+        tx_receipt: &Self::Receipt,
+    ) -> Vote {
+        match (self, tx_receipt) {
+            (PollEventData::Message(message), GatewayEvent::ContractCall(event)) => {
+                verify_msg(event, message, source_gateway_address)
+            }
+            (PollEventData::VerifierSet(verifier_set), GatewayEvent::SignersRotated(event)) => {
+                verify_verifier_set(event, verifier_set, source_gateway_address)
+            }
+            _ => Vote::NotFound,
+        }
+    }
+}
+
+impl From<PollStartedEvent> for voting::PollStartedEvent<PollEventData, String> {
+    fn from(event: PollStartedEvent) -> Self {
+        match event {
+            PollStartedEvent::Messages(message_event) => voting::PollStartedEvent {
+                poll_data: message_event
+                    .messages
+                    .into_iter()
+                    .map(PollEventData::Message)
+                    .collect(),
+                poll_id: message_event.poll_id,
+                source_chain: message_event.source_chain,
+                source_gateway_address: message_event.source_gateway_address,
+                expires_at: message_event.expires_at,
+                confirmation_height: message_event.confirmation_height,
+                participants: message_event.participants,
+            },
+            PollStartedEvent::VerifierSet(verifier_set_event) => voting::PollStartedEvent {
+                poll_data: vec![PollEventData::VerifierSet(verifier_set_event.verifier_set)],
+                poll_id: verifier_set_event.poll_id,
+                source_chain: verifier_set_event.source_chain,
+                source_gateway_address: verifier_set_event.source_gateway_address,
+                expires_at: verifier_set_event.expires_at,
+                confirmation_height: verifier_set_event.confirmation_height,
+                participants: verifier_set_event.participants,
+            },
+        }
+    }
+}
+
+#[derive(Debug, TypedBuilder)]
+pub struct Handler<C>
+where
+    C: StarknetClient,
+{
+    pub verifier: AccountId,
+    pub voting_verifier_contract: AccountId,
+    pub chain: ChainName,
+    pub rpc_client: C,
+    pub monitoring_client: monitoring::Client,
+}
+
+impl<C> Handler<C>
+where
+    C: StarknetClient + Send + Sync,
+{
+    async fn gateway_event(&self, poll_data: &PollEventData) -> Option<GatewayEvent> {
+        match poll_data {
+            PollEventData::Message(message) => self
+                .rpc_client
+                .event_by_message_id_contract_call(message.message_id.clone())
+                .await
+                .map(GatewayEvent::ContractCall),
+            PollEventData::VerifierSet(verifier_set) => self
+                .rpc_client
+                .event_by_message_id_signers_rotated(verifier_set.message_id.clone())
+                .await
+                .map(GatewayEvent::SignersRotated),
+        }
+    }
+}
+
+#[async_trait]
+impl<C> VotingHandler for Handler<C>
+where
+    C: StarknetClient + Send + Sync,
+{
+    type Digest = FieldElementAndEventIndex;
+    type Receipt = GatewayEvent;
+    type ChainAddress = String;
+    type EventData = PollEventData;
+
+    fn chain(&self) -> &ChainName {
+        &self.chain
+    }
+
+    fn verifier(&self) -> &AccountId {
+        &self.verifier
+    }
+
+    fn voting_verifier_contract(&self) -> &AccountId {
+        &self.voting_verifier_contract
+    }
+
+    fn monitoring_client(&self) -> &monitoring::Client {
+        &self.monitoring_client
+    }
+
+    async fn finalized_txs(
+        &self,
+        poll_data: &[Self::EventData],
+        _confirmation_height: Option<u64>,
+    ) -> Result<HashMap<Self::Digest, Self::Receipt>> {
+        let unique_poll_data: HashMap<_, _> = poll_data
+            .iter()
+            .map(|data| (data.message_id().clone(), data))
+            .collect();
+
+        Ok(join_all(
+            unique_poll_data
+                .into_iter()
+                .map(|(message_id, data)| async {
+                    self.gateway_event(data)
+                        .await
+                        .map(|event| (message_id, event))
+                }),
+        )
+        .await
+        .into_iter()
+        .flatten()
+        .collect())
+    }
+}
+
+#[async_trait]
+impl<C> EventHandler for Handler<C>
+where
+    C: StarknetClient + Send + Sync,
+{
+    type Err = Error;
+    type Event = PollStartedEvent;
+
+    async fn handle<HC: EventHandlerClient + Send + 'static>(
+        &self,
+        event: PollStartedEvent,
+        client: &mut HC,
+    ) -> Result<Vec<Any>> {
+        VotingHandler::handle(self, event.into(), client).await
+    }
+
+    fn subscription_params(&self) -> SubscriptionParams {
+        let attributes = HashMap::from([(
+            "source_chain".to_string(),
+            serde_json::Value::String(self.chain.to_string()),
+        )]);
+
+        SubscriptionParams::new(
+            vec![
+                AbciEventTypeFilter {
+                    event_type: MessagesPollStarted::event_type(),
+                    contract: self.voting_verifier_contract.clone(),
+                    attributes: attributes.clone(),
+                },
+                AbciEventTypeFilter {
+                    event_type: VerifierSetPollStarted::event_type(),
+                    contract: self.voting_verifier_contract.clone(),
+                    attributes,
+                },
+            ],
+            false,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use ampd::handlers::test_utils::{into_structured_event, participants};
+    use ampd::monitoring;
+    use ampd::starknet::json_rpc::MockStarknetClient;
+    use ampd::types::starknet::events::contract_call::ContractCallEvent;
+    use ampd::types::TMAddress;
+    use ampd_sdk::event::event_handler::EventHandler;
+    use ampd_sdk::grpc::client::test_utils::MockHandlerTaskClient;
+    use axelar_wasm_std::chain_name;
+    use axelar_wasm_std::voting::Vote;
+    use cosmrs::cosmwasm::MsgExecuteContract;
+    use cosmrs::tx::Msg;
+    use ethers_core::types::H256;
+    use events::Event;
+    use multisig::key::KeyType;
+    use multisig::test::common::{build_verifier_set, ecdsa_test_data};
+    use starknet_core::types::Felt;
+    use tokio::test as async_test;
+    use voting_verifier::events::{
+        PollMetadata, PollStarted, TxEventConfirmation, VerifierSetConfirmation,
+    };
+    use voting_verifier::msg::ExecuteMsg;
+
+    use super::{Handler, MessagesPollStarted, VerifierSetPollStarted};
+
+    const PREFIX: &str = "axelar";
+    const STARKNET: &str = "starknet";
+    const SOURCE_GATEWAY_ADDRESS: &str = "source-gw-addr";
+    const DESTINATION_ADDRESS: &str = "destination-address";
+    // keccak256("hello")
+    const PAYLOAD_HASH: [u8; 32] = [
+        28, 138, 255, 149, 6, 133, 194, 237, 75, 195, 23, 79, 52, 114, 40, 123, 86, 217, 81, 123,
+        156, 148, 129, 39, 49, 154, 9, 167, 163, 109, 234, 200,
+    ];
+
+    fn poll_metadata(participants: Vec<TMAddress>, expires_at: u64) -> PollMetadata {
+        PollMetadata {
+            poll_id: "100".parse().unwrap(),
+            source_chain: chain_name!(STARKNET),
+            source_gateway_address: SOURCE_GATEWAY_ADDRESS.parse().unwrap(),
+            confirmation_height: 15,
+            expires_at,
+            participants: participants
+                .into_iter()
+                .map(|addr| cosmwasm_std::Addr::unchecked(addr.to_string()))
+                .collect(),
+        }
+    }
+
+    fn message_poll_started_event(participants: Vec<TMAddress>, expires_at: u64) -> PollStarted {
+        PollStarted::Messages {
+            metadata: poll_metadata(participants, expires_at),
+            messages: vec![
+                TxEventConfirmation {
+                    message_id:
+                        "0x035410be6f4bf3f67f7c1bb4a93119d9d410b2f981bfafbf5dbbf5d37ae7439e-0"
+                            .parse()
+                            .unwrap(),
+                    source_address:
+                        "0x0000000000000000000000000000000000000000000000000000000000000001"
+                            .parse()
+                            .unwrap(),
+                    destination_chain: chain_name!("ethereum"),
+                    destination_address: DESTINATION_ADDRESS.parse().unwrap(),
+                    payload_hash: PAYLOAD_HASH,
+                },
+                TxEventConfirmation {
+                    message_id:
+                        "0x035410be6f4bf3f67f7c1bb4a93119d9d410b2f981bfafbf5dbbf5d37ae7439e-1"
+                            .parse()
+                            .unwrap(),
+                    source_address:
+                        "0x0000000000000000000000000000000000000000000000000000000000000002"
+                            .parse()
+                            .unwrap(),
+                    destination_chain: chain_name!("ethereum"),
+                    destination_address: DESTINATION_ADDRESS.parse().unwrap(),
+                    payload_hash: PAYLOAD_HASH,
+                },
+            ],
+        }
+    }
+
+    fn verifier_set_poll_started_event(
+        participants: Vec<TMAddress>,
+        expires_at: u64,
+    ) -> PollStarted {
+        PollStarted::VerifierSet {
+            #[allow(deprecated)]
+            verifier_set: VerifierSetConfirmation {
+                message_id: "0x035410be6f4bf3f67f7c1bb4a93119d9d410b2f981bfafbf5dbbf5d37ae7439e-2"
+                    .parse()
+                    .unwrap(),
+                verifier_set: build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers()),
+            },
+            metadata: poll_metadata(participants, expires_at),
+        }
+    }
+
+    fn mock_handler_client(latest_block_height: u64) -> MockHandlerTaskClient {
+        let mut client = MockHandlerTaskClient::new();
+        client
+            .expect_latest_block_height()
+            .returning(move || Ok(latest_block_height));
+        client
+    }
+
+    fn votes(result: &[cosmrs::Any]) -> Vec<Vote> {
+        let msg = MsgExecuteContract::from_any(result.first().unwrap()).unwrap();
+        match serde_json::from_slice(&msg.msg).unwrap() {
+            ExecuteMsg::Vote { votes, .. } => votes,
+            _ => panic!("expected vote msg"),
+        }
+    }
+
+    #[test]
+    fn starknet_verify_msg_should_deserialize_correct_event() {
+        let event: Event = into_structured_event(
+            message_poll_started_event(participants(5, None), 100),
+            &TMAddress::random(PREFIX),
+        );
+        let event: MessagesPollStarted = event.try_into().unwrap();
+
+        assert_eq!(event.poll_id, 100u64.into());
+        assert_eq!(event.source_gateway_address, SOURCE_GATEWAY_ADDRESS);
+        assert_eq!(event.messages.len(), 2);
+    }
+
+    #[test]
+    fn starknet_verify_verifier_set_should_deserialize_correct_event() {
+        let event: Event = into_structured_event(
+            verifier_set_poll_started_event(participants(5, None), 100),
+            &TMAddress::random(PREFIX),
+        );
+        let event: VerifierSetPollStarted = event.try_into().unwrap();
+
+        assert_eq!(event.poll_id, 100u64.into());
+        assert_eq!(
+            event.verifier_set.verifier_set,
+            build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers())
+        );
+    }
+
+    #[async_test]
+    async fn should_skip_expired_poll() {
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let expiration = 100u64;
+        let event: Event = into_structured_event(
+            message_poll_started_event(participants(5, Some(verifier.clone())), expiration),
+            &voting_verifier_contract,
+        );
+
+        let (monitoring_client, _) = monitoring::test_utils::monitoring_client();
+        let handler = Handler::builder()
+            .verifier(verifier.as_ref().clone())
+            .voting_verifier_contract(voting_verifier_contract.as_ref().clone())
+            .chain(chain_name!(STARKNET))
+            .rpc_client(MockStarknetClient::new())
+            .monitoring_client(monitoring_client)
+            .build();
+
+        let mut client = mock_handler_client(expiration + 1);
+        let result = handler.handle(event.try_into().unwrap(), &mut client).await;
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[async_test]
+    async fn should_vote_on_messages_by_their_gateway_events() {
+        let mut rpc_client = MockStarknetClient::new();
+        rpc_client
+            .expect_event_by_message_id_contract_call()
+            .returning(|message_id| {
+                (message_id.event_index == 0).then(|| ContractCallEvent {
+                    from_contract_addr: SOURCE_GATEWAY_ADDRESS.to_string(),
+                    destination_address: DESTINATION_ADDRESS.to_string(),
+                    destination_chain: "ethereum".parse().unwrap(),
+                    source_address: Felt::ONE,
+                    payload_hash: H256::from_slice(&PAYLOAD_HASH),
+                })
+            });
+
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event: Event = into_structured_event(
+            message_poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier_contract,
+        );
+
+        let (monitoring_client, mut receiver) = monitoring::test_utils::monitoring_client();
+        let handler = Handler::builder()
+            .verifier(verifier.as_ref().clone())
+            .voting_verifier_contract(voting_verifier_contract.as_ref().clone())
+            .chain(chain_name!(STARKNET))
+            .rpc_client(rpc_client)
+            .monitoring_client(monitoring_client)
+            .build();
+
+        let mut client = mock_handler_client(99);
+        let result = handler
+            .handle(event.try_into().unwrap(), &mut client)
+            .await
+            .unwrap();
+        assert_eq!(votes(&result), vec![Vote::SucceededOnChain, Vote::NotFound]);
+
+        for vote in [Vote::SucceededOnChain, Vote::NotFound] {
+            assert_eq!(
+                receiver.recv().await.unwrap(),
+                monitoring::metrics::Msg::VerificationVote {
+                    vote_decision: vote,
+                    chain_name: chain_name!(STARKNET),
+                }
+            );
+        }
+        assert!(receiver.try_recv().is_err());
+    }
+}
@@ -0,0 +1,385 @@
+use std::collections::HashMap;
+
+use ampd::types::{PublicKey, TMAddress};
+use ampd_sdk::event::event_handler::{EventHandler, SubscriptionParams};
+use ampd_sdk::grpc::client::types::{Key, KeyAlgorithm};
+use ampd_sdk::grpc::client::EventHandlerClient;
+use async_trait::async_trait;
+use axelar_wasm_std::chain::ChainName;
+use axelar_wasm_std::nonempty;
+use cosmrs::cosmwasm::MsgExecuteContract;
+use cosmrs::tx::Msg;
+use cosmrs::Any;
+use cosmwasm_std::{HexBinary, Uint64};
+use error_stack::{report, ResultExt};
+use events::{try_from, AbciEventTypeFilter, EventType};
+use hex::encode;
+use multisig::msg::ExecuteMsg;
+use multisig::types::MsgToSign;
+use serde::de::Error as DeserializeError;
+use serde::{Deserialize, Deserializer};
+use tracing::info;
+use typed_builder::TypedBuilder;
+use xrpl_types::types::XRPLAccountId;
+
+use crate::error::Error;
+
+pub type Result<T> = error_stack::Result<T, Error>;
+
+#[derive(Clone, Debug, Deserialize)]
+#[try_from("wasm-signing_started")]
+pub struct SigningStartedEvent {
+    session_id: u64,
+    #[serde(deserialize_with = "deserialize_public_keys")]
+    pub_keys: HashMap<TMAddress, PublicKey>,
+    msg: MsgToSign,
+    expires_at: u64,
+    chain: ChainName,
+}
+
+fn deserialize_public_keys<'de, D>(
+    deserializer: D,
+) -> std::result::Result<HashMap<TMAddress, PublicKey>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let keys_by_address: HashMap<TMAddress, multisig::key::PublicKey> =
+        HashMap::deserialize(deserializer)?;
+
+    keys_by_address
+        .into_iter()
+        .map(|(address, pk)| Ok((address, pk.try_into().map_err(D::Error::custom)?)))
+        .collect()
+}
+
+/// XRPL multisig signers sign the encoded unsigned transaction followed by their own XRPL account id
+fn xrpl_msg_digest(pub_key: &PublicKey, msg: &MsgToSign) -> Result<[u8; 32]> {
+    let PublicKey::Secp256k1(_) = pub_key else {
+        return Err(report!(Error::PublicKey))
+            .attach_printable("XRPL signers must use secp256k1 keys");
+    };
+
+    let multisig_pub_key = multisig::key::PublicKey::try_from((
+        multisig::key::KeyType::Ecdsa,
+        HexBinary::from(pub_key.to_bytes()),
+    ))
+    .change_context(Error::PublicKey)?;
+    let xrpl_address = XRPLAccountId::from(&multisig_pub_key);
+
+    xrpl_types::types::message_to_sign(msg.as_ref().to_vec(), &xrpl_address)
+        .change_context(Error::MessageToSign)
+}
+
+#[derive(Debug, TypedBuilder)]
+pub struct Handler {
+    pub verifier: TMAddress,
+    pub multisig: TMAddress,
+    pub chain: ChainName,
+}
+
+impl Handler {
+    fn submit_signature_msg(
+        &self,
+        session_id: impl Into<Uint64>,
+        signature: impl Into<HexBinary>,
+    ) -> MsgExecuteContract {
+        MsgExecuteContract {
+            sender: self.verifier.as_ref().clone(),
+            contract: self.multisig.as_ref().clone(),
+            msg: serde_json::to_vec(&ExecuteMsg::SubmitSignature {
+                session_id: session_id.into(),
+                signature: signature.into(),
+            })
+            .expect("submit signature msg should serialize"),
+            funds: vec![],
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for Handler {
+    type Err = Error;
+    type Event = SigningStartedEvent;
+
+    async fn handle<HC: EventHandlerClient + Send + 'static>(
+        &self,
+        event: SigningStartedEvent,
+        client: &mut HC,
+    ) -> Result<Vec<Any>> {
+        let SigningStartedEvent {
+            session_id,
+            pub_keys,
+            msg,
+            expires_at,
+            chain,
+        } = event;
+
+        if !chain.eq(&self.chain) {
+            info!(
+                chain = chain.to_string(),
+                handler_chain = self.chain.to_string(),
+                "chain mismatch, skipping event"
+            );
+            return Ok(vec![]);
+        }
+
+        let latest_block_height = client
+            .latest_block_height()
+            .await
+            .change_context(Error::EventHandling)?;
+
+        if latest_block_height >= expires_at {
+            info!(
+                session_id = session_id.to_string(),
+                "skipping expired signing session"
+            );
+            return Ok(vec![]);
+        }
+
+        info!(
+            session_id = session_id,
+            msg = encode(&msg),
+            "received signing request",
+        );
+
+        match pub_keys.get(&self.verifier) {
+            Some(pub_key) => {
+                let msg_digest = xrpl_msg_digest(pub_key, &msg)?;
+
+                let data = <nonempty::Vec<u8>>::try_from(msg_digest.to_vec())
+                    .change_context(Error::MessageToSign)?;
+                let key_id = TryInto::<nonempty::String>::try_into(self.multisig.to_string())
+                    .change_context(Error::KeyId)?;
+
+                let signature = client
+                    .sign(
+                        Some(Key {
+                            id: key_id,
+                            algorithm: KeyAlgorithm::Ecdsa,
+                        }),
+                        data,
+                    )
+                    .await
+                    .change_context(Error::Sign)?;
+
+                info!(
+                    signature = encode(signature.as_slice()),
+                    "ready to submit signature"
+                );
+
+                Ok(vec![self
+                    .submit_signature_msg(session_id, signature.as_slice())
+                    .into_any()
+                    .expect("submit signature msg should serialize")])
+            }
+            None => {
+                info!("verifier is not a participant");
+
+                Ok(vec![])
+            }
+        }
+    }
+
+    fn subscription_params(&self) -> SubscriptionParams {
+        let attributes = HashMap::from([(
+            "chain".to_string(),
+            serde_json::Value::String(self.chain.to_string()),
+        )]);
+
+        SubscriptionParams::new(
+            vec![AbciEventTypeFilter {
+                event_type: SigningStartedEvent::event_type(),
+                contract: self.multisig.clone().into(),
+                attributes,
+            }],
+            false,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use ampd::handlers::test_utils::into_structured_event;
+    use ampd::types::TMAddress;
+    use ampd_sdk::event::event_handler::EventHandler;
+    use ampd_sdk::grpc::client::test_utils::MockHandlerTaskClient;
+    use ampd_sdk::grpc::client::types::KeyAlgorithm;
+    use axelar_wasm_std::{chain_name, nonempty};
+    use cosmrs::cosmwasm::MsgExecuteContract;
+    use cosmrs::tx::Msg;
+    use cosmwasm_std::{HexBinary, Uint64};
+    use events::Event;
+    use multisig::msg::ExecuteMsg;
+    use multisig::types::MsgToSign;
+    use rand::rngs::OsRng;
+    use tokio::test as async_test;
+    use xrpl_types::types::XRPLAccountId;
+
+    use super::{Handler, SigningStartedEvent};
+
+    const PREFIX: &str = "axelar";
+    const XRPL: &str = "xrpl";
+
+    fn rand_public_key() -> multisig::key::PublicKey {
+        multisig::key::PublicKey::Ecdsa(HexBinary::from(
+            k256::ecdsa::SigningKey::random(&mut OsRng)
+                .verifying_key()
+                .to_sec1_bytes()
+                .to_vec(),
+        ))
+    }
+
+    fn unsigned_tx() -> HexBinary {
+        HexBinary::from(vec![1u8; 100])
+    }
+
+    fn signing_started_event(
+        multisig: &TMAddress,
+        pub_keys: HashMap<String, multisig::key::PublicKey>,
+        expires_at: u64,
+    ) -> Event {
+        into_structured_event(
+            multisig::events::Event::SigningStarted {
+                session_id: Uint64::one(),
+                verifier_set_id: "verifier_set_id".to_string(),
+                pub_keys,
+                msg: MsgToSign::unchecked(unsigned_tx()),
+                chain_name: chain_name!(XRPL),
+                expires_at,
+            },
+            multisig,
+        )
+    }
+
+    fn handler(verifier: TMAddress, multisig: TMAddress) -> Handler {
+        Handler::builder()
+            .verifier(verifier)
+            .multisig(multisig)
+            .chain(chain_name!(XRPL))
+            .build()
+    }
+
+    fn mock_handler_client(latest_block_height: u64) -> MockHandlerTaskClient {
+        let mut client = MockHandlerTaskClient::new();
+        client
+            .expect_latest_block_height()
+            .returning(move || Ok(latest_block_height));
+        client
+    }
+
+    #[test]
+    fn xrpl_multisig_should_deserialize_correct_event() {
+        let verifier = TMAddress::random(PREFIX);
+        let event = signing_started_event(
+            &TMAddress::random(PREFIX),
+            HashMap::from([(verifier.to_string(), rand_public_key())]),
+            100,
+        );
+
+        let event: SigningStartedEvent = event.try_into().unwrap();
+
+        assert_eq!(event.session_id, 1);
+        assert_eq!(event.chain, chain_name!(XRPL));
+        assert_eq!(event.msg.as_ref(), unsigned_tx().as_slice());
+        assert!(event.pub_keys.contains_key(&verifier));
+    }
+
+    #[async_test]
+    async fn should_sign_unsigned_tx_with_signer_account_id() {
+        let multisig = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let pub_key = rand_public_key();
+        let event = signing_started_event(
+            &multisig,
+            HashMap::from([
+                (verifier.to_string(), pub_key.clone()),
+                (TMAddress::random(PREFIX).to_string(), rand_public_key()),
+            ]),
+            100,
+        );
+
+        let expected_digest = xrpl_types::types::message_to_sign(
+            unsigned_tx().to_vec(),
+            &XRPLAccountId::from(&pub_key),
+        )
+        .unwrap();
+        let signature = nonempty::Vec::try_from(vec![2u8; 64]).unwrap();
+
+        let mut client = mock_handler_client(99);
+        let returned_signature = signature.clone();
+        client
+            .expect_sign()
+            .withf(move |key, msg| {
+                matches!(
+                    key.as_ref().map(|key| &key.algorithm),
+                    Some(KeyAlgorithm::Ecdsa)
+                ) && msg.as_slice() == expected_digest.as_slice()
+            })
+            .times(1)
+            .returning(move |_, _| Ok(returned_signature.clone()));
+
+        let handler = handler(verifier.clone(), multisig.clone());
+        let msgs = handler
+            .handle(event.try_into().unwrap(), &mut client)
+            .await
+            .unwrap();
+
+        assert_eq!(msgs.len(), 1);
+        let msg = MsgExecuteContract::from_any(&msgs[0]).unwrap();
+        assert_eq!(msg.sender, *verifier.as_ref());
+        assert_eq!(msg.contract, *multisig.as_ref());
+        assert_eq!(
+            serde_json::from_slice::<ExecuteMsg>(&msg.msg).unwrap(),
+            ExecuteMsg::SubmitSignature {
+                session_id: Uint64::one(),
+                signature: HexBinary::from(signature.as_slice()),
+            }
+        );
+    }
+
+    #[async_test]
+    async fn should_not_sign_if_verifier_is_not_a_participant() {
+        let multisig = TMAddress::random(PREFIX);
+        let event = signing_started_event(
+            &multisig,
+            HashMap::from([(TMAddress::random(PREFIX).to_string(), rand_public_key())]),
+            100,
+        );
+
+        let mut client = mock_handler_client(99);
+        client.expect_sign().never();
+
+        let handler = handler(TMAddress::random(PREFIX), multisig);
+        let msgs = handler
+            .handle(event.try_into().unwrap(), &mut client)
+            .await
+            .unwrap();
+
+        assert!(msgs.is_empty());
+    }
+
+    #[async_test]
+    async fn should_skip_expired_signing_session() {
+        let multisig = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let expiration = 100u64;
+        let event = signing_started_event(
+            &multisig,
+            HashMap::from([(verifier.to_string(), rand_public_key())]),
+            expiration,
+        );
+
+        let mut client = mock_handler_client(expiration);
+        client.expect_sign().never();
+
+        let handler = handler(verifier, multisig);
+        let msgs = handler
+            .handle(event.try_into().unwrap(), &mut client)
+            .await
+            .unwrap();
+
+        assert!(msgs.is_empty());
+    }
+}
@@ -0,0 +1,445 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use ampd::monitoring;
+use ampd::xrpl::json_rpc::XRPLClient;
+use ampd::xrpl::verifier::verify_message;
+use ampd_handlers::voting::{self, Error, PollEventData as _, VotingHandler};
+use ampd_sdk::event::event_handler::{EventHandler, SubscriptionParams};
+use ampd_sdk::grpc::client::EventHandlerClient;
+use async_trait::async_trait;
+use axelar_wasm_std::chain::ChainName;
+use axelar_wasm_std::msg_id::HexTxHash;
+use axelar_wasm_std::voting::{PollId, Vote};
+use cosmrs::{AccountId, Any};
+use events::{try_from, AbciEventTypeFilter, EventType};
+use futures::future::join_all;
+use serde::Deserialize;
+use typed_builder::TypedBuilder;
+use xrpl_http_client::Transaction;
+use xrpl_types::msg::XRPLMessage;
+use xrpl_types::types::{xrpl_account_id_string, XRPLAccountId};
+
+pub type Result<T> = error_stack::Result<T, Error>;
+
+#[derive(Clone, Debug, Deserialize)]
+#[try_from("wasm-messages_poll_started")]
+pub struct PollStartedEvent {
+    poll_id: PollId,
+    source_chain: ChainName,
+    #[serde(with = "xrpl_account_id_string")]
+    source_gateway_address: XRPLAccountId,
+    confirmation_height: u64,
+    expires_at: u64,
+    messages: Vec<XRPLMessage>,
+    participants: Vec<AccountId>,
+}
+
+#[derive(Clone, Debug)]
+pub struct PollEventData(XRPLMessage);
+
+impl voting::PollEventData for PollEventData {
+    type Digest = HexTxHash;
+    type MessageId = HexTxHash;
+    type ChainAddress = XRPLAccountId;
+    type Receipt = Transaction;
+
+    fn tx_hash(&self) -> Self::Digest {
+        self.0.tx_id()
+    }
+
+    fn message_id(&self) -> &Self::MessageId {
+        match &self.0 {
+            XRPLMessage::ProverMessage(message) => &message.tx_id,
+            XRPLMessage::InterchainTransferMessage(message) => &message.tx_id,
+            XRPLMessage::CallContractMessage(message) => &message.tx_id,
+            XRPLMessage::AddGasMessage(message) => &message.tx_id,
+            XRPLMessage::AddReservesMessage(message) => &message.tx_id,
+        }
+    }
+
+    fn verify(
+        &self,
+        source_gateway_address: &Self::ChainAddress,
+        tx_receipt: &Self::Receipt,
+    ) -> Vote {
+        verify_message(source_gateway_address, tx_receipt, &self.0)
+    }
+}
+
+impl From<PollStartedEvent> for voting::PollStartedEvent<PollEventData, XRPLAccountId> {
+    fn from(event: PollStartedEvent) -> Self {
+        voting::PollStartedEvent {
+            poll_data: event.messages.into_iter().map(PollEventData).collect(),
+            poll_id: event.poll_id,
+            source_chain: event.source_chain,
+            source_gateway_address: event.source_gateway_address,
+            expires_at: event.expires_at,
+            confirmation_height: event.confirmation_height,
+            participants: event.participants,
+        }
+    }
+}
+
+#[derive(Debug, TypedBuilder)]
+pub struct Handler<C>
+where
+    C: XRPLClient,
+{
+    pub verifier: AccountId,
+    pub voting_verifier_contract: AccountId,
+    pub chain: ChainName,
+    pub rpc_client: C,
+    pub monitoring_client: monitoring::Client,
+}
+
+#[async_trait]
+impl<C> VotingHandler for Handler<C>
+where
+    C: XRPLClient + Send + Sync,
+{
+    type Digest = HexTxHash;
+    type Receipt = Transaction;
+    type ChainAddress = XRPLAccountId;
+    type EventData = PollEventData;
+
+    fn chain(&self) -> &ChainName {
+        &self.chain
+    }
+
+    fn verifier(&self) -> &AccountId {
+        &self.verifier
+    }
+
+    fn voting_verifier_contract(&self) -> &AccountId {
+        &self.voting_verifier_contract
+    }
+
+    fn monitoring_client(&self) -> &monitoring::Client {
+        &self.monitoring_client
+    }
+
+    async fn finalized_txs(
+        &self,
+        poll_data: &[Self::EventData],
+        _confirmation_height: Option<u64>,
+    ) -> Result<HashMap<Self::Digest, Self::Receipt>> {
+        let tx_ids: HashSet<_> = poll_data.iter().map(|data| data.tx_hash()).collect();
+
+        Ok(join_all(
+            tx_ids
+                .into_iter()
+                .map(|tx_id| self.rpc_client.tx(tx_id.tx_hash)),
+        )
+        .await
+        .into_iter()
+        .filter_map(std::result::Result::unwrap_or_default)
+        .filter_map(|tx_res| {
+            let tx_common = tx_res.tx.common();
+            let tx_hash = tx_common.hash.clone()?;
+
+            if tx_common.validated != Some(true) {
+                return None;
+            }
+            let hex_tx_hash = HexTxHash::from_str(&format!("0x{}", tx_hash.to_lowercase())).ok()?;
+            Some((hex_tx_hash, tx_res.tx))
+        })
+        .collect())
+    }
+}
+
+#[async_trait]
+impl<C> EventHandler for Handler<C>
+where
+    C: XRPLClient + Send + Sync,
+{
+    type Err = Error;
+    type Event = PollStartedEvent;
+
+    async fn handle<HC: EventHandlerClient + Send + 'static>(
+        &self,
+        event: PollStartedEvent,
+        client: &mut HC,
+    ) -> Result<Vec<Any>> {
+        VotingHandler::handle(self, event.into(), client).await
+    }
+
+    fn subscription_params(&self) -> SubscriptionParams {
+        SubscriptionParams::new(
+            vec![AbciEventTypeFilter {
+                event_type: PollStartedEvent::event_type(),
+                contract: self.voting_verifier_contract.clone(),
+                attributes: HashMap::from([(
+                    "source_chain".to_string(),
+                    serde_json::Value::String(self.chain.to_string()),
+                )]),
+            }],
+            false,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use ampd::handlers::test_utils::{into_structured_event, participants};
+    use ampd::monitoring;
+    use ampd::types::TMAddress;
+    use ampd::xrpl::json_rpc::MockXRPLClient;
+    use ampd_sdk::event::event_handler::EventHandler;
+    use ampd_sdk::grpc::client::test_utils::MockHandlerTaskClient;
+    use axelar_wasm_std::chain_name;
+    use axelar_wasm_std::msg_id::HexTxHash;
+    use axelar_wasm_std::voting::Vote;
+    use cosmrs::cosmwasm::MsgExecuteContract;
+    use cosmrs::tx::Msg;
+    use events::Event;
+    use tokio::test as async_test;
+    use voting_verifier::msg::ExecuteMsg;
+    use xrpl_http_client::TxResponse;
+    use xrpl_types::msg::{XRPLAddReservesMessage, XRPLMessage};
+    use xrpl_types::types::XRPLAccountId;
+
+    use super::{Handler, PollStartedEvent};
+
+    const PREFIX: &str = "axelar";
+    const XRPL: &str = "xrpl";
+
+    fn messages() -> Vec<XRPLMessage> {
+        vec![
+            XRPLMessage::AddReservesMessage(XRPLAddReservesMessage {
+                tx_id: HexTxHash::new([1u8; 32]),
+                amount: 100,
+            }),
+            XRPLMessage::AddReservesMessage(XRPLAddReservesMessage {
+                tx_id: HexTxHash::new([2u8; 32]),
+                amount: 200,
+            }),
+        ]
+    }
+
+    // the XRPL voting verifier is not part of this workspace, so the event is assembled by hand
+    fn poll_started_event(participants: Vec<TMAddress>, expires_at: u64) -> cosmwasm_std::Event {
+        cosmwasm_std::Event::new("messages_poll_started")
+            .add_attribute("poll_id", "\"100\"")
+            .add_attribute("source_chain", format!("\"{XRPL}\""))
+            .add_attribute(
+                "source_gateway_address",
+                format!("\"{}\"", XRPLAccountId::new([3u8; 20])),
+            )
+            .add_attribute("confirmation_height", "1")
+            .add_attribute("expires_at", expires_at.to_string())
+            .add_attribute("messages", serde_json::to_string(&messages()).unwrap())
+            .add_attribute(
+                "participants",
+                serde_json::to_string(
+                    &participants
+                        .into_iter()
+                        .map(|addr| addr.to_string())
+                        .collect::<Vec<_>>(),
+                )
+                .unwrap(),
+            )
+    }
+
+    // a validated payment of `amount` drops to the gateway, as sent to add reserves
+    fn add_reserves_tx(tx_id: [u8; 32], amount: u64) -> TxResponse {
+        serde_json::from_value(serde_json::json!({
+            "TransactionType": "Payment",
+            "Account": XRPLAccountId::new([4u8; 20]).to_string(),
+            "Destination": XRPLAccountId::new([3u8; 20]).to_string(),
+            "Amount": amount.to_string(),
+            "Fee": "12",
+            "Flags": 0,
+            "Sequence": 1,
+            "SigningPubKey": "",
+            "TxnSignature": "",
+            "Memos": [{
+                "Memo": {
+                    "MemoType": hex::encode("type"),
+                    "MemoData": hex::encode("add_reserves"),
+                }
+            }],
+            "hash": hex::encode_upper(tx_id),
+            "ledger_index": 1,
+            "date": 1,
+            "validated": true,
+            "meta": {
+                "AffectedNodes": [],
+                "TransactionIndex": 0,
+                "TransactionResult": "tesSUCCESS",
+                "delivered_amount": amount.to_string(),
+            },
+        }))
+        .unwrap()
+    }
+
+    fn votes(result: &[cosmrs::Any]) -> Vec<Vote> {
+        let msg = MsgExecuteContract::from_any(result.first().unwrap()).unwrap();
+        match serde_json::from_slice(&msg.msg).unwrap() {
+            ExecuteMsg::Vote { votes, .. } => votes,
+            _ => panic!("expected vote msg"),
+        }
+    }
+
+    fn mock_handler_client(latest_block_height: u64) -> MockHandlerTaskClient {
+        let mut client = MockHandlerTaskClient::new();
+        client
+            .expect_latest_block_height()
+            .returning(move || Ok(latest_block_height));
+        client
+    }
+
+    #[test]
+    fn xrpl_verify_msg_should_deserialize_correct_event() {
+        let event: Event = into_structured_event(
+            poll_started_event(participants(5, None), 100),
+            &TMAddress::random(PREFIX),
+        );
+        let event: PollStartedEvent = event.try_into().unwrap();
+
+        assert_eq!(event.poll_id, 100u64.into());
+        assert_eq!(event.source_chain, chain_name!(XRPL));
+        assert_eq!(event.source_gateway_address, XRPLAccountId::new([3u8; 20]));
+        assert_eq!(event.messages, messages());
+    }
+
+    #[async_test]
+    async fn should_skip_expired_poll() {
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let expiration = 100u64;
+        let event: Event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), expiration),
+            &voting_verifier_contract,
+        );
+
+        let (monitoring_client, _) = monitoring::test_utils::monitoring_client();
+        let handler = Handler::builder()
+            .verifier(verifier.as_ref().clone())
+            .voting_verifier_contract(voting_verifier_contract.as_ref().clone())
+            .chain(chain_name!(XRPL))
+            .rpc_client(MockXRPLClient::new())
+            .monitoring_client(monitoring_client)
+            .build();
+
+        let mut client = mock_handler_client(expiration + 1);
+        let result = handler.handle(event.try_into().unwrap(), &mut client).await;
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[async_test]
+    async fn should_vote_not_found_for_unknown_txs() {
+        let mut rpc_client = MockXRPLClient::new();
+        rpc_client.expect_tx().returning(|_| Ok(None));
+
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event: Event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier_contract,
+        );
+
+        let (monitoring_client, mut receiver) = monitoring::test_utils::monitoring_client();
+        let handler = Handler::builder()
+            .verifier(verifier.as_ref().clone())
+            .voting_verifier_contract(voting_verifier_contract.as_ref().clone())
+            .chain(chain_name!(XRPL))
+            .rpc_client(rpc_client)
+            .monitoring_client(monitoring_client)
+            .build();
+
+        let mut client = mock_handler_client(99);
+        let result = handler
+            .handle(event.try_into().unwrap(), &mut client)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 1);
+
+        for _ in 0..2 {
+            assert_eq!(
+                receiver.recv().await.unwrap(),
+                monitoring::metrics::Msg::VerificationVote {
+                    vote_decision: Vote::NotFound,
+                    chain_name: chain_name!(XRPL),
+                }
+            );
+        }
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[async_test]
+    async fn should_vote_on_messages_by_their_validated_payments() {
+        let mut rpc_client = MockXRPLClient::new();
+        rpc_client.expect_tx().returning(|tx_id| {
+            // only the first add reserves message has a matching payment
+            Ok((tx_id == [1u8; 32]).then(|| add_reserves_tx(tx_id, 100)))
+        });
+
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event: Event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier_contract,
+        );
+
+        let (monitoring_client, mut receiver) = monitoring::test_utils::monitoring_client();
+        let handler = Handler::builder()
+            .verifier(verifier.as_ref().clone())
+            .voting_verifier_contract(voting_verifier_contract.as_ref().clone())
+            .chain(chain_name!(XRPL))
+            .rpc_client(rpc_client)
+            .monitoring_client(monitoring_client)
+            .build();
+
+        let mut client = mock_handler_client(99);
+        let result = handler
+            .handle(event.try_into().unwrap(), &mut client)
+            .await
+            .unwrap();
+        assert_eq!(votes(&result), vec![Vote::SucceededOnChain, Vote::NotFound]);
+
+        for vote in [Vote::SucceededOnChain, Vote::NotFound] {
+            assert_eq!(
+                receiver.recv().await.unwrap(),
+                monitoring::metrics::Msg::VerificationVote {
+                    vote_decision: vote,
+                    chain_name: chain_name!(XRPL),
+                }
+            );
+        }
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[async_test]
+    async fn should_vote_not_found_for_payments_with_wrong_amount() {
+        let mut rpc_client = MockXRPLClient::new();
+        rpc_client
+            .expect_tx()
+            .returning(|tx_id| Ok(Some(add_reserves_tx(tx_id, 1))));
+
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event: Event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier_contract,
+        );
+
+        let (monitoring_client, _) = monitoring::test_utils::monitoring_client();
+        let handler = Handler::builder()
+            .verifier(verifier.as_ref().clone())
+            .voting_verifier_contract(voting_verifier_contract.as_ref().clone())
+            .chain(chain_name!(XRPL))
+            .rpc_client(rpc_client)
+            .monitoring_client(monitoring_client)
+            .build();
+
+        let mut client = mock_handler_client(99);
+        let result = handler
+            .handle(event.try_into().unwrap(), &mut client)
+            .await
+            .unwrap();
+        assert_eq!(votes(&result), vec![Vote::NotFound, Vote::NotFound]);
+    }
+}
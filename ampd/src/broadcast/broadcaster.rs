@@ -79,7 +79,47 @@ where
     acc_number: u64,
     acc_sequence: Arc<RwLock<u64>>,
     gas_adjustment: f64,
-    gas_price: DecCoin,
+    gas_price: Arc<RwLock<DecCoin>>,
+}
+
+/// Multiplier applied to the configured gas adjustment and gas price when a transaction is re-broadcast.
+///
+/// The bumped gas adjustment never exceeds `max_gas_adjustment`, and the bumped gas price amount never exceeds
+/// `max_gas_price`. Values that are already above their ceiling are left untouched.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FeeBump {
+    pub multiplier: f64,
+    pub max_gas_adjustment: f64,
+    pub max_gas_price: f64,
+}
+
+impl FeeBump {
+    pub const NONE: FeeBump = FeeBump {
+        multiplier: 1.0,
+        max_gas_adjustment: 0.0,
+        max_gas_price: 0.0,
+    };
+
+    fn apply(&self, value: f64, ceiling: f64) -> f64 {
+        (value * self.multiplier).min(ceiling.max(value))
+    }
+}
+
+/// Options for broadcasting a single transaction
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TxOptions {
+    pub fee_bump: FeeBump,
+    /// Block height after which the transaction can no longer be included on chain, 0 means no timeout
+    pub timeout_height: u64,
+}
+
+impl Default for TxOptions {
+    fn default() -> Self {
+        Self {
+            fee_bump: FeeBump::NONE,
+            timeout_height: 0,
+        }
+    }
 }
 
 // Required parameters for the builder
@@ -153,10 +193,10 @@ where
             acc_number: account.account_number,
             acc_sequence: Arc::new(RwLock::new(account.sequence)),
             gas_adjustment,
-            gas_price,
+            gas_price: Arc::new(RwLock::new(gas_price.clone())),
         };
 
-        let denom: Denom = broadcaster.gas_price.denom.clone().into();
+        let denom: Denom = gas_price.denom.into();
         let address = broadcaster.address.clone();
 
         let balance = cosmos::balance(&mut broadcaster.client, &address, &denom)
@@ -196,6 +236,7 @@ where
     ///
     /// * `Error::EstimateGas` - If the gas estimation fails
     pub async fn estimate_gas(&mut self, msgs: Vec<Any>) -> Result<Gas> {
+        let fee = self.simulation_fee().await?;
         let mut acc_sequence = self.acc_sequence.write().await;
 
         let res = match cosmos::estimate_gas(
@@ -203,6 +244,7 @@ where
             msgs.clone(),
             self.pub_key,
             *acc_sequence,
+            fee.clone(),
         )
         .await
         {
@@ -223,7 +265,8 @@ where
 
                     *acc_sequence = expected_seq;
 
-                    cosmos::estimate_gas(&mut self.client, msgs, self.pub_key, *acc_sequence).await
+                    cosmos::estimate_gas(&mut self.client, msgs, self.pub_key, *acc_sequence, fee)
+                        .await
                 }
                 // Return the error if not a sequence mismatch error
                 None => Err(e),
@@ -267,7 +310,27 @@ where
         Fut: Future<Output = error_stack::Result<Vec<u8>, Err>>,
         Err: Context,
     {
-        let fee = self.estimate_fee(msgs.clone()).await?;
+        self.broadcast_with_options(msgs, TxOptions::default(), sign_fn)
+            .await
+    }
+
+    /// Broadcasts a transaction like [`Broadcaster::broadcast`], but with the gas adjustment and
+    /// gas price bumped according to `options.fee_bump` and the given timeout height.
+    ///
+    /// This is used to broadcast transactions whose messages can be broadcast again if they do not
+    /// make it on chain, e.g. because they ran out of gas or their fee was too low to be included in a block.
+    pub async fn broadcast_with_options<F, Fut, Err>(
+        &mut self,
+        msgs: Vec<Any>,
+        options: TxOptions,
+        sign_fn: F,
+    ) -> Result<TxResponse>
+    where
+        F: Fn(Vec<u8>) -> Fut,
+        Fut: Future<Output = error_stack::Result<Vec<u8>, Err>>,
+        Err: Context,
+    {
+        let fee = self.estimate_fee(msgs.clone(), options.fee_bump).await?;
         let mut acc_sequence = self.acc_sequence.write().await;
 
         let tx = Tx::builder()
@@ -275,6 +338,7 @@ where
             .pub_key(self.pub_key)
             .acc_sequence(*acc_sequence)
             .fee(fee)
+            .timeout_height(options.timeout_height)
             .build()
             .sign_with(&self.chain_id, self.acc_number, sign_fn)
             .await
@@ -298,13 +362,50 @@ where
         }
     }
 
-    async fn estimate_fee(&mut self, msgs: Vec<Any>) -> Result<Fee> {
-        let gas = self.estimate_gas(msgs).await? as f64 * self.gas_adjustment;
+    /// Refetches the account from the chain and resets the local account sequence to the on-chain value.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::AccountQuery` - If querying the account information from the blockchain fails
+    pub async fn refetch_sequence(&mut self) -> Result<()> {
+        let acc_sequence = self.acc_sequence.write().await;
+
+        reset_sequence(&mut self.client, &self.address, acc_sequence).await
+    }
+
+    /// Returns a handle to the gas price used for fee calculation.
+    ///
+    /// The gas price is shared between all clones of this broadcaster,
+    /// so updating it through the handle affects all subsequent broadcasts.
+    pub fn gas_price(&self) -> Arc<RwLock<DecCoin>> {
+        self.gas_price.clone()
+    }
+
+    /// Returns the fee for a single unit of gas at the current gas price, so the simulation
+    /// pays a fee in the right denom and includes the gas cost of deducting it.
+    async fn simulation_fee(&self) -> Result<Fee> {
+        let DecCoin { amount, denom } = self.gas_price.read().await.clone();
+
+        Ok(Fee::from_amount_and_gas(
+            Coin::new(
+                cast(f64::from(amount).ceil()).ok_or(report!(Error::FeeAdjustment))?,
+                denom.as_ref(),
+            )
+            .change_context(Error::FeeAdjustment)?,
+            1u64,
+        ))
+    }
+
+    async fn estimate_fee(&mut self, msgs: Vec<Any>, fee_bump: FeeBump) -> Result<Fee> {
+        let DecCoin { amount, denom } = self.gas_price.read().await.clone();
+        let gas_adjustment = fee_bump.apply(self.gas_adjustment, fee_bump.max_gas_adjustment);
+        let gas_price = fee_bump.apply(amount.into(), fee_bump.max_gas_price);
+        let gas = self.estimate_gas(msgs).await? as f64 * gas_adjustment;
 
         let fee = Fee::from_amount_and_gas(
             Coin::new(
-                cast(gas.mul(self.gas_price.amount).ceil()).ok_or(report!(Error::FeeAdjustment))?,
-                self.gas_price.denom.as_ref(),
+                cast(gas.mul(gas_price).ceil()).ok_or(report!(Error::FeeAdjustment))?,
+                denom.as_ref(),
             )
             .change_context(Error::FeeAdjustment)?,
             cast::<f64, u64>(gas).ok_or(report!(Error::FeeAdjustment))?,
@@ -661,6 +762,43 @@ mod tests {
         assert_eq!(*broadcaster.acc_sequence.read().await, sequence);
     }
 
+    #[tokio::test]
+    async fn estimate_gas_should_simulate_with_fee_at_current_gas_price() {
+        let pub_key = random_cosmos_public_key();
+        let address: TMAddress = pub_key.account_id(PREFIX).unwrap().into();
+
+        let (mut mock_client, mut seq) =
+            setup_client_with_balance(address.to_string(), 42, 10, "1000000".to_string());
+
+        mock_client
+            .expect_simulate()
+            .once()
+            .in_sequence(&mut seq)
+            .withf(|req| {
+                let fee = test_utils::decode_gas_fee(&req.tx_bytes);
+                let amount = fee.amount.first().unwrap();
+
+                fee.gas_limit == 1 && amount.amount == "3" && amount.denom == DENOM
+            })
+            .return_once(|_| {
+                Ok(SimulateResponse {
+                    gas_info: Some(GasInfo {
+                        gas_wanted: 0,
+                        gas_used: 100000,
+                    }),
+                    result: None,
+                })
+            });
+
+        let mut broadcaster = setup_broadcaster(mock_client, pub_key).await.unwrap();
+        *broadcaster.gas_price().write().await = DecCoin::new(2.5, DENOM).unwrap();
+
+        assert_eq!(
+            broadcaster.estimate_gas(vec![dummy_msg()]).await.unwrap(),
+            100000
+        );
+    }
+
     #[tokio::test]
     async fn estimate_gas_should_retry_with_expected_sequence_number() {
         let pub_key = random_cosmos_public_key();
@@ -819,6 +957,136 @@ mod tests {
         assert_eq!(*broadcaster.acc_sequence.read().await, sequence + 1);
     }
 
+    #[tokio::test]
+    async fn broadcast_with_options_should_bump_fee_up_to_ceiling_and_set_timeout_height() {
+        let pub_key = random_cosmos_public_key();
+        let address: TMAddress = pub_key.account_id(PREFIX).unwrap().into();
+        let account_number = 42u64;
+        let sequence = 10u64;
+        let gas_adjustment = 2.0;
+        let gas_price_amount = 0.025;
+        let simulated_gas_used = 100000u64;
+        let timeout_height = 100u64;
+        let options = TxOptions {
+            fee_bump: FeeBump {
+                multiplier: 3.0,
+                max_gas_adjustment: 3.0,
+                max_gas_price: 0.05,
+            },
+            timeout_height,
+        };
+        let expected_gas_limit = 300000u64; // 100000 * min(2 * 3, 3) = 300000
+        let expected_fee_amount = 15000u64; // 300000 * min(0.025 * 3, 0.05) = 15000
+
+        let (mut mock_client, mut seq) = setup_client_with_balance(
+            address.to_string(),
+            account_number,
+            sequence,
+            "1000000".to_string(),
+        );
+
+        mock_client
+            .expect_simulate()
+            .once()
+            .in_sequence(&mut seq)
+            .return_once(move |_| {
+                Ok(SimulateResponse {
+                    gas_info: Some(GasInfo {
+                        gas_wanted: 0,
+                        gas_used: simulated_gas_used,
+                    }),
+                    result: None,
+                })
+            });
+        mock_client
+            .expect_broadcast_tx()
+            .once()
+            .in_sequence(&mut seq)
+            .withf(move |req| {
+                let actual_fee = test_utils::decode_gas_fee(&req.tx_bytes);
+
+                actual_fee.gas_limit == expected_gas_limit
+                    && actual_fee.amount.first().unwrap().amount == expected_fee_amount.to_string()
+                    && test_utils::decode_timeout_height(&req.tx_bytes) == timeout_height
+            })
+            .return_once(move |_| {
+                Ok(BroadcastTxResponse {
+                    tx_response: Some(TxResponse {
+                        txhash: "ABC123".to_string(),
+                        code: 0,
+                        ..Default::default()
+                    }),
+                })
+            });
+
+        let mut broadcaster =
+            setup_broadcaster_with_gas_info(mock_client, pub_key, gas_adjustment, gas_price_amount)
+                .await
+                .unwrap();
+
+        let sign_fn = |_: Vec<u8>| async { Ok::<Vec<u8>, Report<cosmos::Error>>(vec![0u8; 64]) };
+
+        let result = broadcaster
+            .broadcast_with_options(vec![dummy_msg()], options, sign_fn)
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(*broadcaster.acc_sequence.read().await, sequence + 1);
+    }
+
+    #[test]
+    fn fee_bump_should_not_lower_values_above_ceiling() {
+        let fee_bump = FeeBump {
+            multiplier: 1.5,
+            max_gas_adjustment: 1.0,
+            max_gas_price: 1.0,
+        };
+
+        assert_eq!(fee_bump.apply(2.0, fee_bump.max_gas_adjustment), 2.0);
+        assert_eq!(fee_bump.apply(0.5, fee_bump.max_gas_adjustment), 0.75);
+        assert_eq!(
+            FeeBump::NONE.apply(0.025, FeeBump::NONE.max_gas_price),
+            0.025
+        );
+    }
+
+    #[tokio::test]
+    async fn refetch_sequence_should_reset_sequence_to_on_chain_value() {
+        let pub_key = random_cosmos_public_key();
+        let address: TMAddress = pub_key.account_id(PREFIX).unwrap().into();
+        let account_number = 42u64;
+        let sequence = 10u64;
+        let on_chain_sequence = 7u64;
+
+        let (mut mock_client, mut seq) = setup_client_with_balance(
+            address.to_string(),
+            account_number,
+            sequence,
+            "1000000".to_string(),
+        );
+        let base_account = BaseAccount {
+            address: address.to_string(),
+            pub_key: None,
+            account_number,
+            sequence: on_chain_sequence,
+        };
+        mock_client
+            .expect_account()
+            .once()
+            .in_sequence(&mut seq)
+            .return_once(move |_| {
+                Ok(QueryAccountResponse {
+                    account: Some(Any::from_msg(&base_account).unwrap()),
+                })
+            });
+
+        let mut broadcaster = setup_broadcaster(mock_client, pub_key).await.unwrap();
+        assert_eq!(*broadcaster.acc_sequence.read().await, sequence);
+
+        broadcaster.refetch_sequence().await.unwrap();
+        assert_eq!(*broadcaster.acc_sequence.read().await, on_chain_sequence);
+    }
+
     #[tokio::test]
     async fn estimate_gas_should_fail_with_gas_info_missing() {
         let pub_key = random_cosmos_public_key();
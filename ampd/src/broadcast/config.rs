@@ -4,6 +4,7 @@ use cosmrs::tendermint::chain::Id;
 use cosmrs::Gas;
 use serde::{Deserialize, Serialize};
 
+use super::broadcaster::FeeBump;
 use super::dec_coin::DecCoin;
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -30,6 +31,88 @@ pub struct Config {
     // - Too small may cause confirmation requests to be dropped during traffic spikes
     // - Too large may consume excessive memory if confirmations become backlogged
     pub tx_confirmation_queue_cap: usize,
+    #[serde(default)]
+    pub rebroadcast: RebroadcastConfig,
+    #[serde(default)]
+    pub gas_price_oracle: GasPriceOracleConfig,
+}
+
+/// Opt-in recovery policy for txs that are not included on chain or that fail because of
+/// insufficient gas or fees. The messages of such txs are broadcast again with a fee that
+/// grows by `fee_bump_factor` on every attempt, capped by `max_gas_adjustment` and `max_gas_price`.
+/// Disabled by default, i.e. `max_attempts` is 0.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct RebroadcastConfig {
+    // Maximum number of times the messages of a single tx are broadcast again before they are dropped.
+    pub max_attempts: u32,
+    // Factor applied to the gas adjustment and the gas price on every re-broadcast attempt.
+    pub fee_bump_factor: f64,
+    // Upper bound for the bumped gas adjustment.
+    pub max_gas_adjustment: f64,
+    // Upper bound for the bumped gas price amount, denominated in the denom of `gas_price`.
+    // It is absolute, so it also holds when the gas price oracle raises the current gas price.
+    pub max_gas_price: f64,
+    // Number of blocks after which a broadcast tx times out. A tx that is not found on chain is only
+    // broadcast again once its timeout height has passed, so it cannot be included twice.
+    pub timeout_blocks: u64,
+}
+
+impl RebroadcastConfig {
+    /// Returns true if the messages of failed txs should be broadcast again
+    pub fn enabled(&self) -> bool {
+        self.max_attempts > 0
+    }
+
+    /// Returns the fee bump to apply for the given re-broadcast attempt, starting at 1.
+    pub fn fee_bump(&self, attempt: u32) -> FeeBump {
+        FeeBump {
+            multiplier: self
+                .fee_bump_factor
+                .powi(i32::try_from(attempt).unwrap_or(i32::MAX)),
+            max_gas_adjustment: self.max_gas_adjustment,
+            max_gas_price: self.max_gas_price,
+        }
+    }
+}
+
+/// Optional gas price discovery. If enabled, the gas price periodically follows the `minimum-gas-prices`
+/// setting of the connected Axelar node, clamped between `floor` and `ceiling`.
+/// This is the lowest price the node admits to its own mempool, not a network-wide fee market.
+/// The configured `gas_price` only determines the denom then, and is used until the first query succeeds.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct GasPriceOracleConfig {
+    pub enabled: bool,
+    #[serde(with = "humantime_serde")]
+    pub query_interval: Duration,
+    // Lower bound for the queried gas price amount, denominated in the denom of `gas_price`.
+    pub floor: f64,
+    // Upper bound for the queried gas price amount, denominated in the denom of `gas_price`.
+    pub ceiling: f64,
+}
+
+impl Default for GasPriceOracleConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            query_interval: Duration::from_secs(60),
+            floor: 0.00005,
+            ceiling: 0.05,
+        }
+    }
+}
+
+impl Default for RebroadcastConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 0,
+            fee_bump_factor: 1.25,
+            max_gas_adjustment: 3.0,
+            max_gas_price: 0.05,
+            timeout_blocks: 20,
+        }
+    }
 }
 
 impl Default for Config {
@@ -47,6 +130,35 @@ impl Default for Config {
             broadcast_interval: Duration::from_secs(5),
             tx_confirmation_buffer_size: 10,
             tx_confirmation_queue_cap: 1000,
+            rebroadcast: RebroadcastConfig::default(),
+            gas_price_oracle: GasPriceOracleConfig::default(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::RebroadcastConfig;
+
+    #[test]
+    fn rebroadcast_should_be_disabled_by_default() {
+        assert!(!RebroadcastConfig::default().enabled());
+    }
+
+    #[test]
+    fn fee_bump_should_grow_with_attempts() {
+        let config = RebroadcastConfig {
+            fee_bump_factor: 2.0,
+            ..RebroadcastConfig::default()
+        };
+
+        assert_eq!(config.fee_bump(0).multiplier, 1.0);
+        assert_eq!(config.fee_bump(1).multiplier, 2.0);
+        assert_eq!(config.fee_bump(3).multiplier, 8.0);
+        assert_eq!(
+            config.fee_bump(1).max_gas_adjustment,
+            config.max_gas_adjustment
+        );
+        assert_eq!(config.fee_bump(1).max_gas_price, config.max_gas_price);
+    }
+}
@@ -1,21 +1,32 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::mem;
+
+use axelar_wasm_std::nonempty;
 use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
-use error_stack::{report, ResultExt};
+use cosmrs::Any;
+use error_stack::{report, Report, ResultExt};
+use futures::stream::FuturesUnordered;
 use futures::StreamExt;
 use report::LoggableError;
 use thiserror::Error;
-use tokio::sync::mpsc;
-use tokio_stream::wrappers::ReceiverStream;
+use tokio::sync::{mpsc, watch};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, instrument, warn};
 use valuable::Valuable;
 
 use crate::asyncutil::future::{with_retry, RetryPolicy};
 use crate::monitoring::metrics;
-use crate::monitoring::metrics::{Msg, Stage};
+use crate::monitoring::metrics::{Msg, Queue, RebroadcastReason, Stage};
 use crate::{cosmos, monitoring};
 
 type Result<T> = error_stack::Result<T, Error>;
 
+// See: https://github.com/cosmos/cosmos-sdk/blob/main/types/errors/errors.go
+const SDK_CODESPACE: &str = "sdk";
+const SDK_OUT_OF_GAS_CODE: u32 = 11;
+const SDK_INSUFFICIENT_FEE_CODE: u32 = 13;
+const SDK_WRONG_SEQUENCE_CODE: u32 = 32;
+
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("tx {0} not found on chain")]
@@ -24,11 +35,32 @@ pub enum Error {
     FailureOnChain(Box<TxResponse>), // It is boxed to reduce the size of the enum.
     #[error("failed to query for tx {0}")]
     TxQuery(String),
+    #[error("tx {0} was not included on chain before its timeout height")]
+    TimedOut(String),
+}
+
+/// A broadcasted transaction waiting to be confirmed, together with the messages it carries,
+/// so they can be broadcast again if the transaction does not make it on chain.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingTx {
+    pub tx_hash: String,
+    pub msgs: nonempty::Vec<Any>,
+    /// Number of times the messages have been re-broadcast so far, 0 for the original broadcast
+    pub attempt: u32,
+    /// Block height after which the transaction can no longer be included on chain, 0 means no timeout
+    pub timeout_height: u64,
 }
 
-/// Client interface for submitting transaction hashes to be confirmed by a `TxConfirmer`.
+/// A transaction whose messages should be broadcast again
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rebroadcast {
+    pub tx: PendingTx,
+    pub reason: RebroadcastReason,
+}
+
+/// Client interface for submitting transactions to be confirmed by a `TxConfirmer`.
 /// Allows external components to request transaction confirmation tracking in a decoupled manner.
-pub type TxConfirmerClient = mpsc::Sender<String>;
+pub type TxConfirmerClient = mpsc::Sender<PendingTx>;
 
 /// Component responsible for confirming transactions on a Cosmos blockchain.
 /// Monitors transaction execution status by querying the chain until transactions
@@ -40,11 +72,13 @@ pub struct TxConfirmer<T>
 where
     T: cosmos::CosmosClient + Clone,
 {
-    rx: mpsc::Receiver<String>,
+    rx: mpsc::Receiver<PendingTx>,
     client: T,
     retry_policy: RetryPolicy,
     buffer_size: usize,
     monitoring_client: monitoring::Client,
+    rebroadcast_tx: Option<mpsc::Sender<Rebroadcast>>,
+    latest_block_height: Option<watch::Receiver<u64>>,
 }
 
 impl<T> TxConfirmer<T>
@@ -58,7 +92,7 @@ where
     /// * `retry_policy` - Policy defining retry behavior for failed transaction queries
     ///
     /// # Returns
-    /// A tuple containing the confirmer instance and a client for submitting transaction hashes
+    /// A tuple containing the confirmer instance and a client for submitting transactions
     pub fn new_confirmer_and_client(
         client: T,
         retry_policy: RetryPolicy,
@@ -73,19 +107,47 @@ where
             retry_policy,
             buffer_size,
             monitoring_client,
+            rebroadcast_tx: None,
+            latest_block_height: None,
         };
 
         (confirmer, tx)
     }
 
+    /// Enables reporting of transactions that should be broadcast again.
+    ///
+    /// Transactions that failed on chain because they ran out of gas, paid an insufficient fee
+    /// or used a wrong account sequence are sent to the returned receiver.
+    /// Transactions that are not found on chain are only sent once `latest_block_height` has passed
+    /// their timeout height and they are still not found, so they cannot be included on chain twice.
+    /// Transactions without a timeout height are never broadcast again because they are not found.
+    /// If the receiver cannot keep up, the affected transactions are logged and dropped,
+    /// so the confirmer never blocks on it.
+    pub fn with_rebroadcasts(
+        mut self,
+        queue_cap: usize,
+        latest_block_height: watch::Receiver<u64>,
+    ) -> (Self, mpsc::Receiver<Rebroadcast>) {
+        let (tx, rx) = mpsc::channel(queue_cap);
+        self.rebroadcast_tx = Some(tx);
+        self.latest_block_height = Some(latest_block_height);
+
+        (self, rx)
+    }
+
     #[instrument(skip_all)]
-    /// Starts the transaction confirmation process, continuously monitoring submitted transaction hashes.
+    /// Starts the transaction confirmation process, continuously monitoring submitted transactions.
     ///
-    /// Processes transaction hashes from the receiver channel, concurrently confirming
+    /// Processes transactions from the receiver channel, concurrently confirming
     /// up to `TX_CONFIRMATION_BUFFER_SIZE` transactions. For each transaction, it queries
     /// the blockchain repeatedly according to the retry policy until the transaction
     /// is confirmed or definitely failed.
     ///
+    /// Transactions that are not found on chain but have a timeout height wait in a separate queue
+    /// until the latest block height has passed their timeout height, so they don't take up
+    /// any of the concurrent confirmations while waiting. They are queried once more after that.
+    /// Transactions still waiting when the token is cancelled are dropped.
+    ///
     /// # Returns
     /// A Result indicating whether the confirmer completed successfully
     pub async fn run(self, token: CancellationToken) -> Result<()> {
@@ -95,28 +157,102 @@ where
             retry_policy,
             monitoring_client,
             buffer_size,
+            rebroadcast_tx,
+            mut latest_block_height,
         } = self;
-        let stream = ReceiverStream::new(rx)
-            .take_until(token.cancelled())
-            .inspect(|tx_hash| info!(tx_hash, "received tx hash to confirm"))
-            .map(|tx_hash| async {
-                let (res, elapsed) =
-                    metrics::timed(|| async { confirm_tx(&client, tx_hash, retry_policy).await })
-                        .await;
-
-                monitoring_client.metrics().record_metric(Msg::StageResult {
-                    stage: Stage::TransactionConfirmation,
-                    success: res.is_ok(),
-                    duration: elapsed,
-                });
+        let incoming = futures::stream::unfold(rx, |mut rx| {
+            let monitoring_client = &monitoring_client;
 
-                res
-            })
-            .buffer_unordered(buffer_size);
+            async move {
+                let tx = rx.recv().await?;
+                record_queue_depth(monitoring_client, rx.len());
 
-        tokio::pin!(stream);
-        while let Some(result) = stream.next().await {
-            log_confirm_tx_result(result);
+                Some((tx, rx))
+            }
+        })
+        .take_until(token.cancelled())
+        .fuse();
+        tokio::pin!(incoming);
+
+        let mut confirmations = FuturesUnordered::new();
+        let mut timeouts = TimeoutQueue::default();
+        let mut timed_out = VecDeque::new();
+        let mut incoming_closed = false;
+
+        loop {
+            while confirmations.len() < buffer_size {
+                let Some(tx) = timed_out.pop_front() else {
+                    break;
+                };
+                confirmations.push(confirm_pending_tx(
+                    &client,
+                    tx,
+                    retry_policy,
+                    &monitoring_client,
+                    true,
+                ));
+            }
+
+            if incoming_closed && confirmations.is_empty() && timeouts.is_empty() {
+                break;
+            }
+
+            tokio::select! {
+                tx = incoming.next(), if !incoming_closed && confirmations.len() < buffer_size => {
+                    match tx {
+                        Some(tx) => {
+                            info!(tx_hash = tx.tx_hash, "received tx hash to confirm");
+                            confirmations.push(confirm_pending_tx(
+                                &client,
+                                tx,
+                                retry_policy,
+                                &monitoring_client,
+                                false,
+                            ));
+                        }
+                        None => incoming_closed = true,
+                    }
+                }
+                Some((tx, result)) = confirmations.next(), if !confirmations.is_empty() => {
+                    match (result, latest_block_height.as_ref()) {
+                        (Err(err), Some(latest_block_height))
+                            if matches!(err.current_context(), Error::NotFound(_))
+                                && tx.timeout_height > 0 =>
+                        {
+                            if *latest_block_height.borrow() > tx.timeout_height {
+                                timed_out.push_back(tx);
+                            } else {
+                                timeouts.push(tx);
+                            }
+                        }
+                        (result, _) => {
+                            handle_confirm_tx_result(rebroadcast_tx.as_ref(), tx, result)
+                        }
+                    }
+                }
+                changed = wait_for_block(latest_block_height.as_mut()), if !timeouts.is_empty() => {
+                    match changed {
+                        Ok(height) => timed_out.extend(timeouts.pop_timed_out(height)),
+                        Err(_) => {
+                            latest_block_height = None;
+                            for tx in timeouts.drain() {
+                                let err = report!(Error::NotFound(tx.tx_hash.clone()))
+                                    .attach_printable(
+                                        "block height monitor stopped before the tx timed out",
+                                    );
+                                handle_confirm_tx_result(rebroadcast_tx.as_ref(), tx, Err(err));
+                            }
+                        }
+                    }
+                }
+                _ = token.cancelled(), if !timeouts.is_empty() => {
+                    let dropped = timeouts.drain().count();
+                    warn!(
+                        count = dropped,
+                        "dropping txs that are waiting for their timeout height"
+                    );
+                }
+            }
         }
 
         info!("tx confirmer exited");
@@ -125,6 +261,95 @@ where
     }
 }
 
+/// Transactions that were not found on chain, keyed by the height after which they time out
+#[derive(Default)]
+struct TimeoutQueue(BTreeMap<u64, Vec<PendingTx>>);
+
+impl TimeoutQueue {
+    fn push(&mut self, tx: PendingTx) {
+        self.0.entry(tx.timeout_height).or_default().push(tx);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Removes the transactions that can no longer be included on chain at the given block height
+    fn pop_timed_out(&mut self, height: u64) -> impl Iterator<Item = PendingTx> {
+        let waiting = self.0.split_off(&height);
+
+        mem::replace(&mut self.0, waiting).into_values().flatten()
+    }
+
+    fn drain(&mut self) -> impl Iterator<Item = PendingTx> {
+        mem::take(&mut self.0).into_values().flatten()
+    }
+}
+
+async fn wait_for_block(
+    latest_block_height: Option<&mut watch::Receiver<u64>>,
+) -> std::result::Result<u64, watch::error::RecvError> {
+    match latest_block_height {
+        Some(latest_block_height) => {
+            latest_block_height.changed().await?;
+            Ok(*latest_block_height.borrow_and_update())
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Queries the status of a transaction. After its timeout height, a transaction that is still
+/// not found can no longer be included on chain, so it is reported as timed out.
+async fn confirm_pending_tx<T>(
+    client: &T,
+    tx: PendingTx,
+    retry_policy: RetryPolicy,
+    monitoring_client: &monitoring::Client,
+    after_timeout: bool,
+) -> (PendingTx, Result<TxResponse>)
+where
+    T: cosmos::CosmosClient + Clone,
+{
+    if after_timeout {
+        let res = confirm_tx(client, tx.tx_hash.clone(), retry_policy)
+            .await
+            .map_err(|err| {
+                if matches!(err.current_context(), Error::NotFound(_)) {
+                    err.change_context(Error::TimedOut(tx.tx_hash.clone()))
+                } else {
+                    err
+                }
+            });
+
+        return (tx, res);
+    }
+
+    let (res, elapsed) =
+        metrics::timed(|| async { confirm_tx(client, tx.tx_hash.clone(), retry_policy).await })
+            .await;
+
+    monitoring_client.metrics().record_metric(Msg::StageResult {
+        stage: Stage::TransactionConfirmation,
+        success: res.is_ok(),
+        duration: elapsed,
+    });
+
+    (tx, res)
+}
+
+fn handle_confirm_tx_result(
+    rebroadcast_tx: Option<&mpsc::Sender<Rebroadcast>>,
+    tx: PendingTx,
+    result: Result<TxResponse>,
+) {
+    let reason = result.as_ref().err().and_then(rebroadcast_reason);
+    log_confirm_tx_result(result);
+
+    if let (Some(rebroadcast_tx), Some(reason)) = (rebroadcast_tx, reason) {
+        request_rebroadcast(rebroadcast_tx, Rebroadcast { tx, reason });
+    }
+}
+
 pub async fn confirm_tx<T>(
     client: &T,
     tx_hash: String,
@@ -153,6 +378,37 @@ where
         .ok_or(report!(Error::NotFound(tx_hash)))
 }
 
+/// Returns the reason why a transaction should be broadcast again, or `None` if broadcasting
+/// it again would not help or its status is unknown.
+fn rebroadcast_reason(err: &Report<Error>) -> Option<RebroadcastReason> {
+    match err.current_context() {
+        Error::TimedOut(_) => Some(RebroadcastReason::NotIncluded),
+        Error::FailureOnChain(res) if res.codespace == SDK_CODESPACE => match res.code {
+            SDK_OUT_OF_GAS_CODE => Some(RebroadcastReason::OutOfGas),
+            SDK_INSUFFICIENT_FEE_CODE => Some(RebroadcastReason::InsufficientFee),
+            SDK_WRONG_SEQUENCE_CODE => Some(RebroadcastReason::SequenceMismatch),
+            _ => None,
+        },
+        Error::NotFound(_) | Error::FailureOnChain(_) | Error::TxQuery(_) => None,
+    }
+}
+
+fn record_queue_depth(monitoring_client: &monitoring::Client, depth: usize) {
+    monitoring_client.metrics().record_metric(Msg::QueueDepth {
+        queue: Queue::TxConfirmation,
+        depth,
+    });
+}
+
+fn request_rebroadcast(rebroadcast_tx: &mpsc::Sender<Rebroadcast>, rebroadcast: Rebroadcast) {
+    if let Err(err) = rebroadcast_tx.try_send(rebroadcast) {
+        warn!(
+            error = %err,
+            "failed to request re-broadcast of tx, its messages are dropped"
+        );
+    }
+}
+
 fn log_confirm_tx_result(result: Result<TxResponse>) {
     match result {
         Ok(res) => info!(tx_hash = res.txhash, "tx succeeded on chain"),
@@ -176,6 +432,12 @@ fn log_confirm_tx_result(result: Result<TxResponse>) {
                     tx_hash, "failed to query for tx"
                 )
             }
+            Error::TimedOut(tx_hash) => {
+                warn!(
+                    err = LoggableError::from(&err).as_value(),
+                    tx_hash, "tx timed out before it was included on chain"
+                )
+            }
         },
     }
 }
@@ -188,15 +450,55 @@ mod tests {
     use cosmos_sdk_proto::cosmos::tx::v1beta1::{GetTxRequest, GetTxResponse};
     use mockall::predicate;
     use report::ErrorExt;
+    use tokio::sync::watch;
     use tokio_util::sync::CancellationToken;
     use tonic::Status;
     use tracing_test::traced_test;
 
+    use super::{PendingTx, Rebroadcast};
     use crate::asyncutil::future::RetryPolicy;
     use crate::cosmos;
-    use crate::monitoring::metrics::{Msg, Stage};
+    use crate::monitoring::metrics::{Msg, Queue, RebroadcastReason, Stage};
     use crate::monitoring::test_utils;
 
+    fn pending_tx(tx_hash: &str) -> PendingTx {
+        PendingTx {
+            tx_hash: tx_hash.to_string(),
+            msgs: vec![cosmrs::Any {
+                type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                value: vec![1, 2, 3],
+            }]
+            .try_into()
+            .unwrap(),
+            attempt: 0,
+            timeout_height: 0,
+        }
+    }
+
+    fn client_returning_tx_response(code: u32, codespace: &str) -> cosmos::MockCosmosClient {
+        let codespace = codespace.to_string();
+
+        let mut client = cosmos::MockCosmosClient::default();
+        client.expect_clone().return_once(move || {
+            let mut client = cosmos::MockCosmosClient::default();
+            client.expect_tx().return_once(move |req| {
+                Ok(GetTxResponse {
+                    tx_response: Some(TxResponse {
+                        code,
+                        codespace,
+                        txhash: req.hash,
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                })
+            });
+
+            client
+        });
+
+        client
+    }
+
     #[tokio::test(start_paused = true)]
     async fn tx_confirmer_should_exit_when_token_is_cancelled() {
         let tx_hash = "tx_hash";
@@ -238,7 +540,7 @@ mod tests {
 
         let token = CancellationToken::new();
         let handle = tokio::spawn(confirmer.run(token.child_token()));
-        confirmer_client.send(tx_hash.to_string()).await.unwrap();
+        confirmer_client.send(pending_tx(tx_hash)).await.unwrap();
         token.cancel();
 
         let result = tokio::time::timeout(Duration::from_secs(5), handle)
@@ -287,12 +589,19 @@ mod tests {
             queue_cap,
             monitoring_client,
         );
-        confirmer_client.send(tx_hash.to_string()).await.unwrap();
+        confirmer_client.send(pending_tx(tx_hash)).await.unwrap();
         drop(confirmer_client);
         confirmer.run(CancellationToken::new()).await.unwrap();
 
         assert!(logs_contain("tx succeeded on chain"));
 
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            Msg::QueueDepth {
+                queue: Queue::TxConfirmation,
+                depth: 0,
+            }
+        );
         let metrics = receiver.recv().await.unwrap();
         assert!(matches!(
             metrics,
@@ -344,12 +653,19 @@ mod tests {
             queue_cap,
             monitoring_client,
         );
-        confirmer_client.send(tx_hash.to_string()).await.unwrap();
+        confirmer_client.send(pending_tx(tx_hash)).await.unwrap();
         drop(confirmer_client);
         confirmer.run(CancellationToken::new()).await.unwrap();
 
         assert!(logs_contain("tx failed on chain"));
 
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            Msg::QueueDepth {
+                queue: Queue::TxConfirmation,
+                depth: 0,
+            }
+        );
         let metrics = receiver.recv().await.unwrap();
         assert!(matches!(
             metrics,
@@ -392,12 +708,19 @@ mod tests {
             queue_cap,
             monitoring_client,
         );
-        confirmer_client.send(tx_hash.to_string()).await.unwrap();
+        confirmer_client.send(pending_tx(tx_hash)).await.unwrap();
         drop(confirmer_client);
         confirmer.run(CancellationToken::new()).await.unwrap();
 
         assert!(logs_contain("tx not found on chain"));
 
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            Msg::QueueDepth {
+                queue: Queue::TxConfirmation,
+                depth: 0,
+            }
+        );
         let metrics = receiver.recv().await.unwrap();
         assert!(matches!(
             metrics,
@@ -440,12 +763,19 @@ mod tests {
             queue_cap,
             monitoring_client,
         );
-        confirmer_client.send(tx_hash.to_string()).await.unwrap();
+        confirmer_client.send(pending_tx(tx_hash)).await.unwrap();
         drop(confirmer_client);
         confirmer.run(CancellationToken::new()).await.unwrap();
 
         assert!(logs_contain("failed to query for tx"));
 
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            Msg::QueueDepth {
+                queue: Queue::TxConfirmation,
+                depth: 0,
+            }
+        );
         let metrics = receiver.recv().await.unwrap();
         assert!(matches!(
             metrics,
@@ -457,4 +787,314 @@ mod tests {
         ));
         assert!(receiver.try_recv().is_err());
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn tx_confirmer_should_request_rebroadcast_of_tx_not_found_on_chain_after_its_timeout() {
+        let tx_hash = "tx_hash";
+        let timeout_height = 100;
+        let retry_policy = RetryPolicy::repeat_constant(Duration::from_millis(500), 3);
+
+        let mut client = cosmos::MockCosmosClient::default();
+        client.expect_clone().times(6).returning(|| {
+            let mut client = cosmos::MockCosmosClient::default();
+            client
+                .expect_tx()
+                .return_once(|_| Ok(GetTxResponse::default()));
+
+            client
+        });
+
+        let (monitoring_client, _) = test_utils::monitoring_client();
+        let (confirmer, confirmer_client) = super::TxConfirmer::new_confirmer_and_client(
+            client,
+            retry_policy,
+            10,
+            1000,
+            monitoring_client,
+        );
+        let (latest_block_height_tx, latest_block_height_rx) = watch::channel(timeout_height);
+        let (confirmer, mut rebroadcast_rx) =
+            confirmer.with_rebroadcasts(10, latest_block_height_rx);
+        let tx = PendingTx {
+            timeout_height,
+            ..pending_tx(tx_hash)
+        };
+
+        confirmer_client.send(tx.clone()).await.unwrap();
+        drop(confirmer_client);
+        let handle = tokio::spawn(confirmer.run(CancellationToken::new()));
+
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        assert!(rebroadcast_rx.try_recv().is_err());
+
+        latest_block_height_tx.send(timeout_height + 1).unwrap();
+        handle.await.unwrap().unwrap();
+
+        assert_eq!(
+            rebroadcast_rx.recv().await.unwrap(),
+            Rebroadcast {
+                tx,
+                reason: RebroadcastReason::NotIncluded,
+            }
+        );
+        assert!(rebroadcast_rx.recv().await.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    #[traced_test]
+    async fn tx_confirmer_should_keep_confirming_txs_while_others_wait_for_their_timeout() {
+        let timeout_height = 100;
+
+        let mut client = cosmos::MockCosmosClient::default();
+        client.expect_clone().returning(|| {
+            let mut client = cosmos::MockCosmosClient::default();
+            client
+                .expect_tx()
+                .return_once(|req| match req.hash.as_str() {
+                    "waiting_tx_hash" => Ok(GetTxResponse::default()),
+                    _ => Ok(GetTxResponse {
+                        tx_response: Some(TxResponse {
+                            code: 0,
+                            txhash: req.hash,
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                });
+
+            client
+        });
+
+        let (monitoring_client, _) = test_utils::monitoring_client();
+        let (confirmer, confirmer_client) = super::TxConfirmer::new_confirmer_and_client(
+            client,
+            RetryPolicy::repeat_constant(Duration::from_millis(500), 3),
+            1,
+            1000,
+            monitoring_client,
+        );
+        let (latest_block_height_tx, latest_block_height_rx) = watch::channel(timeout_height);
+        let (confirmer, mut rebroadcast_rx) =
+            confirmer.with_rebroadcasts(10, latest_block_height_rx);
+        let waiting_tx = PendingTx {
+            timeout_height,
+            ..pending_tx("waiting_tx_hash")
+        };
+
+        confirmer_client.send(waiting_tx.clone()).await.unwrap();
+        confirmer_client.send(pending_tx("tx_hash")).await.unwrap();
+        drop(confirmer_client);
+        let handle = tokio::spawn(confirmer.run(CancellationToken::new()));
+
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        assert!(logs_contain("tx succeeded on chain"));
+        assert!(!handle.is_finished());
+
+        latest_block_height_tx.send(timeout_height + 1).unwrap();
+        handle.await.unwrap().unwrap();
+
+        assert_eq!(
+            rebroadcast_rx.recv().await.unwrap(),
+            Rebroadcast {
+                tx: waiting_tx,
+                reason: RebroadcastReason::NotIncluded,
+            }
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn tx_confirmer_should_drop_txs_waiting_for_their_timeout_when_token_is_cancelled() {
+        let mut client = cosmos::MockCosmosClient::default();
+        client.expect_clone().times(3).returning(|| {
+            let mut client = cosmos::MockCosmosClient::default();
+            client
+                .expect_tx()
+                .return_once(|_| Ok(GetTxResponse::default()));
+
+            client
+        });
+
+        let (monitoring_client, _) = test_utils::monitoring_client();
+        let (confirmer, confirmer_client) = super::TxConfirmer::new_confirmer_and_client(
+            client,
+            RetryPolicy::repeat_constant(Duration::from_millis(500), 3),
+            10,
+            1000,
+            monitoring_client,
+        );
+        let (_latest_block_height_tx, latest_block_height_rx) = watch::channel(100);
+        let (confirmer, mut rebroadcast_rx) =
+            confirmer.with_rebroadcasts(10, latest_block_height_rx);
+
+        confirmer_client
+            .send(PendingTx {
+                timeout_height: 100,
+                ..pending_tx("tx_hash")
+            })
+            .await
+            .unwrap();
+        let token = CancellationToken::new();
+        let handle = tokio::spawn(confirmer.run(token.child_token()));
+
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        token.cancel();
+
+        tokio::time::timeout(Duration::from_secs(5), handle)
+            .await
+            .expect("Task timed out")
+            .unwrap()
+            .unwrap();
+        assert!(rebroadcast_rx.recv().await.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn tx_confirmer_should_not_request_rebroadcast_of_tx_not_found_on_chain_without_timeout()
+    {
+        let mut client = cosmos::MockCosmosClient::default();
+        client.expect_clone().times(3).returning(|| {
+            let mut client = cosmos::MockCosmosClient::default();
+            client
+                .expect_tx()
+                .return_once(|_| Ok(GetTxResponse::default()));
+
+            client
+        });
+
+        let (monitoring_client, _) = test_utils::monitoring_client();
+        let (confirmer, confirmer_client) = super::TxConfirmer::new_confirmer_and_client(
+            client,
+            RetryPolicy::repeat_constant(Duration::from_millis(500), 3),
+            10,
+            1000,
+            monitoring_client,
+        );
+        let (confirmer, mut rebroadcast_rx) =
+            confirmer.with_rebroadcasts(10, watch::channel(u64::MAX).1);
+
+        confirmer_client.send(pending_tx("tx_hash")).await.unwrap();
+        drop(confirmer_client);
+        confirmer.run(CancellationToken::new()).await.unwrap();
+
+        assert!(rebroadcast_rx.recv().await.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn tx_confirmer_should_not_request_rebroadcast_of_tx_included_before_its_timeout() {
+        let timeout_height = 100;
+        let mut seq = mockall::Sequence::new();
+        let mut client = cosmos::MockCosmosClient::default();
+        client
+            .expect_clone()
+            .times(3)
+            .in_sequence(&mut seq)
+            .returning(|| {
+                let mut client = cosmos::MockCosmosClient::default();
+                client
+                    .expect_tx()
+                    .return_once(|_| Ok(GetTxResponse::default()));
+
+                client
+            });
+        client
+            .expect_clone()
+            .once()
+            .in_sequence(&mut seq)
+            .returning(|| {
+                let mut client = cosmos::MockCosmosClient::default();
+                client.expect_tx().return_once(|req| {
+                    Ok(GetTxResponse {
+                        tx_response: Some(TxResponse {
+                            code: 0,
+                            txhash: req.hash,
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    })
+                });
+
+                client
+            });
+
+        let (monitoring_client, _) = test_utils::monitoring_client();
+        let (confirmer, confirmer_client) = super::TxConfirmer::new_confirmer_and_client(
+            client,
+            RetryPolicy::repeat_constant(Duration::from_millis(500), 3),
+            10,
+            1000,
+            monitoring_client,
+        );
+        let (confirmer, mut rebroadcast_rx) =
+            confirmer.with_rebroadcasts(10, watch::channel(timeout_height + 1).1);
+
+        confirmer_client
+            .send(PendingTx {
+                timeout_height,
+                ..pending_tx("tx_hash")
+            })
+            .await
+            .unwrap();
+        drop(confirmer_client);
+        confirmer.run(CancellationToken::new()).await.unwrap();
+
+        assert!(rebroadcast_rx.recv().await.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn tx_confirmer_should_request_rebroadcast_of_tx_that_failed_with_recoverable_error() {
+        let test_cases = [
+            (11, RebroadcastReason::OutOfGas),
+            (13, RebroadcastReason::InsufficientFee),
+            (32, RebroadcastReason::SequenceMismatch),
+        ];
+
+        for (code, reason) in test_cases {
+            let (monitoring_client, _) = test_utils::monitoring_client();
+            let (confirmer, confirmer_client) = super::TxConfirmer::new_confirmer_and_client(
+                client_returning_tx_response(code, "sdk"),
+                RetryPolicy::repeat_constant(Duration::from_millis(500), 3),
+                10,
+                1000,
+                monitoring_client,
+            );
+            let (confirmer, mut rebroadcast_rx) =
+                confirmer.with_rebroadcasts(10, watch::channel(0).1);
+
+            confirmer_client.send(pending_tx("tx_hash")).await.unwrap();
+            drop(confirmer_client);
+            confirmer.run(CancellationToken::new()).await.unwrap();
+
+            assert_eq!(
+                rebroadcast_rx.recv().await.unwrap(),
+                Rebroadcast {
+                    tx: pending_tx("tx_hash"),
+                    reason,
+                }
+            );
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn tx_confirmer_should_not_request_rebroadcast_of_tx_that_failed_with_unrecoverable_error(
+    ) {
+        let test_cases = [(11, "wasm"), (5, "sdk"), (0, "")];
+
+        for (code, codespace) in test_cases {
+            let (monitoring_client, _) = test_utils::monitoring_client();
+            let (confirmer, confirmer_client) = super::TxConfirmer::new_confirmer_and_client(
+                client_returning_tx_response(code, codespace),
+                RetryPolicy::repeat_constant(Duration::from_millis(500), 3),
+                10,
+                1000,
+                monitoring_client,
+            );
+            let (confirmer, mut rebroadcast_rx) =
+                confirmer.with_rebroadcasts(10, watch::channel(0).1);
+
+            confirmer_client.send(pending_tx("tx_hash")).await.unwrap();
+            drop(confirmer_client);
+            confirmer.run(CancellationToken::new()).await.unwrap();
+
+            assert!(rebroadcast_rx.recv().await.is_none());
+        }
+    }
 }
@@ -127,6 +127,12 @@ impl FromStr for FiniteAmount {
     }
 }
 
+impl From<FiniteAmount> for f64 {
+    fn from(amount: FiniteAmount) -> Self {
+        amount.0
+    }
+}
+
 impl ops::Mul<FiniteAmount> for f64 {
     type Output = f64;
 
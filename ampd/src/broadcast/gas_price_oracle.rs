@@ -0,0 +1,219 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use error_stack::{report, ResultExt};
+use report::LoggableError;
+use tokio::sync::RwLock;
+use tokio::time;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, instrument, warn};
+use valuable::Valuable;
+
+use super::config::GasPriceOracleConfig;
+use super::dec_coin::{DecCoin, Denom};
+use super::{Error, Result};
+use crate::cosmos;
+
+/// Periodically queries the minimum gas price of the connected Axelar node and updates
+/// the gas price the broadcaster uses for gas estimation and fee calculation.
+///
+/// The minimum gas price is the node's local `minimum-gas-prices` setting, i.e. the lowest price
+/// it admits to its own mempool. It is not derived from a fee market, so it does not react to
+/// congestion, and other validators may require a different price.
+///
+/// The queried gas price is clamped between the configured floor and ceiling, so a misconfigured
+/// or misbehaving node can neither stall our txs nor drain the account.
+/// If a query fails, the current gas price is kept.
+#[derive(Debug)]
+pub struct GasPriceOracle<T>
+where
+    T: cosmos::CosmosClient,
+{
+    client: T,
+    gas_price: Arc<RwLock<DecCoin>>,
+    query_interval: Duration,
+    floor: f64,
+    ceiling: f64,
+}
+
+impl<T> GasPriceOracle<T>
+where
+    T: cosmos::CosmosClient,
+{
+    /// Creates a new oracle that updates the given gas price, usually obtained via [`super::Broadcaster::gas_price`].
+    pub fn new(client: T, gas_price: Arc<RwLock<DecCoin>>, config: GasPriceOracleConfig) -> Self {
+        Self {
+            client,
+            gas_price,
+            query_interval: config.query_interval,
+            floor: config.floor,
+            ceiling: config.ceiling,
+        }
+    }
+
+    #[instrument(skip_all)]
+    pub async fn run(mut self, token: CancellationToken) -> Result<()> {
+        let mut interval = time::interval(self.query_interval);
+        interval.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                _ = token.cancelled() => break,
+                _ = interval.tick() => self.update_gas_price().await,
+            }
+        }
+
+        info!("gas price oracle exited");
+
+        Ok(())
+    }
+
+    async fn update_gas_price(&mut self) {
+        let denom = self.gas_price.read().await.denom.clone();
+
+        match query_gas_price(&mut self.client, &denom).await {
+            Ok(amount) => {
+                let amount = amount.max(self.floor).min(self.ceiling);
+
+                match DecCoin::new(amount, denom.as_ref()) {
+                    Ok(gas_price) => {
+                        info!(gas_price = gas_price.to_string(), "updated gas price");
+                        *self.gas_price.write().await = gas_price;
+                    }
+                    Err(err) => warn!(
+                        err = LoggableError::from(&err).as_value(),
+                        "invalid gas price, keeping the current gas price"
+                    ),
+                }
+            }
+            Err(err) => warn!(
+                err = LoggableError::from(&err).as_value(),
+                "failed to query gas price, keeping the current gas price"
+            ),
+        }
+    }
+}
+
+async fn query_gas_price<T>(client: &mut T, denom: &Denom) -> Result<f64>
+where
+    T: cosmos::CosmosClient,
+{
+    let min_gas_prices = cosmos::minimum_gas_price(client)
+        .await
+        .change_context(Error::GasPriceQuery)?;
+
+    parse_gas_price(&min_gas_prices, denom)
+}
+
+/// Picks the amount for the given denom out of a comma separated list of gas prices, e.g. `0.007uaxl,0.1uusdc`.
+fn parse_gas_price(min_gas_prices: &str, denom: &Denom) -> Result<f64> {
+    min_gas_prices
+        .split(',')
+        .map(|gas_price| DecCoin::try_from(gas_price.trim()))
+        .collect::<error_stack::Result<Vec<_>, _>>()
+        .change_context(Error::GasPriceQuery)
+        .attach_printable_lazy(|| min_gas_prices.to_string())?
+        .into_iter()
+        .find(|gas_price| &gas_price.denom == denom)
+        .map(|gas_price| gas_price.amount.into())
+        .ok_or(report!(Error::GasPriceQuery))
+        .attach_printable_lazy(|| format!("no gas price for denom {}", denom))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use axelar_wasm_std::assert_err_contains;
+    use cosmrs::proto::cosmos::base::node::v1beta1::ConfigResponse;
+    use tokio::sync::RwLock;
+
+    use super::{parse_gas_price, GasPriceOracle};
+    use crate::broadcast::config::GasPriceOracleConfig;
+    use crate::broadcast::dec_coin::DecCoin;
+    use crate::broadcast::Error;
+    use crate::cosmos;
+
+    fn oracle_with_min_gas_price(
+        min_gas_price: &str,
+        gas_price: Arc<RwLock<DecCoin>>,
+    ) -> GasPriceOracle<cosmos::MockCosmosClient> {
+        let min_gas_price = min_gas_price.to_string();
+
+        let mut client = cosmos::MockCosmosClient::new();
+        client.expect_node_config().return_once(move |_| {
+            Ok(ConfigResponse {
+                minimum_gas_price: min_gas_price,
+                ..Default::default()
+            })
+        });
+
+        GasPriceOracle::new(
+            client,
+            gas_price,
+            GasPriceOracleConfig {
+                enabled: true,
+                query_interval: Duration::from_secs(1),
+                floor: 0.001,
+                ceiling: 0.1,
+            },
+        )
+    }
+
+    #[test]
+    fn parse_gas_price_should_pick_amount_of_denom() {
+        let denom = "uaxl".parse().unwrap();
+
+        assert_eq!(parse_gas_price("0.007uaxl", &denom).unwrap(), 0.007);
+        assert_eq!(
+            parse_gas_price("0.1uusdc, 0.007uaxl", &denom).unwrap(),
+            0.007
+        );
+        assert_err_contains!(
+            parse_gas_price("0.1uusdc", &denom),
+            Error,
+            Error::GasPriceQuery
+        );
+        assert_err_contains!(parse_gas_price("uaxl", &denom), Error, Error::GasPriceQuery);
+    }
+
+    #[tokio::test]
+    async fn update_gas_price_should_set_queried_gas_price() {
+        let gas_price = Arc::new(RwLock::new(DecCoin::new(0.05, "uaxl").unwrap()));
+        let mut oracle = oracle_with_min_gas_price("0.007uaxl", gas_price.clone());
+
+        oracle.update_gas_price().await;
+
+        assert_eq!(
+            *gas_price.read().await,
+            DecCoin::new(0.007, "uaxl").unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn update_gas_price_should_clamp_queried_gas_price() {
+        let gas_price = Arc::new(RwLock::new(DecCoin::new(0.05, "uaxl").unwrap()));
+
+        let mut oracle = oracle_with_min_gas_price("5uaxl", gas_price.clone());
+        oracle.update_gas_price().await;
+        assert_eq!(*gas_price.read().await, DecCoin::new(0.1, "uaxl").unwrap());
+
+        let mut oracle = oracle_with_min_gas_price("0uaxl", gas_price.clone());
+        oracle.update_gas_price().await;
+        assert_eq!(
+            *gas_price.read().await,
+            DecCoin::new(0.001, "uaxl").unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn update_gas_price_should_keep_gas_price_if_query_fails() {
+        let gas_price = Arc::new(RwLock::new(DecCoin::new(0.05, "uaxl").unwrap()));
+
+        let mut oracle = oracle_with_min_gas_price("0.007uusdc", gas_price.clone());
+        oracle.update_gas_price().await;
+
+        assert_eq!(*gas_price.read().await, DecCoin::new(0.05, "uaxl").unwrap());
+    }
+}
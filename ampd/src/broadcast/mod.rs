@@ -7,15 +7,18 @@ use cosmrs::{Any, Coin, Gas};
 use error_stack::ResultExt;
 use k256::sha2::{Digest, Sha256};
 use prost::encoding::{decode_key, decode_varint, WireType};
+use report::LoggableError;
 use thiserror::Error;
-use tokio::sync::oneshot;
+use tokio::sync::{mpsc, oneshot, watch};
+use tokio_stream::wrappers::ReceiverStream;
 use tokio_stream::StreamExt;
 use tokio_util::sync::CancellationToken;
-use tracing::{error, info, instrument};
+use tracing::{error, info, instrument, warn};
 use typed_builder::TypedBuilder;
+use valuable::Valuable;
 
 use crate::monitoring::metrics;
-use crate::monitoring::metrics::{Msg, Stage};
+use crate::monitoring::metrics::{Msg, Queue, RebroadcastReason, Stage};
 use crate::types::TMAddress;
 use crate::{cosmos, monitoring, tofnd};
 
@@ -23,15 +26,18 @@ mod broadcaster;
 mod config;
 mod confirmer;
 mod dec_coin;
+mod gas_price_oracle;
 mod msg_queue;
 mod proto;
 mod tx;
 
 pub use broadcaster::Broadcaster;
-pub use config::Config;
+use broadcaster::TxOptions;
+pub use config::{Config, RebroadcastConfig};
 pub use confirmer::{confirm_tx, TxConfirmer};
 #[cfg(test)]
 pub use dec_coin::DecCoin;
+pub use gas_price_oracle::GasPriceOracle;
 #[cfg(test)]
 pub use msg_queue::QueueMsg;
 pub use msg_queue::{MsgQueue, MsgQueueClient};
@@ -69,6 +75,8 @@ pub enum Error {
     },
     #[error("failed to confirm tx {0}")]
     ConfirmTx(String),
+    #[error("failed to query gas price")]
+    GasPriceQuery,
 }
 
 /// A task that processes queued messages and broadcasts them to a Cosmos blockchain
@@ -81,6 +89,11 @@ pub enum Error {
 /// new message batches even if previous ones fail. It provides feedback on
 /// transaction results to message submitters through callback channels.
 ///
+/// If a receiver for re-broadcasts is configured and the [`RebroadcastConfig`] enables them,
+/// messages of transactions that the tx confirmer could not confirm are broadcast again with a
+/// bumped fee. Transactions then get a timeout height based on the latest block height,
+/// so the confirmer can tell when a transaction that was not found can no longer be included.
+///
 /// # Type Parameters
 ///
 /// * `T` - A Cosmos client that can communicate with the blockchain
@@ -99,6 +112,12 @@ where
     key_id: String,
     #[builder(default = None, setter(strip_option))]
     tx_confirmer_client: Option<confirmer::TxConfirmerClient>,
+    #[builder(default = None)]
+    rebroadcast_rx: Option<mpsc::Receiver<confirmer::Rebroadcast>>,
+    #[builder(default)]
+    rebroadcast_config: RebroadcastConfig,
+    #[builder(default = None, setter(strip_option))]
+    latest_block_height: Option<watch::Receiver<u64>>,
     monitoring_client: monitoring::Client,
 }
 
@@ -117,6 +136,9 @@ where
     /// 4. Notifies submitters of the transaction result via callbacks
     /// 5. Proceeds to the next batch
     ///
+    /// In between batches, it broadcasts the messages of failed transactions again,
+    /// as reported by the tx confirmer.
+    ///
     /// The task runs until the message queue is closed/exhausted, at which point
     /// it terminates successfully. Errors during broadcasting are logged and
     /// communicated back to submitters but don't halt the task.
@@ -133,41 +155,78 @@ where
             mut signer,
             key_id,
             tx_confirmer_client,
+            rebroadcast_rx,
+            rebroadcast_config,
+            latest_block_height,
             monitoring_client,
         } = self;
 
         let stream = futures::StreamExt::take_until(msg_queue, token.cancelled());
+        let rebroadcasts = match rebroadcast_rx {
+            Some(rx) => futures::StreamExt::left_stream(ReceiverStream::new(rx)),
+            None => futures::StreamExt::right_stream(futures::stream::pending::<
+                confirmer::Rebroadcast,
+            >()),
+        };
 
         tokio::pin!(stream);
-        while let Some(msgs) = stream.next().await {
-            let (res, elapsed) = metrics::timed(|| async {
-                broadcast(
-                    &mut broadcaster,
-                    &mut signer,
-                    &key_id,
-                    msgs.as_ref()
+        tokio::pin!(rebroadcasts);
+        loop {
+            tokio::select! {
+                biased;
+                msgs = stream.next() => {
+                    let Some(msgs) = msgs else {
+                        break;
+                    };
+
+                    let any_msgs: nonempty::Vec<Any> = msgs
+                        .as_ref()
                         .iter()
                         .map(|msg| msg.msg.clone())
                         .collect::<Vec<_>>()
                         .try_into()
-                        .expect("msgs cannot be empty"),
-                )
-                .await
-            })
-            .await;
-
-            monitoring_client.metrics().record_metric(Msg::StageResult {
-                stage: Stage::TransactionBroadcast,
-                success: res.is_ok(),
-                duration: elapsed,
-            });
-
-            handle_tx_res(
-                tx_confirmer_client.as_ref(),
-                res.map(|res| res.txhash),
-                msgs,
-            )
-            .await?;
+                        .expect("msgs cannot be empty");
+                    let options = TxOptions {
+                        timeout_height: timeout_height(&rebroadcast_config, latest_block_height.as_ref()),
+                        ..TxOptions::default()
+                    };
+                    let res = timed_broadcast(
+                        &monitoring_client,
+                        &mut broadcaster,
+                        &mut signer,
+                        &key_id,
+                        any_msgs.clone(),
+                        options,
+                    )
+                    .await;
+
+                    handle_tx_res(
+                        &monitoring_client,
+                        tx_confirmer_client.as_ref(),
+                        res.map(|res| confirmer::PendingTx {
+                            tx_hash: res.txhash,
+                            msgs: any_msgs,
+                            attempt: 0,
+                            timeout_height: options.timeout_height,
+                        }),
+                        msgs,
+                    )
+                    .await?;
+                }
+                Some(rebroadcast) = rebroadcasts.next() => {
+                    handle_rebroadcast(
+                        &monitoring_client,
+                        &mut broadcaster,
+                        &mut signer,
+                        &key_id,
+                        tx_confirmer_client.as_ref(),
+                        &rebroadcast_config,
+                        latest_block_height.as_ref(),
+                        rebroadcast,
+                    )
+                    .await?;
+                }
+            }
         }
 
         info!("broadcaster task exited");
@@ -201,13 +260,41 @@ where
 /// * `Error::FeeAdjustment` - If fee calculation encounters numeric conversion issues
 /// * `Error::SignTx` - If transaction signing fails
 /// * `Error::BroadcastTx` - If the network rejects the transaction
-#[instrument(skip_all)]
 pub async fn broadcast<T, S>(
     broadcaster: &mut Broadcaster<T>,
     signer: &mut S,
     key_id: &str,
     msgs: nonempty::Vec<Any>,
 ) -> Result<TxResponse>
+where
+    T: cosmos::CosmosClient + Debug,
+    S: tofnd::Multisig + Debug,
+{
+    broadcast_with_options(broadcaster, signer, key_id, msgs, TxOptions::default()).await
+}
+
+/// Returns the timeout height for a new transaction if messages of transactions that are not
+/// included on chain are broadcast again, or 0 for no timeout otherwise.
+fn timeout_height(
+    config: &RebroadcastConfig,
+    latest_block_height: Option<&watch::Receiver<u64>>,
+) -> u64 {
+    match latest_block_height {
+        Some(latest_block_height) if config.enabled() => latest_block_height
+            .borrow()
+            .saturating_add(config.timeout_blocks),
+        _ => 0,
+    }
+}
+
+#[instrument(skip_all)]
+async fn broadcast_with_options<T, S>(
+    broadcaster: &mut Broadcaster<T>,
+    signer: &mut S,
+    key_id: &str,
+    msgs: nonempty::Vec<Any>,
+    options: TxOptions,
+) -> Result<TxResponse>
 where
     T: cosmos::CosmosClient + Debug,
     S: tofnd::Multisig + Debug,
@@ -226,7 +313,7 @@ where
     let pub_key = broadcaster.pub_key;
 
     broadcaster
-        .broadcast(vec![batch_req], |sign_doc| {
+        .broadcast_with_options(vec![batch_req], options, |sign_doc| {
             let mut hasher = Sha256::new();
             hasher.update(sign_doc);
 
@@ -247,20 +334,150 @@ where
         })
 }
 
+async fn timed_broadcast<T, S>(
+    monitoring_client: &monitoring::Client,
+    broadcaster: &mut Broadcaster<T>,
+    signer: &mut S,
+    key_id: &str,
+    msgs: nonempty::Vec<Any>,
+    options: TxOptions,
+) -> Result<TxResponse>
+where
+    T: cosmos::CosmosClient + Debug,
+    S: tofnd::Multisig + Debug,
+{
+    let (res, elapsed) = metrics::timed(|| async {
+        broadcast_with_options(broadcaster, signer, key_id, msgs, options).await
+    })
+    .await;
+
+    monitoring_client.metrics().record_metric(Msg::StageResult {
+        stage: Stage::TransactionBroadcast,
+        success: res.is_ok(),
+        duration: elapsed,
+    });
+
+    res
+}
+
+#[instrument(skip_all, fields(tx_hash = rebroadcast.tx.tx_hash, attempt = rebroadcast.tx.attempt, reason = ?rebroadcast.reason))]
+async fn handle_rebroadcast<T, S>(
+    monitoring_client: &monitoring::Client,
+    broadcaster: &mut Broadcaster<T>,
+    signer: &mut S,
+    key_id: &str,
+    tx_confirmer_client: Option<&confirmer::TxConfirmerClient>,
+    config: &RebroadcastConfig,
+    latest_block_height: Option<&watch::Receiver<u64>>,
+    rebroadcast: confirmer::Rebroadcast,
+) -> Result<()>
+where
+    T: cosmos::CosmosClient + Debug,
+    S: tofnd::Multisig + Debug,
+{
+    let confirmer::Rebroadcast {
+        tx: confirmer::PendingTx { msgs, attempt, .. },
+        reason,
+    } = rebroadcast;
+    let exhausted = attempt >= config.max_attempts;
+
+    monitoring_client
+        .metrics()
+        .record_metric(Msg::TransactionRebroadcast { reason, exhausted });
+
+    if exhausted {
+        error!(
+            msg_count = msgs.len(),
+            "exhausted all re-broadcasts of tx, its messages are dropped"
+        );
+        return Ok(());
+    }
+
+    if reason == RebroadcastReason::SequenceMismatch {
+        if let Err(err) = broadcaster.refetch_sequence().await {
+            warn!(
+                err = LoggableError::from(&err).as_value(),
+                "failed to refetch account sequence"
+            );
+        }
+    }
+
+    let attempt = attempt.saturating_add(1);
+    let options = TxOptions {
+        fee_bump: config.fee_bump(attempt),
+        timeout_height: timeout_height(config, latest_block_height),
+    };
+    match timed_broadcast(
+        monitoring_client,
+        broadcaster,
+        signer,
+        key_id,
+        msgs.clone(),
+        options,
+    )
+    .await
+    {
+        Ok(res) => {
+            confirm(
+                monitoring_client,
+                tx_confirmer_client,
+                confirmer::PendingTx {
+                    tx_hash: res.txhash,
+                    msgs,
+                    attempt,
+                    timeout_height: options.timeout_height,
+                },
+            )
+            .await
+        }
+        Err(err) => {
+            warn!(
+                err = LoggableError::from(&err).as_value(),
+                "failed to re-broadcast tx, its messages are dropped"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+async fn confirm(
+    monitoring_client: &monitoring::Client,
+    tx_confirmer_client: Option<&confirmer::TxConfirmerClient>,
+    tx: confirmer::PendingTx,
+) -> Result<()> {
+    let Some(confirmer) = tx_confirmer_client else {
+        return Ok(());
+    };
+
+    let tx_hash = tx.tx_hash.clone();
+    confirmer
+        .send(tx)
+        .await
+        .change_context(Error::ConfirmTx(tx_hash))?;
+
+    monitoring_client.metrics().record_metric(Msg::QueueDepth {
+        queue: Queue::TxConfirmation,
+        depth: confirmer
+            .max_capacity()
+            .saturating_sub(confirmer.capacity()),
+    });
+
+    Ok(())
+}
+
 #[instrument]
 async fn handle_tx_res(
+    monitoring_client: &monitoring::Client,
     tx_confirmer_client: Option<&confirmer::TxConfirmerClient>,
-    tx_hash: Result<String>,
+    tx: Result<confirmer::PendingTx>,
     msgs: nonempty::Vec<msg_queue::QueueMsg>,
 ) -> Result<()> {
-    if let (Some(confirmer), Ok(tx_hash)) = (tx_confirmer_client, &tx_hash) {
-        confirmer
-            .send(tx_hash.clone())
-            .await
-            .change_context(Error::ConfirmTx(tx_hash.clone()))?;
+    if let Ok(tx) = &tx {
+        confirm(monitoring_client, tx_confirmer_client, tx.clone()).await?;
     }
 
-    let tx_hash = tx_hash.map_err(Arc::new);
+    let tx_hash = tx.map(|tx| tx.tx_hash).map_err(Arc::new);
 
     Vec::from(msgs)
         .into_iter()
@@ -381,6 +598,17 @@ pub(crate) mod test_utils {
             .fee
             .unwrap()
     }
+
+    pub fn decode_timeout_height<R>(req: &R) -> u64
+    where
+        R: AsRef<[u8]> + ?Sized,
+    {
+        let tx_raw = cosmrs::proto::cosmos::tx::v1beta1::TxRaw::decode(req.as_ref()).unwrap();
+
+        cosmrs::proto::cosmos::tx::v1beta1::TxBody::decode(tx_raw.body_bytes.as_slice())
+            .unwrap()
+            .timeout_height
+    }
 }
 
 #[cfg(test)]
@@ -397,15 +625,16 @@ mod tests {
     use error_stack::report;
     use futures::StreamExt;
     use mockall::{predicate, Sequence};
-    use tokio::sync::{mpsc, oneshot};
+    use tokio::sync::{mpsc, oneshot, watch};
     use tokio_stream::iter;
     use tokio_stream::wrappers::ReceiverStream;
     use tokio_util::sync::CancellationToken;
 
+    use crate::broadcast::confirmer::{PendingTx, Rebroadcast};
     use crate::broadcast::dec_coin::DecCoin;
     use crate::broadcast::msg_queue::QueueMsg;
-    use crate::broadcast::{broadcaster, BroadcasterTask, Error, MsgQueue};
-    use crate::monitoring::metrics::{Msg, Stage};
+    use crate::broadcast::{broadcaster, BroadcasterTask, Error, MsgQueue, RebroadcastConfig};
+    use crate::monitoring::metrics::{Msg, Queue, RebroadcastReason, Stage};
     use crate::tofnd::{self, MockMultisig};
     use crate::types::random_cosmos_public_key;
     use crate::{broadcast, cosmos, monitoring, PREFIX};
@@ -552,7 +781,10 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(
             vec!["tx_hash_success".to_string()],
-            ReceiverStream::new(rx).collect::<Vec<_>>().await
+            ReceiverStream::new(rx)
+                .map(|tx| tx.tx_hash)
+                .collect::<Vec<_>>()
+                .await
         );
 
         for (i, rx) in receivers.into_iter().enumerate() {
@@ -1479,6 +1711,240 @@ mod tests {
         assert!(receiver.try_recv().is_err());
     }
 
+    #[tokio::test]
+    async fn broadcaster_task_should_rebroadcast_msgs_with_bumped_fee_refetched_sequence_and_timeout(
+    ) {
+        let pub_key = random_cosmos_public_key();
+        let address = pub_key.account_id(PREFIX).unwrap().into();
+        let chain_id: tendermint::chain::Id = "test-chain-id".parse().unwrap();
+        let base_account = broadcast::test_utils::create_base_account(&address);
+        let refetched_account = cosmos_sdk_proto::cosmos::auth::v1beta1::BaseAccount {
+            sequence: 5,
+            ..base_account.clone()
+        };
+        let rebroadcast_config = RebroadcastConfig {
+            max_attempts: 3,
+            fee_bump_factor: 2.0,
+            max_gas_adjustment: 2.0,
+            max_gas_price: 0.03125,
+            timeout_blocks: 10,
+        };
+        let latest_block_height = 50u64;
+        let expected_timeout_height = 60u64;
+        let expected_gas_limit = 200000u64; // 100000 * min(1.5 * 2, 2) = 200000
+        let expected_fee_amount = 6250u64; // 200000 * min(0.025 * 2, 0.03125) = 6250
+
+        let mut mock_signer = MockMultisig::new();
+        mock_signer
+            .expect_sign()
+            .once()
+            .returning(|_, _, _, _| Ok(vec![0u8; 64]));
+
+        let mut seq = Sequence::new();
+        let mut mock_client = cosmos::MockCosmosClient::new();
+        mock_successful_account_and_balance_queries(
+            &mut mock_client,
+            &mut seq,
+            base_account,
+            &address,
+        );
+        mock_client
+            .expect_account()
+            .once()
+            .in_sequence(&mut seq)
+            .return_once(move |_| {
+                Ok(QueryAccountResponse {
+                    account: Some(Any::from_msg(&refetched_account).unwrap()),
+                })
+            });
+        mock_client
+            .expect_simulate()
+            .once()
+            .in_sequence(&mut seq)
+            .return_once(|_| {
+                Ok(SimulateResponse {
+                    gas_info: Some(GasInfo {
+                        gas_wanted: 0,
+                        gas_used: 100000,
+                    }),
+                    result: None,
+                })
+            });
+        mock_client
+            .expect_broadcast_tx()
+            .once()
+            .in_sequence(&mut seq)
+            .withf(move |req| {
+                let fee = broadcast::test_utils::decode_gas_fee(&req.tx_bytes);
+
+                fee.gas_limit == expected_gas_limit
+                    && fee.amount.first().unwrap().amount == expected_fee_amount.to_string()
+                    && broadcast::test_utils::decode_timeout_height(&req.tx_bytes)
+                        == expected_timeout_height
+            })
+            .return_once(|_| {
+                Ok(BroadcastTxResponse {
+                    tx_response: Some(TxResponse {
+                        txhash: "tx_hash_rebroadcast".to_string(),
+                        code: 0,
+                        ..Default::default()
+                    }),
+                })
+            });
+
+        let broadcaster = broadcaster::Broadcaster::builder()
+            .client(mock_client)
+            .chain_id(chain_id)
+            .pub_key(pub_key)
+            .gas_adjustment(1.5)
+            .gas_price(DecCoin::new(0.025, "uaxl").unwrap())
+            .build()
+            .await
+            .unwrap();
+        let (_msg_queue_tx, msg_queue_rx) = mpsc::channel(1);
+        let (confirmer_tx, mut confirmer_rx) = mpsc::channel(1);
+        let (rebroadcast_tx, rebroadcast_rx) = mpsc::channel(1);
+        let (monitoring_client, mut receiver) = monitoring::test_utils::monitoring_client();
+        let broadcaster_task = BroadcasterTask::builder()
+            .broadcaster(broadcaster)
+            .msg_queue(
+                ReceiverStream::<axelar_wasm_std::nonempty::Vec<QueueMsg>>::new(msg_queue_rx),
+            )
+            .signer(mock_signer)
+            .key_id("test-key".to_string())
+            .tx_confirmer_client(confirmer_tx)
+            .rebroadcast_rx(Some(rebroadcast_rx))
+            .rebroadcast_config(rebroadcast_config)
+            .latest_block_height(watch::channel(latest_block_height).1)
+            .monitoring_client(monitoring_client)
+            .build();
+
+        let token = CancellationToken::new();
+        let handle = tokio::spawn(broadcaster_task.run(token.child_token()));
+
+        let msgs: axelar_wasm_std::nonempty::Vec<Any> = vec![dummy_msg()].try_into().unwrap();
+        rebroadcast_tx
+            .send(Rebroadcast {
+                tx: PendingTx {
+                    tx_hash: "tx_hash_failed".to_string(),
+                    msgs: msgs.clone(),
+                    attempt: 0,
+                    timeout_height: latest_block_height,
+                },
+                reason: RebroadcastReason::SequenceMismatch,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            confirmer_rx.recv().await.unwrap(),
+            PendingTx {
+                tx_hash: "tx_hash_rebroadcast".to_string(),
+                msgs,
+                attempt: 1,
+                timeout_height: expected_timeout_height,
+            }
+        );
+
+        token.cancel();
+        assert!(handle.await.unwrap().is_ok());
+
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            Msg::TransactionRebroadcast {
+                reason: RebroadcastReason::SequenceMismatch,
+                exhausted: false,
+            }
+        );
+        assert!(matches!(
+            receiver.recv().await.unwrap(),
+            Msg::StageResult {
+                stage: Stage::TransactionBroadcast,
+                success: true,
+                duration: _,
+            }
+        ));
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            Msg::QueueDepth {
+                queue: Queue::TxConfirmation,
+                depth: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn broadcaster_task_should_drop_msgs_after_exhausting_rebroadcasts() {
+        let pub_key = random_cosmos_public_key();
+        let address = pub_key.account_id(PREFIX).unwrap().into();
+        let chain_id: tendermint::chain::Id = "test-chain-id".parse().unwrap();
+        let base_account = broadcast::test_utils::create_base_account(&address);
+
+        let mut seq = Sequence::new();
+        let mut mock_client = cosmos::MockCosmosClient::new();
+        mock_successful_account_and_balance_queries(
+            &mut mock_client,
+            &mut seq,
+            base_account,
+            &address,
+        );
+
+        let broadcaster = broadcaster::Broadcaster::builder()
+            .client(mock_client)
+            .chain_id(chain_id)
+            .pub_key(pub_key)
+            .gas_adjustment(1.5)
+            .gas_price(DecCoin::new(0.025, "uaxl").unwrap())
+            .build()
+            .await
+            .unwrap();
+        let (_msg_queue_tx, msg_queue_rx) = mpsc::channel(1);
+        let (confirmer_tx, mut confirmer_rx) = mpsc::channel(1);
+        let (rebroadcast_tx, rebroadcast_rx) = mpsc::channel(1);
+        let (monitoring_client, mut receiver) = monitoring::test_utils::monitoring_client();
+        let broadcaster_task = BroadcasterTask::builder()
+            .broadcaster(broadcaster)
+            .msg_queue(
+                ReceiverStream::<axelar_wasm_std::nonempty::Vec<QueueMsg>>::new(msg_queue_rx),
+            )
+            .signer(MockMultisig::new())
+            .key_id("test-key".to_string())
+            .tx_confirmer_client(confirmer_tx)
+            .rebroadcast_rx(Some(rebroadcast_rx))
+            .rebroadcast_config(RebroadcastConfig::default())
+            .monitoring_client(monitoring_client)
+            .build();
+
+        let token = CancellationToken::new();
+        let handle = tokio::spawn(broadcaster_task.run(token.child_token()));
+
+        rebroadcast_tx
+            .send(Rebroadcast {
+                tx: PendingTx {
+                    tx_hash: "tx_hash_failed".to_string(),
+                    msgs: vec![dummy_msg()].try_into().unwrap(),
+                    attempt: RebroadcastConfig::default().max_attempts,
+                    timeout_height: 0,
+                },
+                reason: RebroadcastReason::NotIncluded,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            Msg::TransactionRebroadcast {
+                reason: RebroadcastReason::NotIncluded,
+                exhausted: true,
+            }
+        );
+
+        token.cancel();
+        assert!(handle.await.unwrap().is_ok());
+        assert!(confirmer_rx.recv().await.is_none());
+        assert!(receiver.try_recv().is_err());
+    }
+
     fn create_two_msg_batches() -> Vec<axelar_wasm_std::nonempty::Vec<QueueMsg>> {
         let (tx_1, _) = oneshot::channel();
         let (tx_2, _) = oneshot::channel();
@@ -19,7 +19,7 @@ use tracing::{instrument, warn};
 use valuable::Valuable;
 
 use super::{broadcaster, Error, Result};
-use crate::monitoring::metrics::Msg;
+use crate::monitoring::metrics::{self, Msg};
 use crate::types::TMAddress;
 use crate::{cosmos, monitoring};
 
@@ -302,13 +302,19 @@ impl Stream for MsgQueue {
 
                     // try to add the message to the queue
                     // if the queue returns Some, it means we have a batch ready to send
-                    if let Some(msgs) = me.queue.push_or(msg, handle_queue_err) {
+                    let depth = me.queue.len();
+                    let msgs = me.queue.push_or(msg, handle_queue_err);
+                    if me.queue.len() != depth {
+                        record_queue_depth(me.monitoring_client, me.queue.len());
+                    }
+
+                    if let Some(msgs) = msgs {
                         return Poll::Ready(Some(msgs));
                     }
                 }
                 Poll::Ready(None) => {
                     // input stream is closed, drain any remaining messages and terminate
-                    return Poll::Ready(me.queue.pop_all());
+                    return Poll::Ready(pop_all(me.queue, me.monitoring_client));
                 }
                 Poll::Pending => {
                     // if we have no messages queued, we can't produce anything yet
@@ -318,13 +324,35 @@ impl Stream for MsgQueue {
 
                     // check if the deadline has elapsed
                     // if so, flush the queue regardless of how full it is
-                    return me.deadline.poll(cx).map(|_| me.queue.pop_all());
+                    return me
+                        .deadline
+                        .poll(cx)
+                        .map(|_| pop_all(me.queue, me.monitoring_client));
                 }
             }
         }
     }
 }
 
+fn pop_all(
+    queue: &mut Queue,
+    monitoring_client: &monitoring::Client,
+) -> Option<nonempty::Vec<QueueMsg>> {
+    let msgs = queue.pop_all();
+    if msgs.is_some() {
+        record_queue_depth(monitoring_client, queue.len());
+    }
+
+    msgs
+}
+
+fn record_queue_depth(monitoring_client: &monitoring::Client, depth: usize) {
+    monitoring_client.metrics().record_metric(Msg::QueueDepth {
+        queue: metrics::Queue::MsgQueue,
+        depth,
+    });
+}
+
 fn handle_queue_error(msg: QueueMsg, err: Error) {
     let QueueMsg {
         tx_res_callback, ..
@@ -412,6 +440,10 @@ impl Queue {
     pub fn is_empty(&self) -> bool {
         self.msgs.is_empty()
     }
+
+    pub fn len(&self) -> usize {
+        self.msgs.len()
+    }
 }
 
 #[cfg(test)]
@@ -1051,4 +1083,58 @@ mod tests {
         assert_eq!(metric, Msg::MessageEnqueueError);
         assert!(receiver.try_recv().is_err());
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_record_msg_queue_depth() {
+        let gas_cap = 1000u64;
+        let gas_info = Some(GasInfo {
+            gas_wanted: gas_cap / 10,
+            gas_used: gas_cap / 10,
+        });
+
+        let cosmos_client = setup_client_with_simulate(&TMAddress::random(PREFIX), gas_info, 2);
+        let broadcaster = broadcaster::Broadcaster::builder()
+            .client(cosmos_client)
+            .chain_id("chain-id".parse().unwrap())
+            .pub_key(random_cosmos_public_key())
+            .gas_adjustment(1.5)
+            .gas_price(DecCoin::new(0.025, "uaxl").unwrap())
+            .build()
+            .await
+            .unwrap();
+
+        let (monitoring_client, mut receiver) = monitoring::test_utils::monitoring_client();
+
+        let (msg_queue, mut msg_queue_client) = MsgQueue::new_msg_queue_and_client(
+            broadcaster,
+            10,
+            gas_cap,
+            time::Duration::from_secs(3),
+            monitoring_client,
+        );
+        tokio::pin!(msg_queue);
+
+        msg_queue_client
+            .enqueue_and_forget(dummy_msg())
+            .await
+            .unwrap();
+        msg_queue_client
+            .enqueue_and_forget(dummy_msg())
+            .await
+            .unwrap();
+
+        let actual = msg_queue.next().await.unwrap();
+        assert_eq!(actual.as_ref().len(), 2);
+
+        for depth in [1, 2, 0] {
+            assert_eq!(
+                receiver.recv().await.unwrap(),
+                Msg::QueueDepth {
+                    queue: metrics::Queue::MsgQueue,
+                    depth,
+                }
+            );
+        }
+        assert!(receiver.try_recv().is_err());
+    }
 }
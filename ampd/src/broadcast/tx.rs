@@ -2,10 +2,11 @@ use core::fmt::Debug;
 use std::future::Future;
 
 use cosmrs::proto::cosmos::tx::v1beta1::TxRaw;
+use cosmrs::tendermint::block::Height;
 use cosmrs::tendermint::chain::Id;
 use cosmrs::tx::{BodyBuilder, Fee, SignDoc, SignerInfo};
 use cosmrs::Any;
-use error_stack::{Context, Result, ResultExt};
+use error_stack::{report, Context, Result, ResultExt};
 use report::ResultCompatExt;
 use thiserror::Error;
 use typed_builder::TypedBuilder;
@@ -33,6 +34,9 @@ where
     acc_sequence: u64,
     #[builder(default = zero_fee())]
     fee: Fee,
+    /// Block height after which the tx can no longer be included on chain, 0 means no timeout
+    #[builder(default)]
+    timeout_height: u64,
 }
 
 fn zero_fee() -> Fee {
@@ -59,7 +63,12 @@ where
         Fut: Future<Output = Result<Vec<u8>, Err>>,
         Err: Context,
     {
-        let body = BodyBuilder::new().msgs(self.msgs).finish();
+        let timeout_height =
+            Height::try_from(self.timeout_height).map_err(|_| report!(Error::Marshaling))?;
+        let body = BodyBuilder::new()
+            .msgs(self.msgs)
+            .timeout_height(timeout_height)
+            .finish();
         let auth_info =
             SignerInfo::single_direct(Some(self.pub_key), self.acc_sequence).auth_info(self.fee);
         let sign_doc = SignDoc::new(&body, &auth_info, chain_id, acc_number)
@@ -178,6 +187,29 @@ mod tests {
         assert_eq!(actual_tx, expected_tx);
     }
 
+    #[test]
+    async fn with_dummy_sig_should_set_the_timeout_height() {
+        let pub_key: CosmosPublicKey = ecdsa::SigningKey::random(&mut OsRng).verifying_key().into();
+        let msgs = vec![dummy_msg()];
+
+        let actual_tx = Tx::builder()
+            .msgs(msgs.clone())
+            .pub_key(pub_key)
+            .acc_sequence(0)
+            .timeout_height(100)
+            .build()
+            .with_dummy_sig()
+            .await
+            .unwrap();
+
+        let body = BodyBuilder::new()
+            .msgs(msgs)
+            .timeout_height(100u32)
+            .finish();
+
+        assert_eq!(actual_tx.body_bytes, body.into_bytes().unwrap());
+    }
+
     fn dummy_msg() -> Any {
         MsgSend {
             from_address: AccountId::new("", &[1, 2, 3]).unwrap(),
@@ -0,0 +1,26 @@
+use error_stack::{report, Result, ResultExt};
+use report::ResultCompatExt;
+
+use crate::commands::verifier_pub_key;
+use crate::tofnd::{self, Keystore};
+use crate::{Error, PREFIX};
+
+pub async fn run(config: tofnd::Config) -> Result<Option<String>, Error> {
+    let tofnd::Backend::Keystore(keystore) = &config.backend else {
+        return Err(report!(Error::InvalidInput))
+            .attach_printable("tofnd_config.backend must be set to a keystore");
+    };
+
+    Keystore::create(keystore).change_context(Error::Tofnd)?;
+
+    let account_id = verifier_pub_key(config.clone())
+        .await?
+        .account_id(PREFIX)
+        .change_context(Error::Tofnd)?;
+
+    Ok(Some(format!(
+        "created keystore {} for verifier address {}",
+        keystore.path.display(),
+        account_id
+    )))
+}
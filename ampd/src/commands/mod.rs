@@ -9,12 +9,13 @@ use valuable::Valuable;
 
 use crate::asyncutil::future::RetryPolicy;
 use crate::config::Config;
-use crate::tofnd::{Multisig, MultisigClient};
+use crate::tofnd::Multisig;
 use crate::types::{CosmosPublicKey, TMAddress};
 use crate::{broadcast, cosmos, tofnd, Error, PREFIX};
 
 pub mod bond_verifier;
 pub mod claim_stake;
+pub mod create_keystore;
 pub mod daemon;
 pub mod deregister_chain_support;
 pub mod register_chain_support;
@@ -22,6 +23,7 @@ pub mod register_public_key;
 pub mod send_tokens;
 pub mod set_rewards_proxy;
 pub mod unbond_verifier;
+pub mod unjail_verifier;
 pub mod verifier_address;
 
 #[derive(clap::Args, Debug, Valuable)]
@@ -41,6 +43,8 @@ pub enum SubCommand {
     UnbondVerifier(unbond_verifier::Args),
     /// Claim unbonded stake from the service registry contract
     ClaimStake(claim_stake::Args),
+    /// Unjail the verifier in the service registry contract once its jail duration has passed
+    UnjailVerifier(unjail_verifier::Args),
     /// Register chain support to the service registry contract
     RegisterChainSupport(register_chain_support::Args),
     /// Deregister chain support to the service registry contract
@@ -53,6 +57,8 @@ pub enum SubCommand {
     SendTokens(send_tokens::Args),
     /// Set a proxy address to receive rewards, instead of receiving rewards at the verifier address
     SetRewardsProxy(set_rewards_proxy::Args),
+    /// Create the local keystore configured in tofnd_config.backend
+    CreateKeystore,
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -86,10 +92,9 @@ impl Default for RewardsConfig {
 }
 
 async fn verifier_pub_key(config: tofnd::Config) -> Result<CosmosPublicKey, Error> {
-    let pub_key = MultisigClient::new(config.party_uid, config.url.as_str(), config.timeout)
+    let pub_key = tofnd::Client::new(&config)
         .await
-        .change_context(Error::Connection)
-        .attach_printable(config.url.clone())?
+        .change_context(Error::Connection)?
         .keygen(&config.key_uid, tofnd::Algorithm::Ecdsa)
         .await
         .change_context(Error::Tofnd)?;
@@ -132,15 +137,10 @@ async fn broadcast_tx(
     .await
 }
 
-async fn instantiate_multisig_client(tofnd_config: tofnd::Config) -> Result<MultisigClient, Error> {
-    MultisigClient::new(
-        tofnd_config.party_uid,
-        tofnd_config.url.as_str(),
-        tofnd_config.timeout,
-    )
-    .await
-    .change_context(Error::Connection)
-    .attach_printable(tofnd_config.url)
+async fn instantiate_multisig_client(tofnd_config: tofnd::Config) -> Result<tofnd::Client, Error> {
+    tofnd::Client::new(&tofnd_config)
+        .await
+        .change_context(Error::Connection)
 }
 
 async fn instantiate_broadcaster(
@@ -12,7 +12,7 @@ use valuable::Valuable;
 
 use crate::commands::{broadcast_tx, verifier_pub_key, BroadcastArgs};
 use crate::config::Config;
-use crate::tofnd::{self, Multisig, MultisigClient};
+use crate::tofnd::{self, Multisig};
 use crate::types::TMAddress;
 use crate::{handlers, Error, PREFIX};
 
@@ -59,14 +59,9 @@ pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
 
     let tofnd_config = config.tofnd_config.clone();
 
-    let multisig_client = MultisigClient::new(
-        tofnd_config.party_uid,
-        tofnd_config.url.as_str(),
-        tofnd_config.timeout,
-    )
-    .await
-    .change_context(Error::Connection)
-    .attach_printable(tofnd_config.url)?;
+    let multisig_client = tofnd::Client::new(&tofnd_config)
+        .await
+        .change_context(Error::Connection)?;
     let multisig_key = multisig_client
         .keygen(&multisig_address.to_string(), key_type.into())
         .await
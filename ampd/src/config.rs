@@ -72,11 +72,13 @@ mod tests {
     use super::Config;
     use crate::evm::finalizer::Finalization;
     use crate::handlers::config::{Chain, Config as HandlerConfig};
+    use crate::solana::Commitment;
     use crate::types::TMAddress;
     use crate::url::Url;
-    use crate::{grpc, monitoring};
+    use crate::{grpc, monitoring, tofnd};
 
     const PREFIX: &str = "axelar";
+    const SOLANA: &str = "solana";
     const STACKS: &str = "stacks";
 
     #[test]
@@ -480,6 +482,38 @@ mod tests {
         assert_eq!(cfg.tofnd_config.url.as_str(), url);
         assert_eq!(cfg.tofnd_config.party_uid.as_str(), party_uid);
         assert_eq!(cfg.tofnd_config.key_uid.as_str(), key_uid);
+        assert_eq!(cfg.tofnd_config.backend, tofnd::Backend::Tofnd);
+    }
+
+    #[test]
+    fn deserialize_tofnd_config_with_keystore_backend() {
+        let config_str = "
+            [tofnd_config]
+            url = 'http://localhost:50051/'
+            party_uid = 'party_uid'
+            key_uid = 'key_uid'
+
+            [tofnd_config.timeout]
+            secs = 5
+            nanos = 0
+
+            [tofnd_config.backend]
+            type = 'Keystore'
+            path = '/tmp/keystore.json'
+            password = { env = 'AMPD_KEYSTORE_PASSWORD' }
+            ";
+
+        let cfg: Config = toml::from_str(config_str).unwrap();
+
+        assert_eq!(
+            cfg.tofnd_config.backend,
+            tofnd::Backend::Keystore(tofnd::keystore::Config {
+                path: PathBuf::from("/tmp/keystore.json"),
+                password: tofnd::keystore::PasswordSource::Env(
+                    "AMPD_KEYSTORE_PASSWORD".to_string()
+                ),
+            })
+        );
     }
     #[test]
     fn serialization_roundtrip_preserves_data() {
@@ -538,6 +572,7 @@ mod tests {
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
                     chain_name: chain_name!("Ethereum"),
+                    payload_validation: None,
                 },
                 HandlerConfig::SuiMsgVerifier {
                     cosmwasm_contract: TMAddress::from(
@@ -596,6 +631,7 @@ mod tests {
                     ),
                     rpc_url: Url::new_non_sensitive("http://127.0.0.1").unwrap(),
                     gateway_address: "11111111111111111111111111111112".to_string(),
+                    commitment: Commitment::Finalized,
                     rpc_timeout: Some(Duration::from_secs(3)),
                 },
                 HandlerConfig::SolanaVerifierSetVerifier {
@@ -605,6 +641,7 @@ mod tests {
                     ),
                     rpc_url: Url::new_non_sensitive("http://127.0.0.1").unwrap(),
                     gateway_address: "11111111111111111111111111111112".to_string(),
+                    commitment: Commitment::Finalized,
                     rpc_timeout: Some(Duration::from_secs(3)),
                 },
                 HandlerConfig::StacksMsgVerifier {
@@ -752,5 +789,35 @@ mod tests {
         assert_eq!(cfg.event_sub.poll_interval, Duration::from_secs(5));
         assert_eq!(cfg.event_sub.retry_delay, Duration::from_secs(3));
         assert_eq!(cfg.event_sub.retry_max_attempts, 3);
+        assert_eq!(cfg.event_sub.progress_file, None);
+        assert_eq!(cfg.event_sub.max_catch_up_blocks, 1000);
+        assert_eq!(
+            cfg.event_sub.progress_persist_interval,
+            Duration::from_secs(5)
+        );
+    }
+
+    #[test]
+    fn deserialize_event_sub_config_with_progress_file() {
+        let config_str = "
+            [event_sub]
+            block_processing_buffer = 10
+            poll_interval = '5s'
+            retry_delay = '3s'
+            retry_max_attempts = 3
+            progress_file = '/tmp/ampd/progress.json'
+            max_catch_up_blocks = 500
+            progress_persist_interval = '30s'
+            ";
+        let cfg: Config = toml::from_str(config_str).unwrap();
+        assert_eq!(
+            cfg.event_sub.progress_file,
+            Some(PathBuf::from("/tmp/ampd/progress.json"))
+        );
+        assert_eq!(cfg.event_sub.max_catch_up_blocks, 500);
+        assert_eq!(
+            cfg.event_sub.progress_persist_interval,
+            Duration::from_secs(30)
+        );
     }
 }
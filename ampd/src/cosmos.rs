@@ -11,6 +11,8 @@ use cosmrs::proto::cosmos::auth::v1beta1::{
 use cosmrs::proto::cosmos::bank::v1beta1::query_client::QueryClient as BankQueryClient;
 use cosmrs::proto::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
 use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmrs::proto::cosmos::base::node::v1beta1::service_client::ServiceClient as NodeServiceClient;
+use cosmrs::proto::cosmos::base::node::v1beta1::{ConfigRequest, ConfigResponse};
 use cosmrs::proto::cosmos::tx::v1beta1::service_client::ServiceClient;
 use cosmrs::proto::cosmos::tx::v1beta1::{
     BroadcastMode, BroadcastTxRequest, BroadcastTxResponse, GetTxRequest, GetTxResponse,
@@ -20,7 +22,7 @@ use cosmrs::proto::cosmwasm::wasm::v1::query_client::QueryClient as CosmWasmQuer
 use cosmrs::proto::cosmwasm::wasm::v1::{
     QuerySmartContractStateRequest, QuerySmartContractStateResponse,
 };
-use cosmrs::tx::MessageExt;
+use cosmrs::tx::{Fee, MessageExt};
 use cosmrs::{Any, Coin, Denom, Gas};
 use error_stack::{report, ResultExt};
 use mockall::mock;
@@ -56,6 +58,8 @@ pub enum Error {
     TxBuilding,
     #[error("failed to query the contract state with error {0}")]
     QueryContractState(String),
+    #[error("minimum gas price is missing in the node config response")]
+    MinGasPriceMissing,
 }
 
 mock! {
@@ -74,6 +78,7 @@ mock! {
         async fn tx(&mut self, req: GetTxRequest) -> Result<GetTxResponse>;
         async fn account(&mut self, req: QueryAccountRequest) -> Result<QueryAccountResponse>;
         async fn balance(&mut self, req: QueryBalanceRequest) -> Result<QueryBalanceResponse>;
+        async fn node_config(&mut self, req: ConfigRequest) -> Result<ConfigResponse>;
         async fn smart_contract_state(
             &mut self,
             req: QuerySmartContractStateRequest,
@@ -88,6 +93,7 @@ pub trait CosmosClient {
     async fn tx(&mut self, req: GetTxRequest) -> Result<GetTxResponse>;
     async fn account(&mut self, req: QueryAccountRequest) -> Result<QueryAccountResponse>;
     async fn balance(&mut self, req: QueryBalanceRequest) -> Result<QueryBalanceResponse>;
+    async fn node_config(&mut self, req: ConfigRequest) -> Result<ConfigResponse>;
     async fn smart_contract_state(
         &mut self,
         req: QuerySmartContractStateRequest,
@@ -102,7 +108,7 @@ pub trait CosmosClient {
 /// When `clone()` is called on a CosmosGrpcClient:
 ///
 /// - A new CosmosGrpcClient instance is created with cloned fields
-/// - The underlying gRPC clients (auth, bank, service) are cloned
+/// - The underlying gRPC clients (auth, bank, node, service) are cloned
 /// - The tonic::transport::Channel is cloned, but this doesn't create a new TCP connection
 ///   Instead, it creates a new reference to the same underlying connection pool
 ///
@@ -114,6 +120,7 @@ pub struct CosmosGrpcClient {
     auth: AuthQueryClient<Channel>,
     bank: BankQueryClient<Channel>,
     cosm_wasm: CosmWasmQueryClient<Channel>,
+    node: NodeServiceClient<Channel>,
     service: ServiceClient<Channel>,
 }
 
@@ -131,6 +138,7 @@ impl CosmosGrpcClient {
             auth: AuthQueryClient::new(conn.clone()),
             bank: BankQueryClient::new(conn.clone()),
             cosm_wasm: CosmWasmQueryClient::new(conn.clone()),
+            node: NodeServiceClient::new(conn.clone()),
             service: ServiceClient::new(conn),
         })
     }
@@ -178,6 +186,14 @@ impl CosmosClient for CosmosGrpcClient {
             .map_err(ErrorExt::into_report)
     }
 
+    async fn node_config(&mut self, req: ConfigRequest) -> Result<ConfigResponse> {
+        self.node
+            .config(req)
+            .await
+            .map(Response::into_inner)
+            .map_err(ErrorExt::into_report)
+    }
+
     async fn smart_contract_state(
         &mut self,
         req: QuerySmartContractStateRequest,
@@ -196,16 +212,20 @@ impl Debug for CosmosGrpcClient {
             .field("auth", &REDACTED_VALUE)
             .field("bank", &REDACTED_VALUE)
             .field("cosm_wasm", &REDACTED_VALUE)
+            .field("node", &REDACTED_VALUE)
             .field("service", &REDACTED_VALUE)
             .finish()
     }
 }
 
+/// Simulates a tx with the given messages and fee, and returns the gas it used.
+/// The fee should be non-zero, so the simulation includes the gas cost of deducting it.
 pub async fn estimate_gas<T>(
     client: &mut T,
     msgs: Vec<Any>,
     pub_key: CosmosPublicKey,
     acc_sequence: u64,
+    fee: Fee,
 ) -> Result<Gas>
 where
     T: CosmosClient,
@@ -214,6 +234,7 @@ where
         .msgs(msgs)
         .pub_key(pub_key)
         .acc_sequence(acc_sequence)
+        .fee(fee)
         .build()
         .with_dummy_sig()
         .await
@@ -260,6 +281,25 @@ where
         .and_then(|res| res.tx_response.ok_or(report!(Error::TxResponseMissing)))
 }
 
+/// Queries the minimum gas prices the node accepts for txs, e.g. `0.007uaxl`.
+/// Multiple prices are separated by commas.
+pub async fn minimum_gas_price<T>(client: &mut T) -> Result<String>
+where
+    T: CosmosClient,
+{
+    client
+        .node_config(ConfigRequest {})
+        .await
+        .map(|res| res.minimum_gas_price)
+        .and_then(|min_gas_price| {
+            if min_gas_price.is_empty() {
+                Err(report!(Error::MinGasPriceMissing))
+            } else {
+                Ok(min_gas_price)
+            }
+        })
+}
+
 pub async fn balance<T>(client: &mut T, address: &TMAddress, denom: &Denom) -> Result<Coin>
 where
     T: CosmosClient,
@@ -345,19 +385,27 @@ mod tests {
             value: vec![1, 2, 3],
         }];
         let gas_used = 150000u64;
+        let fee = Fee::from_amount_and_gas(Coin::new(1, "uaxl").unwrap(), 1u64);
 
         let mut mock_client = MockCosmosClient::new();
-        mock_client.expect_simulate().return_once(move |_| {
-            Ok(SimulateResponse {
-                gas_info: Some(cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo {
-                    gas_wanted: 200000,
-                    gas_used,
-                }),
-                result: None,
+        mock_client
+            .expect_simulate()
+            .withf(|req| {
+                let actual_fee = crate::broadcast::test_utils::decode_gas_fee(&req.tx_bytes);
+
+                actual_fee.gas_limit == 1 && actual_fee.amount.first().unwrap().amount == "1"
             })
-        });
+            .return_once(move |_| {
+                Ok(SimulateResponse {
+                    gas_info: Some(cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo {
+                        gas_wanted: 200000,
+                        gas_used,
+                    }),
+                    result: None,
+                })
+            });
 
-        let actual = estimate_gas(&mut mock_client, msgs, pub_key, acc_sequence).await;
+        let actual = estimate_gas(&mut mock_client, msgs, pub_key, acc_sequence, fee).await;
 
         assert_eq!(actual.unwrap(), gas_used);
     }
@@ -379,7 +427,8 @@ mod tests {
             })
         });
 
-        let actual = estimate_gas(&mut mock_client, msgs, pub_key, acc_sequence).await;
+        let fee = Fee::from_amount_and_gas(Coin::new(1, "uaxl").unwrap(), 1u64);
+        let actual = estimate_gas(&mut mock_client, msgs, pub_key, acc_sequence, fee).await;
 
         assert_err_contains!(actual, Error, Error::GasInfoMissing);
     }
@@ -552,6 +601,36 @@ mod tests {
         assert_err_contains!(actual, Error, Error::MalformedResponse);
     }
 
+    #[tokio::test]
+    async fn minimum_gas_price_success() {
+        let mut mock_client = MockCosmosClient::new();
+        mock_client
+            .expect_node_config()
+            .with(predicate::eq(ConfigRequest {}))
+            .return_once(|_| {
+                Ok(ConfigResponse {
+                    minimum_gas_price: "0.007uaxl".to_string(),
+                    ..Default::default()
+                })
+            });
+
+        let actual = minimum_gas_price(&mut mock_client).await;
+
+        assert_eq!(actual.unwrap(), "0.007uaxl");
+    }
+
+    #[tokio::test]
+    async fn minimum_gas_price_missing() {
+        let mut mock_client = MockCosmosClient::new();
+        mock_client
+            .expect_node_config()
+            .return_once(|_| Ok(ConfigResponse::default()));
+
+        let actual = minimum_gas_price(&mut mock_client).await;
+
+        assert_err_contains!(actual, Error, Error::MinGasPriceMissing);
+    }
+
     #[tokio::test]
     async fn tx_success() {
         let tx_hash = "ABC123";
@@ -16,6 +16,7 @@ use valuable::Valuable;
 use crate::asyncutil::future::{with_retry, RetryPolicy};
 use crate::asyncutil::task::TaskError;
 use crate::event_sub::event_filter::EventFilters;
+use crate::event_sub::progress::ProgressStore;
 use crate::monitoring::metrics;
 use crate::monitoring::metrics::{Msg, Stage};
 use crate::{broadcast, cosmos, event_sub, monitoring};
@@ -72,7 +73,10 @@ impl Default for Config {
 
 /// Let the `handler` consume events from the `event_stream`. The token is checked for cancellation
 /// at the end of each consumed block or when the `event_stream` times out. If the token is cancelled or the
-/// `event_stream` is closed, the function returns
+/// `event_stream` is closed, the function returns.
+/// Events of blocks that the handler has already processed according to `progress` are skipped,
+/// and the height of every fully processed block is recorded in `progress`.
+#[allow(clippy::too_many_arguments)]
 #[instrument(fields(handler = %handler_label), skip_all)]
 pub async fn consume_events<H, S, C>(
     handler_label: String,
@@ -82,6 +86,7 @@ pub async fn consume_events<H, S, C>(
     token: CancellationToken,
     msg_queue_client: broadcast::MsgQueueClient<C>,
     monitoring_client: monitoring::Client,
+    progress: ProgressStore,
 ) -> Result<(), Error>
 where
     H: EventHandler,
@@ -96,6 +101,8 @@ where
         ..
     } = event_processor_config;
     let handler_retry = RetryPolicy::repeat_constant(retry_delay, retry_max_attempts);
+    let last_processed_block = progress.height(&handler_label);
+    let mut current_block = None;
 
     let event_stream =
         tokio_stream::StreamExt::timeout_repeating(event_stream, time::interval(stream_timeout));
@@ -112,6 +119,17 @@ where
     while let Some(event) = event_stream.next().await {
         match event {
             StreamStatus::Ok(event) => {
+                if let Event::BlockBegin(height) | Event::BlockEnd(height) = &event {
+                    current_block = Some(*height);
+                }
+
+                if current_block
+                    .zip(last_processed_block)
+                    .is_some_and(|(current, last_processed)| current <= last_processed)
+                {
+                    continue;
+                }
+
                 handle_event(
                     &handler_label,
                     &handler,
@@ -122,6 +140,10 @@ where
                     &monitoring_client,
                 )
                 .await?;
+
+                if let Event::BlockEnd(height) = event {
+                    progress.update(&handler_label, height);
+                }
             }
             StreamStatus::Error(err) => return Err(err.change_context(Error::EventStream)),
             StreamStatus::TimedOut => {
@@ -170,6 +192,13 @@ where
         success: res.is_ok(),
         duration: elapsed,
     });
+    monitoring_client
+        .metrics()
+        .record_metric(Msg::EventHandlerResult {
+            handler: handler_label.to_string(),
+            success: res.is_ok(),
+            duration: elapsed,
+        });
 
     match res {
         Ok(msgs) => {
@@ -285,6 +314,7 @@ mod tests {
     use crate::broadcast::DecCoin;
     use crate::event_processor::{consume_events, Config, Error, EventHandler};
     use crate::event_sub::event_filter::EventFilters;
+    use crate::event_sub::progress::ProgressStore;
     use crate::types::{random_cosmos_public_key, TMAddress};
     use crate::{broadcast, cosmos, event_sub, monitoring, PREFIX};
 
@@ -428,11 +458,77 @@ mod tests {
             CancellationToken::new(),
             msg_queue_client,
             monitoring_client,
+            ProgressStore::default(),
         )
         .await;
         assert!(result.is_ok());
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn skip_already_processed_blocks_and_record_progress() {
+        let pub_key = random_cosmos_public_key();
+        let address: TMAddress = pub_key.account_id(PREFIX).unwrap().into();
+        let chain_id: chain::Id = "test-chain-id".parse().unwrap();
+        let event_config = setup_event_config(
+            Duration::from_secs(1),
+            Duration::from_secs(1000),
+            Duration::from_secs(1),
+        );
+        let events: Vec<Result<Event, event_sub::Error>> = vec![
+            Ok(Event::BlockBegin(1_u32.into())),
+            Ok(Event::BlockEnd(1_u32.into())),
+            Ok(Event::BlockBegin(2_u32.into())),
+            Ok(Event::BlockEnd(2_u32.into())),
+            Ok(Event::BlockBegin(3_u32.into())),
+            Ok(Event::BlockEnd(3_u32.into())),
+        ];
+
+        let mut handler = MockEventHandler::new();
+        handler
+            .expect_handle()
+            .times(2) // only the events of block 3
+            .returning(|_| Ok(vec![]));
+
+        let broadcaster = broadcast::Broadcaster::builder()
+            .client(setup_client(&address))
+            .chain_id(chain_id)
+            .pub_key(pub_key)
+            .gas_adjustment(1.5)
+            .gas_price(DecCoin::new(0.025, "uaxl").unwrap())
+            .build()
+            .await
+            .unwrap();
+
+        let (monitoring_client, _) = test_utils::monitoring_client();
+
+        let (_, msg_queue_client) = broadcast::MsgQueue::new_msg_queue_and_client(
+            broadcaster,
+            10,
+            100,
+            Duration::from_millis(500),
+            monitoring_client.clone(),
+        );
+
+        let progress = ProgressStore::default();
+        progress.update("handler", 2_u32.into());
+        progress.update("other-handler", 1_u32.into());
+
+        let result = consume_events(
+            "handler".to_string(),
+            handler,
+            stream::iter(events),
+            event_config,
+            CancellationToken::new(),
+            msg_queue_client,
+            monitoring_client,
+            progress.clone(),
+        )
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(progress.height("handler"), Some(3_u32.into()));
+        assert_eq!(progress.height("other-handler"), Some(1_u32.into()));
+    }
+
     #[tokio::test(start_paused = true)]
     async fn return_error_when_stream_fails() {
         let pub_key = random_cosmos_public_key();
@@ -483,6 +579,7 @@ mod tests {
             CancellationToken::new(),
             msg_queue_client,
             monitoring_client,
+            ProgressStore::default(),
         )
         .await;
         assert_err_contains!(result, Error, Error::EventStream);
@@ -537,6 +634,7 @@ mod tests {
             CancellationToken::new(),
             msg_queue_client,
             monitoring_client,
+            ProgressStore::default(),
         )
         .await;
         assert!(result.is_ok());
@@ -611,6 +709,7 @@ mod tests {
             CancellationToken::new(),
             msg_queue_client,
             monitoring_client,
+            ProgressStore::default(),
         )
         .await;
         assert!(result.is_ok());
@@ -683,6 +782,7 @@ mod tests {
             CancellationToken::new(),
             msg_queue_client,
             monitoring_client,
+            ProgressStore::default(),
         )
         .await;
         let msgs = msg_queue.collect::<Vec<_>>().await;
@@ -747,6 +847,7 @@ mod tests {
             token.child_token(),
             msg_queue_client,
             monitoring_client,
+            ProgressStore::default(),
         )
         .await;
         assert!(result.is_ok());
@@ -796,6 +897,7 @@ mod tests {
             token.child_token(),
             msg_queue_client,
             monitoring_client,
+            ProgressStore::default(),
         )
         .await;
         assert!(result.is_ok());
@@ -858,6 +960,7 @@ mod tests {
                 cancel_token.clone(),
                 msg_queue_client,
                 monitoring_client,
+                ProgressStore::default(),
             ),
         )
         .await;
@@ -952,6 +1055,7 @@ mod tests {
             CancellationToken::new(),
             msg_queue_client,
             monitoring_client,
+            ProgressStore::default(),
         )
         .await;
 
@@ -979,6 +1083,18 @@ mod tests {
         })
         .await;
 
+        expect_metric_msg(&mut receiver, |m| {
+            matches!(
+                m,
+                metrics::Msg::EventHandlerResult {
+                    handler,
+                    success: false,
+                    ..
+                } if handler == "handler"
+            )
+        })
+        .await;
+
         assert!(receiver.try_recv().is_err());
     }
 
@@ -1025,6 +1141,7 @@ mod tests {
             token.child_token(),
             msg_queue_client,
             monitoring_client,
+            ProgressStore::default(),
         ));
 
         tokio::time::advance(Duration::from_secs(2)).await;
@@ -1084,6 +1201,7 @@ mod tests {
             CancellationToken::new(),
             msg_queue_client,
             monitoring_client,
+            ProgressStore::default(),
         )
         .await;
 
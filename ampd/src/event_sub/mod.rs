@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::time::Duration;
 
 use error_stack::{Report, Result};
@@ -22,6 +23,7 @@ use crate::monitoring::metrics::Msg;
 use crate::tm_client::TmClient;
 
 pub mod event_filter;
+pub mod progress;
 pub mod stream;
 
 #[derive(Error, Debug, Clone)]
@@ -56,6 +58,29 @@ pub struct Config {
     #[serde(with = "humantime_serde")]
     pub retry_delay: Duration,
     pub retry_max_attempts: u64,
+
+    // File to persist the last fully processed block height of each handler.
+    // If not set, ampd starts from the latest block after every restart.
+    #[serde(default)]
+    pub progress_file: Option<PathBuf>,
+    // The maximum number of blocks to replay after a restart.
+    // If ampd was stopped for longer, it skips the older blocks and only catches up on the most recent ones.
+    #[serde(default = "default_max_catch_up_blocks")]
+    pub max_catch_up_blocks: u64,
+    // Interval to write the progress to the progress file. It is also written once on shutdown.
+    #[serde(
+        with = "humantime_serde",
+        default = "default_progress_persist_interval"
+    )]
+    pub progress_persist_interval: Duration,
+}
+
+fn default_max_catch_up_blocks() -> u64 {
+    1000
+}
+
+fn default_progress_persist_interval() -> Duration {
+    Duration::from_secs(5)
 }
 
 impl Default for Config {
@@ -65,6 +90,9 @@ impl Default for Config {
             poll_interval: Duration::from_secs(5),
             retry_delay: Duration::from_secs(3),
             retry_max_attempts: 3,
+            progress_file: None,
+            max_catch_up_blocks: default_max_catch_up_blocks(),
+            progress_persist_interval: default_progress_persist_interval(),
         }
     }
 }
@@ -100,6 +128,8 @@ pub struct EventPublisher<T: TmClient + Sync> {
     block_processing_buffer: usize,
     retry_policy: RetryPolicy,
     monitoring_client: monitoring::Client,
+    resume_after: Option<block::Height>,
+    max_catch_up_blocks: u64,
 }
 
 impl<T: TmClient + Sync + std::fmt::Debug> EventPublisher<T> {
@@ -122,16 +152,36 @@ impl<T: TmClient + Sync + std::fmt::Debug> EventPublisher<T> {
             block_processing_buffer,
             retry_policy,
             monitoring_client,
+            resume_after: None,
+            max_catch_up_blocks: 0,
         };
         let subscriber = EventSubscriber { tx };
 
         (publisher, subscriber)
     }
 
+    /// Resumes streaming right after the given block height instead of starting at the latest block.
+    /// At most `max_catch_up_blocks` blocks behind the latest block are streamed.
+    pub fn resume_after(mut self, height: block::Height, max_catch_up_blocks: u64) -> Self {
+        self.resume_after = Some(height);
+        self.max_catch_up_blocks = max_catch_up_blocks;
+        self
+    }
+
     #[instrument]
     pub async fn run(self, token: CancellationToken) -> Result<(), Error> {
-        let block_stream = stream::blocks(&self.tm_client, self.poll_interval, self.delay)
-            .filter(|_| future::ready(self.has_subscriber())); // skip processing blocks when no subscriber exists
+        let initial_state = match self.resume_after {
+            Some(height) => stream::StreamState::resume(height, self.max_catch_up_blocks),
+            None => stream::StreamState::Start,
+        };
+
+        let block_stream = stream::blocks_from(
+            &self.tm_client,
+            self.poll_interval,
+            self.delay,
+            initial_state,
+        )
+        .filter(|_| future::ready(self.has_subscriber())); // skip processing blocks when no subscriber exists
         let event_stream = stream::events(
             &self.tm_client,
             block_stream,
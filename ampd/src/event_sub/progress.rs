@@ -0,0 +1,272 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::time::Duration;
+
+use error_stack::{report, Result, ResultExt};
+use report::LoggableError;
+use tendermint::block;
+use thiserror::Error;
+use tokio::time;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+use valuable::Valuable;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to read block progress from {0}")]
+    Read(String),
+    #[error("failed to parse block progress from {0}")]
+    Parse(String),
+    #[error("failed to write block progress to {0}")]
+    Write(String),
+    #[error("failed to persist block progress")]
+    Persist,
+}
+
+/// Keeps track of the last block height that each handler has fully processed.
+///
+/// Updates are kept in memory. If a file is configured, [ProgressStore::run] periodically persists them,
+/// so ampd can resume from them after a restart.
+#[derive(Clone, Debug, Default)]
+pub struct ProgressStore {
+    file: Option<PathBuf>,
+    heights: Arc<RwLock<BTreeMap<String, u64>>>,
+    dirty: Arc<AtomicBool>,
+    write_lock: Arc<Mutex<()>>,
+}
+
+impl ProgressStore {
+    /// Creates a new store and loads previously persisted heights from the given file, if it exists
+    pub fn load(file: Option<PathBuf>) -> Result<Self, Error> {
+        let heights = match &file {
+            Some(path) if path.exists() => read(path)?,
+            _ => BTreeMap::new(),
+        };
+
+        Ok(Self {
+            file,
+            heights: Arc::new(RwLock::new(heights)),
+            dirty: Arc::default(),
+            write_lock: Arc::default(),
+        })
+    }
+
+    /// Returns the last block height that was fully processed by the given handler
+    pub fn height(&self, handler: &str) -> Option<block::Height> {
+        self.heights
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(handler)
+            .and_then(|height| block::Height::try_from(*height).ok())
+    }
+
+    /// Returns the last fully processed block height of all handlers
+    pub fn heights(&self) -> BTreeMap<String, u64> {
+        self.heights
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Returns the lowest block height that has been fully processed by the given handlers.
+    /// Handlers without any recorded progress are ignored.
+    pub fn resume_height<'a>(
+        &self,
+        handlers: impl IntoIterator<Item = &'a str>,
+    ) -> Option<block::Height> {
+        let heights = self.heights.read().unwrap_or_else(PoisonError::into_inner);
+
+        handlers
+            .into_iter()
+            .filter_map(|handler| heights.get(handler))
+            .min()
+            .and_then(|height| block::Height::try_from(*height).ok())
+    }
+
+    /// Records that the given handler has fully processed the block at the given height
+    pub fn update(&self, handler: &str, height: block::Height) {
+        self.heights
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(handler.to_string(), height.value());
+        self.dirty.store(true, Ordering::Release);
+    }
+
+    /// Writes the progress to the configured file if it changed since it was last persisted.
+    /// The file is written on a blocking thread, so it does not stall the async runtime.
+    pub async fn persist(&self) -> Result<(), Error> {
+        let Some(path) = self.file.clone() else {
+            return Ok(());
+        };
+
+        if !self.dirty.swap(false, Ordering::AcqRel) {
+            return Ok(());
+        }
+
+        let heights = self.heights();
+        let write_lock = self.write_lock.clone();
+        let res = tokio::task::spawn_blocking(move || {
+            // concurrent writes must not interleave on the temporary file
+            let _guard = write_lock.lock().unwrap_or_else(PoisonError::into_inner);
+            write(&path, &heights)
+        })
+        .await
+        .unwrap_or_else(|err| Err(report!(err).change_context(Error::Persist)));
+
+        if res.is_err() {
+            self.dirty.store(true, Ordering::Release);
+        }
+
+        res
+    }
+
+    /// Persists the progress every `interval` until the token is cancelled.
+    /// Failures are only logged, because the progress is retried with the next interval.
+    pub async fn run(self, interval: Duration, token: CancellationToken) -> Result<(), Error> {
+        let mut interval = time::interval(interval);
+        interval.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => self.persist_or_warn().await,
+                _ = token.cancelled() => break,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Persists the progress and logs a warning if that fails
+    pub async fn persist_or_warn(&self) {
+        let _ = self.persist().await.inspect_err(|err| {
+            warn!(
+                err = LoggableError::from(err).as_value(),
+                "failed to persist block progress"
+            )
+        });
+    }
+}
+
+fn read(path: &Path) -> Result<BTreeMap<String, u64>, Error> {
+    let content =
+        fs::read_to_string(path).change_context_lazy(|| Error::Read(path.display().to_string()))?;
+
+    serde_json::from_str(&content).change_context_lazy(|| Error::Parse(path.display().to_string()))
+}
+
+fn write(path: &Path, heights: &BTreeMap<String, u64>) -> Result<(), Error> {
+    let content = serde_json::to_vec_pretty(heights)
+        .change_context_lazy(|| Error::Write(path.display().to_string()))?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).change_context_lazy(|| Error::Write(path.display().to_string()))?;
+    }
+
+    // write to a temporary file first, so a crash in the middle of writing cannot corrupt the persisted progress
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, content)
+        .change_context_lazy(|| Error::Write(path.display().to_string()))?;
+    fs::rename(&tmp_path, path).change_context_lazy(|| Error::Write(path.display().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use random_string::generate;
+    use tendermint::block;
+
+    use super::ProgressStore;
+
+    fn random_file() -> PathBuf {
+        std::env::temp_dir()
+            .join(generate(10, "abcdefghijklmnopqrstuvwxyz"))
+            .join("progress.json")
+    }
+
+    #[test]
+    fn store_without_file_keeps_progress_in_memory() {
+        let store = ProgressStore::load(None).unwrap();
+        assert_eq!(store.height("handler"), None);
+
+        store.update("handler", 10u32.into());
+        assert_eq!(store.height("handler"), Some(10u32.into()));
+    }
+
+    #[tokio::test]
+    async fn store_should_persist_and_reload_progress() {
+        let file = random_file();
+
+        let store = ProgressStore::load(Some(file.clone())).unwrap();
+        store.update("handler-a", 10u32.into());
+        store.update("handler-b", 20u32.into());
+        store.update("handler-a", 11u32.into());
+        assert!(!file.exists());
+
+        store.persist().await.unwrap();
+
+        let reloaded = ProgressStore::load(Some(file.clone())).unwrap();
+        assert_eq!(reloaded.height("handler-a"), Some(11u32.into()));
+        assert_eq!(reloaded.height("handler-b"), Some(20u32.into()));
+        assert_eq!(reloaded.heights(), store.heights());
+
+        fs::remove_dir_all(file.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn persist_should_only_write_changed_progress() {
+        let file = random_file();
+
+        let store = ProgressStore::load(Some(file.clone())).unwrap();
+        store.update("handler", 10u32.into());
+        store.persist().await.unwrap();
+
+        fs::remove_file(&file).unwrap();
+        store.persist().await.unwrap();
+        assert!(!file.exists());
+
+        store.update("handler", 11u32.into());
+        store.persist().await.unwrap();
+        assert_eq!(
+            ProgressStore::load(Some(file.clone()))
+                .unwrap()
+                .height("handler"),
+            Some(11u32.into())
+        );
+
+        fs::remove_dir_all(file.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn load_should_fail_on_corrupted_file() {
+        let file = random_file();
+        fs::create_dir_all(file.parent().unwrap()).unwrap();
+        fs::write(&file, "not json").unwrap();
+
+        assert!(ProgressStore::load(Some(file.clone())).is_err());
+
+        fs::remove_dir_all(file.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn resume_height_should_be_lowest_height_of_given_handlers() {
+        let store = ProgressStore::load(None).unwrap();
+        store.update("handler-a", 10u32.into());
+        store.update("handler-b", 5u32.into());
+        store.update("removed-handler", 1u32.into());
+
+        assert_eq!(
+            store.resume_height(["handler-a", "handler-b", "new-handler"]),
+            Some(block::Height::from(5u32))
+        );
+        assert_eq!(
+            store.resume_height(["handler-a"]),
+            Some(block::Height::from(10u32))
+        );
+        assert_eq!(store.resume_height(["new-handler"]), None);
+    }
+}
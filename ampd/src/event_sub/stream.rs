@@ -38,6 +38,21 @@ pub fn blocks<Client>(
     poll_interval: Duration,
     stream_delay: Duration,
 ) -> impl Stream<Item = Result<block::Height>> + '_
+where
+    Client: TmClient + Sync + Debug,
+{
+    blocks_from(tm_client, poll_interval, stream_delay, StreamState::Start)
+}
+
+/// Same as [blocks], but the stream starts in the given state.
+/// Use [StreamState::resume] to continue streaming right after a previously processed block height.
+#[instrument]
+pub fn blocks_from<Client>(
+    tm_client: &Client,
+    poll_interval: Duration,
+    stream_delay: Duration,
+    initial_state: StreamState,
+) -> impl Stream<Item = Result<block::Height>> + '_
 where
     Client: TmClient + Sync + Debug,
 {
@@ -46,7 +61,7 @@ where
         .strictly_increasing_values()
         .map(move |result| delay_blocks(result, stream_delay))
         .buffered(1) // so blocks can be filled in without dealing with futures
-        .fill_gaps()
+        .fill_gaps_from(initial_state)
 }
 
 async fn latest_block_height<T: TmClient>(tm_client: &T) -> Result<block::Height> {
@@ -339,9 +354,31 @@ pub enum StreamState {
     /// height is received from the blockchain. The stream will yield `None` until
     /// new blocks become available.
     CaughtUp(block::Height),
+
+    /// State when resuming after a restart, before the latest block height has been received.
+    ///
+    /// # Fields
+    /// * `last_processed` - The last block height that was processed before the restart
+    /// * `max_catch_up_blocks` - The maximum number of blocks to replay up to the latest block height
+    ///
+    /// Once the latest block height is known, all blocks after `last_processed` get streamed,
+    /// but never more than `max_catch_up_blocks` of them. Older blocks are skipped.
+    Resume {
+        last_processed: block::Height,
+        max_catch_up_blocks: u64,
+    },
 }
 
 impl StreamState {
+    /// Creates a state that resumes streaming right after `last_processed`,
+    /// replaying at most `max_catch_up_blocks` blocks up to the latest block height.
+    pub fn resume(last_processed: block::Height, max_catch_up_blocks: u64) -> Self {
+        StreamState::Resume {
+            last_processed,
+            max_catch_up_blocks,
+        }
+    }
+
     /// Updates the state machine with a new latest block height from the blockchain.
     ///
     /// This method drives the state transitions by incorporating new block height information.
@@ -350,6 +387,9 @@ impl StreamState {
     /// # State Transition Logic
     ///
     /// * **Start → First**: When receiving the very first block height
+    /// * **Resume → BlocksAvailable**: When blocks after the last processed one are available,
+    ///   limited to the catch-up window. A window of 0 only streams the latest block
+    /// * **Resume → CaughtUp**: When the latest block height has already been processed
     /// * **Ignore stale updates**: If the new latest is older than or equal to the current latest
     /// * **Transition to BlocksAvailable**: When there's a gap between streamed and new latest
     /// * **Stay in current state**: When the update doesn't change the streaming situation
@@ -388,6 +428,26 @@ impl StreamState {
                 first: new_latest,
                 streamed: false,
             },
+            StreamState::Resume {
+                last_processed,
+                max_catch_up_blocks,
+            } => {
+                let earliest = new_latest
+                    .value()
+                    .saturating_sub(max_catch_up_blocks.max(1));
+                let streamed = block::Height::try_from(earliest)
+                    .expect("height below the latest block height must be valid")
+                    .max(last_processed);
+
+                if streamed >= new_latest {
+                    StreamState::CaughtUp(streamed)
+                } else {
+                    StreamState::BlocksAvailable {
+                        streamed,
+                        latest: new_latest,
+                    }
+                }
+            }
             StreamState::First { first: latest, .. }
             | StreamState::BlocksAvailable { latest, .. }
             | StreamState::CaughtUp(latest)
@@ -414,7 +474,7 @@ impl StreamState {
     ///
     /// # Behavior by State
     ///
-    /// * **Start**: Returns `None` - no blocks available to stream yet
+    /// * **Start** and **Resume**: Returns `None` - no blocks available to stream yet
     /// * **CaughtUp**: Returns `None` - waiting for new blocks from blockchain
     /// * **First**: Returns the first block once, then marks it as streamed
     /// * **BlocksAvailable**: Returns the next sequential block and updates state
@@ -456,7 +516,7 @@ impl StreamState {
     /// * `None` - No blocks available to stream in the current state
     pub fn stream(&mut self) -> Option<block::Height> {
         match *self {
-            StreamState::Start | StreamState::CaughtUp(_) => None,
+            StreamState::Start | StreamState::Resume { .. } | StreamState::CaughtUp(_) => None,
             StreamState::First { first, streamed } => {
                 *self = StreamState::First {
                     first,
@@ -486,10 +546,10 @@ trait FillGapsExt
 where
     Self: TryStream + Sized,
 {
-    fn fill_gaps(self) -> FillGaps<Self> {
+    fn fill_gaps_from(self, state: StreamState) -> FillGaps<Self> {
         FillGaps {
             stream: self,
-            state: StreamState::Start,
+            state,
         }
     }
 }
@@ -534,7 +594,7 @@ mod tests {
 
     use super::super::tests::{block_results_response, random_event};
     use crate::asyncutil::future::RetryPolicy;
-    use crate::event_sub::stream::{blocks, events};
+    use crate::event_sub::stream::{blocks, blocks_from, events, StreamState};
     use crate::event_sub::Error;
     use crate::tm_client::{self, MockTmClient, TmClient};
 
@@ -775,6 +835,86 @@ mod tests {
         }
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn blocks_stream_resumes_after_last_processed_block() {
+        let base_block: tendermint::Block =
+            serde_json::from_str(include_str!("../tests/axelar_block.json")).unwrap();
+        let mut tm_client = MockTmClient::new();
+        let mut call_count = 0u64;
+        let last_processed = 700u64;
+        let latest_height = 705u64;
+
+        tm_client.expect_latest_block().returning(move || {
+            call_count += 1;
+            let height = latest_height + call_count - 1;
+            Ok(create_block_with_height(&base_block, height))
+        });
+
+        let poll_interval = Duration::from_millis(100);
+        let stream_delay = Duration::from_millis(10);
+
+        let stream = blocks_from(
+            &tm_client,
+            poll_interval,
+            stream_delay,
+            StreamState::resume(last_processed.try_into().unwrap(), 100),
+        );
+        let results: Vec<_> = stream.take(8).collect().await; // Should get 701-708
+
+        assert_eq!(results.len(), 8);
+        for (i, height_result) in results.into_iter().enumerate() {
+            assert_eq!(
+                height_result.unwrap().value(),
+                last_processed + 1 + i as u64
+            );
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn blocks_stream_limits_replayed_blocks_to_catch_up_window() {
+        let base_block: tendermint::Block =
+            serde_json::from_str(include_str!("../tests/axelar_block.json")).unwrap();
+        let mut tm_client = MockTmClient::new();
+        let last_processed = 800u64;
+        let latest_height = 900u64;
+        let max_catch_up_blocks = 3;
+
+        tm_client
+            .expect_latest_block()
+            .returning(move || Ok(create_block_with_height(&base_block, latest_height)));
+
+        let poll_interval = Duration::from_millis(100);
+        let stream_delay = Duration::from_millis(10);
+
+        let stream = blocks_from(
+            &tm_client,
+            poll_interval,
+            stream_delay,
+            StreamState::resume(last_processed.try_into().unwrap(), max_catch_up_blocks),
+        );
+        let results: Vec<_> = stream.take(3).collect().await; // Should get 898-900
+
+        assert_eq!(results.len(), 3);
+        for (i, height_result) in results.into_iter().enumerate() {
+            assert_eq!(
+                height_result.unwrap().value(),
+                latest_height - max_catch_up_blocks + 1 + i as u64
+            );
+        }
+    }
+
+    #[test]
+    fn resume_state_waits_until_chain_passes_last_processed_block() {
+        let state = StreamState::resume(1000u32.into(), 100);
+
+        let mut state = state.update_latest(990u32.into());
+        assert_eq!(state.stream(), None);
+
+        let mut state = state.update_latest(1001u32.into());
+        assert_eq!(state.stream(), Some(1001u32.into()));
+        assert_eq!(state.stream(), None);
+    }
+
     #[tokio::test(start_paused = true)]
     async fn events_stream_single_block_with_all_event_types() {
         let mut tm_client = MockTmClient::new();
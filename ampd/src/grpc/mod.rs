@@ -118,7 +118,7 @@ pub struct Server {
     event_sub: event_sub::EventSubscriber,
     msg_queue_client: broadcast::MsgQueueClient<cosmos::CosmosGrpcClient>,
     cosmos_grpc_client: cosmos::CosmosGrpcClient,
-    multisig_client: tofnd::MultisigClient,
+    multisig_client: tofnd::Client,
     service_registry: TMAddress,
     latest_block_height: Receiver<u64>,
     rewards: TMAddress,
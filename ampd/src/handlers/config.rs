@@ -5,9 +5,13 @@ use itertools::Itertools;
 use router_api::ChainName;
 use serde::de::{self, Deserializer};
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 use serde_with::with_prefix;
 
 use crate::evm::finalizer::Finalization;
+use crate::handlers::payload_validation;
+use crate::monitoring::endpoints::readiness::{self, DependencyKind};
+use crate::solana::Commitment;
 use crate::types::TMAddress;
 use crate::url::Url;
 
@@ -46,6 +50,8 @@ pub enum Config {
     MultisigSigner {
         cosmwasm_contract: TMAddress,
         chain_name: ChainName,
+        #[serde(default)]
+        payload_validation: Option<payload_validation::Config>,
     },
     SuiMsgVerifier {
         cosmwasm_contract: TMAddress,
@@ -100,9 +106,26 @@ pub enum Config {
         #[serde(deserialize_with = "Url::deserialize_sensitive")]
         rpc_url: Url,
     },
-    // TODO: Solana handlers not yet implemented
-    // SolanaMsgVerifier { ... },
-    // SolanaVerifierSetVerifier { ... },
+    SolanaMsgVerifier {
+        chain_name: ChainName,
+        cosmwasm_contract: TMAddress,
+        #[serde(deserialize_with = "Url::deserialize_sensitive")]
+        rpc_url: Url,
+        gateway_address: String,
+        #[serde(default)]
+        commitment: Commitment,
+        rpc_timeout: Option<Duration>,
+    },
+    SolanaVerifierSetVerifier {
+        chain_name: ChainName,
+        cosmwasm_contract: TMAddress,
+        #[serde(deserialize_with = "Url::deserialize_sensitive")]
+        rpc_url: Url,
+        gateway_address: String,
+        #[serde(default)]
+        commitment: Commitment,
+        rpc_timeout: Option<Duration>,
+    },
     StacksMsgVerifier {
         chain_name: ChainName,
         cosmwasm_contract: TMAddress,
@@ -220,6 +243,77 @@ macro_rules! ensure_unique_config {
     };
 }
 
+impl Config {
+    /// Registers a readiness probe for the chain RPC this handler depends on under the given name.
+    /// Handlers that do not talk to a chain, like the multisig signers, do not register anything.
+    pub fn register_readiness_probe(&self, name: impl Into<String>, probes: &readiness::Probes) {
+        let kind = DependencyKind::Handler;
+
+        match self {
+            Config::EvmMsgVerifier { chain, .. }
+            | Config::EvmEventVerifier { chain, .. }
+            | Config::EvmVerifierSetVerifier { chain, .. } => match chain.finalization {
+                Finalization::RPCFinalizedBlock => probes.register(
+                    name,
+                    kind,
+                    readiness::json_rpc_probe(
+                        chain.rpc_url.clone(),
+                        "eth_getBlockByNumber",
+                        json!(["finalized", false]),
+                    ),
+                ),
+                Finalization::ConfirmationHeight => probes.register(
+                    name,
+                    kind,
+                    readiness::json_rpc_probe(chain.rpc_url.clone(), "eth_blockNumber", json!([])),
+                ),
+            },
+            Config::SuiMsgVerifier { rpc_url, .. }
+            | Config::SuiVerifierSetVerifier { rpc_url, .. } => probes.register(
+                name,
+                kind,
+                readiness::json_rpc_probe(
+                    rpc_url.clone(),
+                    "sui_getLatestCheckpointSequenceNumber",
+                    json!([]),
+                ),
+            ),
+            Config::XRPLMsgVerifier { chain_rpc_url, .. } => probes.register(
+                name,
+                kind,
+                readiness::json_rpc_probe(chain_rpc_url.clone(), "server_info", json!([{}])),
+            ),
+            Config::StellarMsgVerifier { rpc_url, .. }
+            | Config::StellarVerifierSetVerifier { rpc_url, .. }
+            | Config::SolanaMsgVerifier { rpc_url, .. }
+            | Config::SolanaVerifierSetVerifier { rpc_url, .. } => probes.register(
+                name,
+                kind,
+                readiness::json_rpc_probe(rpc_url.clone(), "getHealth", serde_json::Value::Null),
+            ),
+            Config::StarknetMsgVerifier { rpc_url, .. }
+            | Config::StarknetVerifierSetVerifier { rpc_url, .. } => probes.register(
+                name,
+                kind,
+                readiness::json_rpc_probe(rpc_url.clone(), "starknet_blockNumber", json!([])),
+            ),
+            Config::MvxMsgVerifier { proxy_url, .. }
+            | Config::MvxVerifierSetVerifier { proxy_url, .. } => probes.register(
+                name,
+                kind,
+                readiness::http_get_probe(proxy_url.clone(), "network/config"),
+            ),
+            Config::StacksMsgVerifier { rpc_url, .. }
+            | Config::StacksVerifierSetVerifier { rpc_url, .. } => probes.register(
+                name,
+                kind,
+                readiness::http_get_probe(rpc_url.clone(), "extended/v2/blocks/latest"),
+            ),
+            Config::MultisigSigner { .. } | Config::XRPLMultisigSigner { .. } => {}
+        }
+    }
+}
+
 pub fn deserialize_handler_configs<'de, D>(deserializer: D) -> Result<Vec<Config>, D::Error>
 where
     D: Deserializer<'de>,
@@ -254,6 +348,16 @@ where
         Config::StellarVerifierSetVerifier,
         "Stellar verifier set verifier"
     )?;
+    ensure_unique_config!(
+        &configs,
+        Config::SolanaMsgVerifier,
+        "Solana message verifier"
+    )?;
+    ensure_unique_config!(
+        &configs,
+        Config::SolanaVerifierSetVerifier,
+        "Solana verifier set verifier"
+    )?;
     ensure_unique_config!(
         &configs,
         Config::StacksMsgVerifier,
@@ -272,14 +376,18 @@ where
 mod tests {
     use router_api::chain_name;
     use serde_json::to_value;
+    use solana_multisig_prover_api::encoding::Encoder;
 
     use crate::evm::finalizer::Finalization;
     use crate::handlers::config::{deserialize_handler_configs, Chain, Config};
+    use crate::handlers::payload_validation;
+    use crate::solana::Commitment;
     use crate::types::debug::REDACTED_VALUE;
     use crate::types::TMAddress;
     use crate::url::Url;
     use crate::PREFIX;
 
+    const SOLANA: &str = "solana";
     const STACKS: &str = "stacks";
 
     #[test]
@@ -401,9 +509,39 @@ mod tests {
             )
         );
 
-        // TODO: Solana tests disabled - handlers not yet implemented
-        // let sample_config = Config::SolanaMsgVerifier { ... };
-        // let sample_config = Config::SolanaVerifierSetVerifier { ... };
+        let sample_config = Config::SolanaMsgVerifier {
+            chain_name: chain_name!(SOLANA),
+            cosmwasm_contract: TMAddress::random(PREFIX),
+            rpc_url: Url::new_non_sensitive("http://localhost:8899/").unwrap(),
+            gateway_address: "11111111111111111111111111111112".to_string(),
+            commitment: Commitment::default(),
+            rpc_timeout: None,
+        };
+
+        let configs = vec![sample_config.clone(), sample_config];
+
+        assert!(
+            matches!(deserialize_handler_configs(to_value(configs).unwrap()),
+                Err(e) if e.to_string().contains("only one Solana message verifier config is allowed")
+            )
+        );
+
+        let sample_config = Config::SolanaVerifierSetVerifier {
+            chain_name: chain_name!(SOLANA),
+            cosmwasm_contract: TMAddress::random(PREFIX),
+            rpc_url: Url::new_non_sensitive("http://localhost:8899/").unwrap(),
+            gateway_address: "11111111111111111111111111111112".to_string(),
+            commitment: Commitment::default(),
+            rpc_timeout: None,
+        };
+
+        let configs = vec![sample_config.clone(), sample_config];
+
+        assert!(
+            matches!(deserialize_handler_configs(to_value(configs).unwrap()),
+                Err(e) if e.to_string().contains("only one Solana verifier set verifier config is allowed")
+            )
+        );
 
         let sample_config = Config::StacksMsgVerifier {
             chain_name: chain_name!(STACKS),
@@ -448,4 +586,86 @@ mod tests {
         assert!(!debug_output.contains("API_KEY"));
         assert!(debug_output.contains("RPCFinalizedBlock"));
     }
+
+    #[test]
+    fn multisig_signer_payload_validation_should_be_optional() {
+        let config_toml = "
+        type = 'MultisigSigner'
+        cosmwasm_contract = 'axelar1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqecnww6'
+        chain_name = 'solana'
+        ";
+
+        let config: Config = toml::from_str(config_toml).unwrap();
+        assert!(matches!(
+            config,
+            Config::MultisigSigner {
+                payload_validation: None,
+                ..
+            }
+        ));
+
+        let config_toml = "
+        type = 'MultisigSigner'
+        cosmwasm_contract = 'axelar1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqecnww6'
+        chain_name = 'solana'
+
+        [payload_validation]
+        gateway = 'axelar1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqecnww6'
+        service_registry = 'axelar1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqecnww6'
+        service_name = 'amplifier'
+        encoder = 'solana'
+        domain_separator = '0101010101010101010101010101010101010101010101010101010101010101'
+        ";
+
+        let config: Config = toml::from_str(config_toml).unwrap();
+        assert!(matches!(
+            config,
+            Config::MultisigSigner {
+                payload_validation: Some(payload_validation::Config {
+                    encoder: Encoder::Solana,
+                    domain_separator: [1; 32],
+                    ..
+                }),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn solana_commitment_should_default_to_finalized() {
+        let config_toml = "
+        type = 'SolanaMsgVerifier'
+        chain_name = 'solana'
+        cosmwasm_contract = 'axelar1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqecnww6'
+        rpc_url = 'http://127.0.0.1/'
+        gateway_address = '11111111111111111111111111111112'
+        ";
+
+        let config: Config = toml::from_str(config_toml).unwrap();
+        assert!(matches!(
+            config,
+            Config::SolanaMsgVerifier {
+                commitment: Commitment::Finalized,
+                ..
+            }
+        ));
+
+        let config_toml = "
+        type = 'SolanaVerifierSetVerifier'
+        chain_name = 'solana'
+        cosmwasm_contract = 'axelar1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqecnww6'
+        rpc_url = 'http://127.0.0.1/'
+        gateway_address = '11111111111111111111111111111112'
+        commitment = 'confirmed'
+        ";
+
+        let config: Config = toml::from_str(config_toml).unwrap();
+        assert!(matches!(
+            config,
+            Config::SolanaVerifierSetVerifier {
+                commitment: Commitment::Confirmed,
+                ..
+            }
+        ));
+    }
 }
@@ -10,6 +10,8 @@ pub enum Error {
     Finalizer,
     #[error("failed to prepare message for signing")]
     MessageToSign,
+    #[error("payload of the signing session is invalid")]
+    PayloadValidation,
     #[error("failed to parse public key")]
     PublicKey,
     #[error("failed to get signature from tofnd")]
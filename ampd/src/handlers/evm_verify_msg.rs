@@ -170,12 +170,24 @@ where
         }
 
         if !participants.contains(&self.verifier) {
+            self.monitoring_client
+                .metrics()
+                .record_metric(metrics::Msg::PollSkipped {
+                    chain_name: self.chain.clone(),
+                    reason: metrics::PollSkipReason::NotParticipant,
+                });
             return Ok(vec![]);
         }
 
         let latest_block_height = *self.latest_block_height.borrow();
         if latest_block_height >= expires_at {
             info!(poll_id = poll_id.to_string(), "skipping expired poll");
+            self.monitoring_client
+                .metrics()
+                .record_metric(metrics::Msg::PollSkipped {
+                    chain_name: self.chain.clone(),
+                    reason: metrics::PollSkipReason::Expired,
+                });
             return Ok(vec![]);
         }
 
@@ -467,4 +479,50 @@ mod tests {
 
         assert!(receiver.try_recv().is_err());
     }
+
+    #[async_test]
+    async fn should_record_poll_skipped_metric() {
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let expiration = 100u64;
+        let (monitoring_client, mut receiver) = test_utils::monitoring_client();
+
+        let handler = super::Handler::new(
+            verifier.clone(),
+            voting_verifier_contract.clone(),
+            chain_name!(ETHEREUM),
+            Finalization::RPCFinalizedBlock,
+            MockEthereumClient::new(),
+            watch::channel(expiration + 1).1,
+            monitoring_client,
+        );
+
+        let not_participating: Event = into_structured_event(
+            poll_started_event(participants(5, None), expiration),
+            &voting_verifier_contract,
+        );
+        assert_eq!(handler.handle(&not_participating).await.unwrap(), vec![]);
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            metrics::Msg::PollSkipped {
+                chain_name: chain_name!(ETHEREUM),
+                reason: metrics::PollSkipReason::NotParticipant,
+            }
+        );
+
+        let expired: Event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier)), expiration),
+            &voting_verifier_contract,
+        );
+        assert_eq!(handler.handle(&expired).await.unwrap(), vec![]);
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            metrics::Msg::PollSkipped {
+                chain_name: chain_name!(ETHEREUM),
+                reason: metrics::PollSkipReason::Expired,
+            }
+        );
+
+        assert!(receiver.try_recv().is_err());
+    }
 }
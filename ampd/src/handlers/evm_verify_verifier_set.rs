@@ -163,12 +163,24 @@ where
         }
 
         if !participants.contains(&self.verifier) {
+            self.monitoring_client
+                .metrics()
+                .record_metric(metrics::Msg::PollSkipped {
+                    chain_name: self.chain.clone(),
+                    reason: metrics::PollSkipReason::NotParticipant,
+                });
             return Ok(vec![]);
         }
 
         let latest_block_height = *self.latest_block_height.borrow();
         if latest_block_height >= expires_at {
             info!(poll_id = poll_id.to_string(), "skipping expired poll");
+            self.monitoring_client
+                .metrics()
+                .record_metric(metrics::Msg::PollSkipped {
+                    chain_name: self.chain.clone(),
+                    reason: metrics::PollSkipReason::Expired,
+                });
             return Ok(vec![]);
         }
 
@@ -6,6 +6,9 @@ pub mod evm_verify_verifier_set;
 pub mod multisig;
 pub mod mvx_verify_msg;
 pub mod mvx_verify_verifier_set;
+pub mod payload_validation;
+pub mod solana_verify_msg;
+pub mod solana_verify_verifier_set;
 pub mod stacks_verify_msg;
 pub mod stacks_verify_verifier_set;
 pub mod starknet_verify_msg;
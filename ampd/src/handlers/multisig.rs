@@ -17,9 +17,11 @@ use serde::{Deserialize, Deserializer};
 use tokio::sync::watch::Receiver;
 use tracing::info;
 
+use crate::cosmos::CosmosClient;
 use crate::event_processor::EventHandler;
 use crate::event_sub::event_filter::{EventFilter, EventFilters};
 use crate::handlers::errors::Error::{self, DeserializeEvent, MessageToSign};
+use crate::handlers::payload_validation;
 use crate::tofnd::{self, Multisig};
 use crate::types::{PublicKey, TMAddress};
 
@@ -27,6 +29,7 @@ use crate::types::{PublicKey, TMAddress};
 #[try_from("wasm-signing_started")]
 struct SigningStartedEvent {
     session_id: u64,
+    verifier_set_id: String,
     #[serde(deserialize_with = "deserialize_public_keys")]
     pub_keys: HashMap<TMAddress, PublicKey>,
     msg: MsgToSign,
@@ -50,17 +53,20 @@ where
 }
 
 #[derive(Debug)]
-pub struct Handler<S> {
+pub struct Handler<S, C> {
     verifier: TMAddress,
     multisig: TMAddress,
     chain: ChainName,
     signer: S,
     latest_block_height: Receiver<u64>,
+    cosmos_client: C,
+    payload_validation: Option<payload_validation::Config>,
 }
 
-impl<S> Handler<S>
+impl<S, C> Handler<S, C>
 where
     S: Multisig,
+    C: CosmosClient + Clone,
 {
     pub fn new(
         verifier: TMAddress,
@@ -68,6 +74,8 @@ where
         chain: ChainName,
         signer: S,
         latest_block_height: Receiver<u64>,
+        cosmos_client: C,
+        payload_validation: Option<payload_validation::Config>,
     ) -> Self {
         Self {
             verifier,
@@ -75,6 +83,8 @@ where
             chain,
             signer,
             latest_block_height,
+            cosmos_client,
+            payload_validation,
         }
     }
 
@@ -97,9 +107,10 @@ where
 }
 
 #[async_trait]
-impl<S> EventHandler for Handler<S>
+impl<S, C> EventHandler for Handler<S, C>
 where
     S: Multisig + Sync,
+    C: CosmosClient + Clone + Send + Sync,
 {
     type Err = Error;
 
@@ -110,6 +121,7 @@ where
 
         let SigningStartedEvent {
             session_id,
+            verifier_set_id,
             pub_keys,
             msg,
             expires_at,
@@ -147,6 +159,21 @@ where
 
         match pub_keys.get(&self.verifier) {
             Some(pub_key) => {
+                if let Some(config) = &self.payload_validation {
+                    payload_validation::validate_signing_session(
+                        config,
+                        &mut self.cosmos_client.clone(),
+                        &self.multisig,
+                        self.chain.clone(),
+                        session_id,
+                        verifier_set_id,
+                        &msg,
+                    )
+                    .await
+                    .change_context(Error::PayloadValidation)
+                    .attach_printable_lazy(|| format!("{{ session_id = {session_id} }}"))?;
+                }
+
                 let key_type = match pub_key {
                     PublicKey::Secp256k1(_) => tofnd::Algorithm::Ecdsa,
                     PublicKey::Ed25519(_) => tofnd::Algorithm::Ed25519,
@@ -192,17 +219,28 @@ mod test {
     use std::collections::HashMap;
     use std::convert::{TryFrom, TryInto};
 
+    use axelar_wasm_std::nonempty;
+    use cosmrs::proto::cosmwasm::wasm::v1::QuerySmartContractStateResponse;
     use cosmrs::AccountId;
     use cosmwasm_std::{HexBinary, Uint64};
     use error_stack::{Report, Result};
     use multisig::events::Event;
+    use multisig::key::KeyType;
+    use multisig::test::common::{build_verifier_set, ecdsa_test_data};
     use multisig::types::MsgToSign;
+    use multisig::verifier_set::VerifierSet;
     use rand::rngs::OsRng;
     use router_api::{chain_name, ChainName};
+    use service_registry_api::{AuthorizationState, BondingState, Verifier, WeightedVerifier};
+    use solana_multisig_prover::encoding::EncoderExt;
+    use solana_multisig_prover::msg::{ProofResponse, ProofStatus};
+    use solana_multisig_prover::Payload;
+    use solana_multisig_prover_api::encoding::Encoder;
     use tendermint::abci;
     use tokio::sync::watch;
 
     use super::*;
+    use crate::cosmos::MockCosmosClient;
     use crate::tofnd;
     use crate::tofnd::MockMultisig;
 
@@ -225,6 +263,10 @@ mod test {
     }
 
     fn signing_started_event() -> events::Event {
+        signing_started_event_with_msg(rand_message())
+    }
+
+    fn signing_started_event_with_msg(msg: HexBinary) -> events::Event {
         let pub_keys = (0..10)
             .map(|_| (TMAddress::random(PREFIX).to_string(), rand_public_key()))
             .collect::<HashMap<String, multisig::key::PublicKey>>();
@@ -233,7 +275,7 @@ mod test {
             session_id: Uint64::one(),
             verifier_set_id: "verifier_set_id".to_string(),
             pub_keys,
-            msg: MsgToSign::unchecked(rand_message()),
+            msg: MsgToSign::unchecked(msg),
             chain_name: chain_name!(ETHEREUM),
             expires_at: 100u64,
         };
@@ -288,10 +330,86 @@ mod test {
         chain: ChainName,
         signer: MockMultisig,
         latest_block_height: u64,
-    ) -> Handler<MockMultisig> {
+    ) -> Handler<MockMultisig, MockCosmosClient> {
         let (_, rx) = watch::channel(latest_block_height);
 
-        Handler::new(verifier, multisig, chain, signer, rx)
+        Handler::new(
+            verifier,
+            multisig,
+            chain,
+            signer,
+            rx,
+            MockCosmosClient::new(),
+            None,
+        )
+    }
+
+    fn payload_validation_config() -> payload_validation::Config {
+        payload_validation::Config {
+            gateway: TMAddress::random(PREFIX),
+            service_registry: TMAddress::random(PREFIX),
+            service_name: "amplifier".to_string(),
+            encoder: Encoder::Abi,
+            domain_separator: [1; 32],
+        }
+    }
+
+    // answers the prover, service registry and multisig queries the handler makes to validate a rotation
+    // to the given verifier set, treating all of its signers as active verifiers
+    fn cosmos_client(payload: Payload, verifier_set: VerifierSet) -> MockCosmosClient {
+        let mut client = MockCosmosClient::new();
+        client.expect_clone().returning(move || {
+            let payload = payload.clone();
+            let verifier_set = verifier_set.clone();
+
+            let mut mock = MockCosmosClient::new();
+            mock.expect_smart_contract_state().returning(move |req| {
+                let query: serde_json::Value = serde_json::from_slice(&req.query_data).unwrap();
+                let data = if query.get("authorized_caller").is_some() {
+                    serde_json::to_vec(&TMAddress::random(PREFIX))
+                } else if query.get("proof").is_some() {
+                    serde_json::to_vec(&ProofResponse {
+                        multisig_session_id: Uint64::one(),
+                        message_ids: vec![],
+                        payload: payload.clone(),
+                        status: ProofStatus::Pending,
+                    })
+                } else if query.get("active_verifiers").is_some() {
+                    serde_json::to_vec(&active_verifiers(&verifier_set))
+                } else if let Some(public_key) = query.get("public_key") {
+                    let signer = verifier_set
+                        .signers
+                        .get(public_key["verifier_address"].as_str().unwrap())
+                        .unwrap();
+                    serde_json::to_vec(&signer.pub_key)
+                } else {
+                    serde_json::to_vec(&verifier_set)
+                }
+                .unwrap();
+
+                Ok(QuerySmartContractStateResponse { data })
+            });
+            mock
+        });
+        client
+    }
+
+    fn active_verifiers(verifier_set: &VerifierSet) -> Vec<WeightedVerifier> {
+        verifier_set
+            .signers
+            .values()
+            .map(|signer| WeightedVerifier {
+                verifier_info: Verifier {
+                    address: signer.address.clone(),
+                    bonding_state: BondingState::Bonded {
+                        amount: nonempty::Uint128::one(),
+                    },
+                    authorization_state: AuthorizationState::Authorized,
+                    service_name: "amplifier".to_string(),
+                },
+                weight: nonempty::Uint128::one(),
+            })
+            .collect()
     }
 
     #[test]
@@ -494,4 +612,63 @@ mod test {
 
         assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
     }
+
+    #[tokio::test]
+    async fn should_not_sign_if_payload_does_not_match_message() {
+        let verifier_set = build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers());
+        let payload = Payload::VerifierSet(verifier_set.clone());
+
+        let event = signing_started_event();
+        let signing_started: SigningStartedEvent = ((&event).try_into() as Result<_, _>).unwrap();
+        let verifier = signing_started.pub_keys.keys().next().unwrap().clone();
+        let (_, rx) = watch::channel(99u64);
+
+        let handler = Handler::new(
+            verifier,
+            TMAddress::from(MULTISIG_ADDRESS.parse::<AccountId>().unwrap()),
+            chain_name!(ETHEREUM),
+            MockMultisig::default(),
+            rx,
+            cosmos_client(payload, verifier_set),
+            Some(payload_validation_config()),
+        );
+
+        assert!(matches!(
+            *handler.handle(&event).await.unwrap_err().current_context(),
+            Error::PayloadValidation
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_sign_if_payload_matches_message() {
+        let verifier_set = build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers());
+        let payload = Payload::VerifierSet(verifier_set.clone());
+        let config = payload_validation_config();
+        let digest = config
+            .encoder
+            .digest(&config.domain_separator, &verifier_set, &payload)
+            .unwrap();
+
+        let mut client = MockMultisig::default();
+        client
+            .expect_sign()
+            .returning(move |_, _, _, _| Ok(vec![1; 64]));
+
+        let event = signing_started_event_with_msg(HexBinary::from(digest.as_slice()));
+        let signing_started: SigningStartedEvent = ((&event).try_into() as Result<_, _>).unwrap();
+        let verifier = signing_started.pub_keys.keys().next().unwrap().clone();
+        let (_, rx) = watch::channel(99u64);
+
+        let handler = Handler::new(
+            verifier,
+            TMAddress::from(MULTISIG_ADDRESS.parse::<AccountId>().unwrap()),
+            chain_name!(ETHEREUM),
+            client,
+            rx,
+            cosmos_client(payload, verifier_set),
+            Some(config),
+        );
+
+        assert_eq!(handler.handle(&event).await.unwrap().len(), 1);
+    }
 }
@@ -34,7 +34,7 @@ lazy_static! {
 
 type Result<T> = error_stack::Result<T, Error>;
 
-#[derive(Deserialize, Debug)]
+#[derive(Clone, Deserialize, Debug)]
 pub struct Message {
     pub message_id: HexTxHashAndEventIndex,
     pub destination_address: String,
@@ -123,12 +123,24 @@ where
         };
 
         if !participants.contains(&self.verifier) {
+            self.monitoring_client
+                .metrics()
+                .record_metric(metrics::Msg::PollSkipped {
+                    chain_name: MULTIVERSX_CHAIN_NAME.clone(),
+                    reason: metrics::PollSkipReason::NotParticipant,
+                });
             return Ok(vec![]);
         }
 
         let latest_block_height = *self.latest_block_height.borrow();
         if latest_block_height >= expires_at {
             info!(poll_id = poll_id.to_string(), "skipping expired poll");
+            self.monitoring_client
+                .metrics()
+                .record_metric(metrics::Msg::PollSkipped {
+                    chain_name: MULTIVERSX_CHAIN_NAME.clone(),
+                    reason: metrics::PollSkipReason::Expired,
+                });
 
             return Ok(vec![]);
         }
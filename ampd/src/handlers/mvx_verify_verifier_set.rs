@@ -33,7 +33,7 @@ lazy_static! {
     static ref MULTIVERSX_CHAIN_NAME: ChainName = chain_name!("multiversx");
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Clone, Deserialize, Debug)]
 pub struct VerifierSetConfirmation {
     pub message_id: HexTxHashAndEventIndex,
     pub verifier_set: VerifierSet,
@@ -122,12 +122,24 @@ where
         };
 
         if !participants.contains(&self.verifier) {
+            self.monitoring_client
+                .metrics()
+                .record_metric(metrics::Msg::PollSkipped {
+                    chain_name: MULTIVERSX_CHAIN_NAME.clone(),
+                    reason: metrics::PollSkipReason::NotParticipant,
+                });
             return Ok(vec![]);
         }
 
         let latest_block_height = *self.latest_block_height.borrow();
         if latest_block_height >= expires_at {
             info!(poll_id = poll_id.to_string(), "skipping expired poll");
+            self.monitoring_client
+                .metrics()
+                .record_metric(metrics::Msg::PollSkipped {
+                    chain_name: MULTIVERSX_CHAIN_NAME.clone(),
+                    reason: metrics::PollSkipReason::Expired,
+                });
             return Ok(vec![]);
         }
 
@@ -0,0 +1,452 @@
+use std::collections::{BTreeMap, HashSet};
+
+use async_trait::async_trait;
+use axelar_wasm_std::hash::Hash;
+use cosmwasm_std::Uint64;
+use error_stack::{ensure, Result, ResultExt};
+use multisig::key::{KeyTyped, PublicKey};
+use multisig::types::MsgToSign;
+use multisig::verifier_set::VerifierSet;
+use router_api::{ChainName, CrossChainId};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use service_registry_api::WeightedVerifier;
+use solana_multisig_prover::encoding::EncoderExt;
+use solana_multisig_prover::msg::ProofResponse;
+use solana_multisig_prover::Payload;
+use solana_multisig_prover_api::encoding::Encoder;
+use thiserror::Error;
+
+use crate::cosmos::{self, CosmosClient};
+use crate::types::TMAddress;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to query contract state")]
+    ContractQuery,
+    #[error("failed to encode the payload")]
+    Encoding,
+    #[error("the verifier set to rotate to has no signers")]
+    EmptyVerifierSet,
+    #[error("signer {0} of the verifier set to rotate to is not an active verifier with a registered public key")]
+    UnexpectedSigner(String),
+    #[error("payload does not match the messages stored by the gateway")]
+    PayloadMismatch,
+    #[error("message to sign {actual} does not match the payload digest {expected}")]
+    DigestMismatch { expected: String, actual: String },
+}
+
+/// Describes how the payload of a signing session is encoded for the destination chain, so the handler can
+/// recompute the digest locally instead of trusting the multisig prover.
+/// Verifier set rotations are checked against the chain's active verifiers in the service registry.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Config {
+    pub gateway: TMAddress,
+    pub service_registry: TMAddress,
+    pub service_name: String,
+    pub encoder: Encoder,
+    #[serde(with = "axelar_wasm_std::hex")]
+    pub domain_separator: Hash,
+}
+
+/// Read access to the contract state a signing session is validated against
+#[async_trait]
+pub trait ContractQuerier {
+    async fn query<T>(&mut self, contract: &TMAddress, query: Vec<u8>) -> Result<T, Error>
+    where
+        T: DeserializeOwned + Send + 'static;
+}
+
+#[async_trait]
+impl<C> ContractQuerier for C
+where
+    C: CosmosClient + Send,
+{
+    async fn query<T>(&mut self, contract: &TMAddress, query: Vec<u8>) -> Result<T, Error>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let res = cosmos::contract_state(self, contract, query)
+            .await
+            .change_context(Error::ContractQuery)
+            .attach_printable_lazy(|| format!("{{ contract = {contract} }}"))?;
+
+        serde_json::from_slice(&res).change_context(Error::ContractQuery)
+    }
+}
+
+fn authorized_caller_query(chain_name: ChainName) -> Vec<u8> {
+    serde_json::to_vec(&multisig::msg::QueryMsg::AuthorizedCaller { chain_name })
+        .expect("authorized caller query should serialize")
+}
+
+fn verifier_set_query(verifier_set_id: String) -> Vec<u8> {
+    serde_json::to_vec(&multisig::msg::QueryMsg::VerifierSet { verifier_set_id })
+        .expect("verifier set query should serialize")
+}
+
+fn proof_query(session_id: impl Into<Uint64>) -> Vec<u8> {
+    serde_json::to_vec(&solana_multisig_prover::msg::QueryMsg::Proof {
+        multisig_session_id: session_id.into(),
+    })
+    .expect("proof query should serialize")
+}
+
+fn active_verifiers_query(service_name: String, chain_name: ChainName) -> Vec<u8> {
+    serde_json::to_vec(&service_registry_api::msg::QueryMsg::ActiveVerifiers {
+        service_name,
+        chain_name,
+    })
+    .expect("active verifiers query should serialize")
+}
+
+fn public_key_query(verifier_address: String, pub_key: &PublicKey) -> Vec<u8> {
+    serde_json::to_vec(&multisig::msg::QueryMsg::PublicKey {
+        verifier_address,
+        key_type: pub_key.key_type(),
+    })
+    .expect("public key query should serialize")
+}
+
+fn outgoing_messages_query(message_ids: Vec<CrossChainId>) -> Vec<u8> {
+    serde_json::to_vec(&gateway_api::msg::QueryMsg::OutgoingMessages(message_ids))
+        .expect("outgoing messages query should serialize")
+}
+
+/// Fetches the payload of the signing session from the chain's prover and validates the message to sign against it.
+/// Messages must be stored by the gateway, and a verifier set to rotate to may only consist of active verifiers
+/// of the chain according to the service registry, signing with the public keys they registered with multisig.
+/// Neither is taken from the prover, so a compromised prover cannot get its own payload signed.
+pub async fn validate_signing_session<Q>(
+    config: &Config,
+    querier: &mut Q,
+    multisig: &TMAddress,
+    chain: ChainName,
+    session_id: u64,
+    verifier_set_id: String,
+    msg: &MsgToSign,
+) -> Result<(), Error>
+where
+    Q: ContractQuerier + Send,
+{
+    let prover: TMAddress = querier
+        .query(multisig, authorized_caller_query(chain.clone()))
+        .await?;
+    let proof: ProofResponse = querier.query(&prover, proof_query(session_id)).await?;
+    let verifier_set: VerifierSet = querier
+        .query(multisig, verifier_set_query(verifier_set_id))
+        .await?;
+
+    let expected_payload = match &proof.payload {
+        Payload::Messages(messages) => Payload::Messages(
+            querier
+                .query(
+                    &config.gateway,
+                    outgoing_messages_query(
+                        messages
+                            .iter()
+                            .map(|message| message.cc_id.clone())
+                            .collect(),
+                    ),
+                )
+                .await?,
+        ),
+        Payload::VerifierSet(next_verifier_set) => {
+            let registered_pub_keys =
+                registered_pub_keys(config, querier, multisig, chain, next_verifier_set).await?;
+            validate_verifier_set(next_verifier_set, &registered_pub_keys)?;
+
+            proof.payload.clone()
+        }
+    };
+
+    validate(config, msg, &verifier_set, &proof, &expected_payload)
+}
+
+/// Returns the public keys the signers of the verifier set registered with multisig,
+/// limited to the signers that are active verifiers of the chain
+async fn registered_pub_keys<Q>(
+    config: &Config,
+    querier: &mut Q,
+    multisig: &TMAddress,
+    chain: ChainName,
+    verifier_set: &VerifierSet,
+) -> Result<BTreeMap<String, PublicKey>, Error>
+where
+    Q: ContractQuerier + Send,
+{
+    let active_verifiers: Vec<WeightedVerifier> = querier
+        .query(
+            &config.service_registry,
+            active_verifiers_query(config.service_name.clone(), chain),
+        )
+        .await?;
+    let active_verifiers: HashSet<_> = active_verifiers
+        .into_iter()
+        .map(|verifier| verifier.verifier_info.address.to_string())
+        .collect();
+
+    let mut registered_pub_keys = BTreeMap::new();
+    for signer in verifier_set.signers.values() {
+        let address = signer.address.to_string();
+        if !active_verifiers.contains(&address) {
+            continue;
+        }
+
+        let pub_key: PublicKey = querier
+            .query(multisig, public_key_query(address.clone(), &signer.pub_key))
+            .await?;
+        registered_pub_keys.insert(address, pub_key);
+    }
+
+    Ok(registered_pub_keys)
+}
+
+/// Checks that every signer of the verifier set is an active verifier that signs with its registered public key
+pub fn validate_verifier_set(
+    verifier_set: &VerifierSet,
+    registered_pub_keys: &BTreeMap<String, PublicKey>,
+) -> Result<(), Error> {
+    ensure!(!verifier_set.signers.is_empty(), Error::EmptyVerifierSet);
+
+    for signer in verifier_set.signers.values() {
+        let address = signer.address.to_string();
+        ensure!(
+            registered_pub_keys.get(&address) == Some(&signer.pub_key),
+            Error::UnexpectedSigner(address)
+        );
+    }
+
+    Ok(())
+}
+
+/// Recomputes the digest of the proof's payload and checks that it is the message the verifiers are asked to sign.
+/// The payload also has to be identical to the expected one, i.e. the messages stored by the gateway or the
+/// validated verifier set.
+pub fn validate(
+    config: &Config,
+    msg: &MsgToSign,
+    verifier_set: &VerifierSet,
+    proof: &ProofResponse,
+    expected_payload: &Payload,
+) -> Result<(), Error> {
+    ensure!(proof.payload == *expected_payload, Error::PayloadMismatch);
+
+    let expected = config
+        .encoder
+        .digest(&config.domain_separator, verifier_set, &proof.payload)
+        .change_context(Error::Encoding)?;
+
+    ensure!(
+        expected.as_slice() == msg.as_ref(),
+        Error::DigestMismatch {
+            expected: hex::encode(expected),
+            actual: hex::encode(msg),
+        }
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use cosmwasm_std::{HexBinary, Uint64};
+    use multisig::key::KeyType;
+    use multisig::test::common::{build_verifier_set, ecdsa_test_data, ed25519_test_data};
+    use multisig::types::MsgToSign;
+    use router_api::{chain_name, CrossChainId, Message};
+    use solana_multisig_prover::encoding::EncoderExt;
+    use solana_multisig_prover::msg::{ProofResponse, ProofStatus};
+    use solana_multisig_prover::Payload;
+    use solana_multisig_prover_api::encoding::Encoder;
+
+    use super::{validate, validate_verifier_set, Config, Error};
+    use crate::types::TMAddress;
+    use crate::PREFIX;
+
+    fn config() -> Config {
+        Config {
+            gateway: TMAddress::random(PREFIX),
+            service_registry: TMAddress::random(PREFIX),
+            service_name: "amplifier".to_string(),
+            encoder: Encoder::Abi,
+            domain_separator: [1; 32],
+        }
+    }
+
+    fn messages() -> Vec<Message> {
+        vec![Message {
+            cc_id: CrossChainId::new("source-chain", "0x1234-0").unwrap(),
+            source_address: "source-address".parse().unwrap(),
+            destination_chain: chain_name!("ethereum"),
+            destination_address: "0x3ad1f33ef5814e7adb43ed7fb39f9b45053ecab1"
+                .parse()
+                .unwrap(),
+            payload_hash: [2; 32],
+        }]
+    }
+
+    fn proof(payload: Payload) -> ProofResponse {
+        ProofResponse {
+            multisig_session_id: Uint64::one(),
+            message_ids: payload.message_ids().unwrap_or_default(),
+            payload,
+            status: ProofStatus::Pending,
+        }
+    }
+
+    fn digest(config: &Config, payload: &Payload) -> MsgToSign {
+        let verifier_set = build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers());
+        let digest = config
+            .encoder
+            .digest(&config.domain_separator, &verifier_set, payload)
+            .unwrap();
+
+        MsgToSign::unchecked(HexBinary::from(digest.as_slice()))
+    }
+
+    #[test]
+    fn should_accept_matching_messages_payload() {
+        let config = config();
+        let payload = Payload::Messages(messages());
+        let verifier_set = build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers());
+
+        assert!(validate(
+            &config,
+            &digest(&config, &payload),
+            &verifier_set,
+            &proof(payload.clone()),
+            &payload,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn should_accept_matching_verifier_set_payload() {
+        let config = config();
+        let verifier_set = build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers());
+        let payload = Payload::VerifierSet(verifier_set.clone());
+
+        assert!(validate(
+            &config,
+            &digest(&config, &payload),
+            &verifier_set,
+            &proof(payload.clone()),
+            &payload,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn should_reject_messages_not_stored_by_gateway() {
+        let config = config();
+        let payload = Payload::Messages(messages());
+        let verifier_set = build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers());
+
+        let mut outgoing_messages = messages();
+        outgoing_messages[0].payload_hash = [3; 32];
+
+        assert!(matches!(
+            validate(
+                &config,
+                &digest(&config, &payload),
+                &verifier_set,
+                &proof(payload),
+                &Payload::Messages(outgoing_messages),
+            )
+            .unwrap_err()
+            .current_context(),
+            Error::PayloadMismatch
+        ));
+    }
+
+    #[test]
+    fn should_accept_verifier_set_of_active_verifiers_with_registered_keys() {
+        let verifier_set = build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers());
+        let registered_pub_keys: BTreeMap<_, _> = verifier_set
+            .signers
+            .values()
+            .map(|signer| (signer.address.to_string(), signer.pub_key.clone()))
+            .collect();
+
+        assert!(validate_verifier_set(&verifier_set, &registered_pub_keys).is_ok());
+    }
+
+    #[test]
+    fn should_reject_verifier_set_with_inactive_signer() {
+        let verifier_set = build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers());
+        let mut registered_pub_keys: BTreeMap<_, _> = verifier_set
+            .signers
+            .values()
+            .map(|signer| (signer.address.to_string(), signer.pub_key.clone()))
+            .collect();
+        registered_pub_keys.pop_first();
+
+        assert!(matches!(
+            validate_verifier_set(&verifier_set, &registered_pub_keys)
+                .unwrap_err()
+                .current_context(),
+            Error::UnexpectedSigner(_)
+        ));
+    }
+
+    #[test]
+    fn should_reject_verifier_set_with_unregistered_key() {
+        let verifier_set = build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers());
+        let other_pub_keys: Vec<_> =
+            build_verifier_set(KeyType::Ed25519, &ed25519_test_data::signers())
+                .signers
+                .into_values()
+                .map(|signer| signer.pub_key)
+                .collect();
+        let registered_pub_keys: BTreeMap<_, _> = verifier_set
+            .signers
+            .values()
+            .zip(other_pub_keys)
+            .map(|(signer, pub_key)| (signer.address.to_string(), pub_key))
+            .collect();
+
+        assert!(matches!(
+            validate_verifier_set(&verifier_set, &registered_pub_keys)
+                .unwrap_err()
+                .current_context(),
+            Error::UnexpectedSigner(_)
+        ));
+    }
+
+    #[test]
+    fn should_reject_empty_verifier_set() {
+        let mut verifier_set = build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers());
+        verifier_set.signers.clear();
+
+        assert!(matches!(
+            validate_verifier_set(&verifier_set, &BTreeMap::new())
+                .unwrap_err()
+                .current_context(),
+            Error::EmptyVerifierSet
+        ));
+    }
+
+    #[test]
+    fn should_reject_digest_of_different_payload() {
+        let config = config();
+        let payload = Payload::Messages(messages());
+        let verifier_set = build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers());
+        let msg = MsgToSign::unchecked(HexBinary::from([4; 32].as_slice()));
+
+        assert!(matches!(
+            validate(
+                &config,
+                &msg,
+                &verifier_set,
+                &proof(payload.clone()),
+                &payload
+            )
+            .unwrap_err()
+            .current_context(),
+            Error::DigestMismatch { .. }
+        ));
+    }
+}
@@ -31,7 +31,7 @@ use crate::types::{Hash, TMAddress};
 
 type Result<T> = error_stack::Result<T, Error>;
 
-#[derive(Deserialize, Debug)]
+#[derive(Clone, Deserialize, Debug)]
 pub struct Message {
     pub message_id: Base58SolanaTxSignatureAndEventIndex,
     pub destination_address: String,
@@ -141,12 +141,24 @@ impl<C: SolanaRpcClientProxy> EventHandler for Handler<C> {
         }
 
         if !participants.contains(&self.verifier) {
+            self.monitoring_client
+                .metrics()
+                .record_metric(metrics::Msg::PollSkipped {
+                    chain_name: self.chain_name.clone(),
+                    reason: metrics::PollSkipReason::NotParticipant,
+                });
             return Ok(vec![]);
         }
 
         let latest_block_height = *self.latest_block_height.borrow();
         if latest_block_height >= expires_at {
             info!(poll_id = poll_id.to_string(), "skipping expired poll");
+            self.monitoring_client
+                .metrics()
+                .record_metric(metrics::Msg::PollSkipped {
+                    chain_name: self.chain_name.clone(),
+                    reason: metrics::PollSkipReason::Expired,
+                });
             return Ok(vec![]);
         }
 
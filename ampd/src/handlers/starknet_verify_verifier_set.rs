@@ -36,7 +36,7 @@ lazy_static! {
     static ref STARKNET_CHAIN_NAME: ChainName = chain_name!("starknet");
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Clone, Deserialize, Debug)]
 pub struct VerifierSetConfirmation {
     pub message_id: FieldElementAndEventIndex,
     pub verifier_set: VerifierSet,
@@ -128,11 +128,23 @@ where
         };
 
         if !participants.contains(&self.verifier) {
+            self.monitoring_client
+                .metrics()
+                .record_metric(metrics::Msg::PollSkipped {
+                    chain_name: STARKNET_CHAIN_NAME.clone(),
+                    reason: metrics::PollSkipReason::NotParticipant,
+                });
             return Ok(vec![]);
         }
 
         if *self.latest_block_height.borrow() >= expires_at {
             info!(poll_id = poll_id.to_string(), "skipping expired poll");
+            self.monitoring_client
+                .metrics()
+                .record_metric(metrics::Msg::PollSkipped {
+                    chain_name: STARKNET_CHAIN_NAME.clone(),
+                    reason: metrics::PollSkipReason::Expired,
+                });
             return Ok(vec![]);
         }
 
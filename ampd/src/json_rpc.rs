@@ -8,7 +8,7 @@ use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use crate::monitoring;
-use crate::monitoring::metrics::Msg;
+use crate::monitoring::metrics::{self, Msg};
 use crate::types::debug::REDACTED_VALUE;
 use crate::url::Url;
 
@@ -40,18 +40,15 @@ where
         T: Debug + Serialize + Send + Sync,
         R: DeserializeOwned + Send,
     {
-        let res = self
-            .provider
-            .request(method, params)
-            .await
-            .map_err(Into::into)
-            .map_err(Report::from);
+        let (res, duration) = metrics::timed(|| self.provider.request(method, params)).await;
+        let res = res.map_err(Into::into).map_err(Report::from);
 
         self.monitoring_client
             .metrics()
             .record_metric(Msg::RpcCall {
                 chain_name: self.chain_name.clone(),
                 success: res.is_ok(),
+                duration,
             });
 
         res
@@ -142,13 +139,14 @@ mod test {
         assert!(result.is_err());
 
         let msg = receiver.recv().await.unwrap();
-        assert_eq!(
+        assert!(matches!(
             msg,
             Msg::RpcCall {
-                chain_name: ChainName::from_str("ethereum").unwrap(),
+                chain_name,
                 success: false,
-            }
-        );
+                ..
+            } if chain_name == ChainName::from_str("ethereum").unwrap()
+        ));
         assert!(receiver.try_recv().is_err());
     }
 
@@ -167,13 +165,14 @@ mod test {
         assert!(result.is_ok());
 
         let msg = receiver.recv().await.unwrap();
-        assert_eq!(
+        assert!(matches!(
             msg,
             Msg::RpcCall {
-                chain_name: ChainName::from_str("ethereum").unwrap(),
+                chain_name,
                 success: true,
-            }
-        );
+                ..
+            } if chain_name == ChainName::from_str("ethereum").unwrap()
+        ));
 
         assert!(receiver.try_recv().is_err());
     }
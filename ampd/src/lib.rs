@@ -17,9 +17,10 @@ mod grpc;
 pub mod handlers;
 pub mod json_rpc;
 pub mod monitoring;
-mod mvx;
-mod stacks;
-mod starknet;
+pub mod mvx;
+pub mod solana;
+pub mod stacks;
+pub mod starknet;
 pub mod stellar;
 pub mod sui;
 mod tm_client;
@@ -29,7 +30,7 @@ pub mod types;
 pub mod url;
 #[cfg(not(feature = "url"))]
 mod url;
-mod xrpl;
+pub mod xrpl;
 
 use std::time::Duration;
 
@@ -37,17 +38,22 @@ use asyncutil::future::RetryPolicy;
 use asyncutil::task::{CancellableTask, TaskError, TaskGroup};
 use block_height_monitor::BlockHeightMonitor;
 use broadcast::MsgQueue;
+use cosmos::CosmosClient;
+use cosmrs::proto::cosmos::base::node::v1beta1::ConfigRequest;
 use error_stack::{FutureExt, Result, ResultExt};
 use event_processor::EventHandler;
 use event_sub::EventSub;
 use evm::finalizer::{pick, Finalization};
 use evm::json_rpc::EthereumClient;
 use lazy_static::lazy_static;
+use monitoring::endpoints::readiness::{self, DependencyKind};
 use multiversx_sdk::gateway::GatewayProxy;
 use router_api::{chain_name, ChainName};
+use solana_client::nonblocking::rpc_client::RpcClient;
 use starknet_providers::jsonrpc::HttpTransport;
 use thiserror::Error;
-use tofnd::{Multisig, MultisigClient};
+use tm_client::TmClient;
+use tofnd::Multisig;
 use tokio::signal::unix::{signal, SignalKind};
 use tokio_stream::StreamExt;
 use tokio_util::sync::CancellationToken;
@@ -90,8 +96,11 @@ async fn prepare_app(cfg: Config) -> Result<App, Error> {
         tm_client,
     } = cfg;
 
+    let progress = event_sub::progress::ProgressStore::load(event_sub.progress_file.clone())
+        .change_context(Error::BlockProgress)?;
     let (monitoring_server, monitoring_client) =
-        monitoring::Server::new(monitoring_server).change_context(Error::Monitor)?;
+        monitoring::Server::new_with_progress(monitoring_server, progress.clone())
+            .change_context(Error::Monitor)?;
 
     let tm_client = tm_client::TendermintClient::new(
         tendermint_rpc::HttpClient::new(tm_jsonrpc.as_str())
@@ -101,14 +110,9 @@ async fn prepare_app(cfg: Config) -> Result<App, Error> {
         tm_client.retry_delay,
     );
 
-    let multisig_client = MultisigClient::new(
-        tofnd_config.party_uid,
-        tofnd_config.url.as_str(),
-        tofnd_config.timeout,
-    )
-    .await
-    .change_context(Error::Connection)
-    .attach_printable(tofnd_config.url)?;
+    let multisig_client = tofnd::Client::new(&tofnd_config)
+        .await
+        .change_context(Error::Connection)?;
     let block_height_monitor = BlockHeightMonitor::connect(tm_client.clone())
         .await
         .change_context(Error::Connection)
@@ -131,6 +135,13 @@ async fn prepare_app(cfg: Config) -> Result<App, Error> {
         .await
         .change_context(Error::Connection)
         .attach_printable(tm_grpc.clone())?;
+    register_core_readiness_probes(
+        monitoring_client.readiness(),
+        &multisig_client,
+        &tofnd_config.key_uid,
+        &tm_client,
+        &cosmos_client,
+    );
     let broadcaster = broadcast::Broadcaster::builder()
         .client(cosmos_client.clone())
         .chain_id(broadcast.chain_id)
@@ -140,6 +151,13 @@ async fn prepare_app(cfg: Config) -> Result<App, Error> {
         .build()
         .await
         .change_context(Error::Broadcaster)?;
+    let gas_price_oracle = broadcast.gas_price_oracle.enabled.then(|| {
+        broadcast::GasPriceOracle::new(
+            cosmos_client.clone(),
+            broadcaster.gas_price(),
+            broadcast.gas_price_oracle.clone(),
+        )
+    });
     let (msg_queue, msg_queue_client) = broadcast::MsgQueue::new_msg_queue_and_client(
         broadcaster.clone(),
         broadcast.queue_cap,
@@ -159,7 +177,7 @@ async fn prepare_app(cfg: Config) -> Result<App, Error> {
         .monitoring_client(monitoring_client.clone())
         .build();
     let (tx_confirmer, tx_confirmer_client) = broadcast::TxConfirmer::new_confirmer_and_client(
-        cosmos_client,
+        cosmos_client.clone(),
         RetryPolicy::repeat_constant(
             broadcast.tx_fetch_interval,
             broadcast.tx_fetch_max_retries.saturating_add(1).into(),
@@ -168,12 +186,24 @@ async fn prepare_app(cfg: Config) -> Result<App, Error> {
         broadcast.tx_confirmation_queue_cap,
         monitoring_client.clone(),
     );
+    let (tx_confirmer, rebroadcast_rx) = if broadcast.rebroadcast.enabled() {
+        let (tx_confirmer, rebroadcast_rx) = tx_confirmer.with_rebroadcasts(
+            broadcast.tx_confirmation_queue_cap,
+            block_height_monitor.latest_block_height(),
+        );
+        (tx_confirmer, Some(rebroadcast_rx))
+    } else {
+        (tx_confirmer, None)
+    };
     let broadcaster_task = broadcast::BroadcasterTask::builder()
         .broadcaster(broadcaster)
         .msg_queue(msg_queue)
         .signer(multisig_client.clone())
         .key_id(tofnd_config.key_uid.clone())
         .tx_confirmer_client(tx_confirmer_client)
+        .rebroadcast_rx(rebroadcast_rx)
+        .rebroadcast_config(broadcast.rebroadcast)
+        .latest_block_height(block_height_monitor.latest_block_height())
         .monitoring_client(monitoring_client.clone())
         .build();
 
@@ -192,10 +222,65 @@ async fn prepare_app(cfg: Config) -> Result<App, Error> {
         broadcaster_task,
         msg_queue_client,
         tx_confirmer,
+        gas_price_oracle,
+        cosmos_client,
         monitoring_client,
+        progress,
+        event_sub.progress_persist_interval,
     )
     .configure_handlers(verifier, handlers, event_processor, default_rpc_timeout)
     .await
+    .map(|app| app.resume_from_progress(event_sub.max_catch_up_blocks))
+}
+
+/// Registers the dependencies ampd cannot work without with the readiness endpoint
+fn register_core_readiness_probes(
+    probes: &readiness::Probes,
+    multisig_client: &tofnd::Client,
+    key_uid: &str,
+    tm_client: &tm_client::TendermintClient,
+    cosmos_client: &cosmos::CosmosGrpcClient,
+) {
+    let multisig_client = multisig_client.clone();
+    let key_uid = key_uid.to_string();
+    probes.register("tofnd", DependencyKind::Core, move || {
+        let multisig_client = multisig_client.clone();
+        let key_uid = key_uid.clone();
+
+        async move {
+            multisig_client
+                .keygen(&key_uid, tofnd::Algorithm::Ecdsa)
+                .await
+                .map(|_| ())
+                .change_context(readiness::Error::Unreachable)
+        }
+    });
+
+    let tm_client = tm_client.clone();
+    probes.register("tm_jsonrpc", DependencyKind::Core, move || {
+        let tm_client = tm_client.clone();
+
+        async move {
+            tm_client
+                .latest_block()
+                .await
+                .map(|_| ())
+                .change_context(readiness::Error::Unreachable)
+        }
+    });
+
+    let cosmos_client = cosmos_client.clone();
+    probes.register("tm_grpc", DependencyKind::Core, move || {
+        let mut cosmos_client = cosmos_client.clone();
+
+        async move {
+            cosmos_client
+                .node_config(ConfigRequest {})
+                .await
+                .map(|_| ())
+                .change_context(readiness::Error::Unreachable)
+        }
+    });
 }
 
 async fn check_finalizer<C>(
@@ -218,15 +303,19 @@ struct App {
     event_publisher: event_sub::EventPublisher<tm_client::TendermintClient>,
     event_subscriber: event_sub::EventSubscriber,
     event_processor: TaskGroup<event_processor::Error>,
-    multisig_client: MultisigClient,
+    multisig_client: tofnd::Client,
     block_height_monitor: BlockHeightMonitor<tm_client::TendermintClient>,
     monitoring_server: monitoring::Server,
     grpc_server: grpc::Server,
-    broadcaster_task:
-        broadcast::BroadcasterTask<cosmos::CosmosGrpcClient, MsgQueue, MultisigClient>,
+    broadcaster_task: broadcast::BroadcasterTask<cosmos::CosmosGrpcClient, MsgQueue, tofnd::Client>,
     msg_queue_client: broadcast::MsgQueueClient<cosmos::CosmosGrpcClient>,
     tx_confirmer: broadcast::TxConfirmer<cosmos::CosmosGrpcClient>,
+    gas_price_oracle: Option<broadcast::GasPriceOracle<cosmos::CosmosGrpcClient>>,
+    cosmos_client: cosmos::CosmosGrpcClient,
     monitoring_client: monitoring::Client,
+    progress: event_sub::progress::ProgressStore,
+    progress_persist_interval: Duration,
+    handler_labels: Vec<String>,
 }
 
 impl App {
@@ -234,18 +323,22 @@ impl App {
     fn new(
         event_publisher: event_sub::EventPublisher<tm_client::TendermintClient>,
         event_subscriber: event_sub::EventSubscriber,
-        multisig_client: MultisigClient,
+        multisig_client: tofnd::Client,
         block_height_monitor: BlockHeightMonitor<tm_client::TendermintClient>,
         monitoring_server: monitoring::Server,
         grpc_server: grpc::Server,
         broadcaster_task: broadcast::BroadcasterTask<
             cosmos::CosmosGrpcClient,
             MsgQueue,
-            MultisigClient,
+            tofnd::Client,
         >,
         msg_queue_client: broadcast::MsgQueueClient<cosmos::CosmosGrpcClient>,
         tx_confirmer: broadcast::TxConfirmer<cosmos::CosmosGrpcClient>,
+        gas_price_oracle: Option<broadcast::GasPriceOracle<cosmos::CosmosGrpcClient>>,
+        cosmos_client: cosmos::CosmosGrpcClient,
         monitoring_client: monitoring::Client,
+        progress: event_sub::progress::ProgressStore,
+        progress_persist_interval: Duration,
     ) -> Self {
         let event_processor = TaskGroup::new("event handler");
 
@@ -260,7 +353,12 @@ impl App {
             broadcaster_task,
             msg_queue_client,
             tx_confirmer,
+            gas_price_oracle,
+            cosmos_client,
             monitoring_client,
+            progress,
+            progress_persist_interval,
+            handler_labels: vec![],
         }
     }
 
@@ -282,6 +380,11 @@ impl App {
                 .await
             {
                 Ok((task_name, task)) => {
+                    config.register_readiness_probe(
+                        task_name.clone(),
+                        self.monitoring_client.readiness(),
+                    );
+                    self.handler_labels.push(task_name.clone());
                     self.event_processor = self.event_processor.add_task(task_name, task);
                 }
                 Err(e) => {
@@ -295,6 +398,25 @@ impl App {
         Ok(self)
     }
 
+    /// Lets the event publisher continue after the lowest block height that all configured handlers
+    /// have already processed, so blocks that were missed while ampd was offline are not skipped
+    fn resume_from_progress(mut self, max_catch_up_blocks: u64) -> Self {
+        if let Some(height) = self
+            .progress
+            .resume_height(self.handler_labels.iter().map(String::as_str))
+        {
+            info!(
+                height = height.value(),
+                max_catch_up_blocks, "resuming event processing after last processed block"
+            );
+            self.event_publisher = self
+                .event_publisher
+                .resume_after(height, max_catch_up_blocks);
+        }
+
+        self
+    }
+
     async fn try_create_handler_task(
         &mut self,
         config: &handlers::config::Config,
@@ -420,6 +542,7 @@ impl App {
             handlers::config::Config::MultisigSigner {
                 cosmwasm_contract,
                 chain_name,
+                payload_validation,
             } => {
                 let task_name = format!("{}-multisig-signer", chain_name);
                 Ok((
@@ -432,6 +555,8 @@ impl App {
                             chain_name.clone(),
                             self.multisig_client.clone(),
                             self.block_height_monitor.latest_block_height(),
+                            self.cosmos_client.clone(),
+                            payload_validation.clone(),
                         ),
                         event_processor_config.clone(),
                         self.monitoring_client.clone(),
@@ -719,9 +844,83 @@ impl App {
                     ),
                 ))
             }
-            // TODO: Solana handlers not yet implemented
-            // handlers::config::Config::SolanaMsgVerifier { .. } => { ... }
-            // handlers::config::Config::SolanaVerifierSetVerifier { .. } => { ... }
+            handlers::config::Config::SolanaMsgVerifier {
+                chain_name,
+                cosmwasm_contract,
+                rpc_url,
+                gateway_address,
+                commitment,
+                rpc_timeout,
+            } => {
+                let rpc_client = solana::Client::new(
+                    RpcClient::new_with_timeout_and_commitment(
+                        rpc_url.as_str().to_string(),
+                        rpc_timeout.unwrap_or(default_rpc_timeout),
+                        (*commitment).into(),
+                    ),
+                    self.monitoring_client.clone(),
+                    chain_name.clone(),
+                );
+
+                let task_name = format!("{}-msg-verifier", chain_name);
+                Ok((
+                    task_name.clone(),
+                    self.create_handler_task(
+                        task_name,
+                        handlers::solana_verify_msg::Handler::new(
+                            chain_name.clone(),
+                            verifier.clone(),
+                            cosmwasm_contract.clone(),
+                            rpc_client,
+                            self.block_height_monitor.latest_block_height(),
+                            self.monitoring_client.clone(),
+                            gateway_address,
+                        )
+                        .change_context(Error::Connection)?,
+                        event_processor_config.clone(),
+                        self.monitoring_client.clone(),
+                    ),
+                ))
+            }
+            handlers::config::Config::SolanaVerifierSetVerifier {
+                chain_name,
+                cosmwasm_contract,
+                rpc_url,
+                gateway_address,
+                commitment,
+                rpc_timeout,
+            } => {
+                let rpc_client = solana::Client::new(
+                    RpcClient::new_with_timeout_and_commitment(
+                        rpc_url.as_str().to_string(),
+                        rpc_timeout.unwrap_or(default_rpc_timeout),
+                        (*commitment).into(),
+                    ),
+                    self.monitoring_client.clone(),
+                    chain_name.clone(),
+                );
+
+                let task_name = format!("{}-verifier-set-verifier", chain_name);
+                Ok((
+                    task_name.clone(),
+                    self.create_handler_task(
+                        task_name,
+                        handlers::solana_verify_verifier_set::Handler::new(
+                            chain_name.clone(),
+                            verifier.clone(),
+                            cosmwasm_contract.clone(),
+                            rpc_client,
+                            self.block_height_monitor.latest_block_height(),
+                            self.monitoring_client.clone(),
+                            gateway_address,
+                        )
+                        .await
+                        .change_context(Error::Connection)?,
+                        event_processor_config.clone(),
+                        self.monitoring_client.clone(),
+                    ),
+                ))
+            }
             handlers::config::Config::StacksMsgVerifier {
                 chain_name,
                 cosmwasm_contract,
@@ -806,6 +1005,7 @@ impl App {
                 Err(_) => true,
             });
         let msg_queue_client = self.msg_queue_client.clone();
+        let progress = self.progress.clone();
 
         CancellableTask::create(|token| {
             event_processor::consume_events(
@@ -816,6 +1016,7 @@ impl App {
                 token,
                 msg_queue_client,
                 monitoring_client,
+                progress,
             )
         })
     }
@@ -829,6 +1030,9 @@ impl App {
             grpc_server,
             broadcaster_task,
             tx_confirmer,
+            gas_price_oracle,
+            progress,
+            progress_persist_interval,
             ..
         } = self;
 
@@ -848,7 +1052,7 @@ impl App {
             exit_token.cancel();
         });
 
-        TaskGroup::new("ampd")
+        let tasks = TaskGroup::new("ampd")
             .add_task(
                 "block-height-monitor",
                 CancellableTask::create(|token| {
@@ -901,9 +1105,39 @@ impl App {
                         .change_context(Error::Broadcaster)
                 }),
             )
+            .add_task(
+                "block-progress",
+                CancellableTask::create({
+                    let progress = progress.clone();
+                    move |token| {
+                        progress
+                            .run(progress_persist_interval, token)
+                            .change_context(Error::BlockProgress)
+                    }
+                }),
+            );
+
+        let tasks = match gas_price_oracle {
+            Some(gas_price_oracle) => tasks.add_task(
+                "gas-price-oracle",
+                CancellableTask::create(|token| {
+                    gas_price_oracle
+                        .run(token)
+                        .change_context(Error::GasPriceOracle)
+                }),
+            ),
+            None => tasks,
+        };
+
+        let res = tasks
             .run(main_token)
             .await
-            .change_context(Error::AppFailure)
+            .change_context(Error::AppFailure);
+
+        // the event processor might have recorded more progress after the last periodic write
+        progress.persist_or_warn().await;
+
+        res
     }
 }
 
@@ -917,6 +1151,8 @@ pub enum Error {
     Broadcaster,
     #[error("tx confirmation failed")]
     TxConfirmation,
+    #[error("gas price oracle failed")]
+    GasPriceOracle,
     #[error("tofnd failed")]
     Tofnd,
     #[error("connection failed")]
@@ -939,6 +1175,8 @@ pub enum Error {
     Monitor,
     #[error("gRPC server failed")]
     GrpcServer,
+    #[error("failed to load or persist block progress")]
+    BlockProgress,
 }
 
 #[cfg(test)]
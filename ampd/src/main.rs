@@ -5,9 +5,9 @@ use std::process::ExitCode;
 
 use ::config::{Config as cfg, Environment, File, FileFormat, FileSourceFile};
 use ampd::commands::{
-    bond_verifier, claim_stake, daemon, deregister_chain_support, register_chain_support,
-    register_public_key, send_tokens, set_rewards_proxy, unbond_verifier, verifier_address,
-    SubCommand,
+    bond_verifier, claim_stake, create_keystore, daemon, deregister_chain_support,
+    register_chain_support, register_public_key, send_tokens, set_rewards_proxy, unbond_verifier,
+    unjail_verifier, verifier_address, SubCommand,
 };
 use ampd::config::Config;
 use ampd::Error;
@@ -71,8 +71,10 @@ async fn main() -> ExitCode {
         Some(SubCommand::VerifierAddress) => verifier_address::run(cfg.tofnd_config).await,
         Some(SubCommand::UnbondVerifier(args)) => unbond_verifier::run(cfg, args).await,
         Some(SubCommand::ClaimStake(args)) => claim_stake::run(cfg, args).await,
+        Some(SubCommand::UnjailVerifier(args)) => unjail_verifier::run(cfg, args).await,
         Some(SubCommand::SendTokens(args)) => send_tokens::run(cfg, args).await,
         Some(SubCommand::SetRewardsProxy(args)) => set_rewards_proxy::run(cfg, args).await,
+        Some(SubCommand::CreateKeystore) => create_keystore::run(cfg.tofnd_config).await,
     };
 
     match result {
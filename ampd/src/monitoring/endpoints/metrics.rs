@@ -15,7 +15,8 @@ use prometheus_client::encoding::{
 };
 use prometheus_client::metrics::counter::Counter;
 use prometheus_client::metrics::family::Family;
-use prometheus_client::metrics::gauge::ConstGauge;
+use prometheus_client::metrics::gauge::{ConstGauge, Gauge};
+use prometheus_client::metrics::histogram::{exponential_buckets, Histogram};
 use prometheus_client::registry::{Registry, Unit};
 use router_api::ChainName;
 use sysinfo::{get_current_pid, ProcessRefreshKind, ProcessesToUpdate, RefreshKind, System};
@@ -36,6 +37,30 @@ pub enum Stage {
     TransactionBroadcast,
     TransactionConfirmation,
 }
+
+/// Reason why the messages of a transaction are broadcast again
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, EncodeLabelValue)]
+pub enum RebroadcastReason {
+    NotIncluded,
+    OutOfGas,
+    InsufficientFee,
+    SequenceMismatch,
+}
+
+/// Reason why a verifier does not vote on a poll
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, EncodeLabelValue)]
+pub enum PollSkipReason {
+    Expired,
+    NotParticipant,
+}
+
+/// Queues whose depth is tracked
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, EncodeLabelValue)]
+pub enum Queue {
+    MsgQueue,
+    TxConfirmation,
+}
+
 /// Messages for metrics collection
 ///
 /// These messages are sent to the metrics processor to update various counters
@@ -49,10 +74,16 @@ pub enum Msg {
         vote_decision: voting::Vote,
         chain_name: ChainName,
     },
-    /// Record the result of RPC calls
+    /// Record the result and latency of RPC calls
     RpcCall {
         chain_name: ChainName,
         success: bool,
+        duration: Duration,
+    },
+    /// Record a poll the verifier did not vote on
+    PollSkipped {
+        chain_name: ChainName,
+        reason: PollSkipReason,
     },
     /// Record result and duration of a processing stage operation
     StageResult {
@@ -60,6 +91,12 @@ pub enum Msg {
         success: bool,
         duration: Duration,
     },
+    /// Record result and duration of handling a single event by the given handler
+    EventHandlerResult {
+        handler: String,
+        success: bool,
+        duration: Duration,
+    },
     /// Record the number of errors in message enqueue operations
     MessageEnqueueError,
     /// Record the number of timeouts in event stream
@@ -68,6 +105,14 @@ pub enum Msg {
     EventPublisherError,
     /// Record the number of errors that occur in the grpc service
     GrpcServiceError,
+    /// Record a transaction whose messages are broadcast again, or dropped if the
+    /// maximum number of re-broadcasts is exhausted
+    TransactionRebroadcast {
+        reason: RebroadcastReason,
+        exhausted: bool,
+    },
+    /// Record the current number of items waiting in a queue
+    QueueDepth { queue: Queue, depth: usize },
 }
 
 /// Errors that can occur in metrics processing
@@ -229,8 +274,12 @@ struct Metrics {
     block_received: BlockReceivedMetrics,
     verification_vote: VerificationVoteMetrics,
     rpc_call: RpcCallMetrics,
+    poll_skipped: PollSkippedMetrics,
     stage_result: EventStageMetrics,
+    event_handler: EventHandlerMetrics,
     error_metrics: ErrorMetrics,
+    rebroadcast: RebroadcastMetrics,
+    queue_depth: QueueDepthMetrics,
 }
 
 impl Metrics {
@@ -238,21 +287,33 @@ impl Metrics {
         let block_received = BlockReceivedMetrics::new();
         let verification_vote = VerificationVoteMetrics::new();
         let rpc_call = RpcCallMetrics::new();
+        let poll_skipped = PollSkippedMetrics::new();
         let stage_result = EventStageMetrics::new();
+        let event_handler = EventHandlerMetrics::new();
         let error_metrics = ErrorMetrics::new();
+        let rebroadcast = RebroadcastMetrics::new();
+        let queue_depth = QueueDepthMetrics::new();
 
         block_received.register(registry);
         verification_vote.register(registry);
         rpc_call.register(registry);
+        poll_skipped.register(registry);
         stage_result.register(registry);
+        event_handler.register(registry);
         error_metrics.register_all(registry);
+        rebroadcast.register(registry);
+        queue_depth.register(registry);
 
         Self {
             block_received,
             verification_vote,
             rpc_call,
+            poll_skipped,
             stage_result,
+            event_handler,
             error_metrics,
+            rebroadcast,
+            queue_depth,
         }
     }
 
@@ -272,8 +333,12 @@ impl Metrics {
             Msg::RpcCall {
                 chain_name,
                 success,
+                duration,
             } => {
-                self.rpc_call.record_rpc_call(chain_name, success);
+                self.rpc_call.record_rpc_call(chain_name, success, duration);
+            }
+            Msg::PollSkipped { chain_name, reason } => {
+                self.poll_skipped.record(chain_name, reason);
             }
             Msg::StageResult {
                 stage,
@@ -282,6 +347,13 @@ impl Metrics {
             } => {
                 self.stage_result.record(success, duration, stage);
             }
+            Msg::EventHandlerResult {
+                handler,
+                success,
+                duration,
+            } => {
+                self.event_handler.record(handler, success, duration);
+            }
             Msg::MessageEnqueueError => {
                 self.error_metrics.record_msg_enqueue_error();
             }
@@ -294,6 +366,12 @@ impl Metrics {
             Msg::GrpcServiceError => {
                 self.error_metrics.record_grpc_service_error();
             }
+            Msg::TransactionRebroadcast { reason, exhausted } => {
+                self.rebroadcast.record(reason, exhausted);
+            }
+            Msg::QueueDepth { queue, depth } => {
+                self.queue_depth.set(queue, depth);
+            }
         }
     }
 }
@@ -376,16 +454,28 @@ impl VerificationVoteMetrics {
     }
 }
 
+/// Histogram buckets for latencies in seconds, ranging from 10ms to ~5s
+fn latency_histogram() -> Histogram {
+    Histogram::new(exponential_buckets(0.01, 2.0, 10))
+}
+
 struct RpcCallMetrics {
     total: Family<Vec<(String, String)>, Counter>,
     failed: Family<Vec<(String, String)>, Counter>,
+    duration: Family<Vec<(String, String)>, Histogram>,
 }
 
 impl RpcCallMetrics {
     fn new() -> Self {
         let total = Family::<Vec<(String, String)>, Counter>::default();
         let failed = Family::<Vec<(String, String)>, Counter>::default();
-        Self { total, failed }
+        let duration =
+            Family::<Vec<(String, String)>, Histogram>::new_with_constructor(latency_histogram);
+        Self {
+            total,
+            failed,
+            duration,
+        }
     }
 
     fn register(&self, registry: &mut Registry) {
@@ -400,15 +490,60 @@ impl RpcCallMetrics {
             "number of failed RPC calls per chain",
             self.failed.clone(),
         );
+
+        registry.register_with_unit(
+            "rpc_call_duration",
+            "latency of RPC calls per chain",
+            Unit::Seconds,
+            self.duration.clone(),
+        );
     }
 
-    fn record_rpc_call(&self, chain_name: ChainName, success: bool) {
+    fn record_rpc_call(&self, chain_name: ChainName, success: bool, duration: Duration) {
         let label = vec![("chain_name".to_string(), chain_name.to_string())];
         self.total.get_or_create(&label).inc();
 
         if !success {
             self.failed.get_or_create(&label).inc();
         }
+
+        self.duration
+            .get_or_create(&label)
+            .observe(duration.as_secs_f64());
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct PollSkippedLabel {
+    chain_name: String,
+    reason: PollSkipReason,
+}
+
+struct PollSkippedMetrics {
+    total: Family<PollSkippedLabel, Counter>,
+}
+
+impl PollSkippedMetrics {
+    fn new() -> Self {
+        Self {
+            total: Family::<PollSkippedLabel, Counter>::default(),
+        }
+    }
+
+    fn register(&self, registry: &mut Registry) {
+        registry.register(
+            "polls_skipped",
+            "number of polls the verifier did not vote on per chain",
+            self.total.clone(),
+        );
+    }
+
+    fn record(&self, chain_name: ChainName, reason: PollSkipReason) {
+        let label = PollSkippedLabel {
+            chain_name: chain_name.to_string(),
+            reason,
+        };
+        self.total.get_or_create(&label).inc();
     }
 }
 
@@ -466,6 +601,59 @@ impl EventStageMetrics {
     }
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct EventHandlerLabel {
+    handler: String,
+}
+
+struct EventHandlerMetrics {
+    total: Family<EventHandlerLabel, Counter>,
+    failed: Family<EventHandlerLabel, Counter>,
+    duration: Family<EventHandlerLabel, Histogram>,
+}
+
+impl EventHandlerMetrics {
+    fn new() -> Self {
+        Self {
+            total: Family::<EventHandlerLabel, Counter>::default(),
+            failed: Family::<EventHandlerLabel, Counter>::default(),
+            duration: Family::<EventHandlerLabel, Histogram>::new_with_constructor(
+                latency_histogram,
+            ),
+        }
+    }
+
+    fn register(&self, registry: &mut Registry) {
+        registry.register(
+            "handler_events_processed",
+            "total number of events processed per handler",
+            self.total.clone(),
+        );
+        registry.register(
+            "handler_events_failed",
+            "number of events per handler that failed to be processed",
+            self.failed.clone(),
+        );
+        registry.register_with_unit(
+            "handler_event_duration",
+            "duration of processing an event per handler",
+            Unit::Seconds,
+            self.duration.clone(),
+        );
+    }
+
+    fn record(&self, handler: String, success: bool, duration: Duration) {
+        let label = EventHandlerLabel { handler };
+        self.total.get_or_create(&label).inc();
+        if !success {
+            self.failed.get_or_create(&label).inc();
+        }
+        self.duration
+            .get_or_create(&label)
+            .observe(duration.as_secs_f64());
+    }
+}
+
 struct ErrorMetrics {
     msg_enqueue_error: Counter,
     event_timeout: Counter,
@@ -523,6 +711,79 @@ impl ErrorMetrics {
     }
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct RebroadcastLabel {
+    reason: RebroadcastReason,
+}
+
+struct RebroadcastMetrics {
+    total: Family<RebroadcastLabel, Counter>,
+    exhausted: Family<RebroadcastLabel, Counter>,
+}
+
+impl RebroadcastMetrics {
+    fn new() -> Self {
+        Self {
+            total: Family::<RebroadcastLabel, Counter>::default(),
+            exhausted: Family::<RebroadcastLabel, Counter>::default(),
+        }
+    }
+
+    fn register(&self, registry: &mut Registry) {
+        registry.register(
+            "tx_rebroadcasts",
+            "number of transactions whose messages were broadcast again",
+            self.total.clone(),
+        );
+        registry.register(
+            "tx_rebroadcasts_exhausted",
+            "number of transactions whose messages were dropped after exhausting all re-broadcasts",
+            self.exhausted.clone(),
+        );
+    }
+
+    fn record(&self, reason: RebroadcastReason, exhausted: bool) {
+        let label = RebroadcastLabel { reason };
+
+        if exhausted {
+            self.exhausted.get_or_create(&label).inc();
+        } else {
+            self.total.get_or_create(&label).inc();
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct QueueLabel {
+    queue: Queue,
+}
+
+struct QueueDepthMetrics {
+    depth: Family<QueueLabel, Gauge>,
+}
+
+impl QueueDepthMetrics {
+    fn new() -> Self {
+        Self {
+            depth: Family::<QueueLabel, Gauge>::default(),
+        }
+    }
+
+    fn register(&self, registry: &mut Registry) {
+        registry.register(
+            "queue_depth",
+            "number of items waiting in a queue",
+            self.depth.clone(),
+        );
+    }
+
+    fn set(&self, queue: Queue, depth: usize) {
+        self.depth
+            .get_or_create(&QueueLabel { queue })
+            .set(i64::try_from(depth).unwrap_or(i64::MAX));
+    }
+}
+
 /// Generic function to time an async operation and return both result and duration.
 /// Used when recording EventFlow metrics.
 pub async fn timed<F, Fut, T>(f: F) -> (T, Duration)
@@ -703,6 +964,46 @@ mod tests {
             duration: Duration::from_millis(600),
         });
 
+        // per handler event metrics
+        client.record_metric(Msg::EventHandlerResult {
+            handler: "evm-msg-verifier-ethereum".to_string(),
+            success: true,
+            duration: Duration::from_millis(250),
+        });
+        client.record_metric(Msg::EventHandlerResult {
+            handler: "evm-msg-verifier-ethereum".to_string(),
+            success: false,
+            duration: Duration::from_millis(500),
+        });
+
+        // skipped polls
+        client.record_metric(Msg::PollSkipped {
+            chain_name: chain_name!("ethereum"),
+            reason: PollSkipReason::Expired,
+        });
+        client.record_metric(Msg::PollSkipped {
+            chain_name: chain_name!("ethereum"),
+            reason: PollSkipReason::NotParticipant,
+        });
+        client.record_metric(Msg::PollSkipped {
+            chain_name: chain_name!("polygon"),
+            reason: PollSkipReason::Expired,
+        });
+
+        // queue depths
+        client.record_metric(Msg::QueueDepth {
+            queue: Queue::MsgQueue,
+            depth: 5,
+        });
+        client.record_metric(Msg::QueueDepth {
+            queue: Queue::MsgQueue,
+            depth: 3,
+        });
+        client.record_metric(Msg::QueueDepth {
+            queue: Queue::TxConfirmation,
+            depth: 2,
+        });
+
         // record error metrics
         for _ in 0..2 {
             client.record_metric(Msg::MessageEnqueueError);
@@ -711,16 +1012,32 @@ mod tests {
             client.record_metric(Msg::GrpcServiceError);
         }
 
+        // re-broadcasts
+        client.record_metric(Msg::TransactionRebroadcast {
+            reason: RebroadcastReason::NotIncluded,
+            exhausted: false,
+        });
+        client.record_metric(Msg::TransactionRebroadcast {
+            reason: RebroadcastReason::OutOfGas,
+            exhausted: false,
+        });
+        client.record_metric(Msg::TransactionRebroadcast {
+            reason: RebroadcastReason::NotIncluded,
+            exhausted: true,
+        });
+
         // Wait for the metrics to be updated
         // rpc calls
         client.record_metric(Msg::RpcCall {
             chain_name: ChainName::from_str("ethereum").unwrap(),
             success: true,
+            duration: Duration::from_millis(250),
         });
 
         client.record_metric(Msg::RpcCall {
             chain_name: ChainName::from_str("polygon").unwrap(),
             success: false,
+            duration: Duration::from_millis(500),
         });
 
         // Wait for the metrics to be updated
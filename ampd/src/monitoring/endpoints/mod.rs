@@ -4,6 +4,8 @@
 //!
 //! - **metrics**: Prometheus-compatible metrics endpoint for observability
 //! - **status**: Health check endpoint for service monitoring
+//! - **readiness**: Readiness endpoint that probes the reachability of ampd's external dependencies
 
 pub mod metrics;
+pub mod readiness;
 pub mod status;
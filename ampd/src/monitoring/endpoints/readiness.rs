@@ -0,0 +1,410 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Debug};
+use std::future::Future;
+use std::sync::{Arc, PoisonError, RwLock};
+
+use async_trait::async_trait;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::{get, MethodRouter};
+use axum::Json;
+use error_stack::{report, Result, ResultExt};
+use futures::future::join_all;
+use report::LoggableError;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::task::JoinHandle;
+use tokio::time::{self, Duration};
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+use valuable::Valuable;
+
+use crate::url::Url;
+
+/// How often all registered dependencies get probed. The endpoint only serves cached results.
+const PROBE_INTERVAL: Duration = Duration::from_secs(30);
+/// A dependency that does not respond within this time is considered unreachable
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("dependency is unreachable")]
+    Unreachable,
+    #[error("dependency did not respond within {0:?}")]
+    Timeout(Duration),
+}
+
+/// A check whether an external dependency of ampd is reachable
+#[async_trait]
+pub trait Probe: Send + Sync {
+    async fn probe(&self) -> Result<(), Error>;
+}
+
+#[async_trait]
+impl<F, Fut> Probe for F
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<(), Error>> + Send,
+{
+    async fn probe(&self) -> Result<(), Error> {
+        self().await
+    }
+}
+
+/// Probes a JSON-RPC endpoint by calling the given method, which should be cheap for the node to answer.
+/// If `params` is `null`, the request is sent without params.
+pub fn json_rpc_probe(url: Url, method: &'static str, params: serde_json::Value) -> impl Probe {
+    let client = reqwest::Client::new();
+
+    move || {
+        call_json_rpc(client.post(url.as_str()).json(&JsonRpcRequest {
+            jsonrpc: "2.0",
+            id: 1,
+            method,
+            params: params.clone(),
+        }))
+    }
+}
+
+/// Probes an HTTP endpoint by sending a GET request to the given path relative to the url
+pub fn http_get_probe(url: Url, path: &'static str) -> impl Probe {
+    let client = reqwest::Client::new();
+    let endpoint = format!("{}/{}", url.as_str().trim_end_matches('/'), path);
+
+    move || call_http_get(client.get(endpoint.as_str()))
+}
+
+async fn send(request: reqwest::RequestBuilder) -> Result<reqwest::Response, Error> {
+    request
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .change_context(Error::Unreachable)
+}
+
+async fn call_http_get(request: reqwest::RequestBuilder) -> Result<(), Error> {
+    send(request).await.map(|_| ())
+}
+
+async fn call_json_rpc(request: reqwest::RequestBuilder) -> Result<(), Error> {
+    let response = send(request)
+        .await?
+        .json::<serde_json::Value>()
+        .await
+        .change_context(Error::Unreachable)?;
+
+    match response.get("error") {
+        Some(err) if !err.is_null() => {
+            Err(report!(Error::Unreachable)).attach_printable(format!("node returned {}", err))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[derive(Serialize)]
+struct JsonRpcRequest {
+    jsonrpc: &'static str,
+    id: u64,
+    method: &'static str,
+    #[serde(skip_serializing_if = "serde_json::Value::is_null")]
+    params: serde_json::Value,
+}
+
+/// Whether ampd cannot work at all without a dependency, or only a single handler is affected
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyKind {
+    Core,
+    Handler,
+}
+
+/// Overall readiness of ampd
+///
+/// - `Ready`: all dependencies are reachable
+/// - `Degraded`: all core dependencies are reachable, but some handlers cannot reach their chain
+/// - `NotReady`: a core dependency is unreachable or no probes have completed yet
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadinessState {
+    Ready,
+    Degraded,
+    NotReady,
+}
+
+/// Result of the last probe of a single dependency
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DependencyStatus {
+    pub kind: DependencyKind,
+    pub ok: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Readiness response returned by the `/ready` endpoint
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Readiness {
+    pub state: ReadinessState,
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, DependencyStatus>,
+}
+
+impl Readiness {
+    fn pending() -> Self {
+        Self {
+            state: ReadinessState::NotReady,
+            dependencies: BTreeMap::new(),
+        }
+    }
+
+    fn from_statuses(dependencies: BTreeMap<String, DependencyStatus>) -> Self {
+        let failed = |kind| {
+            dependencies
+                .values()
+                .any(|status| status.kind == kind && !status.ok)
+        };
+
+        let state = if failed(DependencyKind::Core) {
+            ReadinessState::NotReady
+        } else if failed(DependencyKind::Handler) {
+            ReadinessState::Degraded
+        } else {
+            ReadinessState::Ready
+        };
+
+        Self {
+            state,
+            dependencies,
+        }
+    }
+
+    fn status_code(&self) -> StatusCode {
+        match self.state {
+            ReadinessState::Ready | ReadinessState::Degraded => StatusCode::OK,
+            ReadinessState::NotReady => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+}
+
+/// The set of dependencies that are probed by the readiness endpoint.
+///
+/// Dependencies can be registered at any time, e.g. while handlers are created,
+/// and are included from the next probing round on.
+#[derive(Clone, Default)]
+pub struct Probes {
+    probes: Arc<RwLock<BTreeMap<String, (DependencyKind, Arc<dyn Probe>)>>>,
+}
+
+impl Probes {
+    /// Registers a dependency under the given name, replacing any probe previously registered under it
+    pub fn register(
+        &self,
+        name: impl Into<String>,
+        kind: DependencyKind,
+        probe: impl Probe + 'static,
+    ) {
+        self.probes
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(name.into(), (kind, Arc::new(probe)));
+    }
+
+    fn snapshot(&self) -> Vec<(String, DependencyKind, Arc<dyn Probe>)> {
+        self.probes
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(name, (kind, probe))| (name.clone(), *kind, probe.clone()))
+            .collect()
+    }
+}
+
+impl Debug for Probes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(
+                self.probes
+                    .read()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .keys(),
+            )
+            .finish()
+    }
+}
+
+/// Creates a readiness endpoint and the background process that periodically probes all registered dependencies
+///
+/// The endpoint returns the cached result of the last probing round. It responds with
+/// `503 Service Unavailable` while ampd is not ready and with `200 OK` otherwise,
+/// so a handler that cannot reach its chain does not take the whole node out of rotation.
+pub fn create_endpoint(probes: Probes) -> (MethodRouter, Process) {
+    let cache = Arc::new(RwLock::new(Readiness::pending()));
+
+    (
+        get(serve_readiness).with_state(cache.clone()),
+        Process {
+            probes,
+            cache,
+            interval: PROBE_INTERVAL,
+            timeout: PROBE_TIMEOUT,
+        },
+    )
+}
+
+async fn serve_readiness(
+    State(cache): State<Arc<RwLock<Readiness>>>,
+) -> (StatusCode, Json<Readiness>) {
+    let readiness = cache.read().unwrap_or_else(PoisonError::into_inner).clone();
+
+    (readiness.status_code(), Json(readiness))
+}
+
+/// Background process that probes all registered dependencies and caches the results
+pub struct Process {
+    probes: Probes,
+    cache: Arc<RwLock<Readiness>>,
+    interval: Duration,
+    timeout: Duration,
+}
+
+impl Process {
+    /// Runs the probes periodically until cancellation
+    pub fn run(self, cancel: CancellationToken) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = time::interval(self.interval);
+            interval.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    _ = cancel.cancelled() => break,
+                    _ = interval.tick() => self.probe_all().await,
+                }
+            }
+        })
+    }
+
+    async fn probe_all(&self) {
+        let statuses = join_all(self.probes.snapshot().into_iter().map(
+            |(name, kind, probe)| async move {
+                let res = time::timeout(self.timeout, probe.probe())
+                    .await
+                    .unwrap_or_else(|_| Err(report!(Error::Timeout(self.timeout))));
+
+                if let Err(err) = &res {
+                    warn!(
+                        err = LoggableError::from(err).as_value(),
+                        dependency = name.as_str(),
+                        "readiness probe failed"
+                    );
+                }
+
+                let status = DependencyStatus {
+                    kind,
+                    ok: res.is_ok(),
+                    error: res.err().map(|err| err.current_context().to_string()),
+                };
+
+                (name, status)
+            },
+        ))
+        .await;
+
+        *self.cache.write().unwrap_or_else(PoisonError::into_inner) =
+            Readiness::from_statuses(statuses.into_iter().collect());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::Router;
+    use axum_test::TestServer;
+
+    use super::*;
+
+    async fn reachable() -> Result<(), Error> {
+        Ok(())
+    }
+
+    async fn unreachable() -> Result<(), Error> {
+        Err(report!(Error::Unreachable))
+    }
+
+    async fn hanging() -> Result<(), Error> {
+        futures::future::pending().await
+    }
+
+    async fn readiness_after_first_round(probes: Probes) -> (StatusCode, Readiness) {
+        let (router, process) = create_endpoint(probes);
+        _ = process.run(CancellationToken::new());
+
+        let server = TestServer::new(Router::new().route("/ready", router)).unwrap();
+
+        // the first round starts immediately and finishes once all probes have responded or timed out
+        time::sleep(Duration::from_secs(15)).await;
+        let response = server.get("/ready").await;
+
+        (response.status_code(), response.json::<Readiness>())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_not_be_ready_before_first_probing_round() {
+        let (router, _) = create_endpoint(Probes::default());
+        let server = TestServer::new(Router::new().route("/ready", router)).unwrap();
+
+        let response = server.get("/ready").await;
+
+        assert_eq!(response.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.json::<Readiness>(), Readiness::pending());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_be_ready_if_all_dependencies_are_reachable() {
+        let probes = Probes::default();
+        probes.register("tofnd", DependencyKind::Core, reachable);
+        probes.register("ethereum-msg-verifier", DependencyKind::Handler, reachable);
+
+        let (status_code, readiness) = readiness_after_first_round(probes).await;
+
+        assert_eq!(status_code, StatusCode::OK);
+        assert_eq!(readiness.state, ReadinessState::Ready);
+        assert_eq!(readiness.dependencies.len(), 2);
+        assert!(readiness.dependencies.values().all(|status| status.ok));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_be_degraded_if_a_handler_dependency_is_unreachable() {
+        let probes = Probes::default();
+        probes.register("tofnd", DependencyKind::Core, reachable);
+        probes.register("ethereum-msg-verifier", DependencyKind::Handler, reachable);
+        probes.register("sui-msg-verifier", DependencyKind::Handler, unreachable);
+
+        let (status_code, readiness) = readiness_after_first_round(probes).await;
+
+        assert_eq!(status_code, StatusCode::OK);
+        assert_eq!(readiness.state, ReadinessState::Degraded);
+        assert_eq!(
+            readiness.dependencies["sui-msg-verifier"],
+            DependencyStatus {
+                kind: DependencyKind::Handler,
+                ok: false,
+                error: Some(Error::Unreachable.to_string()),
+            }
+        );
+        assert!(readiness.dependencies["ethereum-msg-verifier"].ok);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_not_be_ready_if_a_core_dependency_times_out() {
+        let probes = Probes::default();
+        probes.register("tofnd", DependencyKind::Core, hanging);
+        probes.register("ethereum-msg-verifier", DependencyKind::Handler, reachable);
+
+        let (status_code, readiness) = readiness_after_first_round(probes).await;
+
+        assert_eq!(status_code, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(readiness.state, ReadinessState::NotReady);
+        assert_eq!(
+            readiness.dependencies["tofnd"].error,
+            Some(Error::Timeout(PROBE_TIMEOUT).to_string())
+        );
+    }
+}
@@ -1,7 +1,12 @@
+use std::collections::BTreeMap;
+use std::future;
+
 use axum::routing::{get, MethodRouter};
 use axum::Json;
 use serde::{Deserialize, Serialize};
 
+use crate::event_sub::progress::ProgressStore;
+
 /// Status response for health check endpoints
 ///
 /// This structure represents the health status of the monitoring system
@@ -10,17 +15,27 @@ use serde::{Deserialize, Serialize};
 pub struct Status {
     /// Whether the service is healthy and operational
     pub ok: bool,
+    /// The last fully processed block height of each handler
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub processed_heights: BTreeMap<String, u64>,
 }
 
 /// Creates a status endpoint that returns health information
 ///
-/// This endpoint always returns `{"ok": true}` to indicate that
+/// This endpoint always returns `"ok": true` to indicate that
 /// the monitoring server is running and accepting requests.
+/// Once handlers have processed blocks, the response also contains
+/// the last fully processed block height of each handler.
 ///
 /// # Returns
 ///
 /// A `MethodRouter` configured to handle GET requests and return
 /// a JSON status response.
-pub fn create_endpoint() -> MethodRouter {
-    get(Json(Status { ok: true }))
+pub fn create_endpoint(progress: ProgressStore) -> MethodRouter {
+    get(move || {
+        future::ready(Json(Status {
+            ok: true,
+            processed_heights: progress.heights(),
+        }))
+    })
 }
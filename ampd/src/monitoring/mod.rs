@@ -7,7 +7,7 @@
 //! # Features
 //!
 //! - **Metrics Collection**: Collects and exposes Prometheus-compatible metrics
-//! - **Health Endpoints**: Provides status endpoints for health checks
+//! - **Health Endpoints**: Provides status and readiness endpoints for health checks
 //! - **Configurable Server**: Can be enabled/disabled via configuration
 //! - **Graceful Shutdown**: Handles cancellation tokens for clean shutdown
 
@@ -12,7 +12,8 @@ use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 use tracing::info;
 
-use crate::monitoring::endpoints::status;
+use crate::event_sub::progress::ProgressStore;
+use crate::monitoring::endpoints::{readiness, status};
 use crate::monitoring::metrics;
 
 /// Errors that can occur during monitoring server operations
@@ -138,6 +139,7 @@ impl<'de> Deserialize<'de> for Config {
 #[derive(Clone, Debug)]
 pub struct Client {
     metrics_client: metrics::Client,
+    readiness_probes: readiness::Probes,
 }
 
 impl Client {
@@ -147,12 +149,19 @@ impl Client {
     pub fn metrics(&self) -> &metrics::Client {
         &self.metrics_client
     }
+
+    /// Returns the set of dependencies probed by the readiness endpoint
+    ///
+    /// Use this to register the external dependencies a component relies on.
+    pub fn readiness(&self) -> &readiness::Probes {
+        &self.readiness_probes
+    }
 }
 
 /// The monitoring server that can run in either disabled or enabled mode
 ///
 /// - `Disabled`: Server is disabled, no HTTP endpoints are exposed
-/// - `Enabled`: Server is enabled and runs HTTP endpoints for metrics and status
+/// - `Enabled`: Server is enabled and runs HTTP endpoints for metrics, status and readiness
 pub enum Server {
     Disabled,
     Enabled { server: HttpServer },
@@ -175,11 +184,24 @@ impl Server {
     /// Returns an error if the server cannot be created or if metrics endpoints
     /// cannot be initialized.
     pub fn new(config: Config) -> Result<(Server, Client), Error> {
+        Self::new_with_progress(config, ProgressStore::default())
+    }
+
+    /// Creates a new monitoring server and client pair whose `/status` endpoint
+    /// reports the block processing progress of the handlers in the given store
+    pub fn new_with_progress(
+        config: Config,
+        progress: ProgressStore,
+    ) -> Result<(Server, Client), Error> {
         match config {
             Config::Enabled {
                 bind_address,
                 channel_size,
-            } => Self::create_server_with_client(TcpConnector::from(bind_address), channel_size),
+            } => Self::create_server_with_client(
+                TcpConnector::from(bind_address),
+                channel_size,
+                progress,
+            ),
 
             Config::Disabled => {
                 info!("monitoring server is disabled");
@@ -187,6 +209,7 @@ impl Server {
                     Server::Disabled,
                     Client {
                         metrics_client: metrics::Client::Disabled,
+                        readiness_probes: readiness::Probes::default(),
                     },
                 ))
             }
@@ -196,20 +219,34 @@ impl Server {
     fn create_server_with_client(
         tcp_connector: TcpConnector,
         channel_size: usize,
+        progress: ProgressStore,
     ) -> Result<(Server, Client), Error> {
-        let status_router = status::create_endpoint();
+        let status_router = status::create_endpoint(progress);
         let (metrics_router, metrics_process, metrics_client) =
             metrics::create_endpoint(channel_size);
+        let readiness_probes = readiness::Probes::default();
+        let (readiness_router, readiness_process) =
+            readiness::create_endpoint(readiness_probes.clone());
 
         let server = Server::Enabled {
             server: HttpServer {
                 tcp_connector,
-                routes: HashMap::from([("/status", status_router), ("/metrics", metrics_router)]),
-                endpoint_handles: vec![Box::new(|cancel| metrics_process.run(cancel))],
+                routes: HashMap::from([
+                    ("/status", status_router),
+                    ("/metrics", metrics_router),
+                    ("/ready", readiness_router),
+                ]),
+                endpoint_handles: vec![
+                    Box::new(|cancel| metrics_process.run(cancel)),
+                    Box::new(|cancel| readiness_process.run(cancel)),
+                ],
             },
         };
 
-        let client = Client { metrics_client };
+        let client = Client {
+            metrics_client,
+            readiness_probes,
+        };
 
         Ok((server, client))
     }
@@ -315,7 +352,7 @@ impl From<TcpListener> for TcpConnector {
 /// HTTP server implementation for monitoring endpoints
 ///
 /// This struct contains the actual HTTP server implementation that serves
-/// monitoring endpoints like `/metrics` and `/status`.
+/// monitoring endpoints like `/metrics`, `/status` and `/ready`.
 pub struct HttpServer {
     routes: HashMap<&'static str, MethodRouter>,
     endpoint_handles: Vec<Box<dyn FnOnce(CancellationToken) -> JoinHandle<()> + Send>>,
@@ -520,6 +557,7 @@ mod tests {
 
         let status = response.json::<Status>().await.unwrap();
         assert!(status.ok);
+        assert!(status.processed_heights.is_empty());
 
         cancel.cancel();
         _ = server_handle.await;
@@ -530,6 +568,77 @@ mod tests {
         )
     }
 
+    #[async_test(start_paused = true)]
+    async fn enabled_server_reports_processed_block_heights_on_status_endpoint() {
+        let bind_address = listener().await.bind_address().unwrap();
+        let status_url = create_endpoint_url(bind_address, "status");
+
+        let progress = ProgressStore::default();
+        let (server, _) = Server::new_with_progress(
+            Config::Enabled {
+                bind_address: convert_to_socketaddrv4(bind_address),
+                channel_size: 1000,
+            },
+            progress.clone(),
+        )
+        .unwrap();
+        let cancel = CancellationToken::new();
+
+        let server_handle = tokio::spawn(server.run(cancel.clone()));
+
+        tokio::time::sleep(Duration::from_secs(1)).await;
+
+        progress.update("handler-a", 10u32.into());
+        progress.update("handler-b", 12u32.into());
+
+        let status = reqwest::get(status_url)
+            .await
+            .unwrap()
+            .json::<Status>()
+            .await
+            .unwrap();
+        assert!(status.ok);
+        assert_eq!(
+            status.processed_heights,
+            [("handler-a".to_string(), 10), ("handler-b".to_string(), 12)].into()
+        );
+
+        cancel.cancel();
+        _ = server_handle.await;
+    }
+
+    #[async_test(start_paused = true)]
+    async fn enabled_server_reports_registered_dependencies_on_ready_endpoint() {
+        let bind_address = listener().await.bind_address().unwrap();
+        let ready_url = create_endpoint_url(bind_address, "ready");
+
+        let (server, monitoring_client) = Server::new(Config::Enabled {
+            bind_address: convert_to_socketaddrv4(bind_address),
+            channel_size: 1000,
+        })
+        .unwrap();
+        monitoring_client.readiness().register(
+            "tofnd",
+            readiness::DependencyKind::Core,
+            || async { Ok::<_, error_stack::Report<readiness::Error>>(()) },
+        );
+        let cancel = CancellationToken::new();
+
+        let server_handle = tokio::spawn(server.run(cancel.clone()));
+
+        tokio::time::sleep(Duration::from_secs(1)).await;
+
+        let response = reqwest::get(ready_url).await.unwrap();
+        assert_eq!(reqwest::StatusCode::OK, response.status());
+
+        let readiness = response.json::<readiness::Readiness>().await.unwrap();
+        assert_eq!(readiness.state, readiness::ReadinessState::Ready);
+        assert!(readiness.dependencies["tofnd"].ok);
+
+        cancel.cancel();
+        _ = server_handle.await;
+    }
+
     #[async_test(start_paused = true)]
     async fn enabled_server_continues_serving_after_all_metrics_clients_dropped() {
         let bind_address = listener().await.bind_address().unwrap();
@@ -737,7 +846,10 @@ pub mod test_utils {
     pub fn monitoring_client() -> (Client, mpsc::Receiver<Msg>) {
         let (tx, rx) = mpsc::channel(10);
         let metrics_client = MetricsClient::WithChannel { sender: tx };
-        let monitoring_client = Client { metrics_client };
+        let monitoring_client = Client {
+            metrics_client,
+            readiness_probes: Default::default(),
+        };
         (monitoring_client, rx)
     }
 }
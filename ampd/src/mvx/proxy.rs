@@ -1,4 +1,5 @@
 use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Debug};
 
 use async_trait::async_trait;
 use futures::future::join_all;
@@ -9,7 +10,8 @@ use multiversx_sdk::gateway::GatewayProxy;
 use router_api::ChainName;
 
 use crate::monitoring;
-use crate::monitoring::metrics::Msg;
+use crate::monitoring::metrics::{self, Msg};
+use crate::types::debug::REDACTED_VALUE;
 use crate::types::Hash;
 
 const STATUS_SUCCESS: &str = "success";
@@ -34,6 +36,15 @@ impl Client {
     }
 }
 
+impl Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("proxy", &REDACTED_VALUE)
+            .field("chain_name", &self.chain_name)
+            .finish()
+    }
+}
+
 #[automock]
 #[async_trait]
 pub trait MvxProxy {
@@ -74,16 +85,18 @@ impl MvxProxy for Client {
     }
 
     async fn transaction_info_with_results(&self, tx_hash: &Hash) -> Option<TransactionOnNetwork> {
-        let res = self
-            .proxy
-            .get_transaction_info_with_results(tx_hash.encode_hex::<String>().as_str())
-            .await;
+        let (res, duration) = metrics::timed(|| {
+            self.proxy
+                .get_transaction_info_with_results(tx_hash.encode_hex::<String>().as_str())
+        })
+        .await;
 
         self.monitoring_client
             .metrics()
             .record_metric(Msg::RpcCall {
                 chain_name: self.chain_name.clone(),
                 success: res.is_ok(),
+                duration,
             });
 
         res.ok().filter(Self::is_valid_transaction)
@@ -210,13 +223,14 @@ mod tests {
         assert!(result.is_none());
 
         let msg = receiver.recv().await.unwrap();
-        assert_eq!(
+        assert!(matches!(
             msg,
             Msg::RpcCall {
-                chain_name: ChainName::from_str("multiversx").unwrap(),
+                chain_name,
                 success: false,
-            }
-        );
+                ..
+            } if chain_name == ChainName::from_str("multiversx").unwrap()
+        ));
 
         assert!(receiver.try_recv().is_err());
     }
@@ -1,3 +1,4 @@
+use std::fmt::{self, Debug};
 use std::str::FromStr;
 
 use axelar_solana_gateway::events::{CallContractEvent, GatewayEvent, VerifierSetRotatedEvent};
@@ -8,9 +9,12 @@ use axelar_wasm_std::nonempty;
 use axelar_wasm_std::voting::Vote;
 use borsh::BorshDeserialize;
 use event_cpi::Discriminator;
+use mockall::automock;
 use router_api::ChainName;
-use serde::Deserializer;
+use serde::{Deserialize, Deserializer, Serialize};
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
 use solana_sdk::transaction::TransactionError;
@@ -19,11 +23,31 @@ use solana_transaction_status::{UiCompiledInstruction, UiInnerInstructions, UiIn
 use tracing::{debug, error};
 
 use crate::monitoring;
-use crate::monitoring::metrics::Msg;
+use crate::monitoring::metrics::{self, Msg};
+use crate::types::debug::REDACTED_VALUE;
 
 pub mod msg_verifier;
 pub mod verifier_set_verifier;
 
+/// The commitment level a transaction must have reached before ampd votes on it.
+/// Solana RPC nodes do not return transactions at the `processed` level, so it is not supported.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Commitment {
+    Confirmed,
+    #[default]
+    Finalized,
+}
+
+impl From<Commitment> for CommitmentConfig {
+    fn from(commitment: Commitment) -> Self {
+        match commitment {
+            Commitment::Confirmed => CommitmentConfig::confirmed(),
+            Commitment::Finalized => CommitmentConfig::finalized(),
+        }
+    }
+}
+
 pub struct Client {
     client: RpcClient,
     monitoring_client: monitoring::Client,
@@ -44,6 +68,16 @@ impl Client {
     }
 }
 
+impl Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("client", &REDACTED_VALUE)
+            .field("chain_name", &self.chain_name)
+            .finish()
+    }
+}
+
+#[automock]
 #[async_trait::async_trait]
 pub trait SolanaRpcClientProxy: Send + Sync + 'static {
     async fn tx(&self, signature: &Signature) -> Option<SolanaTransaction>;
@@ -53,19 +87,24 @@ pub trait SolanaRpcClientProxy: Send + Sync + 'static {
 #[async_trait::async_trait]
 impl SolanaRpcClientProxy for Client {
     async fn tx(&self, signature: &Signature) -> Option<SolanaTransaction> {
-        let res = self
-            .client
-            .get_transaction(
+        let (res, duration) = metrics::timed(|| {
+            self.client.get_transaction_with_config(
                 signature,
-                solana_transaction_status::UiTransactionEncoding::Json,
+                RpcTransactionConfig {
+                    encoding: Some(solana_transaction_status::UiTransactionEncoding::Json),
+                    commitment: Some(self.client.commitment()),
+                    max_supported_transaction_version: None,
+                },
             )
-            .await;
+        })
+        .await;
 
         self.monitoring_client
             .metrics()
             .record_metric(Msg::RpcCall {
                 chain_name: self.chain_name.clone(),
                 success: res.is_ok(),
+                duration,
             });
 
         res.ok().and_then(|tx_data| {
@@ -112,13 +151,14 @@ impl SolanaRpcClientProxy for Client {
             gateway_address,
         );
 
-        let res = self.client.get_account(&gateway_root_pda).await;
+        let (res, duration) = metrics::timed(|| self.client.get_account(&gateway_root_pda)).await;
 
         self.monitoring_client
             .metrics()
             .record_metric(Msg::RpcCall {
                 chain_name: self.chain_name.clone(),
                 success: res.is_ok(),
+                duration,
             });
 
         let config_data = res.ok()?.data;
@@ -331,25 +371,27 @@ mod test {
         assert!(result.is_none());
 
         let msg = receiver.recv().await.unwrap();
-        assert_eq!(
+        assert!(matches!(
             msg,
             Msg::RpcCall {
-                chain_name: ChainName::from_str("solana").unwrap(),
+                chain_name,
                 success: false,
-            }
-        );
+                ..
+            } if chain_name == ChainName::from_str("solana").unwrap()
+        ));
 
         let result = client.domain_separator(&axelar_solana_gateway::ID).await;
         assert!(result.is_none());
 
         let msg = receiver.recv().await.unwrap();
-        assert_eq!(
+        assert!(matches!(
             msg,
             Msg::RpcCall {
-                chain_name: ChainName::from_str("solana").unwrap(),
+                chain_name,
                 success: false,
-            }
-        );
+                ..
+            } if chain_name == ChainName::from_str("solana").unwrap()
+        ));
 
         assert!(receiver.try_recv().is_err());
     }
@@ -11,7 +11,8 @@ use router_api::ChainName;
 use serde::Deserialize;
 use thiserror::Error;
 
-use crate::monitoring::metrics::Msg;
+use crate::monitoring::metrics::{self, Msg};
+use crate::types::debug::REDACTED_VALUE;
 use crate::types::Hash;
 use crate::url::Url;
 use crate::{monitoring, Error as BaseError};
@@ -116,14 +117,14 @@ impl Client {
         tx_hash: &Hash,
         finalized_block_height: u64,
     ) -> Option<Transaction> {
-        let res = self
-            .transaction(tx_hash.encode_hex::<String>().as_str())
-            .await;
+        let (res, duration) =
+            metrics::timed(|| self.transaction(tx_hash.encode_hex::<String>().as_str())).await;
         self.monitoring_client
             .metrics()
             .record_metric(Msg::RpcCall {
                 chain_name: self.chain_name.clone(),
                 success: res.is_ok(),
+                duration,
             });
 
         res.ok()
@@ -135,19 +136,21 @@ impl Client {
 
         let endpoint = self.endpoint(endpoint.as_str());
 
-        let res = self
-            .client
-            .get(endpoint.clone())
-            .send()
-            .map_err(|_| Error::LatestBlock { endpoint })
-            .and_then(|response| response.json::<Block>().map_err(|_| Error::Json))
-            .await;
+        let (res, duration) = metrics::timed(|| {
+            self.client
+                .get(endpoint.clone())
+                .send()
+                .map_err(|_| Error::LatestBlock { endpoint })
+                .and_then(|response| response.json::<Block>().map_err(|_| Error::Json))
+        })
+        .await;
 
         self.monitoring_client
             .metrics()
             .record_metric(Msg::RpcCall {
                 chain_name: self.chain_name.clone(),
                 success: res.is_ok(),
+                duration,
             });
 
         res
@@ -177,6 +180,14 @@ impl Client {
     }
 }
 
+impl Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("api_url", &REDACTED_VALUE)
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -444,26 +455,28 @@ mod tests {
         assert!(result.is_err());
 
         let msg = receiver.recv().await.unwrap();
-        assert_eq!(
+        assert!(matches!(
             msg,
             Msg::RpcCall {
-                chain_name: ChainName::from_str("stacks").unwrap(),
+                chain_name,
                 success: false,
-            }
-        );
+                ..
+            } if chain_name == ChainName::from_str("stacks").unwrap()
+        ));
 
         let tx_hash = Hash::from([0u8; 32]);
         let result = client.valid_transaction(&tx_hash, 1).await;
         assert!(result.is_none());
 
         let msg = receiver.recv().await.unwrap();
-        assert_eq!(
+        assert!(matches!(
             msg,
             Msg::RpcCall {
-                chain_name: ChainName::from_str("stacks").unwrap(),
+                chain_name,
                 success: false,
-            }
-        );
+                ..
+            } if chain_name == ChainName::from_str("stacks").unwrap()
+        ));
 
         assert!(receiver.try_recv().is_err());
     }
@@ -14,10 +14,10 @@ use sha3::{Digest, Keccak256};
 
 use crate::stacks::error::Error;
 
-pub(crate) mod error;
-pub(crate) mod finalizer;
-pub(crate) mod http_client;
-pub(crate) mod verifier;
+pub mod error;
+pub mod finalizer;
+pub mod http_client;
+pub mod verifier;
 
 pub struct WeightedSigner {
     pub signer: Vec<u8>,
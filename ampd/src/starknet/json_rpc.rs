@@ -13,7 +13,7 @@ use starknet_providers::{JsonRpcClient, Provider, ProviderError};
 use thiserror::Error;
 
 use crate::monitoring;
-use crate::monitoring::metrics::Msg;
+use crate::monitoring::metrics::{self, Msg};
 use crate::types::starknet::events::contract_call::{ContractCallError, ContractCallEvent};
 use crate::types::starknet::events::signers_rotated::SignersRotatedEvent;
 
@@ -94,16 +94,18 @@ where
         &self,
         message_id: FieldElementAndEventIndex,
     ) -> Option<ContractCallEvent> {
-        let res = self
-            .client
-            .get_transaction_receipt(message_id.tx_hash.clone())
-            .await;
+        let (res, duration) = metrics::timed(|| {
+            self.client
+                .get_transaction_receipt(message_id.tx_hash.clone())
+        })
+        .await;
 
         self.monitoring_client
             .metrics()
             .record_metric(Msg::RpcCall {
                 chain_name: self.chain_name.clone(),
                 success: res.is_ok(),
+                duration,
             });
 
         let receipt_with_block_info = res.ok()?;
@@ -128,16 +130,18 @@ where
         &self,
         message_id: FieldElementAndEventIndex,
     ) -> Option<SignersRotatedEvent> {
-        let res = self
-            .client
-            .get_transaction_receipt(message_id.tx_hash.clone())
-            .await;
+        let (res, duration) = metrics::timed(|| {
+            self.client
+                .get_transaction_receipt(message_id.tx_hash.clone())
+        })
+        .await;
 
         self.monitoring_client
             .metrics()
             .record_metric(Msg::RpcCall {
                 chain_name: self.chain_name.clone(),
                 success: res.is_ok(),
+                duration,
             });
 
         let receipt_with_block_info = res.ok()?;
@@ -526,25 +530,27 @@ mod test {
         assert!(result.is_none());
 
         let msg = receiver.recv().await.unwrap();
-        assert_eq!(
+        assert!(matches!(
             msg,
             Msg::RpcCall {
-                chain_name: ChainName::from_str("starknet").unwrap(),
-                success: false
-            }
-        );
+                chain_name,
+                success: false,
+                ..
+            } if chain_name == ChainName::from_str("starknet").unwrap()
+        ));
 
         let result = client.event_by_message_id_signers_rotated(message_id).await;
         assert!(result.is_none());
 
         let msg = receiver.recv().await.unwrap();
-        assert_eq!(
+        assert!(matches!(
             msg,
             Msg::RpcCall {
-                chain_name: ChainName::from_str("starknet").unwrap(),
+                chain_name,
                 success: false,
-            }
-        );
+                ..
+            } if chain_name == ChainName::from_str("starknet").unwrap()
+        ));
 
         assert!(receiver.try_recv().is_err());
     }
@@ -572,13 +578,14 @@ mod test {
         assert!(result.is_some());
 
         let msg = receiver.recv().await.unwrap();
-        assert_eq!(
+        assert!(matches!(
             msg,
             Msg::RpcCall {
-                chain_name: ChainName::from_str("starknet").unwrap(),
-                success: true
-            }
-        );
+                chain_name,
+                success: true,
+                ..
+            } if chain_name == ChainName::from_str("starknet").unwrap()
+        ));
 
         assert!(receiver.try_recv().is_err());
     }
@@ -603,13 +610,14 @@ mod test {
         assert!(result.is_some());
 
         let msg = receiver.recv().await.unwrap();
-        assert_eq!(
+        assert!(matches!(
             msg,
             Msg::RpcCall {
-                chain_name: ChainName::from_str("starknet").unwrap(),
+                chain_name,
                 success: true,
-            }
-        );
+                ..
+            } if chain_name == ChainName::from_str("starknet").unwrap()
+        ));
 
         assert!(receiver.try_recv().is_err());
     }
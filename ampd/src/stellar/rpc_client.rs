@@ -12,7 +12,7 @@ use thiserror::Error;
 use tracing::warn;
 
 use crate::monitoring;
-use crate::monitoring::metrics::Msg;
+use crate::monitoring::metrics::{self, Msg};
 use crate::url::Url;
 
 #[derive(Error, Debug)]
@@ -171,13 +171,15 @@ impl StellarClient for Client {
         tx_hash: String,
     ) -> error_stack::Result<Option<TxResponse>, Error> {
         let tx_hash = Hash::from_str(tx_hash.as_str()).change_context(Error::TxHash)?;
-        let res = self.validate_tx_response(self.client.get_transaction(&tx_hash).await, tx_hash);
+        let (response, duration) = metrics::timed(|| self.client.get_transaction(&tx_hash)).await;
+        let res = self.validate_tx_response(response, tx_hash);
 
         self.monitoring_client
             .metrics()
             .record_metric(Msg::RpcCall {
                 chain_name: self.chain_name.clone(),
                 success: res.is_some(),
+                duration,
             });
 
         Ok(res)
@@ -195,20 +197,21 @@ impl StellarClient for Client {
         let responses = join_all(
             tx_hashes
                 .iter()
-                .map(|tx_hash| self.client.get_transaction(tx_hash)),
+                .map(|tx_hash| metrics::timed(|| self.client.get_transaction(tx_hash))),
         )
         .await;
 
         Ok(responses
             .into_iter()
             .zip(tx_hashes)
-            .filter_map(|(response, hash)| {
+            .filter_map(|((response, duration), hash)| {
                 let res = self.validate_tx_response(response, hash);
                 self.monitoring_client
                     .metrics()
                     .record_metric(Msg::RpcCall {
                         chain_name: self.chain_name.clone(),
                         success: res.is_some(),
+                        duration,
                     });
 
                 res.map(|tx_response| (tx_response.tx_hash(), tx_response))
@@ -507,13 +510,14 @@ mod tests {
 
         for _ in 0..2 {
             let msg = receiver.recv().await.unwrap();
-            assert_eq!(
+            assert!(matches!(
                 msg,
                 Msg::RpcCall {
-                    chain_name: ChainName::from_str("stellar").unwrap(),
+                    chain_name,
                     success: false,
-                }
-            );
+                    ..
+                } if chain_name == ChainName::from_str("stellar").unwrap()
+            ));
         }
 
         assert!(receiver.try_recv().is_err());
@@ -538,12 +542,13 @@ mod tests {
         assert!(result.unwrap().is_none());
 
         let msg = receiver.recv().await.unwrap();
-        assert_eq!(
+        assert!(matches!(
             msg,
             Msg::RpcCall {
-                chain_name: ChainName::from_str("stellar").unwrap(),
+                chain_name,
                 success: false,
-            }
-        );
+                ..
+            } if chain_name == ChainName::from_str("stellar").unwrap()
+        ));
     }
 }
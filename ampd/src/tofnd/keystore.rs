@@ -0,0 +1,416 @@
+use std::fmt::{self, Debug};
+use std::fs::{self, OpenOptions};
+use std::io::Write as _;
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use async_trait::async_trait;
+use ed25519_dalek::Signer as _;
+use error_stack::{ensure, report, ResultExt};
+use hkdf::Hkdf;
+use k256::ecdsa::signature::hazmat::PrehashSigner;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use tracing::instrument;
+use zeroize::Zeroizing;
+
+use super::{Algorithm, Error, Multisig};
+use crate::types::debug::REDACTED_VALUE;
+use crate::types::PublicKey;
+
+type Result<T> = error_stack::Result<T, Error>;
+
+const KEYSTORE_VERSION: u8 = 1;
+const SEED_LEN: usize = 32;
+const SALT_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+// scrypt cost parameters recommended for interactive logins, see https://www.tarsnap.com/scrypt/scrypt.pdf
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+// only the owner may read the keystore file
+#[cfg(unix)]
+const KEYSTORE_FILE_MODE: u32 = 0o600;
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+pub struct Config {
+    pub path: PathBuf,
+    pub password: PasswordSource,
+}
+
+/// Where to read the password that decrypts the keystore from. The password itself never goes into the config.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum PasswordSource {
+    Env(String),
+    File(PathBuf),
+}
+
+impl PasswordSource {
+    fn read(&self) -> Result<Zeroizing<String>> {
+        match self {
+            PasswordSource::Env(var) => std::env::var(var)
+                .map(Zeroizing::new)
+                .change_context(Error::KeystorePassword)
+                .attach_printable_lazy(|| format!("{{ env = {var} }}")),
+            PasswordSource::File(path) => fs::read_to_string(path)
+                .map(|password| {
+                    let mut password = Zeroizing::new(password);
+                    let len = password.trim_end().len();
+                    password.truncate(len);
+                    password
+                })
+                .change_context(Error::KeystorePassword)
+                .attach_printable_lazy(|| format!("{{ path = {} }}", path.display())),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct KeystoreFile {
+    version: u8,
+    scrypt_log_n: u8,
+    scrypt_r: u32,
+    scrypt_p: u32,
+    #[serde(with = "hex")]
+    salt: Vec<u8>,
+    #[serde(with = "hex")]
+    nonce: Vec<u8>,
+    #[serde(with = "hex")]
+    ciphertext: Vec<u8>,
+}
+
+/// Local signing backend that can stand in for tofnd.
+///
+/// The keystore file only holds a seed encrypted with a password derived key (scrypt + AES-256-GCM).
+/// Like tofnd does with its mnemonic, every key is derived deterministically from that seed, the key uid and
+/// the algorithm, so keygen is idempotent and the file never has to be written after it was created.
+/// The seed and every secret derived from it are zeroized when dropped.
+#[derive(Clone)]
+pub struct Keystore {
+    seed: Arc<Zeroizing<[u8; SEED_LEN]>>,
+}
+
+impl Keystore {
+    /// Loads and decrypts the keystore with the password from the configured source
+    pub fn load(config: &Config) -> Result<Self> {
+        Self::open(&config.path, &config.password.read()?)
+    }
+
+    /// Creates a new keystore with a random seed and encrypts it with the password from the configured source
+    pub fn create(config: &Config) -> Result<Self> {
+        ensure!(
+            !config.path.exists(),
+            Error::KeystoreExists(config.path.display().to_string())
+        );
+
+        let mut seed = Zeroizing::new([0u8; SEED_LEN]);
+        OsRng.fill_bytes(seed.as_mut_slice());
+
+        write(&config.path, &config.password.read()?, &seed, SCRYPT_LOG_N)?;
+
+        Ok(Self {
+            seed: Arc::new(seed),
+        })
+    }
+
+    fn open(path: &Path, password: &str) -> Result<Self> {
+        let file: KeystoreFile = fs::read(path)
+            .change_context(Error::KeystoreRead)
+            .and_then(|content| {
+                serde_json::from_slice(&content).change_context(Error::KeystoreRead)
+            })
+            .attach_printable_lazy(|| format!("{{ path = {} }}", path.display()))?;
+
+        ensure!(
+            file.version == KEYSTORE_VERSION,
+            Error::UnsupportedKeystoreVersion(file.version)
+        );
+        ensure!(file.nonce.len() == NONCE_LEN, Error::KeystoreRead);
+
+        let cipher = cipher(
+            password,
+            &file.salt,
+            file.scrypt_log_n,
+            file.scrypt_r,
+            file.scrypt_p,
+        )?;
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(&file.nonce), file.ciphertext.as_slice())
+            .map(Zeroizing::new)
+            .map_err(|_| report!(Error::KeystoreDecryption))?;
+        let seed: Zeroizing<[u8; SEED_LEN]> = plaintext
+            .as_slice()
+            .try_into()
+            .map(Zeroizing::new)
+            .map_err(|_| report!(Error::KeystoreDecryption))?;
+
+        Ok(Self {
+            seed: Arc::new(seed),
+        })
+    }
+
+    fn signing_key(&self, key_uid: &str, algorithm: Algorithm) -> Result<SigningKey> {
+        let info = format!("ampd-keystore/{}/{key_uid}", algorithm.as_str_name());
+        let mut secret = Zeroizing::new([0u8; 32]);
+        Hkdf::<Sha256>::new(None, self.seed.as_slice())
+            .expand(info.as_bytes(), secret.as_mut_slice())
+            .map_err(|_| report!(Error::LocalSigning))?;
+
+        // both signing key types zeroize themselves on drop
+        match algorithm {
+            Algorithm::Ecdsa => k256::ecdsa::SigningKey::from_slice(secret.as_slice())
+                .map(SigningKey::Secp256k1)
+                .change_context(Error::LocalSigning),
+            Algorithm::Ed25519 => Ok(SigningKey::Ed25519(ed25519_dalek::SigningKey::from_bytes(
+                &secret,
+            ))),
+        }
+    }
+}
+
+impl Debug for Keystore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Keystore")
+            .field("seed", &REDACTED_VALUE)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl Multisig for Keystore {
+    #[instrument]
+    async fn keygen(&self, key_uid: &str, algorithm: Algorithm) -> Result<PublicKey> {
+        self.signing_key(key_uid, algorithm)
+            .map(|signing_key| signing_key.public_key())
+    }
+
+    #[instrument]
+    async fn sign(
+        &self,
+        key_uid: &str,
+        data: [u8; 32],
+        pub_key: PublicKey,
+        algorithm: Algorithm,
+    ) -> Result<Vec<u8>> {
+        let signing_key = self.signing_key(key_uid, algorithm)?;
+        ensure!(
+            signing_key.public_key() == pub_key,
+            Error::UnknownPublicKey(pub_key)
+        );
+
+        signing_key.sign(data)
+    }
+}
+
+enum SigningKey {
+    Secp256k1(k256::ecdsa::SigningKey),
+    Ed25519(ed25519_dalek::SigningKey),
+}
+
+impl SigningKey {
+    fn public_key(&self) -> PublicKey {
+        match self {
+            SigningKey::Secp256k1(key) => PublicKey::Secp256k1(*key.verifying_key()),
+            SigningKey::Ed25519(key) => PublicKey::Ed25519(key.verifying_key()),
+        }
+    }
+
+    // signatures have the same format as the ones returned by the tofnd client
+    fn sign(&self, data: [u8; 32]) -> Result<Vec<u8>> {
+        match self {
+            SigningKey::Secp256k1(key) => key
+                .sign_prehash(&data)
+                .map(|sig: k256::ecdsa::Signature| sig.to_vec())
+                .change_context(Error::LocalSigning),
+            SigningKey::Ed25519(key) => Ok(key.sign(&data).to_bytes().to_vec()),
+        }
+    }
+}
+
+fn cipher(password: &str, salt: &[u8], log_n: u8, r: u32, p: u32) -> Result<Aes256Gcm> {
+    let params = scrypt::Params::new(log_n, r, p, SEED_LEN)
+        .map_err(|_| report!(Error::KeystoreRead).attach_printable("invalid scrypt parameters"))?;
+
+    let mut key = Zeroizing::new([0u8; SEED_LEN]);
+    scrypt::scrypt(password.as_bytes(), salt, &params, key.as_mut_slice())
+        .map_err(|_| report!(Error::KeystoreDecryption))?;
+
+    Ok(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key.as_slice())))
+}
+
+fn write(path: &Path, password: &str, seed: &[u8; SEED_LEN], log_n: u8) -> Result<()> {
+    let mut salt = vec![0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let mut nonce = vec![0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce);
+
+    let ciphertext = cipher(password, &salt, log_n, SCRYPT_R, SCRYPT_P)?
+        .encrypt(Nonce::from_slice(&nonce), seed.as_slice())
+        .map_err(|_| report!(Error::KeystoreWrite))?;
+
+    let file = KeystoreFile {
+        version: KEYSTORE_VERSION,
+        scrypt_log_n: log_n,
+        scrypt_r: SCRYPT_R,
+        scrypt_p: SCRYPT_P,
+        salt,
+        nonce,
+        ciphertext,
+    };
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).change_context(Error::KeystoreWrite)?;
+    }
+
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    options.mode(KEYSTORE_FILE_MODE);
+
+    options
+        .open(path)
+        .and_then(|mut keystore| {
+            keystore.write_all(
+                &serde_json::to_vec_pretty(&file).expect("keystore file should serialize"),
+            )
+        })
+        .change_context(Error::KeystoreWrite)
+        .attach_printable_lazy(|| format!("{{ path = {} }}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use ed25519_dalek::Verifier;
+    use k256::ecdsa::signature::hazmat::PrehashVerifier;
+    use tempfile::TempDir;
+
+    use super::{write, Keystore, SEED_LEN};
+    use crate::tofnd::{Algorithm, Error, Multisig};
+    use crate::types::PublicKey;
+
+    const PASSWORD: &str = "password";
+
+    // a low scrypt cost keeps the tests fast. The keystore file is removed when the returned directory is dropped
+    fn keystore_file(seed: [u8; SEED_LEN]) -> (TempDir, PathBuf) {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("keystore.json");
+        write(&path, PASSWORD, &seed, 4).unwrap();
+        (dir, path)
+    }
+
+    #[tokio::test]
+    async fn keygen_should_be_deterministic() {
+        let (_dir, path) = keystore_file([1; SEED_LEN]);
+        let keystore = Keystore::open(&path, PASSWORD).unwrap();
+        let reopened = Keystore::open(&path, PASSWORD).unwrap();
+
+        for algorithm in [Algorithm::Ecdsa, Algorithm::Ed25519] {
+            assert_eq!(
+                keystore.keygen("key", algorithm).await.unwrap(),
+                reopened.keygen("key", algorithm).await.unwrap()
+            );
+            assert_ne!(
+                keystore.keygen("key", algorithm).await.unwrap(),
+                keystore.keygen("other key", algorithm).await.unwrap()
+            );
+        }
+
+        let (_other_dir, other_path) = keystore_file([2; SEED_LEN]);
+        let other = Keystore::open(&other_path, PASSWORD).unwrap();
+        assert_ne!(
+            keystore.keygen("key", Algorithm::Ecdsa).await.unwrap(),
+            other.keygen("key", Algorithm::Ecdsa).await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_should_produce_valid_signatures() {
+        let (_dir, path) = keystore_file([1; SEED_LEN]);
+        let keystore = Keystore::open(&path, PASSWORD).unwrap();
+        let data = [3u8; 32];
+
+        for algorithm in [Algorithm::Ecdsa, Algorithm::Ed25519] {
+            let pub_key = keystore.keygen("key", algorithm).await.unwrap();
+            let signature = keystore
+                .sign("key", data, pub_key, algorithm)
+                .await
+                .unwrap();
+
+            match pub_key {
+                PublicKey::Secp256k1(key) => key
+                    .verify_prehash(
+                        &data,
+                        &k256::ecdsa::Signature::from_slice(&signature).unwrap(),
+                    )
+                    .unwrap(),
+                PublicKey::Ed25519(key) => key
+                    .verify(
+                        &data,
+                        &ed25519_dalek::Signature::from_slice(&signature).unwrap(),
+                    )
+                    .unwrap(),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn sign_should_fail_for_unknown_public_key() {
+        let (_dir, path) = keystore_file([1; SEED_LEN]);
+        let keystore = Keystore::open(&path, PASSWORD).unwrap();
+        let (_other_dir, other_path) = keystore_file([2; SEED_LEN]);
+        let other = Keystore::open(&other_path, PASSWORD).unwrap();
+        let pub_key = other.keygen("key", Algorithm::Ecdsa).await.unwrap();
+
+        let result = keystore
+            .sign("key", [3u8; 32], pub_key, Algorithm::Ecdsa)
+            .await;
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            Error::UnknownPublicKey(_)
+        ));
+    }
+
+    #[test]
+    fn open_should_fail_with_wrong_password() {
+        let (_dir, path) = keystore_file([1; SEED_LEN]);
+        let result = Keystore::open(&path, "wrong password");
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            Error::KeystoreDecryption
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn keystore_file_should_only_be_readable_by_owner() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let (_dir, path) = keystore_file([1; SEED_LEN]);
+
+        assert_eq!(
+            std::fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+            0o600
+        );
+    }
+
+    #[test]
+    fn write_should_not_overwrite_existing_keystore() {
+        let (_dir, path) = keystore_file([1; SEED_LEN]);
+
+        assert!(matches!(
+            write(&path, PASSWORD, &[2; SEED_LEN], 4)
+                .unwrap_err()
+                .current_context(),
+            Error::KeystoreWrite
+        ));
+        assert!(Keystore::open(&path, PASSWORD).is_ok());
+    }
+}
@@ -1,15 +1,20 @@
 use std::time::Duration;
 
+use async_trait::async_trait;
+use error_stack::ResultExt;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::types::PublicKey;
 use crate::url::Url;
 
 mod grpc;
+pub mod keystore;
 
 #[cfg(test)]
 pub use grpc::MockMultisig;
 pub use grpc::{Multisig, MultisigClient};
+pub use keystore::Keystore;
 pub use proto::Algorithm;
 
 #[derive(Error, Debug)]
@@ -24,6 +29,22 @@ pub enum Error {
     InvalidSignResponse,
     #[error("tofnd failed with error {0}")]
     ExecutionFailed(String),
+    #[error("failed to read the keystore")]
+    KeystoreRead,
+    #[error("failed to write the keystore")]
+    KeystoreWrite,
+    #[error("keystore {0} already exists")]
+    KeystoreExists(String),
+    #[error("unsupported keystore version {0}")]
+    UnsupportedKeystoreVersion(u8),
+    #[error("failed to decrypt the keystore")]
+    KeystoreDecryption,
+    #[error("failed to read the keystore password")]
+    KeystorePassword,
+    #[error("public key {0} does not belong to the keystore")]
+    UnknownPublicKey(PublicKey),
+    #[error("failed to sign with the keystore")]
+    LocalSigning,
 }
 
 #[allow(non_snake_case)]
@@ -38,6 +59,17 @@ pub struct Config {
     pub party_uid: String,
     pub key_uid: String,
     pub timeout: Duration,
+    #[serde(default)]
+    pub backend: Backend,
+}
+
+/// Selects which signer ampd uses. The local keystore is meant for devnets and tests that should run without tofnd.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Default)]
+#[serde(tag = "type")]
+pub enum Backend {
+    #[default]
+    Tofnd,
+    Keystore(keystore::Config),
 }
 
 impl Default for Config {
@@ -48,6 +80,57 @@ impl Default for Config {
             party_uid: "ampd".into(),
             key_uid: "axelar".into(),
             timeout: Duration::from_secs(3),
+            backend: Backend::default(),
+        }
+    }
+}
+
+/// Signing client for the backend selected in the config
+#[derive(Clone, Debug)]
+pub enum Client {
+    Tofnd(MultisigClient),
+    Keystore(Keystore),
+}
+
+impl Client {
+    pub async fn new(config: &Config) -> error_stack::Result<Self, Error> {
+        match &config.backend {
+            Backend::Tofnd => MultisigClient::new(
+                config.party_uid.clone(),
+                config.url.as_str(),
+                config.timeout,
+            )
+            .await
+            .map(Client::Tofnd)
+            .attach_printable_lazy(|| config.url.clone()),
+            Backend::Keystore(keystore) => Keystore::load(keystore).map(Client::Keystore),
+        }
+    }
+}
+
+#[async_trait]
+impl Multisig for Client {
+    async fn keygen(
+        &self,
+        key_uid: &str,
+        algorithm: Algorithm,
+    ) -> error_stack::Result<PublicKey, Error> {
+        match self {
+            Client::Tofnd(client) => client.keygen(key_uid, algorithm).await,
+            Client::Keystore(keystore) => keystore.keygen(key_uid, algorithm).await,
+        }
+    }
+
+    async fn sign(
+        &self,
+        key_uid: &str,
+        data: [u8; 32],
+        pub_key: PublicKey,
+        algorithm: Algorithm,
+    ) -> error_stack::Result<Vec<u8>, Error> {
+        match self {
+            Client::Tofnd(client) => client.sign(key_uid, data, pub_key, algorithm).await,
+            Client::Keystore(keystore) => keystore.sign(key_uid, data, pub_key, algorithm).await,
         }
     }
 }
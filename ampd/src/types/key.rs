@@ -3,6 +3,7 @@ use std::fmt;
 use cosmwasm_std::HexBinary;
 use ed25519_dalek::PUBLIC_KEY_LENGTH;
 use error_stack::{self, Report, ResultExt};
+use multisig::key::KeyTyped;
 use thiserror::Error;
 
 pub type CosmosPublicKey = cosmrs::crypto::PublicKey;
@@ -15,6 +16,8 @@ pub enum Error {
     UnsupportedConversionForCosmosKey(PublicKey),
     #[error("invalid raw bytes")]
     InvalidRawBytes,
+    #[error("key type {0} is not supported")]
+    UnsupportedKeyType(multisig::key::KeyType),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
@@ -73,6 +76,10 @@ impl TryFrom<&multisig::key::PublicKey> for PublicKey {
         match key {
             multisig::key::PublicKey::Ecdsa(key) => Self::new_secp256k1(key),
             multisig::key::PublicKey::Ed25519(key) => Self::new_ed25519(key),
+            // tofnd cannot generate or sign with P-256 keys yet
+            multisig::key::PublicKey::Secp256r1(_) => {
+                Err(Report::new(Error::UnsupportedKeyType(key.key_type())))
+            }
         }
     }
 }
@@ -188,4 +195,17 @@ mod tests {
         let cosmos_public_key: CosmosPublicKey = public_key.try_into().unwrap();
         assert_eq!(cosmos_public_key.to_bytes(), bytes.to_vec());
     }
+
+    #[test]
+    fn conversion_from_multisig_secp256r1_key_fails() {
+        let multisig_key = multisig::key::PublicKey::Secp256r1(
+            multisig::test::common::secp256r1_test_data::pub_key(),
+        );
+
+        assert_err_contains!(
+            PublicKey::try_from(multisig_key),
+            Error,
+            Error::UnsupportedKeyType(multisig::key::KeyType::Secp256r1),
+        );
+    }
 }
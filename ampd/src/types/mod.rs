@@ -14,7 +14,7 @@ use crate::PREFIX;
 
 pub mod debug;
 mod key;
-pub(crate) mod starknet;
+pub mod starknet;
 #[cfg(test)]
 pub use key::test_utils::random_cosmos_public_key;
 pub use key::{CosmosPublicKey, PublicKey};
@@ -1,2 +1,2 @@
-pub(crate) mod contract_call;
-pub(crate) mod signers_rotated;
+pub mod contract_call;
+pub mod signers_rotated;
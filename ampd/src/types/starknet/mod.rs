@@ -1,2 +1,2 @@
 pub(crate) mod byte_array;
-pub(crate) mod events;
+pub mod events;
@@ -1,3 +1,5 @@
+use std::fmt::{self, Debug};
+
 use async_trait::async_trait;
 use cosmwasm_std::HexBinary;
 use mockall::automock;
@@ -5,7 +7,8 @@ use router_api::ChainName;
 use xrpl_http_client::{error, Client as XrplHttpClient, TxRequest, TxResponse};
 
 use crate::monitoring;
-use crate::monitoring::metrics::Msg;
+use crate::monitoring::metrics::{self, Msg};
+use crate::types::debug::REDACTED_VALUE;
 
 type Result<T> = error_stack::Result<T, error::Error>;
 
@@ -29,6 +32,15 @@ impl Client {
     }
 }
 
+impl Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("client", &REDACTED_VALUE)
+            .field("chain_name", &self.chain_name)
+            .finish()
+    }
+}
+
 #[automock]
 #[async_trait]
 pub trait XRPLClient {
@@ -39,13 +51,14 @@ pub trait XRPLClient {
 impl XRPLClient for Client {
     async fn tx(&self, tx_id: [u8; 32]) -> Result<Option<TxResponse>> {
         let req = TxRequest::new(HexBinary::from(tx_id).to_string().as_str());
-        let res = self.client.call(req).await;
+        let (res, duration) = metrics::timed(|| self.client.call(req)).await;
 
         self.monitoring_client
             .metrics()
             .record_metric(Msg::RpcCall {
                 chain_name: self.chain_name.clone(),
                 success: res.is_ok(),
+                duration,
             });
 
         res.map(Some).or_else(|err| match err {
@@ -91,13 +104,14 @@ mod test {
         assert!(result.is_err());
 
         let msg = receiver.recv().await.unwrap();
-        assert_eq!(
+        assert!(matches!(
             msg,
             Msg::RpcCall {
-                chain_name: ChainName::from_str("xrpl").unwrap(),
+                chain_name,
                 success: false,
-            }
-        );
+                ..
+            } if chain_name == ChainName::from_str("xrpl").unwrap()
+        ));
 
         assert!(receiver.try_recv().is_err());
     }
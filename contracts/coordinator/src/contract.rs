@@ -148,6 +148,9 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
         QueryMsg::Deployment { deployment_name } => {
             Ok(to_json_binary(&query::deployment(deps, deployment_name)?)?)
         }
+        QueryMsg::MessageLifecycle { cc_id } => {
+            Ok(to_json_binary(&query::message_lifecycle(deps, cc_id)?)?)
+        }
     }
 }
 
@@ -1,6 +1,6 @@
 use axelar_wasm_std::{nonempty, IntoContractError};
 use cosmwasm_std::Addr;
-use router_api::ChainName;
+use router_api::{ChainName, CrossChainId};
 
 #[derive(thiserror::Error, Debug, PartialEq, IntoContractError)]
 pub enum Error {
@@ -59,4 +59,6 @@ pub enum Error {
     InvalidAddress(String),
     #[error("invalid limit")]
     InvalidLimit,
+    #[error("failed to retrieve the lifecycle of message {0}")]
+    MessageLifecycle(CrossChainId),
 }
@@ -162,6 +162,7 @@ fn instantiate_prover(
             encoder: prover_msg.encoder,
             key_type: prover_msg.key_type,
             domain_separator: prover_msg.domain_separator,
+            payload_limits: solana_multisig_prover_api::msg::PayloadLimits::default(),
         })
         .change_context(Error::InstantiateProver)?,
         label,
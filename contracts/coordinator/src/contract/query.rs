@@ -1,17 +1,42 @@
-use axelar_wasm_std::nonempty;
-use cosmwasm_std::{Addr, Deps, Env, Order, StdError, WasmQuery};
-use error_stack::{Result, ResultExt};
+use axelar_wasm_std::{nonempty, VerificationStatus};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, to_json_vec, Addr, ContractResult, Deps, Empty, Env, Order, QueryRequest,
+    StdError, SystemResult, Uint64, WasmQuery,
+};
+use error_stack::{report, Result, ResultExt};
 use itertools::Itertools;
+use router_api::{ChainName, CrossChainId, Message};
 use service_registry_api::msg::VerifierDetails;
 
 use crate::contract::errors::Error;
-use crate::msg::{ChainContractsKey, ChainContractsResponse, VerifierInfo};
+use crate::msg::{
+    ChainContractsKey, ChainContractsResponse, MessageLifecycleResponse, MessageProof, VerifierInfo,
+};
 use crate::state;
 use crate::state::{
     contracts_by_chain, contracts_by_gateway, contracts_by_prover, contracts_by_verifier,
-    VERIFIER_PROVER_INDEXED_MAP,
+    ChainContractsRecord, VERIFIER_PROVER_INDEXED_MAP,
 };
 
+// The voting verifier and the multisig prover depend on the coordinator, so their messages can't be imported here.
+// These types mirror the parts of their query interfaces used by the coordinator and serialize the same way.
+#[cw_serde]
+enum VotingVerifierQueryMsg {
+    MessageStatusById { cc_id: CrossChainId },
+}
+
+#[cw_serde]
+struct MessageStatus {
+    message: Message,
+    status: VerificationStatus,
+}
+
+#[cw_serde]
+enum ProverQueryMsg {
+    MultisigSessionsByMessage { cc_id: CrossChainId },
+}
+
 pub fn check_verifier_ready_to_unbond(deps: Deps, verifier_address: Addr) -> Result<bool, Error> {
     Ok(!is_verifier_in_any_verifier_set(deps, &verifier_address))
 }
@@ -146,3 +171,442 @@ pub fn deployment(
         })
         .change_context(Error::DeploymentNotFound(deployment_name))
 }
+
+pub fn message_lifecycle(
+    deps: Deps,
+    cc_id: CrossChainId,
+) -> Result<MessageLifecycleResponse, Error> {
+    let unknown = MessageLifecycleResponse {
+        cc_id: cc_id.clone(),
+        verification_status: None,
+        routed: false,
+        destination_chain: None,
+        proof: None,
+    };
+
+    let Some(source) = registered_chain(deps, cc_id.source_chain.normalize())? else {
+        return Ok(unknown);
+    };
+
+    let Some(MessageStatus { message, status }) =
+        message_status(deps, &source.verifier_address, &cc_id)?
+    else {
+        return Ok(unknown);
+    };
+
+    let destination_chain = message.destination_chain;
+    let routed = status == VerificationStatus::SucceededOnSourceChain
+        && is_routed(deps, &cc_id, destination_chain.clone())?;
+
+    let proof = match registered_chain(deps, destination_chain.clone())? {
+        Some(destination) if routed => latest_proof(deps, &destination.prover_address, &cc_id)?,
+        _ => None,
+    };
+
+    Ok(MessageLifecycleResponse {
+        cc_id,
+        verification_status: Some(status),
+        routed,
+        destination_chain: Some(destination_chain),
+        proof,
+    })
+}
+
+/// Checks whether the message has been routed to the gateway of the destination chain, as registered with the router.
+/// The gateway rejects `OutgoingMessages` queries for unknown messages, so a query the gateway itself rejects means the message
+/// hasn't been routed yet, while a failure to reach the gateway fails the lookup.
+fn is_routed(
+    deps: Deps,
+    cc_id: &CrossChainId,
+    destination_chain: ChainName,
+) -> Result<bool, Error> {
+    let protocol =
+        state::protocol_contracts(deps.storage).change_context(Error::ProtocolNotRegistered)?;
+
+    let router: router_api::Client =
+        client::ContractClient::new(deps.querier, &protocol.router).into();
+
+    let gateway = router
+        .chain_info(destination_chain)
+        .change_context(Error::MessageLifecycle(cc_id.clone()))?
+        .gateway
+        .address;
+
+    let request = to_json_vec(&QueryRequest::<Empty>::Wasm(WasmQuery::Smart {
+        contract_addr: gateway.to_string(),
+        msg: to_json_binary(&gateway_api::msg::QueryMsg::OutgoingMessages(vec![
+            cc_id.clone()
+        ]))
+        .change_context(Error::MessageLifecycle(cc_id.clone()))?,
+    }))
+    .change_context(Error::MessageLifecycle(cc_id.clone()))?;
+
+    match deps.querier.raw_query(&request) {
+        SystemResult::Ok(ContractResult::Ok(_)) => Ok(true),
+        SystemResult::Ok(ContractResult::Err(_)) => Ok(false),
+        SystemResult::Err(err) => {
+            Err(report!(err).change_context(Error::MessageLifecycle(cc_id.clone())))
+        }
+    }
+}
+
+fn registered_chain(
+    deps: Deps,
+    chain_name: ChainName,
+) -> Result<Option<ChainContractsRecord>, Error> {
+    match state::contracts_by_chain(deps.storage, chain_name) {
+        Ok(record) => Ok(Some(record)),
+        Err(err) if matches!(err.current_context(), state::Error::ChainNotRegistered(_)) => {
+            Ok(None)
+        }
+        Err(err) => Err(err.change_context(Error::ChainContractsInfo)),
+    }
+}
+
+/// Looks up the message at the voting verifier of its source chain, which knows the message content once it has been put to a poll
+fn message_status(
+    deps: Deps,
+    voting_verifier: &Addr,
+    cc_id: &CrossChainId,
+) -> Result<Option<MessageStatus>, Error> {
+    let voting_verifier: client::ContractClient<(), VotingVerifierQueryMsg> =
+        client::ContractClient::new(deps.querier, voting_verifier);
+
+    voting_verifier
+        .query(&VotingVerifierQueryMsg::MessageStatusById {
+            cc_id: cc_id.clone(),
+        })
+        .change_context(Error::MessageLifecycle(cc_id.clone()))
+}
+
+fn latest_proof(
+    deps: Deps,
+    prover: &Addr,
+    cc_id: &CrossChainId,
+) -> Result<Option<MessageProof>, Error> {
+    let prover: client::ContractClient<(), ProverQueryMsg> =
+        client::ContractClient::new(deps.querier, prover);
+
+    let Some(multisig_session_id) = prover
+        .query::<Vec<Uint64>>(&ProverQueryMsg::MultisigSessionsByMessage {
+            cc_id: cc_id.clone(),
+        })
+        .change_context(Error::MessageLifecycle(cc_id.clone()))?
+        .last()
+        .copied()
+    else {
+        return Ok(None);
+    };
+
+    let protocol =
+        state::protocol_contracts(deps.storage).change_context(Error::ProtocolNotRegistered)?;
+    let multisig: multisig::Client =
+        client::ContractClient::new(deps.querier, &protocol.multisig).into();
+
+    let session = multisig
+        .multisig(multisig_session_id)
+        .change_context(Error::MessageLifecycle(cc_id.clone()))?;
+
+    Ok(Some(MessageProof {
+        multisig_session_id,
+        status: session.state,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use axelar_wasm_std::flagset::FlagSet;
+    use axelar_wasm_std::msg_id::MessageIdFormat;
+    use cosmwasm_std::testing::{mock_dependencies, MockApi, MockQuerier, MockStorage};
+    use cosmwasm_std::{from_json, to_json_binary, OwnedDeps, SystemError, Uint128};
+    use multisig::multisig::Multisig;
+    use multisig::types::MultisigState;
+    use multisig::verifier_set::VerifierSet;
+    use router_api::{address, chain_name, cosmos_addr, ChainEndpoint, Gateway, GatewayDirection};
+
+    use super::*;
+    use crate::state::ProtocolContracts;
+
+    const SOURCE_CHAIN: &str = "ethereum";
+    const DESTINATION_CHAIN: &str = "avalanche";
+
+    fn message() -> Message {
+        Message {
+            cc_id: CrossChainId::new(SOURCE_CHAIN, "0x1234-0").unwrap(),
+            source_address: address!("source-address"),
+            destination_chain: chain_name!(DESTINATION_CHAIN),
+            destination_address: address!("destination-address"),
+            payload_hash: [1; 32],
+        }
+    }
+
+    enum GatewayResponse {
+        Routed,
+        NotRouted,
+        Unreachable,
+    }
+
+    fn setup(
+        message_status: Option<MessageStatus>,
+        gateway: GatewayResponse,
+    ) -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+
+        state::save_protocol_contracts(
+            deps.as_mut().storage,
+            &ProtocolContracts {
+                service_registry: cosmos_addr!("service_registry"),
+                router: cosmos_addr!("router"),
+                multisig: cosmos_addr!("multisig"),
+            },
+        )
+        .unwrap();
+
+        state::save_chain_contracts(
+            deps.as_mut().storage,
+            chain_name!(SOURCE_CHAIN),
+            cosmos_addr!("source_prover"),
+            cosmos_addr!("source_gateway"),
+            cosmos_addr!("source_verifier"),
+        )
+        .unwrap();
+        state::save_chain_contracts(
+            deps.as_mut().storage,
+            chain_name!(DESTINATION_CHAIN),
+            cosmos_addr!("destination_prover"),
+            cosmos_addr!("destination_gateway"),
+            cosmos_addr!("destination_verifier"),
+        )
+        .unwrap();
+
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::Smart { contract_addr, msg }
+                if contract_addr == cosmos_addr!("router").as_str() =>
+            {
+                match from_json::<router_api::msg::QueryMsg>(msg).unwrap() {
+                    router_api::msg::QueryMsg::ChainInfo(chain)
+                        if chain == chain_name!(DESTINATION_CHAIN) =>
+                    {
+                        SystemResult::Ok(
+                            to_json_binary(&ChainEndpoint {
+                                name: chain,
+                                gateway: Gateway {
+                                    address: cosmos_addr!("destination_gateway"),
+                                },
+                                frozen_status: FlagSet::from(GatewayDirection::None),
+                                msg_id_format: MessageIdFormat::HexTxHashAndEventIndex,
+                            })
+                            .into(),
+                        )
+                    }
+                    _ => SystemResult::Ok(ContractResult::Err("chain not found".to_string())),
+                }
+            }
+            WasmQuery::Smart { contract_addr, msg }
+                if contract_addr == cosmos_addr!("destination_gateway").as_str() =>
+            {
+                assert_eq!(
+                    from_json::<gateway_api::msg::QueryMsg>(msg).unwrap(),
+                    gateway_api::msg::QueryMsg::OutgoingMessages(vec![message().cc_id])
+                );
+
+                match gateway {
+                    GatewayResponse::Routed => {
+                        SystemResult::Ok(to_json_binary(&vec![message()]).into())
+                    }
+                    GatewayResponse::NotRouted => {
+                        SystemResult::Ok(ContractResult::Err("message not found".to_string()))
+                    }
+                    GatewayResponse::Unreachable => {
+                        SystemResult::Err(SystemError::NoSuchContract {
+                            addr: contract_addr.clone(),
+                        })
+                    }
+                }
+            }
+            WasmQuery::Smart { contract_addr, msg }
+                if contract_addr == cosmos_addr!("source_verifier").as_str() =>
+            {
+                let VotingVerifierQueryMsg::MessageStatusById { cc_id } = from_json(msg).unwrap();
+                assert_eq!(cc_id, message().cc_id);
+
+                SystemResult::Ok(to_json_binary(&message_status).into())
+            }
+            WasmQuery::Smart { contract_addr, .. }
+                if contract_addr == cosmos_addr!("destination_prover").as_str() =>
+            {
+                SystemResult::Ok(to_json_binary(&vec![Uint64::new(1), Uint64::new(2)]).into())
+            }
+            WasmQuery::Smart { contract_addr, msg }
+                if contract_addr == cosmos_addr!("multisig").as_str() =>
+            {
+                assert_eq!(
+                    from_json::<multisig::msg::QueryMsg>(msg).unwrap(),
+                    multisig::msg::QueryMsg::Multisig {
+                        session_id: Uint64::new(2)
+                    }
+                );
+
+                SystemResult::Ok(
+                    to_json_binary(&Multisig {
+                        state: MultisigState::Completed { completed_at: 10 },
+                        verifier_set: VerifierSet {
+                            signers: BTreeMap::new(),
+                            threshold: Uint128::one(),
+                            created_at: 1,
+                        },
+                        signatures: HashMap::new(),
+                    })
+                    .into(),
+                )
+            }
+            _ => panic!("unexpected query: {:?}", query),
+        });
+
+        deps
+    }
+
+    #[test]
+    fn message_lifecycle_aggregates_state_of_routed_message() {
+        let deps = setup(
+            Some(MessageStatus {
+                message: message(),
+                status: VerificationStatus::SucceededOnSourceChain,
+            }),
+            GatewayResponse::Routed,
+        );
+
+        let res = message_lifecycle(deps.as_ref(), message().cc_id).unwrap();
+
+        assert_eq!(
+            res,
+            MessageLifecycleResponse {
+                cc_id: message().cc_id,
+                verification_status: Some(VerificationStatus::SucceededOnSourceChain),
+                routed: true,
+                destination_chain: Some(chain_name!(DESTINATION_CHAIN)),
+                proof: Some(MessageProof {
+                    multisig_session_id: Uint64::new(2),
+                    status: MultisigState::Completed { completed_at: 10 },
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn message_lifecycle_of_verified_message_that_is_not_routed() {
+        let deps = setup(
+            Some(MessageStatus {
+                message: message(),
+                status: VerificationStatus::SucceededOnSourceChain,
+            }),
+            GatewayResponse::NotRouted,
+        );
+
+        let res = message_lifecycle(deps.as_ref(), message().cc_id).unwrap();
+
+        assert_eq!(
+            res,
+            MessageLifecycleResponse {
+                cc_id: message().cc_id,
+                verification_status: Some(VerificationStatus::SucceededOnSourceChain),
+                routed: false,
+                destination_chain: Some(chain_name!(DESTINATION_CHAIN)),
+                proof: None,
+            }
+        );
+    }
+
+    #[test]
+    fn message_lifecycle_of_message_in_verification_does_not_query_destination() {
+        // the mocked gateway reports the message as routed, so querying it would show up in the response
+        let deps = setup(
+            Some(MessageStatus {
+                message: message(),
+                status: VerificationStatus::InProgress,
+            }),
+            GatewayResponse::Routed,
+        );
+
+        let res = message_lifecycle(deps.as_ref(), message().cc_id).unwrap();
+
+        assert_eq!(
+            res,
+            MessageLifecycleResponse {
+                cc_id: message().cc_id,
+                verification_status: Some(VerificationStatus::InProgress),
+                routed: false,
+                destination_chain: Some(chain_name!(DESTINATION_CHAIN)),
+                proof: None,
+            }
+        );
+    }
+
+    #[test]
+    fn message_lifecycle_of_unknown_message_is_empty() {
+        let deps = setup(None, GatewayResponse::NotRouted);
+
+        let res = message_lifecycle(deps.as_ref(), message().cc_id).unwrap();
+
+        assert_eq!(
+            res,
+            MessageLifecycleResponse {
+                cc_id: message().cc_id,
+                verification_status: None,
+                routed: false,
+                destination_chain: None,
+                proof: None,
+            }
+        );
+    }
+
+    #[test]
+    fn message_lifecycle_of_message_from_unregistered_chain_is_empty() {
+        let deps = setup(None, GatewayResponse::NotRouted);
+        let cc_id = CrossChainId::new("unknown", "0x1234-0").unwrap();
+
+        let res = message_lifecycle(deps.as_ref(), cc_id.clone()).unwrap();
+
+        assert_eq!(
+            res,
+            MessageLifecycleResponse {
+                cc_id,
+                verification_status: None,
+                routed: false,
+                destination_chain: None,
+                proof: None,
+            }
+        );
+    }
+
+    #[test]
+    fn message_lifecycle_fails_if_router_does_not_know_destination_chain() {
+        let deps = setup(
+            Some(MessageStatus {
+                message: Message {
+                    destination_chain: chain_name!("unknown"),
+                    ..message()
+                },
+                status: VerificationStatus::SucceededOnSourceChain,
+            }),
+            GatewayResponse::Routed,
+        );
+
+        assert!(message_lifecycle(deps.as_ref(), message().cc_id).is_err());
+    }
+
+    #[test]
+    fn message_lifecycle_fails_if_gateway_cannot_be_reached() {
+        let deps = setup(
+            Some(MessageStatus {
+                message: message(),
+                status: VerificationStatus::SucceededOnSourceChain,
+            }),
+            GatewayResponse::Unreachable,
+        );
+
+        assert!(message_lifecycle(deps.as_ref(), message().cc_id).is_err());
+    }
+}
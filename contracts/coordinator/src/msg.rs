@@ -3,12 +3,13 @@ use std::collections::HashSet;
 use axelar_wasm_std::address::AddressFormat;
 use axelar_wasm_std::hash::Hash;
 use axelar_wasm_std::msg_id::MessageIdFormat;
-use axelar_wasm_std::{nonempty, MajorityThreshold};
+use axelar_wasm_std::{nonempty, MajorityThreshold, VerificationStatus};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Binary};
+use cosmwasm_std::{Addr, Binary, Uint64};
 use msgs_derive::Permissions;
 use multisig::key::KeyType;
-use router_api::ChainName;
+use multisig::types::MultisigState;
+use router_api::{ChainName, CrossChainId};
 use service_registry_api::Verifier;
 use solana_multisig_prover_api::encoding::Encoder;
 
@@ -144,6 +145,10 @@ pub enum QueryMsg {
 
     #[returns(ChainContractsResponse)]
     Deployment { deployment_name: nonempty::String },
+
+    /// Aggregates the state of a message across the contracts of its source and destination chains
+    #[returns(MessageLifecycleResponse)]
+    MessageLifecycle { cc_id: CrossChainId },
 }
 
 #[cw_serde]
@@ -169,3 +174,23 @@ pub struct ChainContractsResponse {
     pub gateway_address: GatewayAddress,
     pub verifier_address: VerifierAddress,
 }
+
+#[cw_serde]
+pub struct MessageLifecycleResponse {
+    pub cc_id: CrossChainId,
+    /// Verification status of the message on the voting verifier of the source chain.
+    /// None if the source chain is not registered or its voting verifier doesn't know the message
+    pub verification_status: Option<VerificationStatus>,
+    /// Whether the message has been routed to the gateway of its destination chain
+    pub routed: bool,
+    /// Destination chain of the message, as known to the voting verifier of the source chain
+    pub destination_chain: Option<ChainName>,
+    /// The most recent proof that includes the message, if one has been constructed
+    pub proof: Option<MessageProof>,
+}
+
+#[cw_serde]
+pub struct MessageProof {
+    pub multisig_session_id: Uint64,
+    pub status: MultisigState,
+}
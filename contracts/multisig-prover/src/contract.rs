@@ -48,6 +48,7 @@ pub fn instantiate(
         encoder: msg.encoder,
         key_type: msg.key_type,
         domain_separator: msg.domain_separator,
+        payload_limits: msg.payload_limits,
     };
     CONFIG.save(deps.storage, &config)?;
 
@@ -72,6 +73,9 @@ pub fn execute(
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
     match msg.ensure_permissions(deps.storage, &info.sender)? {
         ExecuteMsg::ConstructProof(message_ids) => Ok(execute::construct_proof(deps, message_ids)?),
+        ExecuteMsg::RetryProof {
+            multisig_session_id,
+        } => Ok(execute::retry_proof(deps, multisig_session_id)?),
         ExecuteMsg::UpdateVerifierSet => Ok(execute::update_verifier_set(deps, env)?),
         ExecuteMsg::ConfirmVerifierSet => Ok(execute::confirm_verifier_set(deps, info.sender)?),
         ExecuteMsg::UpdateSigningThreshold {
@@ -83,6 +87,12 @@ pub fn execute(
         ExecuteMsg::UpdateAdmin { new_admin_address } => {
             Ok(execute::update_admin(deps, new_admin_address)?)
         }
+        ExecuteMsg::UpdatePayloadLimits { new_payload_limits } => {
+            Ok(execute::update_payload_limits(deps, new_payload_limits)?)
+        }
+        ExecuteMsg::BackfillMessageMultisigSessions { limit } => {
+            Ok(execute::backfill_message_multisig_sessions(deps, limit)?)
+        }
     }
 }
 
@@ -111,6 +121,17 @@ pub fn query(
         } => to_json_binary(&query::proof(deps, multisig_session_id)?),
         QueryMsg::CurrentVerifierSet => to_json_binary(&query::current_verifier_set(deps)?),
         QueryMsg::NextVerifierSet => to_json_binary(&query::next_verifier_set(deps)?),
+        QueryMsg::MultisigSessionsByMessage { cc_id } => {
+            to_json_binary(&query::multisig_sessions_by_message(deps, &cc_id)?)
+        }
+        QueryMsg::ProofsByMessage { cc_id } => {
+            to_json_binary(&query::proofs_by_message(deps, &cc_id)?)
+        }
+        QueryMsg::Proofs {
+            start_after,
+            limit,
+            status,
+        } => to_json_binary(&query::proofs(deps, start_after, limit, status)?),
     }
     .change_context(ContractError::SerializeResponse)
     .map_err(axelar_wasm_std::error::ContractError::from)
@@ -126,7 +147,8 @@ mod tests {
     use cosmwasm_std::{
         from_json, Addr, Empty, Fraction, OwnedDeps, SubMsgResponse, SubMsgResult, Uint128, Uint64,
     };
-    use multisig::msg::Signer;
+    use multisig::msg::{Signer, SigningSessionStatus};
+    use multisig::types::MultisigState;
     use multisig::verifier_set::VerifierSet;
     use prost::Message;
     use router_api::{cosmos_addr, CrossChainId};
@@ -134,11 +156,15 @@ mod tests {
 
     use super::*;
     use crate::contract::execute::should_update_verifier_set;
-    use crate::msg::{ProofResponse, ProofStatus, VerifierSetResponse};
+    use crate::msg::{
+        PayloadLimits, ProofResponse, ProofStatus, ProofsResponse, VerifierSetResponse,
+        MAX_PAGINATION_LIMIT,
+    };
     use crate::test::test_data::{self, TestOperator};
     use crate::test::test_utils::{
-        mock_querier_handler, ADMIN, COORDINATOR_ADDRESS, GATEWAY_ADDRESS, GOVERNANCE,
-        MULTISIG_ADDRESS, SERVICE_NAME, SERVICE_REGISTRY_ADDRESS, VOTING_VERIFIER_ADDRESS,
+        mock_querier_handler, mock_querier_handler_with_multisig_state, ADMIN, COORDINATOR_ADDRESS,
+        GATEWAY_ADDRESS, GOVERNANCE, MULTISIG_ADDRESS, SERVICE_NAME, SERVICE_REGISTRY_ADDRESS,
+        VOTING_VERIFIER_ADDRESS,
     };
 
     const RELAYER: &str = "relayer";
@@ -171,6 +197,7 @@ mod tests {
                 encoder: Encoder::Abi,
                 key_type: multisig::key::KeyType::Ecdsa,
                 domain_separator: [0; 32],
+                payload_limits: PayloadLimits::default(),
             },
         )
         .unwrap();
@@ -209,6 +236,15 @@ mod tests {
         execute(deps, mock_env(), message_info(&sender, &[]), msg)
     }
 
+    fn execute_update_payload_limits(
+        deps: DepsMut,
+        sender: Addr,
+        new_payload_limits: PayloadLimits,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let msg = ExecuteMsg::UpdatePayloadLimits { new_payload_limits };
+        execute(deps, mock_env(), message_info(&sender, &[]), msg)
+    }
+
     fn execute_update_admin(
         deps: DepsMut,
         sender: Addr,
@@ -238,10 +274,31 @@ mod tests {
         )
     }
 
+    fn execute_retry_proof(
+        deps: DepsMut,
+        multisig_session_id: Uint64,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        execute(
+            deps,
+            mock_env(),
+            message_info(&cosmos_addr!(RELAYER), &[]),
+            ExecuteMsg::RetryProof {
+                multisig_session_id,
+            },
+        )
+    }
+
     fn reply_construct_proof(
         deps: DepsMut,
     ) -> Result<Response, axelar_wasm_std::error::ContractError> {
-        let session_id = to_json_binary(&MULTISIG_SESSION_ID).unwrap();
+        reply_start_multisig(deps, MULTISIG_SESSION_ID)
+    }
+
+    fn reply_start_multisig(
+        deps: DepsMut,
+        multisig_session_id: Uint64,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let session_id = to_json_binary(&multisig_session_id).unwrap();
 
         #[allow(deprecated)]
         // TODO: use `msg_responses` instead when the cosmwasm vm is updated to 2.x.x
@@ -287,6 +344,44 @@ mod tests {
         .map(|res| from_json(res).unwrap())
     }
 
+    fn query_multisig_sessions_by_message(
+        deps: Deps,
+        cc_id: CrossChainId,
+    ) -> Result<Vec<Uint64>, axelar_wasm_std::error::ContractError> {
+        query(
+            deps,
+            mock_env(),
+            QueryMsg::MultisigSessionsByMessage { cc_id },
+        )
+        .map(|res| from_json(res).unwrap())
+    }
+
+    fn query_proofs_by_message(
+        deps: Deps,
+        cc_id: CrossChainId,
+    ) -> Result<Vec<ProofResponse>, axelar_wasm_std::error::ContractError> {
+        query(deps, mock_env(), QueryMsg::ProofsByMessage { cc_id })
+            .map(|res| from_json(res).unwrap())
+    }
+
+    fn query_proofs(
+        deps: Deps,
+        start_after: Option<Uint64>,
+        limit: u32,
+        status: Option<SigningSessionStatus>,
+    ) -> Result<ProofsResponse, axelar_wasm_std::error::ContractError> {
+        query(
+            deps,
+            mock_env(),
+            QueryMsg::Proofs {
+                start_after,
+                limit: limit.try_into().unwrap(),
+                status,
+            },
+        )
+        .map(|res| from_json(res).unwrap())
+    }
+
     fn query_verifier_set(
         deps: Deps,
     ) -> Result<Option<VerifierSetResponse>, axelar_wasm_std::error::ContractError> {
@@ -343,6 +438,7 @@ mod tests {
                 encoder: encoding,
                 key_type: multisig::key::KeyType::Ecdsa,
                 domain_separator: [0; 32],
+                payload_limits: PayloadLimits::default(),
             };
 
             let res = instantiate(deps.as_mut(), env, info, msg);
@@ -693,6 +789,93 @@ mod tests {
         }
     }
 
+    #[test]
+    fn multisig_sessions_by_message_records_each_session_once() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+
+        let cc_id = test_data::messages()[0].cc_id.clone();
+        assert!(
+            query_multisig_sessions_by_message(deps.as_ref(), cc_id.clone())
+                .unwrap()
+                .is_empty()
+        );
+
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap(); // simulate reply from multisig
+
+        assert_eq!(
+            query_multisig_sessions_by_message(deps.as_ref(), cc_id.clone()).unwrap(),
+            vec![MULTISIG_SESSION_ID]
+        );
+
+        // replies for the same session are not recorded twice
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap();
+
+        assert_eq!(
+            query_multisig_sessions_by_message(deps.as_ref(), cc_id).unwrap(),
+            vec![MULTISIG_SESSION_ID]
+        );
+    }
+
+    #[test]
+    fn retry_proof_starts_new_signing_session_for_expired_session() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+
+        let construct_res = execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap(); // simulate reply from multisig
+
+        deps.querier
+            .update_wasm(mock_querier_handler_with_multisig_state(
+                test_data::operators(),
+                VerificationStatus::SucceededOnSourceChain,
+                MultisigState::Expired { expired_at: 12345 },
+            ));
+
+        let res = query_proof(deps.as_ref(), None).unwrap();
+        assert_eq!(res.status, ProofStatus::Expired);
+
+        // the same payload is signed again by the current verifier set
+        let retry_res = execute_retry_proof(deps.as_mut(), MULTISIG_SESSION_ID).unwrap();
+        assert_eq!(retry_res.messages, construct_res.messages);
+        assert_eq!(retry_res.messages[0].id, START_MULTISIG_REPLY_ID);
+    }
+
+    #[test]
+    fn retry_proof_fails_if_session_is_not_closed() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap(); // simulate reply from multisig
+
+        // multisig mock returns a completed session
+        let res = execute_retry_proof(deps.as_mut(), MULTISIG_SESSION_ID);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::ProofNotRetryable(
+                MULTISIG_SESSION_ID
+            ))
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn retry_proof_fails_for_unknown_session() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+
+        let res = execute_retry_proof(deps.as_mut(), MULTISIG_SESSION_ID);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::ProofNotFound(
+                MULTISIG_SESSION_ID
+            ))
+            .to_string()
+        );
+    }
+
     #[test]
     fn test_construct_proof_no_verifier_set() {
         let mut deps = setup_test_case();
@@ -921,4 +1104,313 @@ mod tests {
             Permission::NoPrivilege.into()
         );
     }
+
+    fn message_ids(count: u64) -> Vec<CrossChainId> {
+        (0..count)
+            .map(|i| CrossChainId::new("ganache-1", format!("message-{}", i)).unwrap())
+            .collect()
+    }
+
+    fn max_messages_limits(max_messages: u32, auto_chunk: bool) -> PayloadLimits {
+        PayloadLimits {
+            max_messages: Some(max_messages.try_into().unwrap()),
+            max_payload_size: None,
+            auto_chunk,
+        }
+    }
+
+    /// The mock signatures only match the default test payload, so sessions signing any other payload must not be
+    /// reported as completed
+    fn mock_pending_multisig_sessions(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    ) {
+        deps.querier
+            .update_wasm(mock_querier_handler_with_multisig_state(
+                test_data::operators(),
+                VerificationStatus::SucceededOnSourceChain,
+                MultisigState::Pending,
+            ));
+    }
+
+    #[test]
+    fn non_governance_should_not_be_able_to_call_update_payload_limits() {
+        let mut deps = setup_test_case();
+        let res = execute_update_payload_limits(
+            deps.as_mut(),
+            cosmos_addr!(ADMIN),
+            max_messages_limits(1, true),
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn governance_should_be_able_to_call_update_payload_limits() {
+        let mut deps = setup_test_case();
+        let res = execute_update_payload_limits(
+            deps.as_mut(),
+            cosmos_addr!(GOVERNANCE),
+            max_messages_limits(1, true),
+        );
+        assert!(res.is_ok(), "{:?}", res);
+
+        assert_eq!(
+            CONFIG.load(deps.as_ref().storage).unwrap().payload_limits,
+            max_messages_limits(1, true)
+        );
+    }
+
+    #[test]
+    fn construct_proof_exceeding_payload_limits_fails_without_auto_chunk() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_update_payload_limits(
+            deps.as_mut(),
+            cosmos_addr!(GOVERNANCE),
+            max_messages_limits(2, false),
+        )
+        .unwrap();
+
+        // messages within the limits still fit into a single proof
+        let res = execute_construct_proof(deps.as_mut(), Some(message_ids(2))).unwrap();
+        assert_eq!(res.messages.len(), 1);
+
+        let res = execute_construct_proof(deps.as_mut(), Some(message_ids(3)));
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::PayloadLimitsExceeded)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn construct_proof_with_auto_chunk_starts_session_per_payload() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_update_payload_limits(
+            deps.as_mut(),
+            cosmos_addr!(GOVERNANCE),
+            max_messages_limits(2, true),
+        )
+        .unwrap();
+
+        let res = execute_construct_proof(deps.as_mut(), Some(message_ids(5))).unwrap();
+        assert_eq!(res.messages.len(), 3);
+        assert!(res
+            .messages
+            .iter()
+            .all(|msg| msg.id == START_MULTISIG_REPLY_ID));
+
+        // the multisig contract replies to each session in the order they were started
+        let session_ids = [Uint64::new(1), Uint64::new(2), Uint64::new(3)];
+        let replies = session_ids
+            .iter()
+            .map(|session_id| reply_start_multisig(deps.as_mut(), *session_id).unwrap())
+            .collect::<Vec<_>>();
+
+        for (reply, session_id) in replies.iter().zip(session_ids) {
+            let event = reply
+                .events
+                .iter()
+                .find(|event| event.ty == "proof_under_construction")
+                .unwrap();
+            assert!(event
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "multisig_session_id"
+                    && attr.value == format!("\"{}\"", session_id)));
+        }
+
+        // the data of the last reply contains all session ids
+        let data: Vec<Uint64> = from_json(replies.last().unwrap().data.clone().unwrap()).unwrap();
+        assert_eq!(data, session_ids);
+
+        mock_pending_multisig_sessions(&mut deps);
+
+        let proof_sizes = session_ids
+            .iter()
+            .map(|session_id| {
+                query_proof(deps.as_ref(), Some(*session_id))
+                    .unwrap()
+                    .message_ids
+                    .len()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(proof_sizes, vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn reply_without_pending_signing_session_fails() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap();
+
+        assert!(reply_construct_proof(deps.as_mut()).is_err());
+    }
+
+    fn session_ids(proofs: &[ProofResponse]) -> Vec<Uint64> {
+        proofs
+            .iter()
+            .map(|proof| proof.multisig_session_id)
+            .collect()
+    }
+
+    #[test]
+    fn proofs_by_message_returns_every_proof_of_message() {
+        let mut deps = setup_test_case();
+        mock_pending_multisig_sessions(&mut deps);
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_update_payload_limits(
+            deps.as_mut(),
+            cosmos_addr!(GOVERNANCE),
+            max_messages_limits(2, true),
+        )
+        .unwrap();
+
+        let cc_id = message_ids(1)[0].clone();
+        assert!(query_proofs_by_message(deps.as_ref(), cc_id.clone())
+            .unwrap()
+            .is_empty());
+
+        execute_construct_proof(deps.as_mut(), Some(message_ids(3))).unwrap();
+        reply_start_multisig(deps.as_mut(), Uint64::new(1)).unwrap();
+        reply_start_multisig(deps.as_mut(), Uint64::new(2)).unwrap();
+
+        // the message is proven again on its own
+        execute_construct_proof(deps.as_mut(), Some(vec![cc_id.clone()])).unwrap();
+        reply_start_multisig(deps.as_mut(), Uint64::new(3)).unwrap();
+
+        let proofs = query_proofs_by_message(deps.as_ref(), cc_id.clone()).unwrap();
+        assert_eq!(session_ids(&proofs), vec![Uint64::new(1), Uint64::new(3)]);
+        assert!(proofs
+            .iter()
+            .all(|proof| proof.message_ids.contains(&cc_id)));
+        assert_eq!(
+            proofs[0],
+            query_proof(deps.as_ref(), Some(Uint64::new(1))).unwrap()
+        );
+    }
+
+    #[test]
+    fn proofs_are_paginated_by_session_id() {
+        let mut deps = setup_test_case();
+        mock_pending_multisig_sessions(&mut deps);
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_update_payload_limits(
+            deps.as_mut(),
+            cosmos_addr!(GOVERNANCE),
+            max_messages_limits(1, true),
+        )
+        .unwrap();
+
+        assert_eq!(
+            query_proofs(deps.as_ref(), None, 10, None).unwrap(),
+            ProofsResponse {
+                proofs: vec![],
+                next_start_after: None,
+            }
+        );
+
+        execute_construct_proof(deps.as_mut(), Some(message_ids(5))).unwrap();
+        for session_id in 1..=5 {
+            reply_start_multisig(deps.as_mut(), Uint64::new(session_id)).unwrap();
+        }
+
+        let first_page = query_proofs(deps.as_ref(), None, 2, None).unwrap();
+        assert_eq!(
+            session_ids(&first_page.proofs),
+            vec![Uint64::new(1), Uint64::new(2)]
+        );
+        assert_eq!(first_page.next_start_after, Some(Uint64::new(2)));
+
+        let second_page =
+            query_proofs(deps.as_ref(), first_page.next_start_after, 2, None).unwrap();
+        assert_eq!(
+            session_ids(&second_page.proofs),
+            vec![Uint64::new(3), Uint64::new(4)]
+        );
+        assert_eq!(second_page.next_start_after, Some(Uint64::new(4)));
+
+        let last_page = query_proofs(deps.as_ref(), second_page.next_start_after, 2, None).unwrap();
+        assert_eq!(session_ids(&last_page.proofs), vec![Uint64::new(5)]);
+        assert_eq!(
+            last_page.proofs[0].message_ids,
+            vec![message_ids(5)[4].clone()]
+        );
+        assert_eq!(last_page.next_start_after, None);
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn proofs_limit_caps_scanned_sessions() {
+        let mut deps = setup_test_case();
+        mock_pending_multisig_sessions(&mut deps);
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_update_payload_limits(
+            deps.as_mut(),
+            cosmos_addr!(GOVERNANCE),
+            max_messages_limits(1, true),
+        )
+        .unwrap();
+
+        let session_count = u64::from(MAX_PAGINATION_LIMIT) + 1;
+        execute_construct_proof(deps.as_mut(), Some(message_ids(session_count))).unwrap();
+        for session_id in 1..=session_count {
+            reply_start_multisig(deps.as_mut(), Uint64::new(session_id)).unwrap();
+        }
+
+        let page = query_proofs(deps.as_ref(), None, u32::MAX, None).unwrap();
+        assert_eq!(page.proofs.len(), MAX_PAGINATION_LIMIT as usize);
+        assert_eq!(
+            page.next_start_after,
+            Some(Uint64::from(MAX_PAGINATION_LIMIT))
+        );
+
+        // the filtered out sessions still count towards the limit
+        let page = query_proofs(
+            deps.as_ref(),
+            None,
+            2,
+            Some(SigningSessionStatus::Completed),
+        )
+        .unwrap();
+        assert!(page.proofs.is_empty());
+        assert_eq!(page.next_start_after, Some(Uint64::new(2)));
+    }
+
+    #[test]
+    fn proofs_can_be_filtered_by_status() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap();
+
+        // multisig mock returns a completed session
+        let proofs = query_proofs(
+            deps.as_ref(),
+            None,
+            10,
+            Some(SigningSessionStatus::Completed),
+        )
+        .unwrap();
+        assert_eq!(session_ids(&proofs.proofs), vec![MULTISIG_SESSION_ID]);
+        assert!(
+            query_proofs(deps.as_ref(), None, 10, Some(SigningSessionStatus::Pending))
+                .unwrap()
+                .proofs
+                .is_empty()
+        );
+
+        deps.querier
+            .update_wasm(mock_querier_handler_with_multisig_state(
+                test_data::operators(),
+                VerificationStatus::SucceededOnSourceChain,
+                MultisigState::Expired { expired_at: 12345 },
+            ));
+
+        let proofs =
+            query_proofs(deps.as_ref(), None, 10, Some(SigningSessionStatus::Expired)).unwrap();
+        assert_eq!(session_ids(&proofs.proofs), vec![MULTISIG_SESSION_ID]);
+        assert_eq!(proofs.proofs[0].status, ProofStatus::Expired);
+    }
 }
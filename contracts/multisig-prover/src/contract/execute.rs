@@ -1,23 +1,33 @@
 use std::collections::{BTreeMap, HashSet};
+use std::{mem, slice};
 
 use axelar_wasm_std::permission_control::Permission;
 use axelar_wasm_std::snapshot::{Participant, Snapshot};
 use axelar_wasm_std::{
     address, nonempty, permission_control, FnExt, MajorityThreshold, VerificationStatus,
 };
-use cosmwasm_std::{wasm_execute, Addr, DepsMut, Env, QuerierWrapper, Response, Storage, SubMsg};
-use error_stack::{report, Result, ResultExt};
+use cosmwasm_std::{
+    wasm_execute, Addr, DepsMut, Env, Order, QuerierWrapper, Response, StdResult, Storage, SubMsg,
+    Uint64,
+};
+use cw_storage_plus::Bound;
+use error_stack::{ensure, report, Result, ResultExt};
 use itertools::Itertools;
 use multisig::msg::Signer;
+use multisig::types::MultisigState;
 use multisig::verifier_set::VerifierSet;
 use router_api::{ChainName, CrossChainId, Message};
 use service_registry_api::WeightedVerifier;
+use solana_multisig_prover_api::encoding::Encoder;
 
 use crate::contract::START_MULTISIG_REPLY_ID;
 use crate::encoding::EncoderExt;
 use crate::error::ContractError;
+use crate::msg::PayloadLimits;
+use crate::payload::PayloadId;
 use crate::state::{
-    Config, CONFIG, CURRENT_VERIFIER_SET, NEXT_VERIFIER_SET, PAYLOAD, REPLY_TRACKER,
+    self, Config, ReplyTracker, CONFIG, CURRENT_VERIFIER_SET, MESSAGE_MULTISIG_SESSIONS_BACKFILL,
+    MULTISIG_SESSION_PAYLOAD, NEXT_VERIFIER_SET, PAYLOAD, REPLY_TRACKER,
 };
 use crate::Payload;
 
@@ -34,11 +44,91 @@ pub fn construct_proof(
         config.chain_name.clone(),
     )?;
 
-    let payload = Payload::Messages(messages);
+    let payloads = chunk_messages(&config.encoder, &config.payload_limits, messages)?;
+    ensure!(
+        config.payload_limits.auto_chunk || payloads.len() == 1,
+        ContractError::PayloadLimitsExceeded
+    );
+
+    let payloads = payloads
+        .into_iter()
+        .map(|payload| save_payload(deps.storage, payload))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    start_signing_sessions(deps.storage, config, payloads)
+}
+
+/// Splits the messages into payloads that stay within the payload limits, keeping the order of the messages.
+/// Always returns at least one payload.
+///
+/// The encoders lay out messages as a length prefix followed by the encoded messages, so each message is only encoded
+/// once and a payload's size is the size of an empty payload plus the sizes its messages add, plus the growth of the
+/// length prefix. Only the variable length prefix of BCS grows, by a byte once a payload holds more than 127 messages.
+fn chunk_messages(
+    encoder: &Encoder,
+    limits: &PayloadLimits,
+    messages: Vec<Message>,
+) -> Result<Vec<Payload>, ContractError> {
+    // sizes are only computed if they are limited, because not every encoder supports it
+    let payload_size = |messages: &[Message]| match limits.max_payload_size {
+        Some(_) => encoder.payload_size(&Payload::Messages(messages.to_vec())),
+        None => Ok(0),
+    };
+
+    let empty_payload_size = payload_size(&[])?;
+    let mut payloads = vec![];
+    let mut chunk = vec![];
+    let mut chunk_size = empty_payload_size;
+
+    for message in messages {
+        let message_size =
+            payload_size(slice::from_ref(&message))?.saturating_sub(empty_payload_size);
+        ensure!(
+            !exceeds_limits(limits, 1, empty_payload_size.saturating_add(message_size)),
+            ContractError::MessageExceedsPayloadLimits(message.cc_id)
+        );
+
+        let message_count = chunk.len().saturating_add(1);
+        if exceeds_limits(
+            limits,
+            message_count,
+            chunk_size
+                .saturating_add(message_size)
+                .saturating_add(encoder.length_prefix_growth(message_count)),
+        ) {
+            payloads.push(Payload::Messages(mem::take(&mut chunk)));
+            chunk_size = empty_payload_size;
+        }
+
+        chunk.push(message);
+        chunk_size = chunk_size.saturating_add(message_size);
+    }
+
+    if !chunk.is_empty() || payloads.is_empty() {
+        payloads.push(Payload::Messages(chunk));
+    }
+
+    Ok(payloads)
+}
+
+fn exceeds_limits(limits: &PayloadLimits, message_count: usize, payload_size: usize) -> bool {
+    limits
+        .max_messages
+        .is_some_and(|max_messages| message_count > usize::from(max_messages))
+        || limits
+            .max_payload_size
+            .is_some_and(|max_payload_size| payload_size > usize::from(max_payload_size))
+}
+
+/// Stores the payload under its id, unless the same payload is already stored
+fn save_payload(
+    storage: &mut dyn Storage,
+    payload: Payload,
+) -> Result<(PayloadId, Payload), ContractError> {
     let payload_id = payload.id();
 
     match PAYLOAD
-        .may_load(deps.storage, &payload_id)
+        .may_load(storage, &payload_id)
         .map_err(ContractError::from)?
     {
         Some(stored_payload) => {
@@ -49,36 +139,99 @@ pub fn construct_proof(
         }
         None => {
             PAYLOAD
-                .save(deps.storage, &payload_id, &payload)
+                .save(storage, &payload_id, &payload)
                 .map_err(ContractError::from)?;
         }
     };
 
-    // keep track of the payload id to use during submessage reply
-    REPLY_TRACKER
-        .save(deps.storage, &payload_id)
+    Ok((payload_id, payload))
+}
+
+pub fn retry_proof(
+    deps: DepsMut,
+    multisig_session_id: Uint64,
+) -> error_stack::Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
+
+    let payload_id = MULTISIG_SESSION_PAYLOAD
+        .may_load(deps.storage, multisig_session_id.u64())
+        .map_err(ContractError::from)?
+        .ok_or(ContractError::ProofNotFound(multisig_session_id))?;
+    let payload = PAYLOAD
+        .load(deps.storage, &payload_id)
         .map_err(ContractError::from)?;
 
+    let multisig: multisig::Client =
+        client::ContractClient::new(deps.querier, &config.multisig).into();
+    let session_state = multisig
+        .multisig(multisig_session_id)
+        .change_context(ContractError::FailedToQueryMultisigSession(
+            multisig_session_id,
+        ))?
+        .state;
+
+    ensure!(
+        matches!(
+            session_state,
+            MultisigState::Expired { .. } | MultisigState::Cancelled { .. }
+        ),
+        ContractError::ProofNotRetryable(multisig_session_id)
+    );
+
+    // a verifier set rotation can only be re-signed while it is still waiting for confirmation
+    if let Payload::VerifierSet(verifier_set) = &payload {
+        let next_verifier_set = NEXT_VERIFIER_SET
+            .may_load(deps.storage)
+            .map_err(ContractError::from)?;
+        ensure!(
+            next_verifier_set.as_ref() == Some(verifier_set),
+            ContractError::ProofNotRetryable(multisig_session_id)
+        );
+    }
+
+    start_signing_sessions(deps.storage, config, vec![(payload_id, payload)])
+}
+
+/// Starts a signing session with the current verifier set for each payload. The sessions are started in order,
+/// so the replies of the multisig contract arrive in the same order.
+fn start_signing_sessions(
+    storage: &mut dyn Storage,
+    config: Config,
+    payloads: Vec<(PayloadId, Payload)>,
+) -> error_stack::Result<Response, ContractError> {
     let verifier_set = CURRENT_VERIFIER_SET
-        .may_load(deps.storage)
+        .may_load(storage)
         .map_err(ContractError::from)?
         .ok_or(ContractError::NoVerifierSet)?;
 
-    let digest = config
-        .encoder
-        .digest(&config.domain_separator, &verifier_set, &payload)?;
+    let mut payload_ids = vec![];
+    let mut start_sig_msgs = vec![];
 
-    let start_sig_msg = multisig::msg::ExecuteMsg::StartSigningSession {
-        verifier_set_id: verifier_set.id(),
-        msg: digest.into(),
-        chain_name: config.chain_name,
-        sig_verifier: None,
-    };
+    for (payload_id, payload) in payloads {
+        let digest = config
+            .encoder
+            .digest(&config.domain_separator, &verifier_set, &payload)?;
+
+        let start_sig_msg = multisig::msg::ExecuteMsg::StartSigningSession {
+            verifier_set_id: verifier_set.id(),
+            msg: digest.into(),
+            chain_name: config.chain_name.clone(),
+            sig_verifier: None,
+        };
 
-    let wasm_msg =
-        wasm_execute(config.multisig, &start_sig_msg, vec![]).map_err(ContractError::from)?;
+        let wasm_msg = wasm_execute(config.multisig.clone(), &start_sig_msg, vec![])
+            .map_err(ContractError::from)?;
 
-    Ok(Response::new().add_submessage(SubMsg::reply_on_success(wasm_msg, START_MULTISIG_REPLY_ID)))
+        payload_ids.push(payload_id);
+        start_sig_msgs.push(SubMsg::reply_on_success(wasm_msg, START_MULTISIG_REPLY_ID));
+    }
+
+    // keep track of the payload ids to use during submessage replies
+    REPLY_TRACKER
+        .save(storage, &ReplyTracker::new(payload_ids))
+        .map_err(ContractError::from)?;
+
+    Ok(Response::new().add_submessages(start_sig_msgs))
 }
 
 fn messages(
@@ -262,7 +415,7 @@ pub fn update_verifier_set(
                 .save(deps.storage, &payload_id, &payload)
                 .map_err(ContractError::from)?;
             REPLY_TRACKER
-                .save(deps.storage, &payload_id)
+                .save(deps.storage, &ReplyTracker::new([payload_id]))
                 .map_err(ContractError::from)?;
 
             let digest =
@@ -410,6 +563,70 @@ pub fn update_signing_threshold(
     Ok(Response::new())
 }
 
+pub fn update_payload_limits(
+    deps: DepsMut,
+    new_payload_limits: PayloadLimits,
+) -> Result<Response, ContractError> {
+    CONFIG
+        .update(
+            deps.storage,
+            |mut config| -> std::result::Result<Config, ContractError> {
+                config.payload_limits = new_payload_limits;
+                Ok(config)
+            },
+        )
+        .change_context(ContractError::StorageError)?;
+    Ok(Response::new())
+}
+
+/// Records the multisig sessions of the messages of up to `limit` proofs that were created before sessions were recorded
+/// per message, continuing where the previous call stopped
+pub fn backfill_message_multisig_sessions(
+    deps: DepsMut,
+    limit: nonempty::Uint32,
+) -> Result<Response, ContractError> {
+    let Some(start) = MESSAGE_MULTISIG_SESSIONS_BACKFILL
+        .may_load(deps.storage)
+        .change_context(ContractError::StorageError)?
+    else {
+        return Ok(Response::new());
+    };
+
+    let limit = usize::from(limit);
+    let sessions: Vec<_> = MULTISIG_SESSION_PAYLOAD
+        .range(
+            deps.storage,
+            Some(Bound::inclusive(start)),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<_>>()
+        .change_context(ContractError::StorageError)?;
+
+    for (multisig_session_id, payload_id) in &sessions {
+        let message_ids = PAYLOAD
+            .may_load(deps.storage, payload_id)
+            .change_context(ContractError::StorageError)?
+            .and_then(|payload| payload.message_ids())
+            .unwrap_or_default();
+
+        for message_id in message_ids.iter() {
+            state::save_message_multisig_session(deps.storage, message_id, *multisig_session_id)
+                .change_context(ContractError::StorageError)?;
+        }
+    }
+
+    match sessions.last() {
+        Some((last, _)) if sessions.len() == limit => MESSAGE_MULTISIG_SESSIONS_BACKFILL
+            .save(deps.storage, &last.saturating_add(1))
+            .change_context(ContractError::StorageError)?,
+        _ => MESSAGE_MULTISIG_SESSIONS_BACKFILL.remove(deps.storage),
+    }
+
+    Ok(Response::new())
+}
+
 pub fn update_admin(deps: DepsMut, new_admin_address: String) -> Result<Response, ContractError> {
     let new_admin = address::validate_cosmwasm_address(deps.api, &new_admin_address)
         .change_context(ContractError::FailedToUpdateAdmin)?;
@@ -424,12 +641,18 @@ mod tests {
 
     use axelar_wasm_std::Threshold;
     use cosmwasm_std::testing::{mock_dependencies, mock_env};
-    use router_api::{chain_name, cosmos_addr};
+    use router_api::{address, chain_name, cosmos_addr, CrossChainId, Message};
     use solana_multisig_prover_api::encoding::Encoder;
 
-    use super::{different_set_in_progress, next_verifier_set, should_update_verifier_set};
+    use super::{
+        chunk_messages, different_set_in_progress, next_verifier_set, should_update_verifier_set,
+    };
+    use crate::encoding::EncoderExt;
+    use crate::error::ContractError;
+    use crate::msg::PayloadLimits;
     use crate::state::{Config, NEXT_VERIFIER_SET};
     use crate::test::test_data;
+    use crate::Payload;
 
     #[test]
     fn should_update_verifier_set_no_change() {
@@ -561,6 +784,137 @@ mod tests {
             encoder: Encoder::Abi,
             key_type: multisig::key::KeyType::Ecdsa,
             domain_separator: [0; 32],
+            payload_limits: PayloadLimits::default(),
         }
     }
+
+    fn messages(count: usize) -> Vec<Message> {
+        (0..count)
+            .map(|i| Message {
+                cc_id: CrossChainId::new("ganache-1", format!("message-{}", i)).unwrap(),
+                ..test_data::messages()[0].clone()
+            })
+            .collect()
+    }
+
+    fn sui_messages(count: usize) -> Vec<Message> {
+        (0..count)
+            .map(|i| Message {
+                cc_id: CrossChainId::new("ethereum", format!("message-{}", i)).unwrap(),
+                source_address: address!("0x1a68E002efa42CF3bDEF81d66bB41f9d677420bE"),
+                destination_chain: chain_name!("sui"),
+                destination_address: address!(
+                    "0xdf4dd40feff3c09bb5c559d0cfd7d1c5025fa802bba275453e48af7d2b437727"
+                ),
+                payload_hash: [2; 32],
+            })
+            .collect()
+    }
+
+    fn payload_lengths(payloads: &[Payload]) -> Vec<usize> {
+        payloads
+            .iter()
+            .map(|payload| payload.message_ids().unwrap().len())
+            .collect()
+    }
+
+    #[test]
+    fn chunk_messages_without_limits_returns_single_payload() {
+        let payloads =
+            chunk_messages(&Encoder::Abi, &PayloadLimits::default(), messages(5)).unwrap();
+
+        assert_eq!(payloads, vec![Payload::Messages(messages(5))]);
+    }
+
+    #[test]
+    fn chunk_messages_without_messages_returns_empty_payload() {
+        let limits = PayloadLimits {
+            max_messages: Some(2u32.try_into().unwrap()),
+            max_payload_size: None,
+            auto_chunk: true,
+        };
+
+        let payloads = chunk_messages(&Encoder::Abi, &limits, vec![]).unwrap();
+
+        assert_eq!(payloads, vec![Payload::Messages(vec![])]);
+    }
+
+    #[test]
+    fn chunk_messages_respects_max_messages() {
+        let limits = PayloadLimits {
+            max_messages: Some(2u32.try_into().unwrap()),
+            max_payload_size: None,
+            auto_chunk: true,
+        };
+
+        let payloads = chunk_messages(&Encoder::Abi, &limits, messages(5)).unwrap();
+
+        assert_eq!(payload_lengths(&payloads), vec![2, 2, 1]);
+        assert_eq!(
+            payloads
+                .into_iter()
+                .flat_map(|payload| payload.message_ids().unwrap())
+                .collect::<Vec<_>>(),
+            messages(5)
+                .into_iter()
+                .map(|msg| msg.cc_id)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn chunk_messages_respects_max_payload_size() {
+        let max_payload_size = Encoder::Abi
+            .payload_size(&Payload::Messages(messages(2)))
+            .unwrap();
+        let limits = PayloadLimits {
+            max_messages: None,
+            max_payload_size: Some(u32::try_from(max_payload_size).unwrap().try_into().unwrap()),
+            auto_chunk: true,
+        };
+
+        let payloads = chunk_messages(&Encoder::Abi, &limits, messages(5)).unwrap();
+
+        assert_eq!(payload_lengths(&payloads), vec![2, 2, 1]);
+        for payload in payloads {
+            assert!(Encoder::Abi.payload_size(&payload).unwrap() <= max_payload_size);
+        }
+    }
+
+    #[test]
+    fn chunk_messages_accounts_for_bcs_length_prefix_growth() {
+        // the 128th message adds a byte to the length prefix on top of its own size
+        let max_payload_size = Encoder::Bcs
+            .payload_size(&Payload::Messages(sui_messages(128)))
+            .unwrap()
+            - 1;
+        let limits = PayloadLimits {
+            max_messages: None,
+            max_payload_size: Some(u32::try_from(max_payload_size).unwrap().try_into().unwrap()),
+            auto_chunk: true,
+        };
+
+        let payloads = chunk_messages(&Encoder::Bcs, &limits, sui_messages(128)).unwrap();
+
+        assert_eq!(payload_lengths(&payloads), vec![127, 1]);
+        for payload in payloads {
+            assert!(Encoder::Bcs.payload_size(&payload).unwrap() <= max_payload_size);
+        }
+    }
+
+    #[test]
+    fn chunk_messages_fails_if_single_message_exceeds_max_payload_size() {
+        let limits = PayloadLimits {
+            max_messages: None,
+            max_payload_size: Some(1u32.try_into().unwrap()),
+            auto_chunk: true,
+        };
+
+        let result = chunk_messages(&Encoder::Abi, &limits, messages(2));
+
+        assert_eq!(
+            result.unwrap_err().current_context(),
+            &ContractError::MessageExceedsPayloadLimits(messages(1)[0].cc_id.clone())
+        );
+    }
 }
@@ -3,6 +3,8 @@ use axelar_wasm_std::migrate_from_version;
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{DepsMut, Empty, Env, Response};
 
+use crate::state::MESSAGE_MULTISIG_SESSIONS_BACKFILL;
+
 pub type MigrateMsg = Empty;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -12,5 +14,89 @@ pub fn migrate(
     _env: Env,
     _msg: MigrateMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+    // the multisig sessions of existing proofs are recorded per message in bounded batches through
+    // ExecuteMsg::BackfillMessageMultisigSessions, so the migration doesn't depend on the number of proofs
+    MESSAGE_MULTISIG_SESSIONS_BACKFILL.save(deps.storage, &0)?;
+
     Ok(Response::default())
 }
+
+#[cfg(test)]
+mod tests {
+    use axelar_wasm_std::nonempty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::Empty;
+
+    use super::migrate;
+    use crate::contract::execute::backfill_message_multisig_sessions;
+    use crate::payload::Payload;
+    use crate::state::{
+        self, MESSAGE_MULTISIG_SESSIONS, MESSAGE_MULTISIG_SESSIONS_BACKFILL,
+        MULTISIG_SESSION_PAYLOAD, PAYLOAD,
+    };
+    use crate::test::test_data;
+
+    #[test]
+    fn migrate_starts_backfill_of_message_multisig_sessions_in_batches() {
+        let mut deps = mock_dependencies();
+        cw2::set_contract_version(deps.as_mut().storage, env!("CARGO_PKG_NAME"), "0.4.0").unwrap();
+
+        let messages = test_data::messages();
+        let messages_payload = Payload::Messages(messages.clone());
+        let verifier_set_payload = Payload::VerifierSet(test_data::new_verifier_set());
+
+        // the messages were signed twice, e.g. because the first session expired
+        for (multisig_session_id, payload) in [
+            (1u64, &messages_payload),
+            (2, &verifier_set_payload),
+            (3, &messages_payload),
+        ] {
+            PAYLOAD
+                .save(deps.as_mut().storage, &payload.id(), payload)
+                .unwrap();
+            MULTISIG_SESSION_PAYLOAD
+                .save(deps.as_mut().storage, multisig_session_id, &payload.id())
+                .unwrap();
+        }
+
+        migrate(deps.as_mut(), mock_env(), Empty {}).unwrap();
+        assert!(MESSAGE_MULTISIG_SESSIONS
+            .may_load(deps.as_ref().storage, &messages[0].cc_id)
+            .unwrap()
+            .is_none());
+
+        // a proof started after the upgrade records its session right away
+        state::save_message_multisig_session(deps.as_mut().storage, &messages[0].cc_id, 4).unwrap();
+
+        let limit = nonempty::Uint32::try_from(2u32).unwrap();
+        backfill_message_multisig_sessions(deps.as_mut(), limit).unwrap();
+        assert_eq!(
+            MESSAGE_MULTISIG_SESSIONS_BACKFILL
+                .load(deps.as_ref().storage)
+                .unwrap(),
+            3
+        );
+        assert_eq!(
+            MESSAGE_MULTISIG_SESSIONS
+                .load(deps.as_ref().storage, &messages[0].cc_id)
+                .unwrap(),
+            vec![1, 4]
+        );
+
+        backfill_message_multisig_sessions(deps.as_mut(), limit).unwrap();
+        assert!(MESSAGE_MULTISIG_SESSIONS_BACKFILL
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+
+        assert_eq!(
+            MESSAGE_MULTISIG_SESSIONS
+                .load(deps.as_ref().storage, &messages[0].cc_id)
+                .unwrap(),
+            vec![1, 3, 4]
+        );
+
+        // calls after the backfill has finished do nothing
+        backfill_message_multisig_sessions(deps.as_mut(), limit).unwrap();
+    }
+}
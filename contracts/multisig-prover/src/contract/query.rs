@@ -1,13 +1,22 @@
-use cosmwasm_std::{to_json_binary, Deps, QueryRequest, StdResult, Uint64, WasmQuery};
+use axelar_wasm_std::nonempty;
+use cosmwasm_std::{to_json_binary, Deps, Order, QueryRequest, StdResult, Uint64, WasmQuery};
+use cw_storage_plus::Bound;
 use error_stack::Result;
+use itertools::Itertools;
+use multisig::msg::SigningSessionStatus;
 use multisig::multisig::Multisig;
 use multisig::types::MultisigState;
+use router_api::CrossChainId;
 
 use crate::encoding::EncoderExt;
 use crate::error::ContractError;
-use crate::msg::{ProofResponse, ProofStatus, VerifierSetResponse};
+use crate::msg::{
+    ProofResponse, ProofStatus, ProofsResponse, VerifierSetResponse, MAX_PAGINATION_LIMIT,
+};
+use crate::payload::PayloadId;
 use crate::state::{
-    CONFIG, CURRENT_VERIFIER_SET, MULTISIG_SESSION_PAYLOAD, NEXT_VERIFIER_SET, PAYLOAD,
+    Config, CONFIG, CURRENT_VERIFIER_SET, MESSAGE_MULTISIG_SESSIONS, MULTISIG_SESSION_PAYLOAD,
+    NEXT_VERIFIER_SET, PAYLOAD,
 };
 
 pub fn proof(deps: Deps, multisig_session_id: Uint64) -> Result<ProofResponse, ContractError> {
@@ -17,6 +26,83 @@ pub fn proof(deps: Deps, multisig_session_id: Uint64) -> Result<ProofResponse, C
         .load(deps.storage, multisig_session_id.u64())
         .map_err(ContractError::from)?;
 
+    let multisig = multisig_session(deps, &config, multisig_session_id)?;
+
+    proof_response(deps, &config, multisig_session_id, &payload_id, multisig)
+}
+
+pub fn proofs_by_message(
+    deps: Deps,
+    cc_id: &CrossChainId,
+) -> Result<Vec<ProofResponse>, ContractError> {
+    MESSAGE_MULTISIG_SESSIONS
+        .may_load(deps.storage, cc_id)
+        .map_err(ContractError::from)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|multisig_session_id| proof(deps, Uint64::from(multisig_session_id)))
+        .collect()
+}
+
+pub fn proofs(
+    deps: Deps,
+    start_after: Option<Uint64>,
+    limit: nonempty::Uint32,
+    status: Option<SigningSessionStatus>,
+) -> Result<ProofsResponse, ContractError> {
+    let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
+    let limit = usize::from(limit).min(MAX_PAGINATION_LIMIT as usize);
+
+    let sessions: Vec<_> = MULTISIG_SESSION_PAYLOAD
+        .range(
+            deps.storage,
+            start_after.map(|multisig_session_id| Bound::exclusive(multisig_session_id.u64())),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .try_collect()
+        .map_err(ContractError::from)?;
+
+    let next_start_after = match sessions.last() {
+        Some((multisig_session_id, _)) if sessions.len() == limit => {
+            Some(Uint64::from(*multisig_session_id))
+        }
+        _ => None,
+    };
+
+    let proofs = sessions
+        .into_iter()
+        .map(
+            |(multisig_session_id, payload_id)| -> Result<_, ContractError> {
+                let multisig_session_id = Uint64::from(multisig_session_id);
+                let multisig = multisig_session(deps, &config, multisig_session_id)?;
+
+                Ok((multisig_session_id, payload_id, multisig))
+            },
+        )
+        // filter before building the responses so execute data is only encoded for returned proofs
+        .filter_ok(|(_, _, multisig)| {
+            status.is_none_or(|status| SigningSessionStatus::from(&multisig.state) == status)
+        })
+        .map(|entry| {
+            entry.and_then(|(multisig_session_id, payload_id, multisig)| {
+                proof_response(deps, &config, multisig_session_id, &payload_id, multisig)
+            })
+        })
+        .try_collect()?;
+
+    Ok(ProofsResponse {
+        proofs,
+        next_start_after,
+    })
+}
+
+fn multisig_session(
+    deps: Deps,
+    config: &Config,
+    multisig_session_id: Uint64,
+) -> Result<Multisig, ContractError> {
     let query_msg = multisig::msg::QueryMsg::Multisig {
         session_id: multisig_session_id,
     };
@@ -29,8 +115,18 @@ pub fn proof(deps: Deps, multisig_session_id: Uint64) -> Result<ProofResponse, C
         }))
         .map_err(ContractError::from)?;
 
+    Ok(multisig)
+}
+
+fn proof_response(
+    deps: Deps,
+    config: &Config,
+    multisig_session_id: Uint64,
+    payload_id: &PayloadId,
+    multisig: Multisig,
+) -> Result<ProofResponse, ContractError> {
     let payload = PAYLOAD
-        .load(deps.storage, &payload_id)
+        .load(deps.storage, payload_id)
         .map_err(ContractError::from)?;
 
     let status = match multisig.state {
@@ -44,6 +140,8 @@ pub fn proof(deps: Deps, multisig_session_id: Uint64) -> Result<ProofResponse, C
             )?;
             ProofStatus::Completed { execute_data }
         }
+        MultisigState::Expired { .. } => ProofStatus::Expired,
+        MultisigState::Cancelled { .. } => ProofStatus::Cancelled,
     };
 
     Ok(ProofResponse {
@@ -66,6 +164,15 @@ pub fn next_verifier_set(deps: Deps) -> StdResult<Option<VerifierSetResponse>> {
         .map(|op| op.map(|set| set.into()))
 }
 
+pub fn multisig_sessions_by_message(deps: Deps, cc_id: &CrossChainId) -> StdResult<Vec<Uint64>> {
+    Ok(MESSAGE_MULTISIG_SESSIONS
+        .may_load(deps.storage, cc_id)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(Uint64::from)
+        .collect())
+}
+
 #[cfg(test)]
 mod test {
     use cosmwasm_std::testing::mock_dependencies;
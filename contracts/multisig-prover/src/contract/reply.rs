@@ -1,9 +1,9 @@
-use cosmwasm_std::{from_json, DepsMut, Reply, Response, Uint64};
+use cosmwasm_std::{from_json, to_json_binary, DepsMut, Reply, Response, Uint64};
 use cw_utils::{parse_execute_response_data, MsgExecuteContractResponse, ParseReplyError};
 
 use crate::error::ContractError;
 use crate::events::Event;
-use crate::state::{CONFIG, MULTISIG_SESSION_PAYLOAD, PAYLOAD, REPLY_TRACKER};
+use crate::state::{self, CONFIG, MULTISIG_SESSION_PAYLOAD, PAYLOAD, REPLY_TRACKER};
 
 pub fn start_multisig_reply(deps: DepsMut, reply: Reply) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
@@ -18,7 +18,13 @@ pub fn start_multisig_reply(deps: DepsMut, reply: Reply) -> Result<Response, Con
 
     match parse_execute_response_data(data.as_slice()) {
         Ok(MsgExecuteContractResponse { data: Some(data) }) => {
-            let payload_id = REPLY_TRACKER.load(deps.storage)?;
+            let mut reply_tracker = REPLY_TRACKER.load(deps.storage)?;
+            let payload_id = reply_tracker
+                .pending_payload_ids
+                .pop_front()
+                .ok_or_else(|| ContractError::InvalidContractReply {
+                    reason: "no signing session is awaiting a reply".to_string(),
+                })?;
 
             let multisig_session_id: Uint64 =
                 from_json(data).map_err(|_| ContractError::InvalidContractReply {
@@ -32,12 +38,26 @@ pub fn start_multisig_reply(deps: DepsMut, reply: Reply) -> Result<Response, Con
                 .message_ids()
                 .unwrap_or_default();
 
-            Ok(Response::new().add_event(Event::ProofUnderConstruction {
-                destination_chain: config.chain_name,
-                message_ids,
-                payload_id,
-                multisig_session_id,
-            }))
+            for message_id in message_ids.iter() {
+                state::save_message_multisig_session(
+                    deps.storage,
+                    message_id,
+                    multisig_session_id.u64(),
+                )?;
+            }
+
+            // every reply returns the ids of all sessions started so far, so the data of the last reply covers the whole batch
+            reply_tracker.multisig_session_ids.push(multisig_session_id);
+            REPLY_TRACKER.save(deps.storage, &reply_tracker)?;
+
+            Ok(Response::new()
+                .add_event(Event::ProofUnderConstruction {
+                    destination_chain: config.chain_name,
+                    message_ids,
+                    payload_id,
+                    multisig_session_id,
+                })
+                .set_data(to_json_binary(&reply_tracker.multisig_session_ids)?))
         }
         Ok(MsgExecuteContractResponse { data: None }) => Err(ContractError::InvalidContractReply {
             reason: "no data".to_string(),
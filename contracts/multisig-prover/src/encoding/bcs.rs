@@ -13,7 +13,7 @@ use sui_gateway::{CommandType, ExecuteData, Message, MessageToSign, Proof, Weigh
 use crate::error::ContractError;
 use crate::Payload;
 
-fn encode_payload(payload: &Payload) -> Result<Vec<u8>, ContractError> {
+pub fn encode_payload(payload: &Payload) -> Result<Vec<u8>, ContractError> {
     let encoded: Vec<u8> = match payload {
         Payload::Messages(messages) => bcs::to_bytes(
             &messages
@@ -33,6 +33,18 @@ fn encode_payload(payload: &Payload) -> Result<Vec<u8>, ContractError> {
     Ok(encoded)
 }
 
+/// Size in bytes of the ULEB128 length prefix BCS puts in front of a sequence with `len` elements
+pub fn length_prefix_size(len: usize) -> usize {
+    let mut len = len >> 7;
+    let mut size = 1;
+    while len > 0 {
+        len >>= 7;
+        size += 1;
+    }
+
+    size
+}
+
 pub fn payload_digest(
     domain_separator: &Hash,
     verifier_set: &VerifierSet,
@@ -28,6 +28,12 @@ pub trait EncoderExt {
         sigs: Vec<SignerWithSig>,
         payload: &Payload,
     ) -> Result<HexBinary, ContractError>;
+
+    /// Size in bytes of the encoded payload, without the proof that is added to the execute data
+    fn payload_size(&self, payload: &Payload) -> Result<usize, ContractError>;
+
+    /// Bytes the length prefix of a payload with `message_count` messages takes beyond the prefix of an empty payload
+    fn length_prefix_growth(&self, message_count: usize) -> usize;
 }
 
 impl EncoderExt for Encoder {
@@ -40,10 +46,10 @@ impl EncoderExt for Encoder {
         match self {
             Encoder::Abi => abi::payload_digest(domain_separator, verifier_set, payload),
             Encoder::Bcs => bcs::payload_digest(domain_separator, verifier_set, payload),
+            Encoder::Solana => solana::payload_digest(domain_separator, payload),
             Encoder::StellarXdr => {
                 stellar_xdr::payload_digest(domain_separator, verifier_set, payload)
             }
-            _ => bail!(ContractError::EncoderNotImplemented),
         }
     }
 
@@ -57,8 +63,60 @@ impl EncoderExt for Encoder {
         match self {
             Encoder::Abi => abi::encode_execute_data(domain_separator, verifier_set, sigs, payload),
             Encoder::Bcs => bcs::encode_execute_data(domain_separator, verifier_set, sigs, payload),
+            Encoder::Solana => {
+                solana::encode_execute_data(sigs, verifier_set, payload, domain_separator)
+            }
             Encoder::StellarXdr => stellar_xdr::encode_execute_data(verifier_set, sigs, payload),
-            _ => bail!(ContractError::EncoderNotImplemented),
         }
     }
+
+    fn payload_size(&self, payload: &Payload) -> Result<usize, ContractError> {
+        let encoded = match self {
+            Encoder::Abi => abi::encode_payload(payload)?,
+            Encoder::Bcs => bcs::encode_payload(payload)?,
+            Encoder::StellarXdr => stellar_xdr::encode_payload(payload)?,
+            Encoder::Solana => bail!(ContractError::EncoderNotImplemented),
+        };
+
+        Ok(encoded.len())
+    }
+
+    fn length_prefix_growth(&self, message_count: usize) -> usize {
+        match self {
+            Encoder::Bcs => {
+                bcs::length_prefix_size(message_count).saturating_sub(bcs::length_prefix_size(0))
+            }
+            // the other encoders prefix the messages with a fixed width length
+            Encoder::Abi | Encoder::Solana | Encoder::StellarXdr => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_multisig_prover_api::encoding::Encoder;
+
+    use crate::encoding::EncoderExt;
+    use crate::test::test_data::messages;
+    use crate::Payload;
+
+    #[test]
+    fn payload_size_grows_with_messages() {
+        let one_message = Payload::Messages(messages());
+        let two_messages = Payload::Messages(messages().into_iter().cycle().take(2).collect());
+
+        assert!(
+            Encoder::Abi.payload_size(&one_message).unwrap()
+                < Encoder::Abi.payload_size(&two_messages).unwrap()
+        );
+    }
+
+    #[test]
+    fn bcs_length_prefix_grows_past_127_messages() {
+        assert_eq!(Encoder::Bcs.length_prefix_growth(0), 0);
+        assert_eq!(Encoder::Bcs.length_prefix_growth(127), 0);
+        assert_eq!(Encoder::Bcs.length_prefix_growth(128), 1);
+        assert_eq!(Encoder::Bcs.length_prefix_growth(16_384), 2);
+        assert_eq!(Encoder::Abi.length_prefix_growth(128), 0);
+    }
 }
@@ -1,6 +1,3 @@
-// TODO: Solana encoding not yet wired up - suppress dead_code warnings
-#![allow(dead_code)]
-
 use std::array::TryFromSliceError;
 use std::collections::BTreeMap;
 
@@ -121,6 +118,10 @@ fn to_pub_key(pk: &PublicKey) -> error_stack::Result<solana_axelar_std::PublicKe
             reason: "Ed25519 public keys are not supported".to_string(),
         }
         .into()),
+        PublicKey::Secp256r1(_hb) => Err(ContractError::InvalidPublicKey {
+            reason: "Secp256r1 public keys are not supported".to_string(),
+        }
+        .into()),
     }
 }
 
@@ -183,6 +184,10 @@ fn to_signature(
             reason: "Ed25519 signatures are not supported".to_string(),
         }
         .into()),
+        Signature::Secp256r1(_sig) => Err(ContractError::InvalidSignature {
+            reason: "Secp256r1 signatures are not supported".to_string(),
+        }
+        .into()),
     }
 }
 
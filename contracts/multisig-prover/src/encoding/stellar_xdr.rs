@@ -52,7 +52,28 @@ pub fn encode_execute_data(
     signatures: Vec<SignerWithSig>,
     payload: &Payload,
 ) -> Result<HexBinary, ContractError> {
-    let payload = match payload {
+    let payload = to_sc_val(payload)?;
+
+    let proof =
+        Proof::try_from((verifier_set.clone(), signatures)).change_context(ContractError::Proof)?;
+
+    let execute_data = ScVal::try_from((payload, proof))
+        .expect("must convert tuple of size 2 to ScVec")
+        .to_xdr(Limits::none())
+        .change_context(ContractError::SerializeData)?;
+
+    Ok(execute_data.as_slice().into())
+}
+
+/// `encode_payload` returns the XDR encoded payload as it is passed to the external gateway.
+pub fn encode_payload(payload: &Payload) -> Result<Vec<u8>, ContractError> {
+    to_sc_val(payload)?
+        .to_xdr(Limits::none())
+        .change_context(ContractError::SerializeData)
+}
+
+fn to_sc_val(payload: &Payload) -> Result<ScVal, ContractError> {
+    match payload {
         Payload::Messages(messages) => ScVal::try_from(
             messages
                 .iter()
@@ -66,17 +87,7 @@ pub fn encode_execute_data(
                 .change_context(ContractError::InvalidVerifierSet)?,
         ),
     }
-    .change_context(ContractError::SerializeData)?;
-
-    let proof =
-        Proof::try_from((verifier_set.clone(), signatures)).change_context(ContractError::Proof)?;
-
-    let execute_data = ScVal::try_from((payload, proof))
-        .expect("must convert tuple of size 2 to ScVec")
-        .to_xdr(Limits::none())
-        .change_context(ContractError::SerializeData)?;
-
-    Ok(execute_data.as_slice().into())
+    .change_context(ContractError::SerializeData)
 }
 #[cfg(test)]
 mod tests {
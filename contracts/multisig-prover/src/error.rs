@@ -1,7 +1,7 @@
 use axelar_wasm_std::{nonempty, IntoContractError};
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint64};
 use cw_utils::ParseReplyError;
-use router_api::ChainName;
+use router_api::{ChainName, CrossChainId};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq, IntoContractError)]
@@ -109,4 +109,19 @@ pub enum ContractError {
 
     #[error("encoder is not implemented")]
     EncoderNotImplemented,
+
+    #[error("no proof found for multisig session {0}")]
+    ProofNotFound(Uint64),
+
+    #[error("proof for multisig session {0} cannot be retried")]
+    ProofNotRetryable(Uint64),
+
+    #[error("failed to query multisig session {0}")]
+    FailedToQueryMultisigSession(Uint64),
+
+    #[error("message {0} does not fit into a payload on its own")]
+    MessageExceedsPayloadLimits(CrossChainId),
+
+    #[error("messages exceed the payload limits and auto chunking is disabled")]
+    PayloadLimitsExceeded,
 }
@@ -1,5 +1,5 @@
 pub mod contract;
-mod encoding;
+pub mod encoding;
 pub mod error;
 pub mod events;
 pub mod msg;
@@ -1,20 +1,36 @@
-use axelar_wasm_std::MajorityThreshold;
+use axelar_wasm_std::{nonempty, MajorityThreshold};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{HexBinary, Uint64};
 use msgs_derive::Permissions;
+use multisig::msg::SigningSessionStatus;
 use router_api::CrossChainId;
-pub use solana_multisig_prover_api::msg::InstantiateMsg;
+pub use solana_multisig_prover_api::msg::{InstantiateMsg, PayloadLimits};
 
 pub use crate::contract::MigrateMsg;
 use crate::Payload;
 
+// Pagination limits
+const DEFAULT_PAGINATION_LIMIT: u32 = 30;
+pub(crate) const MAX_PAGINATION_LIMIT: u32 = 100;
+
+fn default_pagination_limit() -> nonempty::Uint32 {
+    nonempty::Uint32::try_from(DEFAULT_PAGINATION_LIMIT)
+        .expect("default pagination limit must be a u32")
+}
+
 #[cw_serde]
 #[derive(Permissions)]
 pub enum ExecuteMsg {
     // Start building a proof that includes specified messages
     // Queries the gateway for actual message contents
+    // If auto chunking is enabled, messages that exceed the payload limits are split into multiple payloads, each with its own
+    // signing session. The response data contains the ids of all started multisig sessions.
     #[permission(Any)]
     ConstructProof(Vec<CrossChainId>),
+    // Starts a new signing session for the payload of a proof whose signing session expired or was cancelled.
+    // The new session is signed by the current verifier set.
+    #[permission(Any)]
+    RetryProof { multisig_session_id: Uint64 },
     #[permission(Elevated)]
     UpdateVerifierSet,
 
@@ -28,6 +44,13 @@ pub enum ExecuteMsg {
     },
     #[permission(Governance)]
     UpdateAdmin { new_admin_address: String },
+    // Updates the limits on the payloads of message proofs. Proofs that are already under construction are not affected.
+    #[permission(Governance)]
+    UpdatePayloadLimits { new_payload_limits: PayloadLimits },
+    // Records the multisig sessions of the messages of up to `limit` proofs that were created before the upgrade that
+    // introduced MultisigSessionsByMessage, continuing where the previous call stopped. Does nothing once the backfill is done.
+    #[permission(Any)]
+    BackfillMessageMultisigSessions { limit: nonempty::Uint32 },
 }
 
 #[cw_serde]
@@ -43,12 +66,41 @@ pub enum QueryMsg {
     /// Returns a `VerifierSetResponse` with the next verifier set id and the verifier set itself.
     #[returns(Option<VerifierSetResponse>)]
     NextVerifierSet,
+
+    /// Returns the multisig session ids of all proofs that include the given message, oldest first.
+    /// Proofs created before the upgrade that introduced this query are only included once they have been backfilled
+    /// with BackfillMessageMultisigSessions.
+    #[returns(Vec<Uint64>)]
+    MultisigSessionsByMessage { cc_id: CrossChainId },
+
+    /// Returns all proofs that include the given message, oldest first.
+    /// Like MultisigSessionsByMessage, this only includes pre-upgrade proofs once they have been backfilled.
+    #[returns(Vec<ProofResponse>)]
+    ProofsByMessage { cc_id: CrossChainId },
+
+    /// Returns the proofs ordered by multisig session id, optionally filtered by the status of their signing session.
+    /// The list is paginated by:
+    /// - start_after: the multisig session id to start after, which the next page of results should start.
+    /// - limit: limit the number of multisig sessions scanned, default is 30 and at most 100.
+    ///
+    /// Sessions are filtered after they are scanned, so a page can hold fewer proofs than the limit even if more
+    /// remain. The next page starts after the response's `next_start_after`, which is `None` if fewer sessions than
+    /// the limit were left to scan.
+    #[returns(ProofsResponse)]
+    Proofs {
+        start_after: Option<Uint64>,
+        #[serde(default = "default_pagination_limit")]
+        limit: nonempty::Uint32,
+        status: Option<SigningSessionStatus>,
+    },
 }
 
 #[cw_serde]
 pub enum ProofStatus {
     Pending,
     Completed { execute_data: HexBinary }, // encoded data and proof sent to destination gateway
+    Expired,   // the signing session expired before enough signatures were submitted
+    Cancelled, // the signing session was cancelled
 }
 
 #[cw_serde]
@@ -59,6 +111,12 @@ pub struct ProofResponse {
     pub status: ProofStatus,
 }
 
+#[cw_serde]
+pub struct ProofsResponse {
+    pub proofs: Vec<ProofResponse>,
+    pub next_start_after: Option<Uint64>,
+}
+
 #[cw_serde]
 pub struct VerifierSetResponse {
     pub id: String,
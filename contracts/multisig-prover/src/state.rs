@@ -1,12 +1,15 @@
+use std::collections::VecDeque;
+
 use axelar_wasm_std::hash::Hash;
 use axelar_wasm_std::MajorityThreshold;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, StdResult, Storage, Uint64};
 use cw_storage_plus::{Item, Map};
 use multisig::key::KeyType;
 use multisig::verifier_set::VerifierSet;
-use router_api::ChainName;
+use router_api::{ChainName, CrossChainId};
 use solana_multisig_prover_api::encoding::Encoder;
+use solana_multisig_prover_api::msg::PayloadLimits;
 
 use crate::payload::{Payload, PayloadId};
 
@@ -24,13 +27,58 @@ pub struct Config {
     pub encoder: Encoder,
     pub key_type: KeyType,
     pub domain_separator: Hash,
+    #[serde(default)]
+    pub payload_limits: PayloadLimits,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const PAYLOAD: Map<&PayloadId, Payload> = Map::new("payload");
 pub const MULTISIG_SESSION_PAYLOAD: Map<u64, PayloadId> = Map::new("multisig_session_payload");
+/// Multisig session ids of the proofs that include a message, in the order the sessions were started
+pub const MESSAGE_MULTISIG_SESSIONS: Map<&CrossChainId, Vec<u64>> =
+    Map::new("message_multisig_sessions");
+/// The multisig session id from which the backfill of MESSAGE_MULTISIG_SESSIONS continues. Only set while proofs that were
+/// created before sessions were recorded per message remain to be backfilled
+pub const MESSAGE_MULTISIG_SESSIONS_BACKFILL: Item<u64> =
+    Item::new("message_multisig_sessions_backfill");
+
+/// Records the multisig session of a proof that includes the message, unless it has already been recorded.
+/// Sessions are kept sorted, so sessions that are backfilled after newer ones were recorded still come first
+pub fn save_message_multisig_session(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+    multisig_session_id: u64,
+) -> StdResult<()> {
+    MESSAGE_MULTISIG_SESSIONS.update(storage, cc_id, |sessions| -> StdResult<_> {
+        let mut sessions = sessions.unwrap_or_default();
+        if let Err(idx) = sessions.binary_search(&multisig_session_id) {
+            sessions.insert(idx, multisig_session_id);
+        }
+        Ok(sessions)
+    })?;
+
+    Ok(())
+}
+
+/// Tracks the signing sessions started in the current transaction until the multisig contract replies with their ids
+#[cw_serde]
+pub struct ReplyTracker {
+    /// Payloads whose signing sessions have not been replied to yet, in the order the sessions were started
+    pub pending_payload_ids: VecDeque<PayloadId>,
+    /// Multisig session ids of the signing sessions that have been replied to
+    pub multisig_session_ids: Vec<Uint64>,
+}
+
+impl ReplyTracker {
+    pub fn new(payload_ids: impl IntoIterator<Item = PayloadId>) -> Self {
+        Self {
+            pending_payload_ids: payload_ids.into_iter().collect(),
+            multisig_session_ids: vec![],
+        }
+    }
+}
 
-pub const REPLY_TRACKER: Item<PayloadId> = Item::new("reply_tracker");
+pub const REPLY_TRACKER: Item<ReplyTracker> = Item::new("reply_tracker");
 
 pub const CURRENT_VERIFIER_SET: Item<VerifierSet> = Item::new("current_verifier_set");
 pub const NEXT_VERIFIER_SET: Item<VerifierSet> = Item::new("next_verifier_set");
@@ -4,7 +4,7 @@ use multisig::msg::Signer;
 use multisig::multisig::Multisig;
 use multisig::types::MultisigState;
 use multisig::verifier_set::VerifierSet;
-use router_api::cosmos_addr;
+use router_api::{cosmos_addr, Message};
 use service_registry_api::{AuthorizationState, BondingState, Verifier, WeightedVerifier};
 
 use super::test_data::{self, TestOperator};
@@ -21,17 +21,36 @@ pub const SERVICE_NAME: &str = "validators";
 pub fn mock_querier_handler(
     operators: Vec<TestOperator>,
     verifier_set_status: VerificationStatus,
+) -> impl Fn(&WasmQuery) -> QuerierResult {
+    mock_querier_handler_with_multisig_state(
+        operators,
+        verifier_set_status,
+        MultisigState::Completed {
+            completed_at: 12345,
+        },
+    )
+}
+
+/// Same as `mock_querier_handler`, but multisig sessions are reported in the given state
+pub fn mock_querier_handler_with_multisig_state(
+    operators: Vec<TestOperator>,
+    verifier_set_status: VerificationStatus,
+    multisig_state: MultisigState,
 ) -> impl Fn(&WasmQuery) -> QuerierResult {
     move |wq: &WasmQuery| match wq {
-        WasmQuery::Smart { contract_addr, .. }
+        WasmQuery::Smart { contract_addr, msg }
             if contract_addr == cosmos_addr!(GATEWAY_ADDRESS).as_str() =>
         {
-            gateway_mock_querier_handler()
+            gateway_mock_querier_handler(from_json(msg).unwrap())
         }
         WasmQuery::Smart { contract_addr, msg }
             if contract_addr == cosmos_addr!(MULTISIG_ADDRESS).as_str() =>
         {
-            multisig_mock_querier_handler(from_json(msg).unwrap(), operators.clone())
+            multisig_mock_querier_handler(
+                from_json(msg).unwrap(),
+                operators.clone(),
+                multisig_state.clone(),
+            )
         }
         WasmQuery::Smart { contract_addr, msg }
             if contract_addr == cosmos_addr!(SERVICE_REGISTRY_ADDRESS).as_str() =>
@@ -47,17 +66,29 @@ pub fn mock_querier_handler(
     }
 }
 
-fn gateway_mock_querier_handler() -> QuerierResult {
-    Ok(to_json_binary(&test_data::messages()).into()).into()
+// returns the test message for every requested id, so proofs with any number of messages can be constructed
+fn gateway_mock_querier_handler(msg: gateway_api::msg::QueryMsg) -> QuerierResult {
+    let gateway_api::msg::QueryMsg::OutgoingMessages(message_ids) = msg;
+
+    let messages = message_ids
+        .into_iter()
+        .map(|cc_id| Message {
+            cc_id,
+            ..test_data::messages()[0].clone()
+        })
+        .collect::<Vec<_>>();
+
+    Ok(to_json_binary(&messages).into()).into()
 }
 
 fn multisig_mock_querier_handler(
     msg: multisig::msg::QueryMsg,
     operators: Vec<TestOperator>,
+    state: MultisigState,
 ) -> QuerierResult {
     let result = match msg {
         multisig::msg::QueryMsg::Multisig { session_id: _ } => {
-            to_json_binary(&mock_multisig(operators))
+            to_json_binary(&mock_multisig(operators, state))
         }
         multisig::msg::QueryMsg::PublicKey {
             verifier_address,
@@ -75,7 +106,7 @@ fn multisig_mock_querier_handler(
     Ok(result.into()).into()
 }
 
-fn mock_multisig(operators: Vec<TestOperator>) -> Multisig {
+fn mock_multisig(operators: Vec<TestOperator>, state: MultisigState) -> Multisig {
     let quorum = test_data::quorum();
 
     let signers = operators
@@ -111,9 +142,7 @@ fn mock_multisig(operators: Vec<TestOperator>) -> Multisig {
     };
 
     Multisig {
-        state: MultisigState::Completed {
-            completed_at: 12345,
-        },
+        state,
         verifier_set,
         signatures,
     }
@@ -137,6 +166,7 @@ fn service_registry_mock_querier_handler(
             bond_denom: "uaxl".to_string(),
             unbonding_period_days: 1,
             description: "verifiers".to_string(),
+            weighting_mode: service_registry_api::WeightingMode::Uniform,
         }),
         service_registry_api::msg::QueryMsg::ActiveVerifiers {
             service_name: _,
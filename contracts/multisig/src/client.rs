@@ -36,6 +36,15 @@ pub enum Error {
 
     #[error("failed to query signing parameters")]
     SigningParameters,
+
+    #[error("failed to query signing sessions for chain {chain_name}")]
+    SigningSessions { chain_name: ChainName },
+
+    #[error("failed to query signing history of verifier {verifier} for chain {chain_name}")]
+    VerifierSigningHistory {
+        verifier: String,
+        chain_name: ChainName,
+    },
 }
 
 impl<'a> From<client::ContractClient<'a, ExecuteMsg, QueryMsg>> for Client<'a> {
@@ -67,6 +76,15 @@ impl Error {
                 Error::AuthorizedCallersForChain { chain_name }
             }
             QueryMsg::SigningParameters => Error::SigningParameters,
+            QueryMsg::SigningSessions { chain_name, .. } => Error::SigningSessions { chain_name },
+            QueryMsg::VerifierSigningHistory {
+                verifier,
+                chain_name,
+                ..
+            } => Error::VerifierSigningHistory {
+                verifier,
+                chain_name,
+            },
         }
     }
 }
@@ -98,6 +116,11 @@ impl Client<'_> {
         })
     }
 
+    pub fn cancel_signing_session(&self, session_id: Uint64) -> CosmosMsg {
+        self.client
+            .execute(&ExecuteMsg::CancelSigningSession { session_id })
+    }
+
     pub fn register_verifier_set(&self, verifier_set: VerifierSet) -> CosmosMsg {
         self.client
             .execute(&ExecuteMsg::RegisterVerifierSet { verifier_set })
@@ -416,6 +439,16 @@ mod test {
                         .into())
                         .into()
                     }
+                    QueryMsg::SigningSessions { .. } => Ok(to_json_binary(&Vec::<
+                        crate::msg::SigningSessionResponse,
+                    >::new(
+                    ))
+                    .into())
+                    .into(),
+                    QueryMsg::VerifierSigningHistory { .. } => {
+                        Ok(to_json_binary(&Vec::<crate::msg::VerifierSigningRecord>::new()).into())
+                            .into()
+                    }
                 }
             }
             _ => panic!("unexpected query: {:?}", msg),
@@ -458,6 +491,7 @@ mod test {
                 let pub_key = match key_type {
                     KeyType::Ecdsa => PublicKey::Ecdsa(signer.pub_key),
                     KeyType::Ed25519 => PublicKey::Ed25519(signer.pub_key),
+                    KeyType::Secp256r1 => PublicKey::Secp256r1(signer.pub_key),
                 };
 
                 match client.register_public_key(pub_key, signer.signed_address) {
@@ -545,6 +579,18 @@ mod test {
         }
     }
 
+    #[test]
+    fn construct_cancel_signing_session_msg() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        match client.cancel_signing_session(Uint64::one()) {
+            CosmosMsg::Wasm(msg) => goldie::assert_json!(&msg),
+            _ => panic!("cannot deserialize wasm message"),
+        }
+    }
+
     #[test]
     fn construct_enable_signing_msg() {
         let (querier, addr) = setup_queries_to_succeed();
@@ -59,7 +59,7 @@ pub fn instantiate(
     Ok(Response::default())
 }
 
-#[ensure_permissions(proxy(coordinator = find_coordinator), direct(authorized = can_start_signing_session))]
+#[ensure_permissions(proxy(coordinator = find_coordinator), direct(authorized = is_authorized_caller))]
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
@@ -90,6 +90,15 @@ pub fn execute(
             session_id,
             signature,
         } => execute::submit_signature(deps, env, info, session_id, signature),
+        ExecuteMsg::CancelSigningSession { session_id } => {
+            execute::cancel_signing_session(deps, env, session_id)
+        }
+        ExecuteMsg::ExpireSigningSession { session_id } => {
+            execute::expire_signing_session(deps, env, session_id)
+        }
+        ExecuteMsg::BackfillSigningSessionIndexes { limit } => {
+            execute::backfill_signing_session_indexes(deps, limit)
+        }
         ExecuteMsg::RegisterVerifierSet { verifier_set } => {
             execute::register_verifier_set(deps, verifier_set)
         }
@@ -131,7 +140,7 @@ fn validate_contract_addresses(
         .try_collect()
 }
 
-fn can_start_signing_session(
+fn is_authorized_caller(
     storage: &dyn Storage,
     sender_addr: &Addr,
     msg: &ExecuteMsg,
@@ -143,6 +152,16 @@ fn can_start_signing_session(
                     .change_context(permission_control::Error::Unauthorized)?,
             )
         }
+        ExecuteMsg::CancelSigningSession { session_id } => {
+            let session = SIGNING_SESSIONS
+                .load(storage, session_id.u64())
+                .change_context(permission_control::Error::Unauthorized)?;
+
+            Ok(
+                query::caller_authorized(storage, sender_addr.clone(), session.chain_name)
+                    .change_context(permission_control::Error::Unauthorized)?,
+            )
+        }
         _ => Err(report!(permission_control::Error::WrongVariant)),
     }
 }
@@ -157,11 +176,13 @@ fn find_coordinator(storage: &dyn Storage) -> error_stack::Result<Addr, Contract
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(
     deps: Deps,
-    _env: Env,
+    env: Env,
     msg: QueryMsg,
 ) -> Result<Binary, axelar_wasm_std::error::ContractError> {
     match msg {
-        QueryMsg::Multisig { session_id } => to_json_binary(&query::multisig(deps, session_id)?)?,
+        QueryMsg::Multisig { session_id } => {
+            to_json_binary(&query::multisig(deps, session_id, env.block.height)?)?
+        }
         QueryMsg::VerifierSet { verifier_set_id } => {
             to_json_binary(&query::verifier_set(deps, verifier_set_id)?)?
         }
@@ -185,6 +206,32 @@ pub fn query(
             to_json_binary(&query::prover_for_chain(deps, chain_name)?)?
         }
         QueryMsg::SigningParameters => to_json_binary(&query::signing_parameters(deps)?)?,
+        QueryMsg::SigningSessions {
+            chain_name,
+            status,
+            start_after,
+            limit,
+        } => to_json_binary(&query::signing_sessions(
+            deps,
+            chain_name,
+            status,
+            start_after,
+            limit,
+            env.block.height,
+        )?)?,
+        QueryMsg::VerifierSigningHistory {
+            verifier,
+            chain_name,
+            start_after,
+            limit,
+        } => to_json_binary(&query::verifier_signing_history(
+            deps,
+            address::validate_cosmwasm_address(deps.api, &verifier)?,
+            chain_name,
+            start_after,
+            limit,
+            env.block.height,
+        )?)?,
     }
     .then(Ok)
 }
@@ -206,10 +253,12 @@ mod tests {
 
     use super::*;
     use crate::key::{KeyType, PublicKey, Signature};
+    use crate::msg::{SigningSessionResponse, SigningSessionStatus, VerifierSigningRecord};
     use crate::multisig::Multisig;
     use crate::state::load_session_signatures;
     use crate::test::common::{
-        build_verifier_set, ecdsa_test_data, ed25519_test_data, signature_test_data, TestSigner,
+        build_verifier_set, ecdsa_test_data, ed25519_test_data, secp256r1_test_data,
+        signature_test_data, TestSigner,
     };
     use crate::types::MultisigState;
     use crate::verifier_set::VerifierSet;
@@ -255,6 +304,7 @@ mod tests {
         let signers = match key_type {
             KeyType::Ecdsa => ecdsa_test_data::signers(),
             KeyType::Ed25519 => ed25519_test_data::signers(),
+            KeyType::Secp256r1 => secp256r1_test_data::signers(),
         };
 
         let verifier_set = build_verifier_set(key_type, &signers);
@@ -603,6 +653,8 @@ mod tests {
                     chain_name: chain_name.clone(),
                     event_id: session_id.to_string().try_into().unwrap(),
                     verifier_address: signer.address.clone().into(),
+                    event_kind: Some(rewards::msg::EventKind::SigningSession),
+                    late: false,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -751,6 +803,8 @@ mod tests {
                     chain_name: chain_name.clone(),
                     event_id: session_id.to_string().try_into().unwrap(),
                     verifier_address: signer.address.clone().into(),
+                    event_kind: Some(rewards::msg::EventKind::SigningSession),
+                    late: true,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -845,6 +899,538 @@ mod tests {
         );
     }
 
+    fn do_cancel_signing_session(
+        deps: DepsMut,
+        sender: Addr,
+        session_id: Uint64,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let msg = ExecuteMsg::CancelSigningSession { session_id };
+        execute(deps, mock_env(), message_info(&sender, &[]), msg.into())
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn submit_signature_to_expired_pending_session_expires_session() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name = chain_name!(MOCK_CHAIN);
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(cosmos_addr!(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+        do_start_signing_session(
+            deps.as_mut(),
+            cosmos_addr!(PROVER),
+            &ecdsa_subkey,
+            chain_name.clone(),
+        )
+        .unwrap();
+
+        let session_id = Uint64::one();
+        let signers = ecdsa_test_data::signers();
+        do_sign(deps.as_mut(), mock_env(), session_id, &signers[0]).unwrap();
+
+        let expires_at = mock_env().block.height + SIGNATURE_BLOCK_EXPIRY;
+        let mut env = mock_env();
+        env.block.height = expires_at + 1;
+
+        // the session is reported as expired before the transition is recorded
+        let res: Multisig = from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::Multisig { session_id },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            res.state,
+            MultisigState::Expired {
+                expired_at: expires_at
+            }
+        );
+
+        let res = do_sign(deps.as_mut(), env.clone(), session_id, &signers[1]).unwrap();
+        assert!(res.messages.is_empty());
+        assert_eq!(res.events.len(), 1);
+        assert_eq!(res.events[0].ty, "signing_expired");
+        assert_eq!(
+            event_attribute(&res.events[0], "expired_at"),
+            Some(expires_at.to_string().as_str())
+        );
+
+        let session = SIGNING_SESSIONS
+            .load(deps.as_ref().storage, session_id.u64())
+            .unwrap();
+        assert_eq!(
+            session.state,
+            MultisigState::Expired {
+                expired_at: expires_at
+            }
+        );
+        let signatures = load_session_signatures(deps.as_ref().storage, session_id.u64()).unwrap();
+        assert_eq!(signatures.len(), 1);
+
+        let res = do_sign(deps.as_mut(), env, session_id, &signers[2]);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::SigningSessionClosed {
+                session_id
+            })
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn cancel_signing_session() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name = chain_name!(MOCK_CHAIN);
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(cosmos_addr!(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+
+        for sender in [cosmos_addr!(PROVER), cosmos_addr!(GOVERNANCE)] {
+            let res = do_start_signing_session(
+                deps.as_mut(),
+                cosmos_addr!(PROVER),
+                &ecdsa_subkey,
+                chain_name.clone(),
+            )
+            .unwrap();
+            let session_id: Uint64 = from_json(res.data.unwrap()).unwrap();
+
+            let res = do_cancel_signing_session(deps.as_mut(), sender, session_id).unwrap();
+            assert_eq!(res.events.len(), 1);
+            assert_eq!(res.events[0].ty, "signing_cancelled");
+            assert_eq!(
+                event_attribute(&res.events[0], "session_id"),
+                Some(session_id.to_string().as_str())
+            );
+
+            let session = SIGNING_SESSIONS
+                .load(deps.as_ref().storage, session_id.u64())
+                .unwrap();
+            assert_eq!(
+                session.state,
+                MultisigState::Cancelled {
+                    cancelled_at: mock_env().block.height
+                }
+            );
+
+            let signer = ecdsa_test_data::signers().first().unwrap().to_owned();
+            let res = do_sign(deps.as_mut(), mock_env(), session_id, &signer);
+            assert_eq!(
+                res.unwrap_err().to_string(),
+                axelar_wasm_std::error::ContractError::from(ContractError::SigningSessionClosed {
+                    session_id
+                })
+                .to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn cancel_signing_session_fails_if_unauthorized_or_closed() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name = chain_name!(MOCK_CHAIN);
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(cosmos_addr!(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+        do_start_signing_session(
+            deps.as_mut(),
+            cosmos_addr!(PROVER),
+            &ecdsa_subkey,
+            chain_name.clone(),
+        )
+        .unwrap();
+        let session_id = Uint64::one();
+
+        let res = do_cancel_signing_session(deps.as_mut(), cosmos_addr!(ADMIN), session_id);
+        assert!(res.is_err());
+
+        for signer in ecdsa_test_data::signers().iter().take(2) {
+            do_sign(deps.as_mut(), mock_env(), session_id, signer).unwrap();
+        }
+
+        let res = do_cancel_signing_session(deps.as_mut(), cosmos_addr!(PROVER), session_id);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::SigningSessionClosed {
+                session_id
+            })
+            .to_string()
+        );
+    }
+
+    fn do_expire_signing_session(
+        deps: DepsMut,
+        env: Env,
+        session_id: Uint64,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let msg = ExecuteMsg::ExpireSigningSession { session_id };
+        execute(
+            deps,
+            env,
+            message_info(&cosmos_addr!("anyone"), &[]),
+            msg.into(),
+        )
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn expire_signing_session_can_only_be_called_once_expiry_has_passed() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name = chain_name!(MOCK_CHAIN);
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(cosmos_addr!(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+        do_start_signing_session(
+            deps.as_mut(),
+            cosmos_addr!(PROVER),
+            &ecdsa_subkey,
+            chain_name.clone(),
+        )
+        .unwrap();
+        let session_id = Uint64::one();
+
+        let expires_at = mock_env().block.height + SIGNATURE_BLOCK_EXPIRY;
+        let mut env = mock_env();
+        env.block.height = expires_at;
+
+        let res = do_expire_signing_session(deps.as_mut(), env.clone(), session_id);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::SigningSessionNotExpired {
+                session_id
+            })
+            .to_string()
+        );
+
+        env.block.height = expires_at + 1;
+        let res = do_expire_signing_session(deps.as_mut(), env.clone(), session_id).unwrap();
+        assert_eq!(res.events.len(), 1);
+        assert_eq!(res.events[0].ty, "signing_expired");
+        assert_eq!(
+            event_attribute(&res.events[0], "expired_at"),
+            Some(expires_at.to_string().as_str())
+        );
+
+        let session = SIGNING_SESSIONS
+            .load(deps.as_ref().storage, session_id.u64())
+            .unwrap();
+        assert_eq!(
+            session.state,
+            MultisigState::Expired {
+                expired_at: expires_at
+            }
+        );
+
+        let res = do_expire_signing_session(deps.as_mut(), env, session_id);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::SigningSessionClosed {
+                session_id
+            })
+            .to_string()
+        );
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn expire_signing_session_fails_for_completed_session() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name = chain_name!(MOCK_CHAIN);
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(cosmos_addr!(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+        do_start_signing_session(
+            deps.as_mut(),
+            cosmos_addr!(PROVER),
+            &ecdsa_subkey,
+            chain_name.clone(),
+        )
+        .unwrap();
+        let session_id = Uint64::one();
+
+        for signer in ecdsa_test_data::signers() {
+            do_sign(deps.as_mut(), mock_env(), session_id, &signer).unwrap();
+        }
+
+        let mut env = mock_env();
+        env.block.height += SIGNATURE_BLOCK_EXPIRY + 1;
+
+        let res = do_expire_signing_session(deps.as_mut(), env, session_id);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::SigningSessionClosed {
+                session_id
+            })
+            .to_string()
+        );
+    }
+
+    fn query_signing_sessions(
+        deps: Deps,
+        env: Env,
+        chain_name: ChainName,
+        status: Option<SigningSessionStatus>,
+        start_after: Option<Uint64>,
+        limit: u32,
+    ) -> Vec<Uint64> {
+        let res: Vec<SigningSessionResponse> = from_json(
+            query(
+                deps,
+                env,
+                QueryMsg::SigningSessions {
+                    chain_name,
+                    status,
+                    start_after,
+                    limit: limit.try_into().unwrap(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        res.into_iter().map(|session| session.session_id).collect()
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn query_signing_sessions_by_chain_and_status() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name = chain_name!(MOCK_CHAIN);
+        let other_chain_name = chain_name!("other-chain");
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![
+                (cosmos_addr!(PROVER), chain_name.clone()),
+                (cosmos_addr!("other_prover"), other_chain_name.clone()),
+            ],
+        )
+        .unwrap();
+
+        for _ in 0..3 {
+            do_start_signing_session(
+                deps.as_mut(),
+                cosmos_addr!(PROVER),
+                &ecdsa_subkey,
+                chain_name.clone(),
+            )
+            .unwrap();
+        }
+        do_start_signing_session(
+            deps.as_mut(),
+            cosmos_addr!("other_prover"),
+            &ecdsa_subkey,
+            other_chain_name.clone(),
+        )
+        .unwrap();
+
+        // session 1 completes, session 2 is cancelled and session 3 stays pending
+        for signer in ecdsa_test_data::signers().iter().take(2) {
+            do_sign(deps.as_mut(), mock_env(), Uint64::one(), signer).unwrap();
+        }
+        do_cancel_signing_session(deps.as_mut(), cosmos_addr!(PROVER), Uint64::from(2u64)).unwrap();
+
+        let query_ids = |status, start_after, limit| {
+            query_signing_sessions(
+                deps.as_ref(),
+                mock_env(),
+                chain_name.clone(),
+                status,
+                start_after,
+                limit,
+            )
+        };
+        assert_eq!(
+            query_ids(None, None, u32::MAX),
+            vec![Uint64::from(1u64), Uint64::from(2u64), Uint64::from(3u64)]
+        );
+        assert_eq!(
+            query_ids(Some(SigningSessionStatus::Completed), None, u32::MAX),
+            vec![Uint64::from(1u64)]
+        );
+        assert_eq!(
+            query_ids(Some(SigningSessionStatus::Cancelled), None, u32::MAX),
+            vec![Uint64::from(2u64)]
+        );
+        assert_eq!(
+            query_ids(Some(SigningSessionStatus::Pending), None, u32::MAX),
+            vec![Uint64::from(3u64)]
+        );
+        assert_eq!(
+            query_ids(None, Some(Uint64::one()), 1),
+            vec![Uint64::from(2u64)]
+        );
+
+        assert_eq!(
+            query_signing_sessions(
+                deps.as_ref(),
+                mock_env(),
+                other_chain_name,
+                None,
+                None,
+                u32::MAX,
+            ),
+            vec![Uint64::from(4u64)]
+        );
+
+        // pending sessions are reported as expired once their expiry has passed
+        let mut env = mock_env();
+        env.block.height += SIGNATURE_BLOCK_EXPIRY + 1;
+        assert!(query_signing_sessions(
+            deps.as_ref(),
+            env.clone(),
+            chain_name.clone(),
+            Some(SigningSessionStatus::Pending),
+            None,
+            u32::MAX,
+        )
+        .is_empty());
+        assert_eq!(
+            query_signing_sessions(
+                deps.as_ref(),
+                env,
+                chain_name,
+                Some(SigningSessionStatus::Expired),
+                None,
+                u32::MAX,
+            ),
+            vec![Uint64::from(3u64)]
+        );
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn query_expired_signing_sessions_includes_recorded_and_lazily_expired_sessions() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name = chain_name!(MOCK_CHAIN);
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(cosmos_addr!(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+
+        for _ in 0..3 {
+            do_start_signing_session(
+                deps.as_mut(),
+                cosmos_addr!(PROVER),
+                &ecdsa_subkey,
+                chain_name.clone(),
+            )
+            .unwrap();
+        }
+
+        // only the expiry of session 2 is recorded, sessions 1 and 3 are still stored as pending
+        let mut env = mock_env();
+        env.block.height += SIGNATURE_BLOCK_EXPIRY + 1;
+        do_expire_signing_session(deps.as_mut(), env.clone(), Uint64::from(2u64)).unwrap();
+
+        assert_eq!(
+            query_signing_sessions(
+                deps.as_ref(),
+                env.clone(),
+                chain_name.clone(),
+                Some(SigningSessionStatus::Expired),
+                None,
+                u32::MAX,
+            ),
+            vec![Uint64::from(1u64), Uint64::from(2u64), Uint64::from(3u64)]
+        );
+        assert_eq!(
+            query_signing_sessions(
+                deps.as_ref(),
+                env,
+                chain_name,
+                Some(SigningSessionStatus::Expired),
+                Some(Uint64::one()),
+                1,
+            ),
+            vec![Uint64::from(2u64)]
+        );
+    }
+
+    #[test]
+    fn query_verifier_signing_history() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name = chain_name!(MOCK_CHAIN);
+        let other_chain_name = chain_name!("other-chain");
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![
+                (cosmos_addr!(PROVER), chain_name.clone()),
+                (cosmos_addr!("other_prover"), other_chain_name.clone()),
+            ],
+        )
+        .unwrap();
+
+        for (prover, chain_name) in [
+            (cosmos_addr!(PROVER), &chain_name),
+            (cosmos_addr!(PROVER), &chain_name),
+            (cosmos_addr!("other_prover"), &other_chain_name),
+        ] {
+            do_start_signing_session(deps.as_mut(), prover, &ecdsa_subkey, chain_name.clone())
+                .unwrap();
+        }
+
+        let signers = ecdsa_test_data::signers();
+        do_sign(deps.as_mut(), mock_env(), Uint64::one(), &signers[0]).unwrap();
+        do_sign(deps.as_mut(), mock_env(), Uint64::from(2u64), &signers[1]).unwrap();
+
+        let query_history = |verifier: &Addr, start_after, limit: u32| {
+            from_json::<Vec<VerifierSigningRecord>>(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::VerifierSigningHistory {
+                        verifier: verifier.to_string(),
+                        chain_name: chain_name.clone(),
+                        start_after,
+                        limit: limit.try_into().unwrap(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+
+        // the session of the other chain is not included
+        assert_eq!(
+            query_history(&signers[0].address, None, u32::MAX),
+            vec![
+                VerifierSigningRecord {
+                    session_id: Uint64::one(),
+                    state: MultisigState::Pending,
+                    signed: true,
+                },
+                VerifierSigningRecord {
+                    session_id: Uint64::from(2u64),
+                    state: MultisigState::Pending,
+                    signed: false,
+                },
+            ]
+        );
+        assert_eq!(
+            query_history(&signers[0].address, Some(Uint64::one()), 1),
+            vec![VerifierSigningRecord {
+                session_id: Uint64::from(2u64),
+                state: MultisigState::Pending,
+                signed: false,
+            }]
+        );
+        assert!(query_history(&cosmos_addr!("non_participant"), None, u32::MAX).is_empty());
+    }
+
     #[test]
     fn query_signing_session() {
         let (mut deps, ecdsa_subkey, ed25519_subkey) = setup();
@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 
 use axelar_wasm_std::nonempty;
-use cosmwasm_std::{ensure, OverflowError, OverflowOperation, WasmMsg};
+use cosmwasm_std::{ensure, Order, OverflowError, OverflowOperation, WasmMsg};
+use cw_storage_plus::Bound;
 use router_api::ChainName;
 use sha3::{Digest, Keccak256};
 
@@ -10,6 +11,7 @@ use crate::key::{KeyTyped, PublicKey, Signature};
 use crate::signing::{validate_session_signature, SigningSession};
 use crate::state::{
     load_session_signatures, remove_prover, save_prover, save_pub_key, save_signature,
+    save_verifier_sessions, SIGNING_SESSION_INDEXES_BACKFILL,
 };
 use crate::verifier_set::VerifierSet;
 
@@ -59,6 +61,8 @@ pub fn start_signing_session(
     SIGNING_SESSIONS
         .save(deps.storage, session_id.into(), &signing_session)
         .map_err(ContractError::from)?;
+    save_verifier_sessions(deps.storage, &signing_session, &verifier_set)
+        .map_err(ContractError::from)?;
 
     let event = Event::SigningStarted {
         session_id,
@@ -102,6 +106,10 @@ pub fn submit_signature(
         }),
     }?;
 
+    if session.is_expired(env.block.height) {
+        return record_expiry(deps, session);
+    }
+
     let signature: Signature = (pub_key.key_type(), signature).try_into()?;
 
     let sig_verifier = session.sig_verifier.as_ref().map(|address| {
@@ -143,6 +151,128 @@ pub fn submit_signature(
     })
 }
 
+pub fn expire_signing_session(
+    deps: DepsMut,
+    env: Env,
+    session_id: Uint64,
+) -> error_stack::Result<Response, ContractError> {
+    let session = SIGNING_SESSIONS
+        .load(deps.storage, session_id.into())
+        .map_err(|_| ContractError::SigningSessionNotFound { session_id })?;
+
+    ensure!(
+        session.state == MultisigState::Pending,
+        ContractError::SigningSessionClosed { session_id }
+    );
+    ensure!(
+        session.is_expired(env.block.height),
+        ContractError::SigningSessionNotExpired { session_id }
+    );
+
+    record_expiry(deps, session)
+}
+
+/// Indexes up to `limit` signing sessions that were started before the signing session indexes were introduced,
+/// continuing where the previous call stopped
+pub fn backfill_signing_session_indexes(
+    deps: DepsMut,
+    limit: nonempty::Uint32,
+) -> error_stack::Result<Response, ContractError> {
+    let Some(start) = SIGNING_SESSION_INDEXES_BACKFILL
+        .may_load(deps.storage)
+        .map_err(ContractError::from)?
+    else {
+        return Ok(Response::new());
+    };
+
+    let limit = usize::from(limit);
+    let sessions: Vec<SigningSession> = SIGNING_SESSIONS
+        .range(
+            deps.storage,
+            Some(Bound::inclusive(start)),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map_ok(|(_, session)| session)
+        .try_collect()
+        .map_err(ContractError::from)?;
+
+    for session in &sessions {
+        // writing the index entries of a session that is already indexed has no effect
+        SIGNING_SESSIONS
+            .replace(deps.storage, session.id.u64(), Some(session), None)
+            .map_err(ContractError::from)?;
+
+        let verifier_set = VERIFIER_SETS
+            .load(deps.storage, &session.verifier_set_id)
+            .map_err(ContractError::from)?;
+        save_verifier_sessions(deps.storage, session, &verifier_set)
+            .map_err(ContractError::from)?;
+    }
+
+    match sessions.last() {
+        Some(session) if sessions.len() == limit => SIGNING_SESSION_INDEXES_BACKFILL
+            .save(deps.storage, &session.id.u64().saturating_add(1))
+            .map_err(ContractError::from)?,
+        _ => SIGNING_SESSION_INDEXES_BACKFILL.remove(deps.storage),
+    }
+
+    Ok(Response::new())
+}
+
+/// Records that a pending session expired. A request that revealed the expiry has no further effect,
+/// but it succeeds so the state transition is persisted.
+fn record_expiry(
+    deps: DepsMut,
+    mut session: SigningSession,
+) -> error_stack::Result<Response, ContractError> {
+    session.state = MultisigState::Expired {
+        expired_at: session.expires_at,
+    };
+    SIGNING_SESSIONS
+        .save(deps.storage, session.id.u64(), &session)
+        .map_err(ContractError::from)?;
+
+    Ok(Response::new().add_event(Event::SigningExpired {
+        session_id: session.id,
+        expired_at: session.expires_at,
+        chain_name: session.chain_name,
+    }))
+}
+
+pub fn cancel_signing_session(
+    deps: DepsMut,
+    env: Env,
+    session_id: Uint64,
+) -> error_stack::Result<Response, ContractError> {
+    let mut session = SIGNING_SESSIONS
+        .load(deps.storage, session_id.into())
+        .map_err(|_| ContractError::SigningSessionNotFound { session_id })?;
+
+    if session.is_expired(env.block.height) {
+        return record_expiry(deps, session);
+    }
+
+    ensure!(
+        session.state == MultisigState::Pending,
+        ContractError::SigningSessionClosed { session_id }
+    );
+
+    session.state = MultisigState::Cancelled {
+        cancelled_at: env.block.height,
+    };
+    SIGNING_SESSIONS
+        .save(deps.storage, session.id.u64(), &session)
+        .map_err(ContractError::from)?;
+
+    Ok(Response::new().add_event(Event::SigningCancelled {
+        session_id,
+        cancelled_at: env.block.height,
+        chain_name: session.chain_name,
+    }))
+}
+
 pub fn register_verifier_set(
     deps: DepsMut,
     verifier_set: VerifierSet,
@@ -240,6 +370,9 @@ fn signing_response(
     signature: Signature,
     rewards_contract: String,
 ) -> error_stack::Result<Response, ContractError> {
+    // a signature submitted after the session already completed (within the grace period) counts as late participation
+    let late = matches!(session.state, MultisigState::Completed { .. }) && !state_changed;
+
     let rewards_msg = WasmMsg::Execute {
         contract_addr: rewards_contract,
         msg: to_json_binary(&rewards::msg::ExecuteMsg::RecordParticipation {
@@ -250,6 +383,8 @@ fn signing_response(
                 .try_into()
                 .expect("couldn't convert session_id to nonempty string"),
             verifier_address: signer.to_string(),
+            event_kind: Some(rewards::msg::EventKind::SigningSession),
+            late,
         })
         .map_err(ContractError::from)?,
         funds: vec![],
@@ -3,6 +3,8 @@ use axelar_wasm_std::migrate_from_version;
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{DepsMut, Empty, Env, Response};
 
+use crate::state::SIGNING_SESSION_INDEXES_BACKFILL;
+
 pub type MigrateMsg = Empty;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -12,5 +14,143 @@ pub fn migrate(
     _env: Env,
     _msg: MigrateMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+    // sessions started before the indexes were introduced are only stored under their id. They are indexed in bounded
+    // batches through ExecuteMsg::BackfillSigningSessionIndexes, so the migration doesn't depend on the number of sessions
+    SIGNING_SESSION_INDEXES_BACKFILL.save(deps.storage, &0)?;
+
     Ok(Response::default())
 }
+
+#[cfg(feature = "test")]
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{Deps, HexBinary, Uint64};
+    use cw_storage_plus::Map;
+    use router_api::chain_name;
+
+    use super::*;
+    use crate::contract::execute::backfill_signing_session_indexes;
+    use crate::contract::query;
+    use crate::msg::{SigningSessionStatus, VerifierSigningRecord};
+    use crate::signing::SigningSession;
+    use crate::state::VERIFIER_SETS;
+    use crate::test::common::{build_verifier_set, ecdsa_test_data};
+    use crate::types::{MsgToSign, MultisigState};
+
+    const OLD_SIGNING_SESSIONS: Map<u64, SigningSession> = Map::new("signing_sessions");
+
+    #[test]
+    fn migrate_starts_backfill_of_signing_session_indexes_in_batches() {
+        let mut deps = mock_dependencies();
+        cw2::set_contract_version(deps.as_mut().storage, env!("CARGO_PKG_NAME"), "2.3.0").unwrap();
+
+        let verifier_set =
+            build_verifier_set(crate::key::KeyType::Ecdsa, &ecdsa_test_data::signers());
+        let verifier_set_id = verifier_set.id();
+        VERIFIER_SETS
+            .save(deps.as_mut().storage, &verifier_set_id, &verifier_set)
+            .unwrap();
+
+        let chain_name = chain_name!("ethereum");
+        let block_height = mock_env().block.height;
+        for (session_id, state) in [
+            (1u64, MultisigState::Completed { completed_at: 1 }),
+            (2u64, MultisigState::Pending),
+        ] {
+            let mut session = SigningSession::new(
+                Uint64::new(session_id),
+                verifier_set_id.clone(),
+                chain_name.clone(),
+                MsgToSign::unchecked(HexBinary::from([1; 32])),
+                block_height,
+                None,
+            );
+            session.state = state;
+
+            OLD_SIGNING_SESSIONS
+                .save(deps.as_mut().storage, session_id, &session)
+                .unwrap();
+        }
+
+        migrate(deps.as_mut(), mock_env(), Empty {}).unwrap();
+
+        let indexed_sessions = |deps: Deps| {
+            query::signing_sessions(
+                deps,
+                chain_name.clone(),
+                None,
+                None,
+                10u32.try_into().unwrap(),
+                block_height,
+            )
+            .unwrap()
+            .len()
+        };
+        assert_eq!(indexed_sessions(deps.as_ref()), 0);
+
+        backfill_signing_session_indexes(deps.as_mut(), 1u32.try_into().unwrap()).unwrap();
+        assert_eq!(indexed_sessions(deps.as_ref()), 1);
+        assert_eq!(
+            SIGNING_SESSION_INDEXES_BACKFILL
+                .load(deps.as_ref().storage)
+                .unwrap(),
+            2
+        );
+
+        backfill_signing_session_indexes(deps.as_mut(), 10u32.try_into().unwrap()).unwrap();
+        assert!(SIGNING_SESSION_INDEXES_BACKFILL
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+
+        let sessions = query::signing_sessions(
+            deps.as_ref(),
+            chain_name.clone(),
+            None,
+            None,
+            10u32.try_into().unwrap(),
+            block_height,
+        )
+        .unwrap();
+        assert_eq!(sessions.len(), 2);
+
+        let completed = query::signing_sessions(
+            deps.as_ref(),
+            chain_name.clone(),
+            Some(SigningSessionStatus::Completed),
+            None,
+            10u32.try_into().unwrap(),
+            block_height,
+        )
+        .unwrap();
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].session_id, Uint64::one());
+
+        let verifier = ecdsa_test_data::signers()[0].address.clone();
+        let history = query::verifier_signing_history(
+            deps.as_ref(),
+            verifier,
+            chain_name,
+            None,
+            10u32.try_into().unwrap(),
+            block_height,
+        )
+        .unwrap();
+        assert_eq!(
+            history,
+            vec![
+                VerifierSigningRecord {
+                    session_id: Uint64::one(),
+                    state: MultisigState::Completed { completed_at: 1 },
+                    signed: false,
+                },
+                VerifierSigningRecord {
+                    session_id: Uint64::new(2),
+                    state: MultisigState::Pending,
+                    signed: false,
+                },
+            ]
+        );
+    }
+}
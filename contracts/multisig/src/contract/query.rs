@@ -1,22 +1,31 @@
+use axelar_wasm_std::nonempty;
+use cosmwasm_std::Order;
+use cw_storage_plus::Bound;
 use error_stack::{report, Result};
 use router_api::ChainName;
 
 use super::*;
 use crate::key::{KeyType, PublicKey};
-use crate::msg::SigningParameters;
+use crate::msg::{
+    SigningParameters, SigningSessionResponse, SigningSessionStatus, VerifierSigningRecord,
+};
 use crate::multisig::Multisig;
-use crate::state::{chain_by_prover, load_pub_key, load_session_signatures, prover_by_chain};
+use crate::signing::SigningSession;
+use crate::state::{
+    chain_by_prover, load_pub_key, load_session_signatures, prover_by_chain, status_key,
+    SIGNATURES, VERIFIER_SESSIONS,
+};
 use crate::verifier_set::VerifierSet;
 use crate::ContractError;
 
-pub fn multisig(deps: Deps, session_id: Uint64) -> StdResult<Multisig> {
+pub fn multisig(deps: Deps, session_id: Uint64, block_height: u64) -> StdResult<Multisig> {
     let session = SIGNING_SESSIONS.load(deps.storage, session_id.into())?;
 
     let verifier_set = VERIFIER_SETS.load(deps.storage, &session.verifier_set_id)?;
     let signatures = load_session_signatures(deps.storage, session.id.u64())?;
 
     Ok(Multisig {
-        state: session.state,
+        state: session.state_at(block_height),
         verifier_set,
         signatures,
     })
@@ -47,6 +56,128 @@ pub fn prover_for_chain(deps: Deps, chain_name: ChainName) -> Result<Addr, Contr
         .ok_or(report!(ContractError::ProverNotFound(chain_name)))
 }
 
+pub fn signing_sessions(
+    deps: Deps,
+    chain_name: ChainName,
+    status: Option<SigningSessionStatus>,
+    start_after: Option<Uint64>,
+    limit: nonempty::Uint32,
+    block_height: u64,
+) -> StdResult<Vec<SigningSessionResponse>> {
+    let sessions: Box<dyn Iterator<Item = StdResult<SigningSession>>> = match status {
+        None => Box::new(
+            SIGNING_SESSIONS
+                .idx
+                .by_chain
+                .prefix(chain_name)
+                .range(
+                    deps.storage,
+                    start_after.map(|session_id| Bound::exclusive(session_id.u64())),
+                    None,
+                    Order::Ascending,
+                )
+                .map_ok(|(_, session)| session),
+        ),
+        // pending sessions are only reported as pending until their expiry has passed
+        Some(SigningSessionStatus::Pending) => Box::new(
+            sessions_by_stored_status(
+                deps.storage,
+                chain_name,
+                SigningSessionStatus::Pending,
+                start_after,
+            )
+            .filter_ok(move |session| !session.is_expired(block_height)),
+        ),
+        Some(SigningSessionStatus::Expired) => Box::new(
+            sessions_by_stored_status(
+                deps.storage,
+                chain_name.clone(),
+                SigningSessionStatus::Expired,
+                start_after,
+            )
+            .merge_by(
+                sessions_by_stored_status(
+                    deps.storage,
+                    chain_name,
+                    SigningSessionStatus::Pending,
+                    start_after,
+                )
+                .filter_ok(move |session| session.is_expired(block_height)),
+                |a, b| match (a, b) {
+                    (Ok(a), Ok(b)) => a.id <= b.id,
+                    _ => true,
+                },
+            ),
+        ),
+        Some(status) => Box::new(sessions_by_stored_status(
+            deps.storage,
+            chain_name,
+            status,
+            start_after,
+        )),
+    };
+
+    sessions
+        .map_ok(|session| SigningSessionResponse {
+            state: session.state_at(block_height),
+            session_id: session.id,
+            verifier_set_id: session.verifier_set_id,
+            chain_name: session.chain_name,
+            expires_at: session.expires_at,
+        })
+        .take(limit.into())
+        .collect()
+}
+
+fn sessions_by_stored_status(
+    storage: &dyn Storage,
+    chain_name: ChainName,
+    status: SigningSessionStatus,
+    start_after: Option<Uint64>,
+) -> impl Iterator<Item = StdResult<SigningSession>> + '_ {
+    SIGNING_SESSIONS
+        .idx
+        .by_chain_and_status
+        .prefix((chain_name, status_key(status)))
+        .range(
+            storage,
+            start_after.map(|session_id| Bound::exclusive(session_id.u64())),
+            None,
+            Order::Ascending,
+        )
+        .map_ok(|(_, session)| session)
+}
+
+pub fn verifier_signing_history(
+    deps: Deps,
+    verifier: Addr,
+    chain_name: ChainName,
+    start_after: Option<Uint64>,
+    limit: nonempty::Uint32,
+    block_height: u64,
+) -> StdResult<Vec<VerifierSigningRecord>> {
+    VERIFIER_SESSIONS
+        .prefix((&verifier, chain_name))
+        .keys(
+            deps.storage,
+            start_after.map(|session_id| Bound::exclusive(session_id.u64())),
+            None,
+            Order::Ascending,
+        )
+        .take(limit.into())
+        .map(|session_id| {
+            let session_id = session_id?;
+            let session = SIGNING_SESSIONS.load(deps.storage, session_id)?;
+
+            Ok(VerifierSigningRecord {
+                session_id: session.id,
+                state: session.state_at(block_height),
+                signed: SIGNATURES.has(deps.storage, (session_id, verifier.as_str())),
+            })
+        })
+        .collect()
+}
+
 pub fn signing_parameters(deps: Deps) -> Result<SigningParameters, ContractError> {
     let config = CONFIG
         .load(deps.storage)
@@ -57,6 +57,9 @@ pub enum ContractError {
     #[error("signing session {session_id:?} not found")]
     SigningSessionNotFound { session_id: Uint64 },
 
+    #[error("signing session {session_id:?} has not expired yet")]
+    SigningSessionNotExpired { session_id: Uint64 },
+
     #[error("number of participants does not match number of public keys")]
     PublicKeysMismatchParticipants,
 
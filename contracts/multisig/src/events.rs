@@ -29,6 +29,18 @@ pub enum Event {
         completed_at: u64,
         chain_name: ChainName,
     },
+    // Emitted when a pending signing session is found to have expired
+    SigningExpired {
+        session_id: Uint64,
+        expired_at: u64,
+        chain_name: ChainName,
+    },
+    // Emitted when a pending signing session is cancelled
+    SigningCancelled {
+        session_id: Uint64,
+        cancelled_at: u64,
+        chain_name: ChainName,
+    },
     PublicKeyRegistered {
         verifier: Addr,
         public_key: PublicKey,
@@ -82,6 +94,22 @@ impl From<Event> for cosmwasm_std::Event {
                 .add_attribute("session_id", session_id)
                 .add_attribute("completed_at", completed_at.to_string())
                 .add_attribute("chain", chain_name),
+            Event::SigningExpired {
+                session_id,
+                expired_at,
+                chain_name,
+            } => cosmwasm_std::Event::new("signing_expired")
+                .add_attribute("session_id", session_id)
+                .add_attribute("expired_at", expired_at.to_string())
+                .add_attribute("chain", chain_name),
+            Event::SigningCancelled {
+                session_id,
+                cancelled_at,
+                chain_name,
+            } => cosmwasm_std::Event::new("signing_cancelled")
+                .add_attribute("session_id", session_id)
+                .add_attribute("cancelled_at", cancelled_at.to_string())
+                .add_attribute("chain", chain_name),
             Event::PublicKeyRegistered {
                 verifier,
                 public_key,
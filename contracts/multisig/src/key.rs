@@ -5,11 +5,13 @@ use cosmwasm_std::{HexBinary, StdError, StdResult};
 use cw_storage_plus::{KeyDeserialize, PrimaryKey};
 use enum_display_derive::Display;
 use error_stack::{Report, ResultExt};
+use p256::elliptic_curve::sec1::ToEncodedPoint;
 use serde::de::Error;
 use serde::{Deserialize, Deserializer};
 
 use crate::ed25519::{ed25519_verify, ED25519_SIGNATURE_LEN};
 use crate::secp256k1::ecdsa_verify;
+use crate::secp256r1::{secp256r1_verify, SECP256R1_SIGNATURE_LEN};
 use crate::ContractError;
 
 const ECDSA_COMPRESSED_PUBKEY_LEN: usize = 33;
@@ -19,6 +21,7 @@ const ECDSA_COMPRESSED_PUBKEY_LEN: usize = 33;
 pub enum KeyType {
     Ecdsa,
     Ed25519,
+    Secp256r1,
 }
 
 #[cw_serde]
@@ -27,6 +30,7 @@ pub enum Signature {
     Ecdsa(NonRecoverable),
     EcdsaRecoverable(Recoverable),
     Ed25519(HexBinary),
+    Secp256r1(HexBinary),
 }
 
 #[cw_serde]
@@ -124,6 +128,10 @@ pub enum PublicKey {
 
     #[serde(deserialize_with = "deserialize_ed25519_key")]
     Ed25519(HexBinary),
+
+    /// Secp256r1 (P-256) public key must be in compressed format (33 bytes)
+    #[serde(deserialize_with = "deserialize_secp256r1_key")]
+    Secp256r1(HexBinary),
 }
 
 fn deserialize_ecdsa_key<'de, D>(deserializer: D) -> Result<HexBinary, D::Error>
@@ -146,6 +154,16 @@ where
     Ok(pk)
 }
 
+fn deserialize_secp256r1_key<'de, D>(deserializer: D) -> Result<HexBinary, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let pk: HexBinary = Deserialize::deserialize(deserializer)?;
+    PublicKey::try_from((KeyType::Secp256r1, pk.clone()))
+        .map_err(|e| Error::custom(format!("failed to deserialize public key: {}", e)))?;
+    Ok(pk)
+}
+
 pub trait KeyTyped {
     fn matches_type<T>(&self, other: &T) -> bool
     where
@@ -162,6 +180,7 @@ impl KeyTyped for PublicKey {
         match self {
             PublicKey::Ecdsa(_) => KeyType::Ecdsa,
             PublicKey::Ed25519(_) => KeyType::Ed25519,
+            PublicKey::Secp256r1(_) => KeyType::Secp256r1,
         }
     }
 }
@@ -171,6 +190,7 @@ impl KeyTyped for Signature {
         match self {
             Signature::Ecdsa(_) | Signature::EcdsaRecoverable(_) => KeyType::Ecdsa,
             Signature::Ed25519(_) => KeyType::Ed25519,
+            Signature::Secp256r1(_) => KeyType::Secp256r1,
         }
     }
 }
@@ -188,6 +208,7 @@ impl Signature {
         let res = match self.key_type() {
             KeyType::Ecdsa => ecdsa_verify(msg.as_ref(), self.as_ref(), pub_key.as_ref()),
             KeyType::Ed25519 => ed25519_verify(msg.as_ref(), self.as_ref(), pub_key.as_ref()),
+            KeyType::Secp256r1 => secp256r1_verify(msg.as_ref(), self.as_ref(), pub_key.as_ref()),
         }?;
 
         if !res {
@@ -255,6 +276,14 @@ fn validate_and_normalize_public_key(
         .change_context(ContractError::InvalidPublicKey)?
         .to_bytes()
         .into()),
+        // p256 does not compress points by default, so the compressed encoding has to be requested explicitly
+        KeyType::Secp256r1 => Ok(p256::PublicKey::from_sec1_bytes(
+            check_ecdsa_format(pub_key)?.as_slice(),
+        )
+        .change_context(ContractError::InvalidPublicKey)?
+        .to_encoded_point(true)
+        .as_bytes()
+        .into()),
     }
 }
 
@@ -267,6 +296,7 @@ impl TryFrom<(KeyType, HexBinary)> for PublicKey {
         match key_type {
             KeyType::Ecdsa => Ok(PublicKey::Ecdsa(pub_key)),
             KeyType::Ed25519 => Ok(PublicKey::Ed25519(pub_key)),
+            KeyType::Secp256r1 => Ok(PublicKey::Secp256r1(pub_key)),
         }
     }
 }
@@ -279,6 +309,7 @@ impl TryFrom<(KeyType, HexBinary)> for Signature {
             (KeyType::Ecdsa, Recoverable::LEN) => Ok(Signature::EcdsaRecoverable(Recoverable(sig))),
             (KeyType::Ecdsa, NonRecoverable::LEN) => Ok(Signature::Ecdsa(NonRecoverable(sig))),
             (KeyType::Ed25519, ED25519_SIGNATURE_LEN) => Ok(Signature::Ed25519(sig)),
+            (KeyType::Secp256r1, SECP256R1_SIGNATURE_LEN) => Ok(Signature::Secp256r1(sig)),
             (_, _) => Err(ContractError::InvalidSignatureFormat {
                 reason: format!(
                     "could not find a match for key type {} and signature length {}",
@@ -295,6 +326,7 @@ impl AsRef<[u8]> for PublicKey {
         match self {
             PublicKey::Ecdsa(pk) => pk.as_ref(),
             PublicKey::Ed25519(pk) => pk.as_ref(),
+            PublicKey::Secp256r1(pk) => pk.as_ref(),
         }
     }
 }
@@ -305,6 +337,7 @@ impl AsRef<[u8]> for Signature {
             Signature::Ecdsa(sig) => sig.as_ref(),
             Signature::EcdsaRecoverable(sig) => sig.as_ref(),
             Signature::Ed25519(sig) => sig.as_ref(),
+            Signature::Secp256r1(sig) => sig.as_ref(),
         }
     }
 }
@@ -314,6 +347,7 @@ impl From<PublicKey> for HexBinary {
         match original {
             PublicKey::Ecdsa(key) => key,
             PublicKey::Ed25519(key) => key,
+            PublicKey::Secp256r1(key) => key,
         }
     }
 }
@@ -630,3 +664,117 @@ mod ed25519_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod secp256r1_tests {
+    use axelar_wasm_std::assert_err_contains;
+    use cosmwasm_std::HexBinary;
+
+    use super::{KeyType, PublicKey};
+    use crate::key::Signature;
+    use crate::test::common::secp256r1_test_data;
+    use crate::types::MsgToSign;
+    use crate::ContractError;
+
+    #[test]
+    fn deserialize_secp256r1_key() {
+        let key =
+            PublicKey::try_from((KeyType::Secp256r1, secp256r1_test_data::pub_key())).unwrap();
+
+        let serialized = serde_json::to_string(&key).unwrap();
+        let deserialized: Result<PublicKey, _> = serde_json::from_str(&serialized);
+        assert!(deserialized.is_ok());
+        assert_eq!(deserialized.unwrap(), key);
+    }
+
+    #[test]
+    fn deserialize_secp256r1_key_fails() {
+        let uncompressed_pub_key = PublicKey::Secp256r1(HexBinary::from_hex("0457c2a57ad65a750f35fd33303e9e38643b57d6439081bd9d3ea0c165d470ab8c7e2f589a818822d68363ec268e59edc8853a4a473012fc4461fec9d41c904b7f").unwrap());
+
+        let serialized = serde_json::to_string(&uncompressed_pub_key).unwrap();
+        let deserialized: Result<PublicKey, _> = serde_json::from_str(&serialized);
+        assert!(deserialized.is_err());
+    }
+
+    #[test]
+    fn validate_secp256r1_public_key_not_on_curve() {
+        // valid compressed secp256k1 key whose x coordinate has no matching point on the P-256 curve
+        let secp256k1_pub_key = HexBinary::from_hex(
+            "03f57d1a813febaccbe6429603f9ec57969511b76cd680452dba91fa01f54e756d",
+        )
+        .unwrap();
+
+        assert_eq!(
+            *PublicKey::try_from((KeyType::Secp256r1, secp256k1_pub_key))
+                .unwrap_err()
+                .current_context(),
+            ContractError::InvalidPublicKey
+        );
+    }
+
+    #[test]
+    fn test_try_from_hexbinary_to_secp256r1_public_key() {
+        let hex = secp256r1_test_data::pub_key();
+        let pub_key = PublicKey::try_from((KeyType::Secp256r1, hex.clone())).unwrap();
+        assert_eq!(HexBinary::from(pub_key), hex);
+    }
+
+    #[test]
+    fn test_try_from_hexbinary_to_signature() {
+        let hex = secp256r1_test_data::signature();
+        let signature = Signature::try_from((KeyType::Secp256r1, hex.clone())).unwrap();
+        assert_eq!(signature.as_ref(), hex.as_ref());
+    }
+
+    #[test]
+    fn test_try_from_hexbinary_to_recoverable_signature_fails() {
+        let hex = HexBinary::from_hex(&(secp256r1_test_data::signature().to_hex() + "00")).unwrap();
+        assert_eq!(
+            Signature::try_from((KeyType::Secp256r1, hex)).unwrap_err(),
+            ContractError::InvalidSignatureFormat {
+                reason: "could not find a match for key type Secp256r1 and signature length 65"
+                    .into()
+            }
+        );
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let signature =
+            Signature::try_from((KeyType::Secp256r1, secp256r1_test_data::signature())).unwrap();
+        let message = MsgToSign::from(secp256r1_test_data::message());
+        let public_key =
+            PublicKey::try_from((KeyType::Secp256r1, secp256r1_test_data::pub_key())).unwrap();
+        let result = signature.verify(message, &public_key);
+        assert!(result.is_ok(), "{:?}", result)
+    }
+
+    #[test]
+    fn should_fail_sig_verification_with_ecdsa_key() {
+        let signature =
+            Signature::try_from((KeyType::Secp256r1, secp256r1_test_data::signature())).unwrap();
+        let message = MsgToSign::from(secp256r1_test_data::message());
+        let public_key = PublicKey::Ecdsa(secp256r1_test_data::pub_key());
+        let result = signature.verify(message, &public_key);
+        assert_err_contains!(result, ContractError, ContractError::KeyTypeMismatch);
+    }
+
+    #[test]
+    fn should_fail_sig_verification_when_invalid_sig() {
+        let invalid_signature = HexBinary::from_hex(
+            "d19f3243007f4fedf93d95c68b55387e8169d0a23f1380604ac7f80a7adba2b75ce9e3494c694b2f252b68f3e1f5c04456d0f576ae14f637a429110bee3612e9",
+        )
+        .unwrap();
+
+        let signature = Signature::try_from((KeyType::Secp256r1, invalid_signature)).unwrap();
+        let message = MsgToSign::from(secp256r1_test_data::message());
+        let public_key =
+            PublicKey::try_from((KeyType::Secp256r1, secp256r1_test_data::pub_key())).unwrap();
+        let result = signature.verify(message, &public_key);
+        assert_err_contains!(
+            result,
+            ContractError,
+            ContractError::SignatureVerificationFailed { .. }
+        );
+    }
+}
@@ -8,8 +8,17 @@ use router_api::ChainName;
 
 pub use crate::contract::MigrateMsg;
 use crate::key::{KeyType, PublicKey, Signature};
+use crate::types::MultisigState;
 use crate::verifier_set::VerifierSet;
 
+// Pagination limits
+const DEFAULT_PAGINATION_LIMIT: u32 = u32::MAX;
+
+fn default_pagination_limit() -> nonempty::Uint32 {
+    nonempty::Uint32::try_from(DEFAULT_PAGINATION_LIMIT)
+        .expect("default pagination limit must be a u32")
+}
+
 #[cw_serde]
 pub struct InstantiateMsg {
     /// The governance address is allowed to modify the authorized caller list for this contract
@@ -45,6 +54,18 @@ pub enum ExecuteMsg {
         session_id: Uint64,
         signature: HexBinary,
     },
+    /// Cancels a pending signing session, so it can no longer be completed.
+    /// Can only be called by governance or the contract authorized to sign for the session's chain.
+    #[permission(Governance, Specific(authorized))]
+    CancelSigningSession { session_id: Uint64 },
+    /// Records that a pending signing session has passed its expiry without being completed.
+    /// Can be called by anyone once the session's expiry block has passed.
+    #[permission(Any)]
+    ExpireSigningSession { session_id: Uint64 },
+    /// Indexes up to `limit` signing sessions that were started before the SigningSessions and VerifierSigningHistory
+    /// queries were introduced, continuing where the previous call stopped. Does nothing once all sessions are indexed.
+    #[permission(Any)]
+    BackfillSigningSessionIndexes { limit: nonempty::Uint32 },
     #[permission(Any)]
     RegisterVerifierSet { verifier_set: VerifierSet },
     #[permission(Any)]
@@ -114,6 +135,70 @@ pub enum QueryMsg {
 
     #[returns(SigningParameters)]
     SigningParameters,
+
+    // Returns the signing sessions of a chain, optionally filtered by status, ordered by session id.
+    // Sessions started before this query was introduced are only included once BackfillSigningSessionIndexes has indexed them.
+    // The list is paginated by:
+    // - start_after: the session id to start after, which the next page of results should start.
+    // - limit: limit the number of sessions returned, default is u32::MAX.
+    #[returns(Vec<SigningSessionResponse>)]
+    SigningSessions {
+        chain_name: ChainName,
+        status: Option<SigningSessionStatus>,
+        start_after: Option<Uint64>,
+        #[serde(default = "default_pagination_limit")]
+        limit: nonempty::Uint32,
+    },
+
+    // Returns the signing sessions of a chain the verifier was a participant in, and whether it signed them,
+    // ordered by session id. Like SigningSessions, it only includes pre-upgrade sessions once they have been indexed.
+    // The list is paginated by:
+    // - start_after: the session id to start after, which the next page of results should start.
+    // - limit: limit the number of sessions returned, default is u32::MAX.
+    #[returns(Vec<VerifierSigningRecord>)]
+    VerifierSigningHistory {
+        verifier: String,
+        chain_name: ChainName,
+        start_after: Option<Uint64>,
+        #[serde(default = "default_pagination_limit")]
+        limit: nonempty::Uint32,
+    },
+}
+
+#[cw_serde]
+#[derive(Copy)]
+pub enum SigningSessionStatus {
+    Pending,
+    Completed,
+    Expired,
+    Cancelled,
+}
+
+impl From<&MultisigState> for SigningSessionStatus {
+    fn from(state: &MultisigState) -> Self {
+        match state {
+            MultisigState::Pending => SigningSessionStatus::Pending,
+            MultisigState::Completed { .. } => SigningSessionStatus::Completed,
+            MultisigState::Expired { .. } => SigningSessionStatus::Expired,
+            MultisigState::Cancelled { .. } => SigningSessionStatus::Cancelled,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct SigningSessionResponse {
+    pub session_id: Uint64,
+    pub verifier_set_id: String,
+    pub chain_name: ChainName,
+    pub state: MultisigState,
+    pub expires_at: u64,
+}
+
+#[cw_serde]
+pub struct VerifierSigningRecord {
+    pub session_id: Uint64,
+    pub state: MultisigState,
+    pub signed: bool,
 }
 
 #[cw_serde]
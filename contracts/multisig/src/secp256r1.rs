@@ -0,0 +1,40 @@
+use crate::ContractError;
+
+pub const SECP256R1_SIGNATURE_LEN: usize = 64;
+
+pub fn secp256r1_verify(
+    msg_hash: &[u8],
+    sig: &[u8],
+    pub_key: &[u8],
+) -> Result<bool, ContractError> {
+    cosmwasm_crypto::secp256r1_verify(msg_hash, sig, pub_key).map_err(|err| {
+        ContractError::SignatureVerificationFailed {
+            reason: err.to_string(),
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::HexBinary;
+
+    use super::*;
+    use crate::test::common::secp256r1_test_data;
+
+    #[test]
+    fn should_fail_sig_verification_instead_of_truncating() {
+        let sig_with_extra_byte = secp256r1_test_data::signature().to_hex() + "00";
+
+        let signature = HexBinary::from_hex(&sig_with_extra_byte).unwrap().to_vec();
+        let message = secp256r1_test_data::message().to_vec();
+        let public_key = secp256r1_test_data::pub_key().to_vec();
+
+        let result = secp256r1_verify(&message, &signature, &public_key);
+        assert_eq!(
+            result.unwrap_err(),
+            ContractError::SignatureVerificationFailed {
+                reason: "Invalid signature format".into(),
+            }
+        );
+    }
+}
@@ -2,12 +2,15 @@ use std::collections::HashMap;
 
 use axelar_wasm_std::nonempty;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, HexBinary, Order, StdResult, Storage, Uint64};
-use cw_storage_plus::{index_list, Index, IndexList, IndexedMap, Item, Map, UniqueIndex};
+use cosmwasm_std::{Addr, Empty, HexBinary, Order, StdResult, Storage, Uint64};
+use cw_storage_plus::{
+    index_list, Index, IndexList, IndexedMap, Item, Map, MultiIndex, UniqueIndex,
+};
 use error_stack::ResultExt;
 use router_api::ChainName;
 
 use crate::key::{KeyType, KeyTyped, PublicKey, Signature};
+use crate::msg::SigningSessionStatus;
 use crate::signing::SigningSession;
 use crate::verifier_set::VerifierSet;
 use crate::ContractError;
@@ -23,7 +26,72 @@ type VerifierSetId = str;
 
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const SIGNING_SESSION_COUNTER: Item<Uint64> = Item::new("signing_session_counter");
-pub const SIGNING_SESSIONS: Map<u64, SigningSession> = Map::new("signing_sessions");
+
+#[index_list(SigningSession)]
+pub struct SigningSessionIndexes<'a> {
+    pub by_chain: MultiIndex<'a, ChainName, SigningSession, u64>,
+    /// Sessions by chain and stored status. Pending sessions might have expired since the status was stored.
+    pub by_chain_and_status: MultiIndex<'a, (ChainName, String), SigningSession, u64>,
+}
+
+pub const SIGNING_SESSIONS: IndexedMap<u64, SigningSession, SigningSessionIndexes> =
+    IndexedMap::new(
+        "signing_sessions",
+        SigningSessionIndexes {
+            by_chain: MultiIndex::new(
+                |_pk: &[u8], session| session.chain_name.clone(),
+                "signing_sessions",
+                "signing_sessions_by_chain",
+            ),
+            by_chain_and_status: MultiIndex::new(
+                |_pk: &[u8], session| {
+                    (
+                        session.chain_name.clone(),
+                        status_key(SigningSessionStatus::from(&session.state)),
+                    )
+                },
+                "signing_sessions",
+                "signing_sessions_by_chain_and_status",
+            ),
+        },
+    );
+
+pub fn status_key(status: SigningSessionStatus) -> String {
+    match status {
+        SigningSessionStatus::Pending => "pending",
+        SigningSessionStatus::Completed => "completed",
+        SigningSessionStatus::Expired => "expired",
+        SigningSessionStatus::Cancelled => "cancelled",
+    }
+    .to_string()
+}
+
+/// The session id from which the backfill of the signing session indexes continues. Only set while sessions that were
+/// started before the indexes were introduced remain to be indexed
+pub const SIGNING_SESSION_INDEXES_BACKFILL: Item<u64> =
+    Item::new("signing_session_indexes_backfill");
+
+/// Sessions by participating verifier, chain and session id, recorded when the session starts
+pub const VERIFIER_SESSIONS: Map<(&Addr, ChainName, u64), Empty> = Map::new("verifier_sessions");
+
+pub fn save_verifier_sessions(
+    storage: &mut dyn Storage,
+    session: &SigningSession,
+    verifier_set: &VerifierSet,
+) -> StdResult<()> {
+    verifier_set.signers.values().try_for_each(|signer| {
+        VERIFIER_SESSIONS.save(
+            storage,
+            (
+                &signer.address,
+                session.chain_name.clone(),
+                session.id.u64(),
+            ),
+            &Empty {},
+        )
+    })
+}
+
 // The keys represent the addresses that can start a signing session.
 type ProverChainPair = (Addr, ChainName);
 
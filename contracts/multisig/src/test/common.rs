@@ -135,6 +135,71 @@ pub mod ed25519_test_data {
     }
 }
 
+pub mod secp256r1_test_data {
+    use p256::ecdsa::{Signature, SigningKey};
+    use router_api::cosmos_addr;
+    use sha3::{Digest, Keccak256};
+
+    use super::*;
+
+    pub fn new(address: Addr, signing_key: SigningKey) -> TestSigner {
+        let address_hash = Keccak256::digest(address.as_bytes());
+        let verifying_key = signing_key.verifying_key();
+        let signature: Signature = signing_key.sign_prehash(message().as_slice()).unwrap();
+        let signed_address: Signature = signing_key.sign_prehash(address_hash.as_slice()).unwrap();
+
+        TestSigner {
+            address,
+            pub_key: verifying_key
+                .to_encoded_point(true)
+                .as_bytes()
+                .to_vec()
+                .into(),
+            signature: signature.to_bytes().to_vec().into(),
+            signed_address: signed_address.to_bytes().to_vec().into(),
+        }
+    }
+
+    pub fn pub_key() -> HexBinary {
+        HexBinary::from_hex("0357c2a57ad65a750f35fd33303e9e38643b57d6439081bd9d3ea0c165d470ab8c")
+            .unwrap()
+    }
+
+    pub fn signature() -> HexBinary {
+        HexBinary::from_hex("d19f3243007f4fedf93d95c68b55387e8169d0a23f1380604ac7f80a7adba2b75ce9e3494c694b2f252b68f3e1f5c04456d0f576ae14f637a429110bee3612e8")
+            .unwrap()
+    }
+
+    pub fn message() -> HexBinary {
+        HexBinary::from_hex("fa0609efd1dfeedfdcc8ba51520fae2d5176b7621d2560f071e801b0817e1537")
+            .unwrap()
+    }
+
+    pub fn signers() -> Vec<TestSigner> {
+        let addresses = vec![
+            cosmos_addr!("signer1"),
+            cosmos_addr!("signer2"),
+            cosmos_addr!("signer3"),
+        ]
+        .into_iter();
+        let signing_keys = vec![
+            "0002735b006b54c6f73c23f3bb0331ce930baed3afe7a56629129efc54652101",
+            "1f33707db21df35e138c071766c0bbdd5430869980f97ec9a90afbf0d8700d11",
+            "1064549e232c591f916533b36df33e6ab9a491103912ace1e3b8b9d51b155666",
+        ]
+        .into_iter()
+        .map(|hex| {
+            p256::ecdsa::SigningKey::from_slice(HexBinary::from_hex(hex).unwrap().as_slice())
+                .unwrap()
+        });
+
+        addresses
+            .zip(signing_keys)
+            .map(|(address, signing_key)| new(address, signing_key))
+            .collect()
+    }
+}
+
 #[allow(clippy::arithmetic_side_effects)]
 pub fn build_verifier_set(key_type: KeyType, signers: &[TestSigner]) -> VerifierSet {
     let mut total_weight = Uint128::zero();
@@ -29,6 +29,12 @@ pub enum MultisigState {
     Completed {
         completed_at: u64, // block at which the session was completed
     },
+    Expired {
+        expired_at: u64, // last block at which the session could have been completed
+    },
+    Cancelled {
+        cancelled_at: u64, // block at which the session was cancelled
+    },
 }
 
 impl From<HexBinary> for MsgToSign {
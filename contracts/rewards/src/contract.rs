@@ -1,16 +1,20 @@
+use std::collections::HashSet;
+
 use axelar_wasm_std::{address, nonempty, permission_control};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Response,
+    to_json_binary, Addr, BankMsg, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
+    QuerierWrapper, Response, Storage,
 };
 use error_stack::ResultExt;
 use itertools::Itertools;
+use service_registry_api::Misbehaviour;
 
 use crate::error::ContractError;
 use crate::events;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::state::{self, Config, PoolId, CONFIG};
+use crate::msg::{ExecuteMsg, InstantiateMsg, PayoutMode, QueryMsg};
+use crate::state::{self, Config, MissedEpochsReporting, Payout, PoolId, CONFIG};
 
 mod execute;
 mod migrations;
@@ -37,6 +41,7 @@ pub fn instantiate(
         deps.storage,
         &Config {
             rewards_denom: msg.rewards_denom,
+            payout_mode: PayoutMode::Push,
         },
     )?;
 
@@ -55,6 +60,8 @@ pub fn execute(
             chain_name,
             event_id,
             verifier_address,
+            event_kind,
+            late,
         } => {
             let verifier_address = address::validate_cosmwasm_address(deps.api, &verifier_address)?;
             let pool_id = PoolId {
@@ -67,24 +74,28 @@ pub fn execute(
                 verifier_address,
                 pool_id,
                 env.block.height,
+                event_kind,
+                late,
             )?;
 
             Ok(Response::new())
         }
         ExecuteMsg::AddRewards { pool_id } => {
-            let amount = info
+            // exactly one coin must be attached, so that other funds aren't silently swallowed
+            let coin = info
                 .funds
                 .iter()
-                .find(|coin| coin.denom == state::load_config(deps.storage).rewards_denom)
-                .filter(|_| info.funds.len() == 1) // filter here to make sure expected denom is the only one attached to this message, and other funds aren't silently swallowed
-                .ok_or(ContractError::WrongDenom)?
-                .amount;
-
-            execute::add_rewards(
-                deps.storage,
-                PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
-                nonempty::Uint128::try_from(amount).change_context(ContractError::ZeroRewards)?,
-            )?;
+                .exactly_one()
+                .map_err(|_| ContractError::WrongDenom)?;
+            let pool_id = PoolId::try_from_msg_pool_id(deps.api, pool_id)?;
+            let amount = nonempty::Uint128::try_from(coin.amount)
+                .change_context(ContractError::ZeroRewards)?;
+
+            if coin.denom == state::load_config(deps.storage).rewards_denom {
+                execute::add_rewards(deps.storage, pool_id, amount)?;
+            } else {
+                execute::add_denom_rewards(deps.storage, pool_id, &coin.denom, amount)?;
+            }
 
             Ok(Response::new())
         }
@@ -92,31 +103,44 @@ pub fn execute(
             pool_id,
             epoch_count,
         } => {
+            let pool_id = PoolId::try_from_msg_pool_id(deps.api, pool_id)?;
             let rewards_distribution = execute::distribute_rewards(
                 deps.storage,
-                PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
+                pool_id.clone(),
                 env.block.height,
                 epoch_count,
             )?;
 
-            let msgs = rewards_distribution
-                .rewards
-                .clone()
-                .into_iter()
-                .sorted()
-                .map(|(verifier, amount)| BankMsg::Send {
-                    to_address: verifier
-                        .proxy_address
-                        .unwrap_or(verifier.verifier_address)
-                        .into(),
-                    amount: vec![Coin {
-                        denom: state::load_config(deps.storage).rewards_denom.clone(),
+            let report_msg = match state::may_load_missed_epochs_reporting(deps.storage)? {
+                Some(reporting) => report_missed_epochs(
+                    deps.storage,
+                    deps.querier,
+                    &pool_id,
+                    &rewards_distribution.epochs_processed,
+                    reporting,
+                )?,
+                None => None,
+            };
+
+            // vested or credited rewards are sent once claimed
+            let msgs = match rewards_distribution.payout {
+                Payout::Send => rewards_distribution
+                    .rewards_by_verifier(&state::load_config(deps.storage).rewards_denom)
+                    .into_iter()
+                    .map(|(verifier, amount)| BankMsg::Send {
+                        to_address: verifier
+                            .proxy_address
+                            .unwrap_or(verifier.verifier_address)
+                            .into(),
                         amount,
-                    }],
-                });
+                    })
+                    .collect(),
+                Payout::Credit | Payout::Vest => vec![],
+            };
 
             Ok(Response::new()
                 .add_messages(msgs)
+                .add_messages(report_msg)
                 .add_event(events::Event::from(rewards_distribution)))
         }
         ExecuteMsg::UpdatePoolParams { params, pool_id } => {
@@ -153,7 +177,118 @@ pub fn execute(
 
             Ok(Response::new())
         }
+        ExecuteMsg::UpdateMissedEpochsReporting { reporting } => {
+            let reporting = reporting
+                .map(|reporting| MissedEpochsReporting::try_from_msg_reporting(deps.api, reporting))
+                .transpose()?;
+
+            execute::update_missed_epochs_reporting(deps.storage, reporting)?;
+
+            Ok(Response::new())
+        }
+        ExecuteMsg::SetPayoutMode { payout_mode } => {
+            execute::set_payout_mode(deps.storage, payout_mode)?;
+
+            Ok(Response::new())
+        }
+        ExecuteMsg::SetPoolDenom {
+            pool_id,
+            denom,
+            rewards_per_epoch,
+        } => {
+            execute::set_pool_denom(
+                deps.storage,
+                PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
+                denom.into(),
+                rewards_per_epoch,
+            )?;
+
+            Ok(Response::new())
+        }
+        ExecuteMsg::SetPoolVesting { pool_id, vesting } => {
+            execute::set_pool_vesting(
+                deps.storage,
+                PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
+                vesting,
+            )?;
+
+            Ok(Response::new())
+        }
+        ExecuteMsg::SetPoolScoring { pool_id, scoring } => {
+            execute::set_pool_scoring(
+                deps.storage,
+                PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
+                scoring,
+            )?;
+
+            Ok(Response::new())
+        }
+        ExecuteMsg::ClaimRewards { verifier_address } => {
+            let verifier_address = match verifier_address {
+                Some(verifier_address) => {
+                    address::validate_cosmwasm_address(deps.api, &verifier_address)?
+                }
+                None => info.sender.clone(),
+            };
+
+            let (recipient, amount) = execute::claim_rewards(
+                deps.storage,
+                &info.sender,
+                &verifier_address,
+                env.block.height,
+            )?;
+
+            Ok(Response::new()
+                .add_message(BankMsg::Send {
+                    to_address: recipient.to_string(),
+                    amount: amount.clone(),
+                })
+                .add_event(events::Event::RewardsClaimed {
+                    verifier_address,
+                    recipient,
+                    amount,
+                }))
+        }
+    }
+}
+
+/// Tracks the missed epochs of the verifiers that are currently active for the service and chain of the pool,
+/// and builds the report of those that reached the maximum. Missed epochs are not tracked if the active verifiers
+/// cannot be queried, so that reporting never blocks the distribution of rewards.
+fn report_missed_epochs(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    pool_id: &PoolId,
+    epochs: &[u64],
+    reporting: MissedEpochsReporting,
+) -> error_stack::Result<Option<CosmosMsg>, ContractError> {
+    let service_registry: service_registry_api::Client =
+        client::ContractClient::new(querier, &reporting.service_registry).into();
+
+    let Ok(active_verifiers) = service_registry
+        .active_verifiers(reporting.service_name.clone(), pool_id.chain_name.clone())
+    else {
+        return Ok(None);
+    };
+    let active_verifiers: HashSet<_> = active_verifiers
+        .into_iter()
+        .map(|verifier| verifier.verifier_info.address)
+        .collect();
+
+    let verifiers_to_report =
+        execute::track_missed_epochs(storage, pool_id, epochs, &reporting, &active_verifiers)?;
+    if verifiers_to_report.is_empty() {
+        return Ok(None);
     }
+
+    Ok(Some(service_registry.report_misbehaviour(
+        reporting.service_name,
+        pool_id.chain_name.clone(),
+        verifiers_to_report.iter().map(Addr::to_string).collect(),
+        Misbehaviour::MissedEpochs {
+            count: reporting.max_consecutive_missed_epochs.into(),
+        },
+    )))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -191,17 +326,70 @@ pub fn query(
                 .change_context(ContractError::SerializeResponse)
                 .map_err(axelar_wasm_std::error::ContractError::from)
         }
+        QueryMsg::MissedEpochsReporting => {
+            let reporting = state::may_load_missed_epochs_reporting(deps.storage)?
+                .map(crate::msg::MissedEpochsReporting::from);
+            to_json_binary(&reporting)
+                .change_context(ContractError::SerializeResponse)
+                .map_err(axelar_wasm_std::error::ContractError::from)
+        }
+        QueryMsg::PayoutMode => to_json_binary(&state::load_config(deps.storage).payout_mode)
+            .change_context(ContractError::SerializeResponse)
+            .map_err(axelar_wasm_std::error::ContractError::from),
+        QueryMsg::UnclaimedRewards { verifier } => {
+            let rewards = query::unclaimed_rewards(
+                deps.storage,
+                &address::validate_cosmwasm_address(deps.api, &verifier)?,
+            )?;
+            to_json_binary(&rewards)
+                .change_context(ContractError::SerializeResponse)
+                .map_err(axelar_wasm_std::error::ContractError::from)
+        }
+        QueryMsg::VestingRewards { verifier } => {
+            let rewards = query::vesting_rewards(
+                deps.storage,
+                &address::validate_cosmwasm_address(deps.api, &verifier)?,
+                env.block.height,
+            )?;
+            to_json_binary(&rewards)
+                .change_context(ContractError::SerializeResponse)
+                .map_err(axelar_wasm_std::error::ContractError::from)
+        }
+        QueryMsg::RewardsHistory {
+            verifier,
+            start_after,
+            limit,
+        } => {
+            let start_after = start_after
+                .map(|id| {
+                    PoolId::try_from_msg_pool_id(deps.api, id.pool_id)
+                        .map(|pool_id| (pool_id, id.epoch_num))
+                })
+                .transpose()?;
+            let history = query::rewards_history(
+                deps.storage,
+                &address::validate_cosmwasm_address(deps.api, &verifier)?,
+                start_after,
+                limit,
+            )?;
+            to_json_binary(&history)
+                .change_context(ContractError::SerializeResponse)
+                .map_err(axelar_wasm_std::error::ContractError::from)
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{coins, Addr, BlockInfo, Uint128};
+    use cosmwasm_std::{coin, coins, Addr, BlockInfo, Uint128};
     use cw_multi_test::{App, ContractWrapper, Executor};
     use router_api::{chain_name, cosmos_addr, cosmos_address};
 
     use super::*;
-    use crate::msg::{ExecuteMsg, InstantiateMsg, Params, PoolId, QueryMsg, RewardsPool};
+    use crate::msg::{
+        EpochRewards, ExecuteMsg, InstantiateMsg, Params, PoolDenom, PoolId, PoolRewards, QueryMsg,
+        RewardsPool,
+    };
 
     const USER: &str = "user";
     const VERIFIER: &str = "verifier";
@@ -301,6 +489,8 @@ mod tests {
                 chain_name: chain_name.clone(),
                 event_id: "some event".try_into().unwrap(),
                 verifier_address: verifier.to_string(),
+                event_kind: None,
+                late: false,
             },
             &[],
         );
@@ -313,6 +503,8 @@ mod tests {
                 chain_name: chain_name.clone(),
                 event_id: "some other event".try_into().unwrap(),
                 verifier_address: verifier.to_string(),
+                event_kind: None,
+                late: false,
             },
             &[],
         );
@@ -331,7 +523,10 @@ mod tests {
                 rewards_per_epoch: updated_params.rewards_per_epoch.into(),
                 participation_threshold: updated_params.participation_threshold,
                 current_epoch_num: 0u64.into(),
-                last_distribution_epoch: None
+                last_distribution_epoch: None,
+                additional_denoms: vec![],
+                vesting: None,
+                scoring: None,
             }
         );
 
@@ -462,6 +657,8 @@ mod tests {
                 chain_name: chain_name.clone(),
                 event_id: "some event".try_into().unwrap(),
                 verifier_address: verifier.to_string(),
+                event_kind: None,
+                late: false,
             },
             &[],
         )
@@ -520,6 +717,8 @@ mod tests {
                 chain_name: chain_name.clone(),
                 event_id: "some other event".try_into().unwrap(),
                 verifier_address: verifier.to_string(),
+                event_kind: None,
+                late: false,
             },
             &[],
         )
@@ -554,6 +753,390 @@ mod tests {
         assert_eq!(balance.amount, Uint128::from(params.rewards_per_epoch));
     }
 
+    /// Tests that in pull payout mode distributed rewards are credited to the verifier,
+    /// can be queried, and are sent to the verifier once claimed
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn test_rewards_pull_flow() {
+        let chain_name = chain_name!(MOCK_CHAIN);
+        let user = cosmos_addr!(USER);
+        let verifier = cosmos_addr!(VERIFIER);
+        let pool_contract = cosmos_addr!(POOL_CONTRACT);
+
+        const AXL_DENOMINATION: &str = "uaxl";
+        let mut app = App::new(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &user, coins(100000, AXL_DENOMINATION))
+                .unwrap()
+        });
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let governance_address = cosmos_addr!(GOVERNANCE);
+        let params = Params {
+            epoch_duration: 10u64.try_into().unwrap(),
+            rewards_per_epoch: Uint128::from(100u128).try_into().unwrap(),
+            participation_threshold: (1, 2).try_into().unwrap(),
+        };
+        let contract_address = app
+            .instantiate_contract(
+                code_id,
+                cosmos_addr!(ROUTER),
+                &InstantiateMsg {
+                    governance_address: governance_address.to_string(),
+                    rewards_denom: AXL_DENOMINATION.to_string(),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        let pool_id = PoolId {
+            chain_name: chain_name.clone(),
+            contract: pool_contract.to_string(),
+        };
+
+        app.execute_contract(
+            governance_address.clone(),
+            contract_address.clone(),
+            &ExecuteMsg::CreatePool {
+                params: params.clone(),
+                pool_id: pool_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // only governance can change the payout mode
+        assert!(app
+            .execute_contract(
+                user.clone(),
+                contract_address.clone(),
+                &ExecuteMsg::SetPayoutMode {
+                    payout_mode: PayoutMode::Pull,
+                },
+                &[],
+            )
+            .is_err());
+
+        app.execute_contract(
+            governance_address,
+            contract_address.clone(),
+            &ExecuteMsg::SetPayoutMode {
+                payout_mode: PayoutMode::Pull,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let res: PayoutMode = app
+            .wrap()
+            .query_wasm_smart(contract_address.clone(), &QueryMsg::PayoutMode)
+            .unwrap();
+        assert_eq!(res, PayoutMode::Pull);
+
+        app.execute_contract(
+            user.clone(),
+            contract_address.clone(),
+            &ExecuteMsg::AddRewards {
+                pool_id: pool_id.clone(),
+            },
+            &coins(200, AXL_DENOMINATION),
+        )
+        .unwrap();
+
+        app.execute_contract(
+            pool_contract.clone(),
+            contract_address.clone(),
+            &ExecuteMsg::RecordParticipation {
+                chain_name: chain_name.clone(),
+                event_id: "some event".try_into().unwrap(),
+                verifier_address: verifier.to_string(),
+                event_kind: None,
+                late: false,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let old_height = app.block_info().height;
+        app.set_block(BlockInfo {
+            height: old_height + u64::from(params.epoch_duration) * 2,
+            ..app.block_info()
+        });
+
+        app.execute_contract(
+            user.clone(),
+            contract_address.clone(),
+            &ExecuteMsg::DistributeRewards {
+                pool_id: pool_id.clone(),
+                epoch_count: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        // nothing is sent to the verifier until the rewards are claimed
+        let balance = app
+            .wrap()
+            .query_balance(verifier.clone(), AXL_DENOMINATION)
+            .unwrap();
+        assert_eq!(balance.amount, Uint128::zero());
+
+        let res: Vec<PoolRewards> = app
+            .wrap()
+            .query_wasm_smart(
+                contract_address.clone(),
+                &QueryMsg::UnclaimedRewards {
+                    verifier: verifier.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            res,
+            vec![PoolRewards {
+                pool_id: pool_id.clone(),
+                amount: coins(100, AXL_DENOMINATION),
+            }]
+        );
+
+        let res: Vec<EpochRewards> = app
+            .wrap()
+            .query_wasm_smart(
+                contract_address.clone(),
+                &QueryMsg::RewardsHistory {
+                    verifier: verifier.to_string(),
+                    start_after: None,
+                    limit: 10u32.try_into().unwrap(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            res,
+            vec![EpochRewards {
+                pool_id: pool_id.clone(),
+                epoch_num: 0,
+                amount: coins(100, AXL_DENOMINATION),
+            }]
+        );
+
+        // only the verifier or its proxy can claim the rewards
+        assert!(app
+            .execute_contract(
+                user,
+                contract_address.clone(),
+                &ExecuteMsg::ClaimRewards {
+                    verifier_address: Some(verifier.to_string()),
+                },
+                &[],
+            )
+            .is_err());
+
+        app.execute_contract(
+            verifier.clone(),
+            contract_address.clone(),
+            &ExecuteMsg::ClaimRewards {
+                verifier_address: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let balance = app
+            .wrap()
+            .query_balance(verifier.clone(), AXL_DENOMINATION)
+            .unwrap();
+        assert_eq!(balance.amount, Uint128::from(params.rewards_per_epoch));
+
+        let res: Vec<PoolRewards> = app
+            .wrap()
+            .query_wasm_smart(
+                contract_address.clone(),
+                &QueryMsg::UnclaimedRewards {
+                    verifier: verifier.to_string(),
+                },
+            )
+            .unwrap();
+        assert!(res.is_empty());
+
+        // claiming again fails since there is nothing left to claim
+        assert!(app
+            .execute_contract(
+                verifier,
+                contract_address,
+                &ExecuteMsg::ClaimRewards {
+                    verifier_address: None,
+                },
+                &[],
+            )
+            .is_err());
+    }
+
+    /// Tests that rewards can be added and distributed in an additional denom of a pool
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn test_rewards_in_additional_denom() {
+        let chain_name = chain_name!(MOCK_CHAIN);
+        let user = cosmos_addr!(USER);
+        let verifier = cosmos_addr!(VERIFIER);
+        let pool_contract = cosmos_addr!(POOL_CONTRACT);
+
+        const AXL_DENOMINATION: &str = "uaxl";
+        const USDC_DENOMINATION: &str = "uusdc";
+        let mut app = App::new(|router, _, storage| {
+            router
+                .bank
+                .init_balance(
+                    storage,
+                    &user,
+                    vec![
+                        coin(100000, AXL_DENOMINATION),
+                        coin(100000, USDC_DENOMINATION),
+                        coin(100000, "uother"),
+                    ],
+                )
+                .unwrap()
+        });
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let governance_address = cosmos_addr!(GOVERNANCE);
+        let params = Params {
+            epoch_duration: 10u64.try_into().unwrap(),
+            rewards_per_epoch: Uint128::from(100u128).try_into().unwrap(),
+            participation_threshold: (1, 2).try_into().unwrap(),
+        };
+        let contract_address = app
+            .instantiate_contract(
+                code_id,
+                cosmos_addr!(ROUTER),
+                &InstantiateMsg {
+                    governance_address: governance_address.to_string(),
+                    rewards_denom: AXL_DENOMINATION.to_string(),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        let pool_id = PoolId {
+            chain_name: chain_name.clone(),
+            contract: pool_contract.to_string(),
+        };
+
+        app.execute_contract(
+            governance_address.clone(),
+            contract_address.clone(),
+            &ExecuteMsg::CreatePool {
+                params: params.clone(),
+                pool_id: pool_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            governance_address,
+            contract_address.clone(),
+            &ExecuteMsg::SetPoolDenom {
+                pool_id: pool_id.clone(),
+                denom: USDC_DENOMINATION.try_into().unwrap(),
+                rewards_per_epoch: Uint128::from(30u128),
+            },
+            &[],
+        )
+        .unwrap();
+
+        for funds in [coins(200, AXL_DENOMINATION), coins(50, USDC_DENOMINATION)] {
+            app.execute_contract(
+                user.clone(),
+                contract_address.clone(),
+                &ExecuteMsg::AddRewards {
+                    pool_id: pool_id.clone(),
+                },
+                &funds,
+            )
+            .unwrap();
+        }
+
+        // denoms that weren't added to the pool are rejected
+        assert!(app
+            .execute_contract(
+                user.clone(),
+                contract_address.clone(),
+                &ExecuteMsg::AddRewards {
+                    pool_id: pool_id.clone(),
+                },
+                &coins(50, "uother"),
+            )
+            .is_err());
+
+        let res: RewardsPool = app
+            .wrap()
+            .query_wasm_smart(
+                contract_address.clone(),
+                &QueryMsg::RewardsPool {
+                    pool_id: pool_id.clone(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            res.additional_denoms,
+            vec![PoolDenom {
+                denom: USDC_DENOMINATION.to_string(),
+                balance: Uint128::from(50u128),
+                rewards_per_epoch: Uint128::from(30u128),
+            }]
+        );
+
+        app.execute_contract(
+            pool_contract.clone(),
+            contract_address.clone(),
+            &ExecuteMsg::RecordParticipation {
+                chain_name: chain_name.clone(),
+                event_id: "some event".try_into().unwrap(),
+                verifier_address: verifier.to_string(),
+                event_kind: None,
+                late: false,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let old_height = app.block_info().height;
+        app.set_block(BlockInfo {
+            height: old_height + u64::from(params.epoch_duration) * 2,
+            ..app.block_info()
+        });
+
+        app.execute_contract(
+            user,
+            contract_address.clone(),
+            &ExecuteMsg::DistributeRewards {
+                pool_id,
+                epoch_count: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            app.wrap()
+                .query_balance(verifier.clone(), AXL_DENOMINATION)
+                .unwrap(),
+            coin(100, AXL_DENOMINATION)
+        );
+        assert_eq!(
+            app.wrap()
+                .query_balance(verifier, USDC_DENOMINATION)
+                .unwrap(),
+            coin(30, USDC_DENOMINATION)
+        );
+    }
+
     // test that pool parameter updates take effect in the current epoch, even when there is
     // an existing tally
     #[test]
@@ -617,6 +1200,8 @@ mod tests {
                 chain_name: chain_name.clone(),
                 event_id: "some event".try_into().unwrap(),
                 verifier_address: verifier.to_string(),
+                event_kind: None,
+                late: false,
             },
             &[],
         )
@@ -661,7 +1246,10 @@ mod tests {
                 rewards_per_epoch: updated_params.rewards_per_epoch.into(),
                 participation_threshold: updated_params.participation_threshold,
                 current_epoch_num: 0u64.into(),
-                last_distribution_epoch: None
+                last_distribution_epoch: None,
+                additional_denoms: vec![],
+                vesting: None,
+                scoring: None,
             }
         );
 
@@ -805,7 +1393,10 @@ mod tests {
                 rewards_per_epoch: updated_params.rewards_per_epoch.into(),
                 participation_threshold: updated_params.participation_threshold,
                 current_epoch_num: 0u64.into(),
-                last_distribution_epoch: None
+                last_distribution_epoch: None,
+                additional_denoms: vec![],
+                vesting: None,
+                scoring: None,
             }
         );
 
@@ -819,6 +1410,8 @@ mod tests {
                 chain_name: chain_name.clone(),
                 event_id: "some event".try_into().unwrap(),
                 verifier_address: verifier.to_string(),
+                event_kind: None,
+                late: false,
             },
             &[],
         )
@@ -934,6 +1527,8 @@ mod tests {
                 chain_name: chain_name.clone(),
                 event_id: "some event".try_into().unwrap(),
                 verifier_address: verifier.to_string(),
+                event_kind: None,
+                late: false,
             },
             &[],
         )
@@ -989,7 +1584,10 @@ mod tests {
                 rewards_per_epoch: updated_params.rewards_per_epoch.into(),
                 participation_threshold: updated_params.participation_threshold,
                 current_epoch_num: 1u64.into(),
-                last_distribution_epoch: None
+                last_distribution_epoch: None,
+                additional_denoms: vec![],
+                vesting: None,
+                scoring: None,
             }
         );
 
@@ -1,15 +1,15 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use axelar_wasm_std::{nonempty, FnExt};
-use cosmwasm_std::{Addr, OverflowError, OverflowOperation, Storage, Uint128};
+use cosmwasm_std::{Addr, Coin, Coins, OverflowError, OverflowOperation, Storage, Uint128};
 use error_stack::{ensure, Report, Result};
 use itertools::Itertools;
 
 use crate::error::ContractError;
-use crate::msg::Params;
+use crate::msg::{EventKind, Params, PayoutMode, Scoring, Vesting};
 use crate::state::{
-    self, Epoch, EpochTally, Event, ParamsSnapshot, PoolId, RewardsDistribution, RewardsPool,
-    StorageState,
+    self, Config, DenomRewards, Epoch, EpochTally, Event, MissedEpochsReporting, ParamsSnapshot,
+    Payout, PoolId, RewardsDistribution, RewardsPool, StorageState,
 };
 
 const DEFAULT_EPOCHS_TO_PROCESS: u64 = 10;
@@ -21,15 +21,23 @@ pub fn record_participation(
     verifier: Addr,
     pool_id: PoolId,
     block_height: u64,
+    event_kind: Option<EventKind>,
+    late: bool,
 ) -> Result<(), ContractError> {
-    let current_params = state::load_rewards_pool_params(storage, pool_id.clone())?;
-    let cur_epoch = Epoch::current(&current_params, block_height)?;
+    let pool = state::load_rewards_pool(storage, pool_id.clone())?;
+    let cur_epoch = Epoch::current(&pool.params, block_height)?;
 
     let event = load_or_store_event(storage, event_id, pool_id.clone(), cur_epoch.epoch_num)?;
 
+    // the scoring of the pool is snapshotted when the tally is created, so changes take effect from the next epoch
     state::load_epoch_tally(storage, pool_id.clone(), event.epoch_num)?
-        .unwrap_or(EpochTally::new(pool_id, cur_epoch, current_params.params))
-        .record_participation(verifier)
+        .unwrap_or(EpochTally::new(
+            pool_id,
+            cur_epoch,
+            pool.params.params,
+            pool.scoring,
+        ))
+        .record_participation(verifier, event_kind, late)
         .then(|mut tally| {
             if matches!(event, StorageState::New(_)) {
                 tally.event_count = tally.event_count.saturating_add(1)
@@ -78,6 +86,15 @@ pub fn distribute_rewards(
     }
 
     let rewards = process_rewards_for_epochs(storage, pool_id.clone(), from, to)?;
+    let additional_rewards = process_additional_rewards_for_epochs(storage, &pool_id, from, to)?;
+    let payout = pay_out(
+        storage,
+        &pool_id,
+        &rewards,
+        &additional_rewards,
+        cur_block_height,
+    )?;
+
     state::save_rewards_watermark(storage, pool_id, to)?;
     Ok(RewardsDistribution {
         rewards: rewards
@@ -86,12 +103,111 @@ pub fn distribute_rewards(
                 state::load_verifier(storage, &addr).map(|verifier| (verifier, amount))
             })
             .try_collect()?,
+        additional_rewards: additional_rewards
+            .into_iter()
+            .map(|(addr, amount)| {
+                state::load_verifier(storage, &addr).map(|verifier| (verifier, amount.into_vec()))
+            })
+            .try_collect()?,
+        payout,
         epochs_processed: (from..=to).collect(),
         current_epoch: cur_epoch.clone(),
         can_distribute_more: to < cur_epoch.epoch_num.saturating_sub(EPOCH_PAYOUT_DELAY),
     })
 }
 
+/// Vests or credits the distributed rewards depending on the pool and payout mode.
+/// Rewards that are neither vested nor credited must be sent to the verifiers
+fn pay_out(
+    storage: &mut dyn Storage,
+    pool_id: &PoolId,
+    rewards: &HashMap<Addr, Uint128>,
+    additional_rewards: &HashMap<Addr, Coins>,
+    cur_block_height: u64,
+) -> Result<Payout, ContractError> {
+    let config = state::load_config(storage);
+    let vesting = state::load_rewards_pool(storage, pool_id.clone())?.vesting;
+
+    if vesting.is_none() && config.payout_mode == PayoutMode::Push {
+        return Ok(Payout::Send);
+    }
+
+    let mut all_rewards = additional_rewards.clone();
+    for (verifier, amount) in rewards {
+        all_rewards
+            .entry(verifier.clone())
+            .or_default()
+            .add(Coin::new(*amount, config.rewards_denom.clone()))
+            .map_err(ContractError::from)?;
+    }
+
+    match vesting {
+        Some(vesting) => {
+            state::add_vesting_rewards(storage, pool_id, &all_rewards, cur_block_height, &vesting)?;
+            Ok(Payout::Vest)
+        }
+        None => {
+            state::add_unclaimed_rewards(storage, pool_id, &all_rewards)?;
+            Ok(Payout::Credit)
+        }
+    }
+}
+
+/// Updates the number of consecutive epochs in which each verifier missed the participation threshold
+/// and returns the verifiers that reached the configured maximum. Their count is reset once they are returned.
+/// Every verifier that is active for the service and chain of the pool is tracked, including those that never
+/// participated in any event, and all others are dropped. Epochs without events don't count as missed.
+pub fn track_missed_epochs(
+    storage: &mut dyn Storage,
+    pool_id: &PoolId,
+    epochs: &[u64],
+    reporting: &MissedEpochsReporting,
+    active_verifiers: &HashSet<Addr>,
+) -> Result<Vec<Addr>, ContractError> {
+    let (Some(from), Some(to)) = (epochs.first(), epochs.last()) else {
+        return Ok(vec![]);
+    };
+
+    let max_consecutive_missed_epochs = u64::from(reporting.max_consecutive_missed_epochs);
+    let tallies: Vec<_> = iterate_epoch_tallies(storage, pool_id, *from, *to)
+        .filter(|tally| tally.event_count > 0)
+        .collect();
+
+    let (mut missed_epochs, inactive): (BTreeMap<_, _>, BTreeMap<_, _>) =
+        state::load_consecutive_missed_epochs(storage, pool_id.clone())?
+            .into_iter()
+            .partition(|(verifier, _)| active_verifiers.contains(verifier));
+    state::remove_consecutive_missed_epochs(storage, pool_id.clone(), inactive.into_keys());
+    let mut verifiers_to_report = vec![];
+
+    if !tallies.is_empty() {
+        for verifier in active_verifiers {
+            missed_epochs.entry(verifier.clone()).or_default();
+        }
+    }
+
+    for tally in tallies {
+        let rewarded: HashSet<_> = tally.verifiers_to_reward().into_iter().collect();
+
+        for (verifier, count) in missed_epochs.iter_mut() {
+            *count = if rewarded.contains(verifier) {
+                0
+            } else {
+                count.saturating_add(1)
+            };
+
+            if *count >= max_consecutive_missed_epochs {
+                verifiers_to_report.push(verifier.clone());
+                *count = 0;
+            }
+        }
+    }
+
+    state::save_consecutive_missed_epochs(storage, pool_id.clone(), &missed_epochs)?;
+
+    Ok(verifiers_to_report.into_iter().unique().collect())
+}
+
 fn process_rewards_for_epochs(
     storage: &mut dyn Storage,
     pool_id: PoolId,
@@ -106,15 +222,87 @@ fn process_rewards_for_epochs(
     Ok(rewards)
 }
 
+/// Sums up the rewards of each verifier over the given epochs. In pull payout mode, the rewards of every epoch
+/// are also recorded in the rewards history
 fn cumulate_rewards(
     storage: &mut dyn Storage,
     pool_id: &PoolId,
     from: u64,
     to: u64,
 ) -> Result<HashMap<Addr, Uint128>, ContractError> {
-    iterate_epoch_tallies(storage, pool_id, from, to)
-        .map(|tally| tally.rewards_by_verifier())
-        .try_fold(HashMap::new(), merge_rewards)
+    let tallies: Vec<_> = iterate_epoch_tallies(storage, pool_id, from, to).collect();
+
+    let config = state::load_config(storage);
+
+    tallies
+        .into_iter()
+        .map(|tally| {
+            let rewards = tally.rewards_by_verifier();
+            if config.payout_mode == PayoutMode::Pull {
+                state::save_epoch_rewards(
+                    storage,
+                    pool_id,
+                    tally.epoch.epoch_num,
+                    &config.rewards_denom,
+                    &rewards,
+                )?;
+            }
+            Ok::<_, Report<ContractError>>(rewards)
+        })
+        .try_fold(HashMap::new(), |cumulated, rewards| {
+            merge_rewards(cumulated, rewards?)
+        })
+}
+
+/// Distributes the additional denoms of the pool, using the current rate of each denom. Additional denoms are co-funded,
+/// so each denom is distributed from its own watermark up to the epoch its balance covers, instead of failing the distribution.
+/// A denom that lags behind catches up by at most as many epochs as the distribution processes
+fn process_additional_rewards_for_epochs(
+    storage: &mut dyn Storage,
+    pool_id: &PoolId,
+    from: u64,
+    to: u64,
+) -> Result<HashMap<Addr, Coins>, ContractError> {
+    let epoch_count = to.saturating_sub(from).saturating_add(1);
+    let record_history = state::load_config(storage).payout_mode == PayoutMode::Pull;
+    let mut pool = state::load_rewards_pool(storage, pool_id.clone())?;
+    let mut additional_rewards: HashMap<Addr, Coins> = HashMap::new();
+
+    for (denom, denom_rewards) in pool.additional_denoms.iter_mut() {
+        let denom_from = denom_rewards
+            .last_distributed_epoch
+            .map_or(0, |last_distributed| last_distributed.saturating_add(1));
+        let denom_to = std::cmp::min(to, denom_from.saturating_add(epoch_count).saturating_sub(1));
+
+        for epoch_num in denom_from..=denom_to {
+            let rewards = state::load_epoch_tally(storage, pool_id.clone(), epoch_num)?
+                .map(|tally| tally.split_rewards(denom_rewards.rewards_per_epoch))
+                .unwrap_or_default();
+            let total: Uint128 = rewards.values().sum();
+
+            let Ok(balance) = denom_rewards.balance.checked_sub(total) else {
+                break;
+            };
+            denom_rewards.balance = balance;
+            denom_rewards.last_distributed_epoch = Some(epoch_num);
+
+            if record_history {
+                state::save_epoch_rewards(storage, pool_id, epoch_num, denom, &rewards)?;
+            }
+
+            for (verifier, amount) in rewards {
+                additional_rewards
+                    .entry(verifier)
+                    .or_default()
+                    .add(Coin::new(amount, denom.clone()))
+                    .map_err(ContractError::from)?;
+            }
+        }
+    }
+
+    state::save_rewards_pool(storage, &pool)?;
+
+    Ok(additional_rewards)
 }
 
 fn iterate_epoch_tallies<'a>(
@@ -153,6 +341,9 @@ pub fn create_pool(
         id: pool_id.clone(),
         balance: Uint128::zero(),
         params: params_snapshot,
+        additional_denoms: BTreeMap::new(),
+        vesting: None,
+        scoring: None,
     };
 
     state::save_rewards_pool(storage, &pool)
@@ -223,6 +414,72 @@ pub fn add_rewards(
     Ok(())
 }
 
+/// Adds tokens of one of the additional denoms of the pool
+pub fn add_denom_rewards(
+    storage: &mut dyn Storage,
+    pool_id: PoolId,
+    denom: &str,
+    amount: nonempty::Uint128,
+) -> Result<(), ContractError> {
+    let mut pool = state::load_rewards_pool(storage, pool_id)?;
+    let denom_rewards = pool
+        .additional_denoms
+        .get_mut(denom)
+        .ok_or(ContractError::WrongDenom)?;
+    denom_rewards.balance = denom_rewards
+        .balance
+        .checked_add(Uint128::from(amount))
+        .map_err(Into::<ContractError>::into)
+        .map_err(Report::from)?;
+
+    state::save_rewards_pool(storage, &pool)
+}
+
+pub fn set_pool_denom(
+    storage: &mut dyn Storage,
+    pool_id: PoolId,
+    denom: String,
+    rewards_per_epoch: Uint128,
+) -> Result<(), ContractError> {
+    ensure!(
+        denom != state::load_config(storage).rewards_denom,
+        ContractError::InvalidAdditionalDenom
+    );
+
+    // a new denom is only distributed for epochs after the ones already distributed in the rewards denom
+    let last_distributed_epoch = state::load_rewards_watermark(storage, pool_id.clone())?;
+    let mut pool = state::load_rewards_pool(storage, pool_id)?;
+    pool.additional_denoms
+        .entry(denom)
+        .or_insert_with(|| DenomRewards {
+            last_distributed_epoch,
+            ..DenomRewards::default()
+        })
+        .rewards_per_epoch = rewards_per_epoch;
+
+    state::save_rewards_pool(storage, &pool)
+}
+
+pub fn set_pool_vesting(
+    storage: &mut dyn Storage,
+    pool_id: PoolId,
+    vesting: Option<Vesting>,
+) -> Result<(), ContractError> {
+    let pool = state::load_rewards_pool(storage, pool_id)?;
+
+    state::save_rewards_pool(storage, &RewardsPool { vesting, ..pool })
+}
+
+pub fn set_pool_scoring(
+    storage: &mut dyn Storage,
+    pool_id: PoolId,
+    scoring: Option<Scoring>,
+) -> Result<(), ContractError> {
+    let pool = state::load_rewards_pool(storage, pool_id)?;
+
+    state::save_rewards_pool(storage, &RewardsPool { scoring, ..pool })
+}
+
 /// Merges rewards_2 into rewards_1. For each (address, amount) pair in rewards_2,
 /// adds the rewards amount to the existing rewards amount in rewards_1. If the
 /// address is not yet in rewards_1, initializes the rewards amount to the amount in
@@ -248,6 +505,19 @@ fn merge_rewards(
         })
 }
 
+pub fn update_missed_epochs_reporting(
+    storage: &mut dyn Storage,
+    reporting: Option<MissedEpochsReporting>,
+) -> Result<(), ContractError> {
+    match reporting {
+        Some(reporting) => state::save_missed_epochs_reporting(storage, &reporting),
+        None => {
+            state::remove_missed_epochs_reporting(storage);
+            Ok(())
+        }
+    }
+}
+
 pub fn set_verifier_proxy(
     storage: &mut dyn Storage,
     proxy_address: &Addr,
@@ -260,13 +530,57 @@ pub fn remove_verifier_proxy(storage: &mut dyn Storage, verifier_addr: &Addr) {
     state::remove_verifier_proxy(storage, verifier_addr)
 }
 
+pub fn set_payout_mode(
+    storage: &mut dyn Storage,
+    payout_mode: PayoutMode,
+) -> Result<(), ContractError> {
+    let config = state::load_config(storage);
+
+    state::save_config(
+        storage,
+        &Config {
+            payout_mode,
+            ..config
+        },
+    )
+}
+
+/// Removes the unclaimed rewards of the verifier across all pools and releases its vested rewards. Returns the address
+/// the rewards should be sent to, which is the proxy address if one is set, and the claimed amount.
+pub fn claim_rewards(
+    storage: &mut dyn Storage,
+    sender: &Addr,
+    verifier_addr: &Addr,
+    block_height: u64,
+) -> Result<(Addr, Vec<Coin>), ContractError> {
+    let proxy_address = state::may_load_verifier_proxy(storage, verifier_addr)?;
+    ensure!(
+        sender == verifier_addr || proxy_address.as_ref() == Some(sender),
+        ContractError::Unauthorized
+    );
+
+    let unclaimed_rewards = state::take_unclaimed_rewards(storage, verifier_addr)?;
+    let vested_rewards = state::release_vesting_rewards(storage, verifier_addr, block_height)?;
+
+    let mut amount = Coins::default();
+    for coin in unclaimed_rewards.into_iter().chain(vested_rewards) {
+        amount.add(coin).map_err(ContractError::from)?;
+    }
+    ensure!(!amount.is_empty(), ContractError::NoRewardsToClaim);
+
+    Ok((
+        proxy_address.unwrap_or(verifier_addr.clone()),
+        amount.into_vec(),
+    ))
+}
+
 #[cfg(test)]
 mod test {
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
 
     use axelar_wasm_std::nonempty;
     use cosmwasm_std::testing::{mock_dependencies, MockApi, MockQuerier, MockStorage};
-    use cosmwasm_std::{Addr, OwnedDeps, Uint128, Uint64};
+    use cosmwasm_std::{coins, Addr, OwnedDeps, Uint128, Uint64};
     use router_api::{chain_name, cosmos_addr};
 
     use super::*;
@@ -432,6 +746,8 @@ mod test {
                         verifier.clone(),
                         pool_id.clone(),
                         cur_height,
+                        None,
+                        false,
                     )
                     .unwrap();
                 }
@@ -488,6 +804,8 @@ mod test {
                 verifiers.clone(),
                 pool_id.clone(),
                 height_at_epoch_end + i as u64,
+                None,
+                false,
             )
             .unwrap();
         }
@@ -584,6 +902,8 @@ mod test {
                     verifier.clone(),
                     pool_contract.clone(),
                     block_height_started,
+                    None,
+                    false,
                 )
                 .unwrap();
             }
@@ -1059,6 +1379,8 @@ mod test {
                     verifier.clone(),
                     pool_contract.clone(),
                     block_height_started,
+                    None,
+                    false,
                 )
                 .unwrap();
             }
@@ -1145,6 +1467,8 @@ mod test {
                     verifier.clone(),
                     pool_contract.clone(),
                     block_height_started,
+                    None,
+                    false,
                 )
                 .unwrap();
             }
@@ -1241,6 +1565,8 @@ mod test {
                     verifier.clone(),
                     pool_contract.clone(),
                     block_height_started,
+                    None,
+                    false,
                 )
                 .unwrap();
             }
@@ -1332,6 +1658,8 @@ mod test {
                         verifier.clone(),
                         pool_id.clone(),
                         block_height_started + epoch as u64 * epoch_duration,
+                        None,
+                        false,
                     );
                 }
             }
@@ -1406,6 +1734,8 @@ mod test {
                 verifier.clone(),
                 pool_id.clone(),
                 height,
+                None,
+                false,
             );
         }
 
@@ -1508,6 +1838,8 @@ mod test {
             verifier.clone(),
             pool_id.clone(),
             block_height_started,
+            None,
+            false,
         );
 
         let rewards_added = 1000u128;
@@ -1593,6 +1925,8 @@ mod test {
             verifier.clone(),
             pool_id.clone(),
             block_height_started,
+            None,
+            false,
         );
 
         // rewards per epoch is 100, we only add 10
@@ -1661,6 +1995,8 @@ mod test {
             verifier.clone(),
             pool_id.clone(),
             block_height_started,
+            None,
+            false,
         );
 
         let rewards_added = 1000u128;
@@ -1706,7 +2042,9 @@ mod test {
                 chain_name: chain_name!(MOCK_CHAIN),
                 contract: cosmos_addr!("contract")
             },
-            block_height_started
+            block_height_started,
+            None,
+            false,
         )
         .is_err());
     }
@@ -1780,6 +2118,8 @@ mod test {
                 verifier.clone(),
                 pool_id.clone(),
                 height,
+                None,
+                false,
             )
             .unwrap();
             cur_height = height;
@@ -1880,6 +2220,941 @@ mod test {
         assert!(!distribution.can_distribute_more);
     }
 
+    /// Tests that verifiers missing the participation threshold for too many consecutive epochs are reported,
+    /// and that epochs without events don't count towards the missed epochs
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn track_missed_epochs_reports_verifiers_missing_consecutive_epochs() {
+        let block_height_started = 0u64;
+        let epoch_duration = 1000u64;
+        let pool_id = PoolId {
+            chain_name: chain_name!(MOCK_CHAIN),
+            contract: cosmos_addr!(POOL_CONTRACT),
+        };
+
+        let mut mock_deps = setup(0, block_height_started, epoch_duration, pool_id.clone());
+
+        let verifier1 = cosmos_addr!("verifier1");
+        let verifier2 = cosmos_addr!("verifier2");
+        let verifier3 = cosmos_addr!("verifier3");
+        let epoch_count = 5;
+        // Each epoch (except epoch 2, which has no events) has 2 events to participate in.
+        // verifier1 always participates, verifier2 stops participating after epoch 0
+        // and verifier3 only participates in every other epoch with events
+        let verifier_participation_per_epoch = HashMap::from([
+            (
+                verifier1.clone(),
+                [vec![1, 2], vec![1, 2], vec![], vec![1, 2], vec![1, 2]],
+            ),
+            (
+                verifier2.clone(),
+                [vec![1, 2], vec![], vec![], vec![], vec![]],
+            ),
+            (
+                verifier3.clone(),
+                [vec![1, 2], vec![], vec![], vec![1, 2], vec![]],
+            ),
+        ]);
+        record_participation_per_epoch(
+            &mut mock_deps,
+            &pool_id,
+            verifier_participation_per_epoch,
+            epoch_duration,
+            epoch_count,
+        );
+
+        let missed_epochs = track_missed_epochs(
+            mock_deps.as_mut().storage,
+            &pool_id,
+            &(0..epoch_count as u64).collect::<Vec<_>>(),
+            &missed_epochs_reporting(2),
+            &HashSet::from([verifier1.clone(), verifier2.clone(), verifier3.clone()]),
+        )
+        .unwrap();
+
+        assert_eq!(missed_epochs, vec![verifier2.clone()]);
+        assert_eq!(
+            state::load_consecutive_missed_epochs(mock_deps.as_ref().storage, pool_id).unwrap(),
+            BTreeMap::from([(verifier1, 0), (verifier2, 1), (verifier3, 1)])
+        );
+    }
+
+    /// Tests that verifiers that are no longer active are neither counted nor reported, and are dropped from tracking
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn track_missed_epochs_ignores_verifiers_that_are_not_active() {
+        let epoch_duration = 1000u64;
+        let pool_id = PoolId {
+            chain_name: chain_name!(MOCK_CHAIN),
+            contract: cosmos_addr!(POOL_CONTRACT),
+        };
+
+        let mut mock_deps = setup(0, 0, epoch_duration, pool_id.clone());
+
+        let verifier1 = cosmos_addr!("verifier1");
+        let verifier2 = cosmos_addr!("verifier2");
+        let epoch_count = 4;
+        // verifier2 stops participating after epoch 0, e.g. because it deregistered from the chain
+        let verifier_participation_per_epoch = HashMap::from([
+            (verifier1.clone(), [vec![1], vec![1], vec![1], vec![1]]),
+            (verifier2.clone(), [vec![1], vec![], vec![], vec![]]),
+        ]);
+        record_participation_per_epoch(
+            &mut mock_deps,
+            &pool_id,
+            verifier_participation_per_epoch,
+            epoch_duration,
+            epoch_count,
+        );
+
+        let reporting = missed_epochs_reporting(2);
+        let missed_epochs = track_missed_epochs(
+            mock_deps.as_mut().storage,
+            &pool_id,
+            &[0],
+            &reporting,
+            &HashSet::from([verifier1.clone(), verifier2.clone()]),
+        )
+        .unwrap();
+        assert!(missed_epochs.is_empty());
+
+        let missed_epochs = track_missed_epochs(
+            mock_deps.as_mut().storage,
+            &pool_id,
+            &[1, 2, 3],
+            &reporting,
+            &HashSet::from([verifier1.clone()]),
+        )
+        .unwrap();
+        assert!(missed_epochs.is_empty());
+        assert_eq!(
+            state::load_consecutive_missed_epochs(mock_deps.as_ref().storage, pool_id).unwrap(),
+            BTreeMap::from([(verifier1, 0)])
+        );
+    }
+
+    /// Tests that active verifiers that never participated in any event are counted and reported as well
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn track_missed_epochs_reports_active_verifiers_that_never_participated() {
+        let epoch_duration = 1000u64;
+        let pool_id = PoolId {
+            chain_name: chain_name!(MOCK_CHAIN),
+            contract: cosmos_addr!(POOL_CONTRACT),
+        };
+
+        let mut mock_deps = setup(0, 0, epoch_duration, pool_id.clone());
+
+        let verifier1 = cosmos_addr!("verifier1");
+        let verifier2 = cosmos_addr!("verifier2");
+        let epoch_count = 3;
+        // verifier2 is active but has no participation recorded in any epoch
+        let verifier_participation_per_epoch =
+            HashMap::from([(verifier1.clone(), [vec![1], vec![1], vec![1]])]);
+        record_participation_per_epoch(
+            &mut mock_deps,
+            &pool_id,
+            verifier_participation_per_epoch,
+            epoch_duration,
+            epoch_count,
+        );
+
+        let missed_epochs = track_missed_epochs(
+            mock_deps.as_mut().storage,
+            &pool_id,
+            &(0..epoch_count as u64).collect::<Vec<_>>(),
+            &missed_epochs_reporting(2),
+            &HashSet::from([verifier1.clone(), verifier2.clone()]),
+        )
+        .unwrap();
+
+        assert_eq!(missed_epochs, vec![verifier2.clone()]);
+        assert_eq!(
+            state::load_consecutive_missed_epochs(mock_deps.as_ref().storage, pool_id).unwrap(),
+            BTreeMap::from([(verifier1, 0), (verifier2, 1)])
+        );
+    }
+
+    fn missed_epochs_reporting(max_consecutive_missed_epochs: u64) -> MissedEpochsReporting {
+        MissedEpochsReporting {
+            service_registry: cosmos_addr!("service_registry"),
+            service_name: "amplifier".to_string(),
+            max_consecutive_missed_epochs: max_consecutive_missed_epochs.try_into().unwrap(),
+        }
+    }
+
+    #[allow(clippy::arithmetic_side_effects)]
+    fn record_participation_per_epoch<const N: usize>(
+        mock_deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        pool_id: &PoolId,
+        verifier_participation_per_epoch: HashMap<Addr, [Vec<u32>; N]>,
+        epoch_duration: u64,
+        epoch_count: usize,
+    ) {
+        for (verifier, events_participated) in verifier_participation_per_epoch {
+            for (epoch, events) in events_participated.iter().enumerate().take(epoch_count) {
+                for event in events {
+                    let event_id = event.to_string() + &epoch.to_string() + "event";
+                    record_participation(
+                        mock_deps.as_mut().storage,
+                        event_id.try_into().unwrap(),
+                        verifier.clone(),
+                        pool_id.clone(),
+                        epoch as u64 * epoch_duration,
+                        None,
+                        false,
+                    )
+                    .unwrap();
+                }
+            }
+        }
+    }
+
+    /// Tests that rewards are credited to the verifiers instead of being sent in pull payout mode,
+    /// and that the rewards of each epoch are recorded in the rewards history
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn distribute_rewards_in_pull_mode_credits_unclaimed_rewards() {
+        let epoch_duration = 1000u64;
+        let rewards_per_epoch = 100u128;
+        let pool_id = PoolId {
+            chain_name: chain_name!(MOCK_CHAIN),
+            contract: cosmos_addr!(POOL_CONTRACT),
+        };
+        let mut mock_deps = setup(0, 0, epoch_duration, pool_id.clone());
+        let verifier = cosmos_addr!(VERIFIER);
+
+        set_payout_mode(mock_deps.as_mut().storage, PayoutMode::Pull).unwrap();
+
+        for epoch_num in 0..2u64 {
+            record_participation(
+                mock_deps.as_mut().storage,
+                format!("event{}", epoch_num).try_into().unwrap(),
+                verifier.clone(),
+                pool_id.clone(),
+                epoch_num * epoch_duration,
+                None,
+                false,
+            )
+            .unwrap();
+        }
+
+        add_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            Uint128::from(rewards_per_epoch * 2).try_into().unwrap(),
+        )
+        .unwrap();
+
+        distribute_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            epoch_duration * 3,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            state::load_unclaimed_rewards(mock_deps.as_ref().storage, &verifier).unwrap(),
+            vec![(pool_id.clone(), coins(rewards_per_epoch * 2, "AXL"))]
+        );
+
+        let history =
+            state::load_rewards_history(mock_deps.as_ref().storage, &verifier, None, 10).unwrap();
+        assert_eq!(
+            history,
+            (0..2u64)
+                .map(|epoch_num| state::EpochRewards {
+                    pool_id: pool_id.clone(),
+                    epoch_num,
+                    amount: coins(rewards_per_epoch, "AXL"),
+                })
+                .collect::<Vec<_>>()
+        );
+
+        let history = state::load_rewards_history(
+            mock_deps.as_ref().storage,
+            &verifier,
+            Some((pool_id.clone(), 0)),
+            10,
+        )
+        .unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].epoch_num, 1);
+    }
+
+    #[test]
+    fn distribute_rewards_in_push_mode_does_not_credit_unclaimed_rewards() {
+        let epoch_duration = 1000u64;
+        let pool_id = PoolId {
+            chain_name: chain_name!(MOCK_CHAIN),
+            contract: cosmos_addr!(POOL_CONTRACT),
+        };
+        let mut mock_deps = setup(0, 0, epoch_duration, pool_id.clone());
+        let verifier = cosmos_addr!(VERIFIER);
+
+        record_participation(
+            mock_deps.as_mut().storage,
+            "event".try_into().unwrap(),
+            verifier.clone(),
+            pool_id.clone(),
+            0,
+            None,
+            false,
+        )
+        .unwrap();
+        add_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            Uint128::from(100u128).try_into().unwrap(),
+        )
+        .unwrap();
+
+        distribute_rewards(
+            mock_deps.as_mut().storage,
+            pool_id,
+            epoch_duration * 2,
+            None,
+        )
+        .unwrap();
+
+        assert!(
+            state::load_unclaimed_rewards(mock_deps.as_ref().storage, &verifier)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            state::load_rewards_history(mock_deps.as_ref().storage, &verifier, None, 10)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    /// Tests that rewards can be claimed by the verifier or its proxy, and are sent to the proxy if one is set
+    #[test]
+    fn claim_rewards_by_verifier_or_proxy() {
+        let pool_id = PoolId {
+            chain_name: chain_name!(MOCK_CHAIN),
+            contract: cosmos_addr!(POOL_CONTRACT),
+        };
+        let other_pool_id = PoolId {
+            chain_name: chain_name!(MOCK_CHAIN),
+            contract: cosmos_addr!(SOME_CONTRACT),
+        };
+        let mut mock_deps = setup(0, 0, 1000, pool_id.clone());
+        let verifier = cosmos_addr!(VERIFIER);
+        let proxy = cosmos_addr!("proxy");
+
+        for pool_id in [&pool_id, &other_pool_id] {
+            state::add_unclaimed_rewards(
+                mock_deps.as_mut().storage,
+                pool_id,
+                &HashMap::from([(verifier.clone(), Coins::from(Coin::new(100u128, "AXL")))]),
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            claim_rewards(mock_deps.as_mut().storage, &verifier, &verifier, 0).unwrap(),
+            (verifier.clone(), coins(200, "AXL"))
+        );
+        assert!(
+            state::load_unclaimed_rewards(mock_deps.as_ref().storage, &verifier)
+                .unwrap()
+                .is_empty()
+        );
+
+        state::add_unclaimed_rewards(
+            mock_deps.as_mut().storage,
+            &pool_id,
+            &HashMap::from([(verifier.clone(), Coins::from(Coin::new(50u128, "AXL")))]),
+        )
+        .unwrap();
+        set_verifier_proxy(mock_deps.as_mut().storage, &proxy, &verifier).unwrap();
+
+        assert_eq!(
+            claim_rewards(mock_deps.as_mut().storage, &proxy, &verifier, 0).unwrap(),
+            (proxy, coins(50, "AXL"))
+        );
+    }
+
+    #[test]
+    fn claim_rewards_fails_if_unauthorized_or_nothing_to_claim() {
+        let pool_id = PoolId {
+            chain_name: chain_name!(MOCK_CHAIN),
+            contract: cosmos_addr!(POOL_CONTRACT),
+        };
+        let mut mock_deps = setup(0, 0, 1000, pool_id.clone());
+        let verifier = cosmos_addr!(VERIFIER);
+
+        let err = claim_rewards(mock_deps.as_mut().storage, &verifier, &verifier, 0).unwrap_err();
+        assert_eq!(err.current_context(), &ContractError::NoRewardsToClaim);
+
+        state::add_unclaimed_rewards(
+            mock_deps.as_mut().storage,
+            &pool_id,
+            &HashMap::from([(verifier.clone(), Coins::from(Coin::new(100u128, "AXL")))]),
+        )
+        .unwrap();
+
+        let err = claim_rewards(
+            mock_deps.as_mut().storage,
+            &cosmos_addr!("someone"),
+            &verifier,
+            0,
+        )
+        .unwrap_err();
+        assert_eq!(err.current_context(), &ContractError::Unauthorized);
+        assert_eq!(
+            state::load_unclaimed_rewards(mock_deps.as_ref().storage, &verifier).unwrap(),
+            vec![(pool_id, coins(100, "AXL"))]
+        );
+    }
+
+    /// Tests that additional denoms are distributed at their own rate alongside the rewards denom,
+    /// and that a denom with an insufficient balance only distributes the epochs it covers until it is funded again
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn distribute_rewards_with_additional_denoms() {
+        let epoch_duration = 1000u64;
+        let pool_id = PoolId {
+            chain_name: chain_name!(MOCK_CHAIN),
+            contract: cosmos_addr!(POOL_CONTRACT),
+        };
+        let mut mock_deps = setup(0, 0, epoch_duration, pool_id.clone());
+        let verifier = cosmos_addr!(VERIFIER);
+
+        set_payout_mode(mock_deps.as_mut().storage, PayoutMode::Pull).unwrap();
+
+        set_pool_denom(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            "uusdc".to_string(),
+            Uint128::from(40u128),
+        )
+        .unwrap();
+        set_pool_denom(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            "ueth".to_string(),
+            Uint128::from(100u128),
+        )
+        .unwrap();
+
+        add_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            Uint128::from(200u128).try_into().unwrap(),
+        )
+        .unwrap();
+        add_denom_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            "uusdc",
+            Uint128::from(100u128).try_into().unwrap(),
+        )
+        .unwrap();
+        add_denom_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            "ueth",
+            Uint128::from(150u128).try_into().unwrap(),
+        )
+        .unwrap();
+
+        for epoch_num in 0..2u64 {
+            record_participation(
+                mock_deps.as_mut().storage,
+                format!("event{}", epoch_num).try_into().unwrap(),
+                verifier.clone(),
+                pool_id.clone(),
+                epoch_num * epoch_duration,
+                None,
+                false,
+            )
+            .unwrap();
+        }
+
+        let distribution = distribute_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            epoch_duration * 3,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(distribution.payout, Payout::Credit);
+        assert_eq!(
+            distribution.rewards,
+            HashMap::from([(
+                make_verifier_with_no_proxy(&verifier),
+                Uint128::from(200u128)
+            )])
+        );
+        // ueth would need 200 tokens for two epochs, so only the first epoch is distributed
+        assert_eq!(
+            distribution.additional_rewards,
+            HashMap::from([(
+                make_verifier_with_no_proxy(&verifier),
+                vec![Coin::new(100u128, "ueth"), Coin::new(80u128, "uusdc")]
+            )])
+        );
+
+        let pool = state::load_rewards_pool(mock_deps.as_ref().storage, pool_id.clone()).unwrap();
+        assert_eq!(pool.balance, Uint128::zero());
+        assert_eq!(
+            pool.additional_denoms,
+            BTreeMap::from([
+                (
+                    "ueth".to_string(),
+                    DenomRewards {
+                        balance: Uint128::from(50u128),
+                        rewards_per_epoch: Uint128::from(100u128),
+                        last_distributed_epoch: Some(0),
+                    }
+                ),
+                (
+                    "uusdc".to_string(),
+                    DenomRewards {
+                        balance: Uint128::from(20u128),
+                        rewards_per_epoch: Uint128::from(40u128),
+                        last_distributed_epoch: Some(1),
+                    }
+                ),
+            ])
+        );
+
+        let history =
+            state::load_rewards_history(mock_deps.as_ref().storage, &verifier, None, 10).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(
+            history[0].amount,
+            vec![
+                Coin::new(100u128, "AXL"),
+                Coin::new(100u128, "ueth"),
+                Coin::new(40u128, "uusdc")
+            ]
+        );
+        assert_eq!(
+            history[1].amount,
+            vec![Coin::new(100u128, "AXL"), Coin::new(40u128, "uusdc")]
+        );
+
+        // once funded again, the unpaid epoch of ueth is distributed with the next distribution
+        add_denom_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            "ueth",
+            Uint128::from(50u128).try_into().unwrap(),
+        )
+        .unwrap();
+
+        let distribution = distribute_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            epoch_duration * 4,
+            None,
+        )
+        .unwrap();
+        assert_eq!(distribution.epochs_processed, vec![2]);
+        assert_eq!(
+            distribution.additional_rewards,
+            HashMap::from([(make_verifier_with_no_proxy(&verifier), coins(100, "ueth"))])
+        );
+
+        let history =
+            state::load_rewards_history(mock_deps.as_ref().storage, &verifier, None, 10).unwrap();
+        assert_eq!(
+            history[1].amount,
+            vec![
+                Coin::new(100u128, "AXL"),
+                Coin::new(100u128, "ueth"),
+                Coin::new(40u128, "uusdc")
+            ]
+        );
+    }
+
+    #[test]
+    fn additional_denoms_added_later_are_not_distributed_for_past_epochs() {
+        let epoch_duration = 1000u64;
+        let pool_id = PoolId {
+            chain_name: chain_name!(MOCK_CHAIN),
+            contract: cosmos_addr!(POOL_CONTRACT),
+        };
+        let mut mock_deps = setup(0, 0, epoch_duration, pool_id.clone());
+
+        record_participation(
+            mock_deps.as_mut().storage,
+            "event".try_into().unwrap(),
+            cosmos_addr!(VERIFIER),
+            pool_id.clone(),
+            0,
+            None,
+            false,
+        )
+        .unwrap();
+        add_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            Uint128::from(100u128).try_into().unwrap(),
+        )
+        .unwrap();
+        distribute_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            epoch_duration * 2,
+            None,
+        )
+        .unwrap();
+
+        set_pool_denom(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            "uusdc".to_string(),
+            Uint128::from(40u128),
+        )
+        .unwrap();
+
+        let pool = state::load_rewards_pool(mock_deps.as_ref().storage, pool_id).unwrap();
+        assert_eq!(
+            pool.additional_denoms["uusdc"].last_distributed_epoch,
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn additional_denoms_must_differ_from_rewards_denom_and_be_added_before_funding() {
+        let pool_id = PoolId {
+            chain_name: chain_name!(MOCK_CHAIN),
+            contract: cosmos_addr!(POOL_CONTRACT),
+        };
+        let mut mock_deps = setup(0, 0, 1000, pool_id.clone());
+
+        let err = set_pool_denom(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            "AXL".to_string(),
+            Uint128::from(40u128),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.current_context(),
+            &ContractError::InvalidAdditionalDenom
+        );
+
+        let err = add_denom_rewards(
+            mock_deps.as_mut().storage,
+            pool_id,
+            "uusdc",
+            Uint128::from(100u128).try_into().unwrap(),
+        )
+        .unwrap_err();
+        assert_eq!(err.current_context(), &ContractError::WrongDenom);
+    }
+
+    /// Tests that rewards of a pool with vesting are released linearly when claimed
+    #[test]
+    fn distribute_rewards_with_vesting_releases_rewards_linearly() {
+        let epoch_duration = 1000u64;
+        let pool_id = PoolId {
+            chain_name: chain_name!(MOCK_CHAIN),
+            contract: cosmos_addr!(POOL_CONTRACT),
+        };
+        let mut mock_deps = setup(0, 0, epoch_duration, pool_id.clone());
+        let verifier = cosmos_addr!(VERIFIER);
+
+        set_pool_vesting(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            Some(Vesting {
+                duration: 100u64.try_into().unwrap(),
+            }),
+        )
+        .unwrap();
+
+        record_participation(
+            mock_deps.as_mut().storage,
+            "event".try_into().unwrap(),
+            verifier.clone(),
+            pool_id.clone(),
+            0,
+            None,
+            false,
+        )
+        .unwrap();
+        add_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            Uint128::from(100u128).try_into().unwrap(),
+        )
+        .unwrap();
+
+        let distribution =
+            distribute_rewards(mock_deps.as_mut().storage, pool_id.clone(), 2000, None).unwrap();
+        assert_eq!(distribution.payout, Payout::Vest);
+        assert!(
+            state::load_unclaimed_rewards(mock_deps.as_ref().storage, &verifier)
+                .unwrap()
+                .is_empty()
+        );
+
+        let vesting_rewards =
+            state::load_vesting_rewards(mock_deps.as_ref().storage, &verifier).unwrap();
+        assert_eq!(
+            vesting_rewards,
+            vec![state::VestingRewards {
+                pool_id: pool_id.clone(),
+                start_height: 2000,
+                end_height: 2100,
+                total: coins(100, "AXL"),
+                released: vec![],
+            }]
+        );
+
+        assert_eq!(
+            claim_rewards(mock_deps.as_mut().storage, &verifier, &verifier, 2025).unwrap(),
+            (verifier.clone(), coins(25, "AXL"))
+        );
+        let err =
+            claim_rewards(mock_deps.as_mut().storage, &verifier, &verifier, 2025).unwrap_err();
+        assert_eq!(err.current_context(), &ContractError::NoRewardsToClaim);
+
+        assert_eq!(
+            claim_rewards(mock_deps.as_mut().storage, &verifier, &verifier, 2200).unwrap(),
+            (verifier.clone(), coins(75, "AXL"))
+        );
+        assert!(
+            state::load_vesting_rewards(mock_deps.as_ref().storage, &verifier)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    /// Tests that the rewards of each distribution vest on their own schedule, so that later distributions
+    /// don't delay the rewards of earlier ones
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn distribute_rewards_with_vesting_keeps_a_tranche_per_distribution() {
+        let epoch_duration = 1000u64;
+        let pool_id = PoolId {
+            chain_name: chain_name!(MOCK_CHAIN),
+            contract: cosmos_addr!(POOL_CONTRACT),
+        };
+        let mut mock_deps = setup(0, 0, epoch_duration, pool_id.clone());
+        let verifier = cosmos_addr!(VERIFIER);
+
+        set_pool_vesting(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            Some(Vesting {
+                duration: 2000u64.try_into().unwrap(),
+            }),
+        )
+        .unwrap();
+
+        for epoch_num in 0..2u64 {
+            record_participation(
+                mock_deps.as_mut().storage,
+                format!("event{}", epoch_num).try_into().unwrap(),
+                verifier.clone(),
+                pool_id.clone(),
+                epoch_num * epoch_duration,
+                None,
+                false,
+            )
+            .unwrap();
+        }
+        add_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            Uint128::from(200u128).try_into().unwrap(),
+        )
+        .unwrap();
+
+        distribute_rewards(mock_deps.as_mut().storage, pool_id.clone(), 2000, None).unwrap();
+        distribute_rewards(mock_deps.as_mut().storage, pool_id.clone(), 3000, None).unwrap();
+
+        assert!(
+            state::load_unclaimed_rewards(mock_deps.as_ref().storage, &verifier)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            state::load_vesting_rewards(mock_deps.as_ref().storage, &verifier).unwrap(),
+            vec![
+                state::VestingRewards {
+                    pool_id: pool_id.clone(),
+                    start_height: 2000,
+                    end_height: 4000,
+                    total: coins(100, "AXL"),
+                    released: vec![],
+                },
+                state::VestingRewards {
+                    pool_id: pool_id.clone(),
+                    start_height: 3000,
+                    end_height: 5000,
+                    total: coins(100, "AXL"),
+                    released: vec![],
+                }
+            ]
+        );
+
+        // the first tranche has fully vested, the second one half way
+        assert_eq!(
+            claim_rewards(mock_deps.as_mut().storage, &verifier, &verifier, 4000).unwrap(),
+            (verifier.clone(), coins(150, "AXL"))
+        );
+        assert_eq!(
+            state::load_vesting_rewards(mock_deps.as_ref().storage, &verifier).unwrap(),
+            vec![state::VestingRewards {
+                pool_id: pool_id.clone(),
+                start_height: 3000,
+                end_height: 5000,
+                total: coins(100, "AXL"),
+                released: coins(50, "AXL"),
+            }]
+        );
+    }
+
+    /// Tests that tranches that have fully vested by the time of a distribution are credited to the unclaimed rewards,
+    /// so the number of tranches stays bounded
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn distribute_rewards_with_vesting_credits_fully_vested_tranches() {
+        let epoch_duration = 1000u64;
+        let pool_id = PoolId {
+            chain_name: chain_name!(MOCK_CHAIN),
+            contract: cosmos_addr!(POOL_CONTRACT),
+        };
+        let mut mock_deps = setup(0, 0, epoch_duration, pool_id.clone());
+        let verifier = cosmos_addr!(VERIFIER);
+
+        set_pool_vesting(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            Some(Vesting {
+                duration: 1000u64.try_into().unwrap(),
+            }),
+        )
+        .unwrap();
+
+        for epoch_num in 0..2u64 {
+            record_participation(
+                mock_deps.as_mut().storage,
+                format!("event{}", epoch_num).try_into().unwrap(),
+                verifier.clone(),
+                pool_id.clone(),
+                epoch_num * epoch_duration,
+                None,
+                false,
+            )
+            .unwrap();
+        }
+        add_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            Uint128::from(200u128).try_into().unwrap(),
+        )
+        .unwrap();
+
+        distribute_rewards(mock_deps.as_mut().storage, pool_id.clone(), 2000, None).unwrap();
+        distribute_rewards(mock_deps.as_mut().storage, pool_id.clone(), 3000, None).unwrap();
+
+        assert_eq!(
+            state::load_unclaimed_rewards(mock_deps.as_ref().storage, &verifier).unwrap(),
+            vec![(pool_id.clone(), coins(100, "AXL"))]
+        );
+        assert_eq!(
+            state::load_vesting_rewards(mock_deps.as_ref().storage, &verifier).unwrap(),
+            vec![state::VestingRewards {
+                pool_id: pool_id.clone(),
+                start_height: 3000,
+                end_height: 4000,
+                total: coins(100, "AXL"),
+                released: vec![],
+            }]
+        );
+
+        assert_eq!(
+            claim_rewards(mock_deps.as_mut().storage, &verifier, &verifier, 3500).unwrap(),
+            (verifier.clone(), coins(150, "AXL"))
+        );
+    }
+
+    /// Tests that with scoring enabled, rewards are split in proportion to the weighted participation scores,
+    /// and late participation only counts with the late participation factor
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn distribute_rewards_with_scoring_splits_rewards_by_score() {
+        let epoch_duration = 1000u64;
+        let pool_id = PoolId {
+            chain_name: chain_name!(MOCK_CHAIN),
+            contract: cosmos_addr!(POOL_CONTRACT),
+        };
+        let mut mock_deps = setup(0, 0, epoch_duration, pool_id.clone());
+        let verifier1 = cosmos_addr!("verifier1");
+        let verifier2 = cosmos_addr!("verifier2");
+
+        set_payout_mode(mock_deps.as_mut().storage, PayoutMode::Pull).unwrap();
+        set_pool_scoring(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            Some(Scoring {
+                messages_poll_weight: 3u64.try_into().unwrap(),
+                verifier_set_poll_weight: 1u64.try_into().unwrap(),
+                signing_session_weight: 2u64.try_into().unwrap(),
+                default_weight: 1u64.try_into().unwrap(),
+                late_participation_factor: (1, 2).try_into().unwrap(),
+            }),
+        )
+        .unwrap();
+
+        record_participation(
+            mock_deps.as_mut().storage,
+            "event1".try_into().unwrap(),
+            verifier1.clone(),
+            pool_id.clone(),
+            0,
+            Some(EventKind::MessagesPoll),
+            false,
+        )
+        .unwrap();
+        record_participation(
+            mock_deps.as_mut().storage,
+            "event2".try_into().unwrap(),
+            verifier2.clone(),
+            pool_id.clone(),
+            0,
+            Some(EventKind::SigningSession),
+            true,
+        )
+        .unwrap();
+
+        add_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            Uint128::from(100u128).try_into().unwrap(),
+        )
+        .unwrap();
+
+        distribute_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            epoch_duration * 2,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            state::load_unclaimed_rewards(mock_deps.as_ref().storage, &verifier1).unwrap(),
+            vec![(pool_id.clone(), coins(75, "AXL"))]
+        );
+        assert_eq!(
+            state::load_unclaimed_rewards(mock_deps.as_ref().storage, &verifier2).unwrap(),
+            vec![(pool_id, coins(25, "AXL"))]
+        );
+    }
+
     fn make_verifier_with_no_proxy(addr: &Addr) -> Verifier {
         Verifier {
             verifier_address: addr.to_owned(),
@@ -1920,6 +3195,9 @@ mod test {
                     id: pool_id,
                     params: params_snapshot,
                     balance: Uint128::zero(),
+                    additional_denoms: BTreeMap::new(),
+                    vesting: None,
+                    scoring: None,
                 },
             )
             .unwrap();
@@ -1927,6 +3205,7 @@ mod test {
 
         let config = Config {
             rewards_denom: "AXL".to_string(),
+            payout_mode: PayoutMode::Push,
         };
 
         CONFIG.save(storage, &config).unwrap();
@@ -1967,12 +3246,16 @@ mod test {
                 id: pool_id,
                 params: params_snapshot,
                 balance: Uint128::zero(),
+                additional_denoms: BTreeMap::new(),
+                vesting: None,
+                scoring: None,
             },
         )
         .unwrap();
 
         let config = Config {
             rewards_denom: "AXL".to_string(),
+            payout_mode: PayoutMode::Push,
         };
 
         CONFIG.save(storage, &config).unwrap();
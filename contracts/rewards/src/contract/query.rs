@@ -1,4 +1,5 @@
-use cosmwasm_std::{Storage, Uint64};
+use axelar_wasm_std::nonempty;
+use cosmwasm_std::{Addr, Storage, Uint64};
 use error_stack::Result;
 
 use crate::error::ContractError;
@@ -26,6 +27,17 @@ pub fn rewards_pool(
         participation_threshold: params.participation_threshold,
         current_epoch_num: cur_epoch.epoch_num.into(),
         last_distribution_epoch,
+        additional_denoms: pool
+            .additional_denoms
+            .into_iter()
+            .map(|(denom, denom_rewards)| msg::PoolDenom {
+                denom,
+                balance: denom_rewards.balance,
+                rewards_per_epoch: denom_rewards.rewards_per_epoch,
+            })
+            .collect(),
+        vesting: pool.vesting,
+        scoring: pool.scoring,
     })
 }
 
@@ -50,14 +62,56 @@ pub fn participation(
             event_count: tally.event_count,
             participation: tally.verifier_participation(),
             rewards_by_verifier: tally.rewards_by_verifier(),
+            scores: tally.verifier_scores(),
             epoch: tally.epoch.into(),
             params: tally.params,
+            scoring: tally.scoring,
         })),
     }
 }
 
+pub fn unclaimed_rewards(
+    storage: &dyn Storage,
+    verifier: &Addr,
+) -> Result<Vec<msg::PoolRewards>, ContractError> {
+    Ok(state::load_unclaimed_rewards(storage, verifier)?
+        .into_iter()
+        .map(|(pool_id, amount)| msg::PoolRewards {
+            pool_id: pool_id.into(),
+            amount,
+        })
+        .collect())
+}
+
+pub fn vesting_rewards(
+    storage: &dyn Storage,
+    verifier: &Addr,
+    block_height: u64,
+) -> Result<Vec<msg::VestingRewards>, ContractError> {
+    Ok(state::load_vesting_rewards(storage, verifier)?
+        .iter()
+        .map(|vesting_rewards| vesting_rewards.to_msg(block_height))
+        .collect())
+}
+
+pub fn rewards_history(
+    storage: &dyn Storage,
+    verifier: &Addr,
+    start_after: Option<(PoolId, u64)>,
+    limit: nonempty::Uint32,
+) -> Result<Vec<msg::EpochRewards>, ContractError> {
+    Ok(
+        state::load_rewards_history(storage, verifier, start_after, limit.into())?
+            .into_iter()
+            .map(msg::EpochRewards::from)
+            .collect(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
     use cosmwasm_std::testing::mock_dependencies;
     use cosmwasm_std::{Uint128, Uint64};
     use msg::Participation;
@@ -91,6 +145,9 @@ mod tests {
             id: pool_id.clone(),
             balance: initial_balance,
             params: params_snapshot.clone(),
+            additional_denoms: BTreeMap::new(),
+            vesting: None,
+            scoring: None,
         };
 
         state::save_rewards_pool(storage, &rewards_pool).unwrap();
@@ -120,6 +177,9 @@ mod tests {
                     .epoch_num
                     .into(),
                 last_distribution_epoch: None,
+                additional_denoms: vec![],
+                vesting: None,
+                scoring: None,
             }
         );
     }
@@ -154,6 +214,9 @@ mod tests {
                     .epoch_num
                     .into(),
                 last_distribution_epoch: Some(last_distribution_epoch.into()),
+                additional_denoms: vec![],
+                vesting: None,
+                scoring: None,
             }
         );
     }
@@ -180,6 +243,7 @@ mod tests {
                 pool_id.clone(),
                 Epoch::current(&current_params, old_block_height).unwrap(),
                 tally_params.clone(),
+                None,
             ),
         )
         .unwrap();
@@ -198,6 +262,9 @@ mod tests {
                     .epoch_num
                     .into(),
                 last_distribution_epoch: None,
+                additional_denoms: vec![],
+                vesting: None,
+                scoring: None,
             }
         );
     }
@@ -232,9 +299,10 @@ mod tests {
             pool_id.clone(),
             epoch.clone(),
             current_params.params.clone(),
+            None,
         );
-        tally = tally.record_participation(cosmos_addr!("verifier_1"));
-        tally = tally.record_participation(cosmos_addr!("verifier_2"));
+        tally = tally.record_participation(cosmos_addr!("verifier_1"), None, false);
+        tally = tally.record_participation(cosmos_addr!("verifier_2"), None, false);
         tally.event_count = tally.event_count.saturating_add(1);
         state::save_epoch_tally(deps.as_mut().storage, &tally).unwrap();
 
@@ -246,6 +314,8 @@ mod tests {
                 .unwrap()
                 .into(),
             params: current_params.params.clone(),
+            scoring: None,
+            scores: HashMap::new(),
         };
 
         // get participation for current epoch
@@ -49,6 +49,39 @@ pub enum ContractError {
     #[error("error loading verifier proxy address")]
     LoadProxyAddress,
 
+    #[error("error loading missed epochs reporting")]
+    LoadMissedEpochsReporting,
+
+    #[error("error saving missed epochs reporting")]
+    SaveMissedEpochsReporting,
+
+    #[error("error loading consecutive missed epochs")]
+    LoadConsecutiveMissedEpochs,
+
+    #[error("error saving consecutive missed epochs")]
+    SaveConsecutiveMissedEpochs,
+
+    #[error("error saving config")]
+    SaveConfig,
+
+    #[error("error loading unclaimed rewards")]
+    LoadUnclaimedRewards,
+
+    #[error("error saving unclaimed rewards")]
+    SaveUnclaimedRewards,
+
+    #[error("error loading rewards history")]
+    LoadRewardsHistory,
+
+    #[error("error saving rewards history")]
+    SaveRewardsHistory,
+
+    #[error("error loading vesting rewards")]
+    LoadVestingRewards,
+
+    #[error("error saving vesting rewards")]
+    SaveVestingRewards,
+
     #[error("invalid event id")]
     InvalidEventId,
 
@@ -64,12 +97,18 @@ pub enum ContractError {
     #[error("no rewards to distribute")]
     NoRewardsToDistribute,
 
+    #[error("no rewards to claim")]
+    NoRewardsToClaim,
+
     #[error("caller is not authorized")]
     Unauthorized,
 
     #[error("wrong denom for rewards")]
     WrongDenom,
 
+    #[error("the rewards denom can't be added as an additional denom")]
+    InvalidAdditionalDenom,
+
     #[error("rewards amount is zero")]
     ZeroRewards,
 
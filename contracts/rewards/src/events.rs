@@ -1,7 +1,7 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Coin, Uint128};
 
-use crate::state::{Epoch, RewardsDistribution};
+use crate::state::{Epoch, Payout, RewardsDistribution};
 
 #[cw_serde]
 pub struct VerifierDistribution {
@@ -9,13 +9,28 @@ pub struct VerifierDistribution {
     pub proxy_address: Option<Addr>,
     pub amount: Uint128,
 }
+
+#[cw_serde]
+pub struct VerifierAdditionalDistribution {
+    pub verifier_address: Addr,
+    pub proxy_address: Option<Addr>,
+    pub amount: Vec<Coin>,
+}
+
 pub enum Event {
     RewardsDistributed {
         rewards: Vec<VerifierDistribution>,
+        additional_rewards: Vec<VerifierAdditionalDistribution>,
+        payout: Payout,
         epochs_processed: Vec<u64>,
         current_epoch: Epoch,
         can_distribute_more: bool,
     },
+    RewardsClaimed {
+        verifier_address: Addr,
+        recipient: Addr,
+        amount: Vec<Coin>,
+    },
 }
 
 impl From<RewardsDistribution> for Event {
@@ -30,6 +45,16 @@ impl From<RewardsDistribution> for Event {
                     amount,
                 })
                 .collect(),
+            additional_rewards: value
+                .additional_rewards
+                .into_iter()
+                .map(|(v, amount)| VerifierAdditionalDistribution {
+                    verifier_address: v.verifier_address,
+                    proxy_address: v.proxy_address,
+                    amount,
+                })
+                .collect(),
+            payout: value.payout,
             epochs_processed: value.epochs_processed,
             current_epoch: value.current_epoch,
             can_distribute_more: value.can_distribute_more,
@@ -42,6 +67,8 @@ impl From<Event> for cosmwasm_std::Event {
         match other {
             Event::RewardsDistributed {
                 rewards,
+                additional_rewards,
+                payout,
                 epochs_processed,
                 current_epoch,
                 can_distribute_more: more_epochs_to_distribute,
@@ -50,6 +77,15 @@ impl From<Event> for cosmwasm_std::Event {
                     "rewards",
                     serde_json::to_string(&rewards).expect("failed to serialize rewards"),
                 )
+                .add_attribute(
+                    "additional_rewards",
+                    serde_json::to_string(&additional_rewards)
+                        .expect("failed to serialize additional rewards"),
+                )
+                .add_attribute(
+                    "payout",
+                    serde_json::to_string(&payout).expect("failed to serialize payout"),
+                )
                 .add_attribute(
                     "epochs_processed",
                     serde_json::to_string(&epochs_processed)
@@ -61,6 +97,17 @@ impl From<Event> for cosmwasm_std::Event {
                         .expect("failed to serialize current epoch"),
                 )
                 .add_attribute("can_distribute_more", more_epochs_to_distribute.to_string()),
+            Event::RewardsClaimed {
+                verifier_address,
+                recipient,
+                amount,
+            } => cosmwasm_std::Event::new("rewards_claimed")
+                .add_attribute("verifier_address", verifier_address)
+                .add_attribute("recipient", recipient)
+                .add_attribute(
+                    "amount",
+                    serde_json::to_string(&amount).expect("failed to serialize amount"),
+                ),
         }
     }
 }
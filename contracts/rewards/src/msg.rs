@@ -2,12 +2,20 @@ use std::collections::HashMap;
 
 use axelar_wasm_std::{nonempty, Threshold};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Uint128, Uint64};
+use cosmwasm_std::{Addr, Coin, Fraction, Uint128, Uint64};
 use msgs_derive::Permissions;
 use router_api::{Address, ChainName};
 
 pub use crate::contract::MigrateMsg;
 
+// Pagination limits
+const DEFAULT_PAGINATION_LIMIT: u32 = u32::MAX;
+
+fn default_pagination_limit() -> nonempty::Uint32 {
+    nonempty::Uint32::try_from(DEFAULT_PAGINATION_LIMIT)
+        .expect("default pagination limit must be a u32")
+}
+
 #[cw_serde]
 pub struct Epoch {
     pub epoch_num: u64,
@@ -20,6 +28,17 @@ pub struct PoolId {
     pub contract: String,
 }
 
+/// Determines how distributed rewards reach the verifiers
+#[cw_serde]
+#[derive(Default)]
+pub enum PayoutMode {
+    /// Rewards are sent to each verifier (or its proxy) as part of `DistributeRewards`
+    #[default]
+    Push,
+    /// Rewards are credited to each verifier during `DistributeRewards` and must be claimed with `ClaimRewards`
+    Pull,
+}
+
 #[cw_serde]
 pub struct InstantiateMsg {
     pub governance_address: String,
@@ -42,6 +61,72 @@ pub struct Params {
     pub participation_threshold: Threshold,
 }
 
+/// Linear vesting schedule for distributed rewards
+#[cw_serde]
+pub struct Vesting {
+    /// Number of blocks over which the rewards of a distribution are released. Rewards vest linearly, starting at the block of the distribution.
+    /// Rewards that have not vested yet by the next distribution vest again together with its rewards
+    pub duration: nonempty::Uint64,
+}
+
+/// Kind of event a verifier participated in
+#[cw_serde]
+#[derive(Copy)]
+pub enum EventKind {
+    /// Vote on a poll to verify messages
+    MessagesPoll,
+    /// Vote on a poll to confirm a verifier set
+    VerifierSetPoll,
+    /// Signature in a signing session
+    SigningSession,
+}
+
+/// Weights used to score verifier participation. With scoring, rewards are split amongst the verifiers that met the
+/// participation threshold in proportion to their score, which is the sum of the weights of their participations
+#[cw_serde]
+pub struct Scoring {
+    pub messages_poll_weight: nonempty::Uint64,
+    pub verifier_set_poll_weight: nonempty::Uint64,
+    pub signing_session_weight: nonempty::Uint64,
+    /// Weight of participations recorded without an event kind
+    pub default_weight: nonempty::Uint64,
+    /// Fraction of the weight that counts for late participation, such as a signature submitted after the signing session reached quorum
+    pub late_participation_factor: Threshold,
+}
+
+impl Scoring {
+    pub fn weight(&self, event_kind: Option<EventKind>, late: bool) -> u64 {
+        let weight = match event_kind {
+            Some(EventKind::MessagesPoll) => self.messages_poll_weight,
+            Some(EventKind::VerifierSetPoll) => self.verifier_set_poll_weight,
+            Some(EventKind::SigningSession) => self.signing_session_weight,
+            None => self.default_weight,
+        };
+
+        if late {
+            Uint64::from(weight)
+                .multiply_ratio(
+                    self.late_participation_factor.numerator(),
+                    self.late_participation_factor.denominator(),
+                )
+                .u64()
+        } else {
+            weight.into()
+        }
+    }
+}
+
+/// Parameters for reporting verifiers that repeatedly miss the participation threshold to the service registry.
+/// The rewards contract must be registered as a reporter in the slashing config of the service.
+#[cw_serde]
+pub struct MissedEpochsReporting {
+    pub service_registry: String,
+    pub service_name: String,
+    /// Number of consecutive epochs in which a verifier can miss the participation threshold of a pool before it gets reported.
+    /// Epochs without any events are skipped. Verifiers are tracked from the first epoch in which they participate in a pool.
+    pub max_consecutive_missed_epochs: nonempty::Uint64,
+}
+
 #[cw_serde]
 #[derive(Permissions)]
 pub enum ExecuteMsg {
@@ -59,9 +144,16 @@ pub enum ExecuteMsg {
         chain_name: ChainName,
         event_id: nonempty::String,
         verifier_address: String,
+        /// Kind of the event, used to weight the participation if the pool has scoring enabled
+        #[serde(default)]
+        event_kind: Option<EventKind>,
+        /// True if the verifier participated after the event reached quorum, such as a signature submitted after the signing session completed
+        #[serde(default)]
+        late: bool,
     },
 
-    /// Distribute rewards up to epoch T - 2 (i.e. if we are currently in epoch 10, distribute all undistributed rewards for epochs 0-8) and send the required number of tokens to each verifier
+    /// Distribute rewards up to epoch T - 2 (i.e. if we are currently in epoch 10, distribute all undistributed rewards for epochs 0-8) and send the required number of tokens to each verifier.
+    /// In pull payout mode, the rewards are credited to each verifier instead and can be claimed with `ClaimRewards`.
     /// This call will error if the pool does not yet exist.
     #[permission(Any)]
     DistributeRewards {
@@ -71,7 +163,7 @@ pub enum ExecuteMsg {
     },
 
     /// Add tokens to an existing rewards pool.
-    /// Any attached funds with a denom matching the rewards denom are added to the pool.
+    /// Exactly one coin must be attached, either in the rewards denom or in one of the additional denoms of the pool.
     /// This call will error if the pool does not yet exist.
     #[permission(Any)]
     AddRewards { pool_id: PoolId },
@@ -93,6 +185,50 @@ pub enum ExecuteMsg {
     /// Removes any proxy address associated with the sender. Future verifier rewards will be distributed to the sender
     #[permission(Any)]
     RemoveVerifierProxy {},
+
+    /// Enables reporting of verifiers that miss the participation threshold of a pool for too many consecutive epochs.
+    /// Reports are sent when rewards are distributed. Setting `reporting` to None disables reporting and resets all tracked misses.
+    /// Callable only by governance.
+    #[permission(Governance)]
+    UpdateMissedEpochsReporting {
+        reporting: Option<MissedEpochsReporting>,
+    },
+
+    /// Sets how distributed rewards reach the verifiers. Rewards credited before switching to push mode can still be claimed.
+    /// Callable only by governance.
+    #[permission(Governance)]
+    SetPayoutMode { payout_mode: PayoutMode },
+
+    /// Allows rewards for the pool to be added and distributed in the given denom, in addition to the rewards denom,
+    /// and sets the number of tokens of that denom distributed per epoch. Overwrites the rate if the denom was already added.
+    /// Setting `rewards_per_epoch` to zero stops distributing the denom. Callable only by governance.
+    #[permission(Governance)]
+    SetPoolDenom {
+        pool_id: PoolId,
+        denom: nonempty::String,
+        rewards_per_epoch: Uint128,
+    },
+
+    /// Releases the rewards distributed from the pool through a linear vesting schedule. Vested rewards are released with `ClaimRewards`.
+    /// Setting `vesting` to None disables vesting for future distributions. Callable only by governance.
+    #[permission(Governance)]
+    SetPoolVesting {
+        pool_id: PoolId,
+        vesting: Option<Vesting>,
+    },
+
+    /// Enables scoring of verifier participation for the pool, or disables it if `scoring` is None.
+    /// Changes take effect from the next epoch. Callable only by governance.
+    #[permission(Governance)]
+    SetPoolScoring {
+        pool_id: PoolId,
+        scoring: Option<Scoring>,
+    },
+
+    /// Claims the unclaimed rewards of a verifier across all pools, including any vested rewards that have not been released yet. If `verifier_address` is not specified, the sender is the verifier.
+    /// Callable by the verifier or its proxy. The rewards are sent to the proxy address if one is set, otherwise to the verifier.
+    #[permission(Any)]
+    ClaimRewards { verifier_address: Option<String> },
 }
 
 #[cw_serde]
@@ -112,6 +248,35 @@ pub enum QueryMsg {
     /// Gets the proxy address associated with the verifier, if any
     #[returns(Option<Addr>)]
     VerifierProxy { verifier: Address },
+
+    /// Gets the parameters for reporting verifiers that miss too many consecutive epochs, if reporting is enabled
+    #[returns(Option<MissedEpochsReporting>)]
+    MissedEpochsReporting,
+
+    /// Gets how distributed rewards reach the verifiers
+    #[returns(PayoutMode)]
+    PayoutMode,
+
+    /// Gets the rewards credited to the verifier that have not been claimed yet, per pool
+    #[returns(Vec<PoolRewards>)]
+    UnclaimedRewards { verifier: String },
+
+    /// Gets the vesting rewards of the verifier across all pools that have not been fully released yet
+    #[returns(Vec<VestingRewards>)]
+    VestingRewards { verifier: String },
+
+    /// Gets the rewards the verifier earned per epoch across all pools, ordered by pool and epoch.
+    /// The history is only recorded while the payout mode is pull.
+    /// The list is paginated by:
+    /// - start_after: the pool and epoch to start after, which the next page of results should start.
+    /// - limit: limit the number of entries returned, default is u32::MAX.
+    #[returns(Vec<EpochRewards>)]
+    RewardsHistory {
+        verifier: String,
+        start_after: Option<EpochRewardsId>,
+        #[serde(default = "default_pagination_limit")]
+        limit: nonempty::Uint32,
+    },
 }
 
 #[cw_serde]
@@ -122,6 +287,16 @@ pub struct RewardsPool {
     pub participation_threshold: Threshold,
     pub current_epoch_num: Uint64,
     pub last_distribution_epoch: Option<Uint64>,
+    pub additional_denoms: Vec<PoolDenom>,
+    pub vesting: Option<Vesting>,
+    pub scoring: Option<Scoring>,
+}
+
+#[cw_serde]
+pub struct PoolDenom {
+    pub denom: String,
+    pub balance: Uint128,
+    pub rewards_per_epoch: Uint128,
 }
 
 #[cw_serde]
@@ -131,4 +306,38 @@ pub struct Participation {
     pub rewards_by_verifier: HashMap<Addr, Uint128>, // maps a verifier address to amount of rewards
     pub epoch: Epoch,
     pub params: Params,
+    pub scoring: Option<Scoring>,
+    pub scores: HashMap<Addr, u64>, // maps a verifier address to participation score. Empty if scoring is disabled
+}
+
+#[cw_serde]
+pub struct PoolRewards {
+    pub pool_id: PoolId,
+    pub amount: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct EpochRewardsId {
+    pub pool_id: PoolId,
+    pub epoch_num: u64,
+}
+
+#[cw_serde]
+pub struct EpochRewards {
+    pub pool_id: PoolId,
+    pub epoch_num: u64,
+    pub amount: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct VestingRewards {
+    pub pool_id: PoolId,
+    pub start_height: u64,
+    pub end_height: u64,
+    /// Rewards released over the vesting period
+    pub total: Vec<Coin>,
+    /// Rewards already claimed
+    pub released: Vec<Coin>,
+    /// Rewards that have vested but have not been claimed yet
+    pub releasable: Vec<Coin>,
 }
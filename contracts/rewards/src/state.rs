@@ -1,15 +1,15 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::ops::Deref;
 
 use axelar_wasm_std::{nonempty, Threshold};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Api, StdResult, Storage, Uint128};
-use cw_storage_plus::{Item, Key, KeyDeserialize, Map, Prefixer, PrimaryKey};
+use cosmwasm_std::{Addr, Api, Coin, Coins, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Item, Key, KeyDeserialize, Map, Prefixer, PrimaryKey};
 use error_stack::{Result, ResultExt};
 use router_api::ChainName;
 
 use crate::error::ContractError;
-use crate::msg::{self, Params};
+use crate::msg::{self, EventKind, Params, PayoutMode, Scoring, Vesting};
 
 /// Maps a (pool id, epoch number) pair to a tally for that epoch and rewards pool
 const TALLIES: Map<TallyId, EpochTally> = Map::new("tallies");
@@ -28,9 +28,60 @@ pub const VERIFIER_PROXY_ADDRESSES: Map<Addr, Addr> = Map::new("verifier_proxy_a
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
+const MISSED_EPOCHS_REPORTING: Item<MissedEpochsReporting> = Item::new("missed_epochs_reporting");
+
+/// Maps a (pool id, verifier) pair to the number of consecutive epochs in which the verifier missed the participation threshold of the pool.
+/// Only tracked while missed epochs reporting is enabled
+const CONSECUTIVE_MISSED_EPOCHS: Map<(PoolId, Addr), u64> = Map::new("consecutive_missed_epochs");
+
+/// Maps a (verifier, pool id) pair to the rewards credited to the verifier in pull payout mode that have not been claimed yet
+const UNCLAIMED_REWARDS: Map<(Addr, PoolId), Vec<Coin>> = Map::new("unclaimed_rewards");
+
+/// Maps a (verifier, pool id) pair to the vesting tranches of the verifier for the pool. Each tranche holds the rewards of one distribution,
+/// released through the vesting schedule of the pool that started at the block of the distribution
+const VESTING_REWARDS: Map<(Addr, PoolId), Vec<VestingRewards>> = Map::new("vesting_rewards");
+
+/// Maps a (verifier, pool id, epoch number) triple to the rewards the verifier earned in that epoch. Recorded when rewards are distributed
+const REWARDS_HISTORY: Map<(Addr, PoolId, u64), EpochRewards> = Map::new("rewards_history");
+
 #[cw_serde]
 pub struct Config {
     pub rewards_denom: String,
+    #[serde(default)]
+    pub payout_mode: PayoutMode,
+}
+
+#[cw_serde]
+pub struct MissedEpochsReporting {
+    pub service_registry: Addr,
+    pub service_name: String,
+    pub max_consecutive_missed_epochs: nonempty::Uint64,
+}
+
+impl MissedEpochsReporting {
+    pub fn try_from_msg_reporting(
+        api: &dyn Api,
+        reporting: msg::MissedEpochsReporting,
+    ) -> Result<Self, axelar_wasm_std::address::Error> {
+        Ok(Self {
+            service_registry: axelar_wasm_std::address::validate_cosmwasm_address(
+                api,
+                &reporting.service_registry,
+            )?,
+            service_name: reporting.service_name,
+            max_consecutive_missed_epochs: reporting.max_consecutive_missed_epochs,
+        })
+    }
+}
+
+impl From<MissedEpochsReporting> for msg::MissedEpochsReporting {
+    fn from(reporting: MissedEpochsReporting) -> Self {
+        Self {
+            service_registry: reporting.service_registry.into(),
+            service_name: reporting.service_name,
+            max_consecutive_missed_epochs: reporting.max_consecutive_missed_epochs,
+        }
+    }
 }
 
 #[cw_serde]
@@ -67,6 +118,15 @@ impl PoolId {
     }
 }
 
+impl From<PoolId> for msg::PoolId {
+    fn from(pool_id: PoolId) -> Self {
+        Self {
+            chain_name: pool_id.chain_name,
+            contract: pool_id.contract.into(),
+        }
+    }
+}
+
 impl PrimaryKey<'_> for PoolId {
     type Prefix = ChainName;
     type SubPrefix = ();
@@ -136,22 +196,42 @@ pub struct EpochTally {
     pub participation: HashMap<String, u64>, // maps a verifier address to participation count. Can't use Addr as key else deserialization will fail
     pub epoch: Epoch,
     pub params: Params,
+    /// Scoring of the pool when the tally was created. Rewards are split evenly if None
+    #[serde(default)]
+    pub scoring: Option<Scoring>,
+    #[serde(default)]
+    pub scores: HashMap<String, u64>, // maps a verifier address to participation score. Only tracked with scoring
 }
 
 impl EpochTally {
-    pub fn new(pool_id: PoolId, epoch: Epoch, params: Params) -> Self {
+    pub fn new(pool_id: PoolId, epoch: Epoch, params: Params, scoring: Option<Scoring>) -> Self {
         EpochTally {
             pool_id,
             event_count: 0,
             participation: HashMap::new(),
             epoch,
             params,
+            scoring,
+            scores: HashMap::new(),
         }
     }
 
     /// IMPORTANT: verifier address must be validated before calling this function
     /// TODO: panic if address is invalid?
-    pub fn record_participation(mut self, verifier: Addr) -> Self {
+    pub fn record_participation(
+        mut self,
+        verifier: Addr,
+        event_kind: Option<EventKind>,
+        late: bool,
+    ) -> Self {
+        if let Some(scoring) = &self.scoring {
+            let weight = scoring.weight(event_kind, late);
+            self.scores
+                .entry(verifier.to_string())
+                .and_modify(|score| *score = score.saturating_add(weight))
+                .or_insert(weight);
+        }
+
         self.participation
             .entry(verifier.to_string())
             .and_modify(|count| *count = count.saturating_add(1))
@@ -160,8 +240,17 @@ impl EpochTally {
     }
 
     pub fn rewards_by_verifier(&self) -> HashMap<Addr, Uint128> {
+        self.split_rewards(self.params.rewards_per_epoch.into())
+    }
+
+    /// Splits the total rewards amongst the verifiers that met the participation threshold,
+    /// in proportion to their score if scoring is enabled and evenly otherwise
+    pub fn split_rewards(&self, total_rewards: Uint128) -> HashMap<Addr, Uint128> {
         let verifiers_to_reward = self.verifiers_to_reward();
-        let total_rewards: Uint128 = self.params.rewards_per_epoch.into();
+
+        if self.scoring.is_some() {
+            return self.split_rewards_by_score(total_rewards, verifiers_to_reward);
+        }
 
         let rewards_per_verifier = total_rewards
             .checked_div(Uint128::from(verifiers_to_reward.len() as u128))
@@ -179,7 +268,45 @@ impl EpochTally {
             .collect()
     }
 
-    fn verifiers_to_reward(&self) -> Vec<Addr> {
+    fn split_rewards_by_score(
+        &self,
+        total_rewards: Uint128,
+        verifiers_to_reward: Vec<Addr>,
+    ) -> HashMap<Addr, Uint128> {
+        let scores: Vec<_> = verifiers_to_reward
+            .into_iter()
+            .map(|verifier| {
+                let score = self
+                    .scores
+                    .get(verifier.as_str())
+                    .copied()
+                    .unwrap_or_default();
+                (verifier, score)
+            })
+            .collect();
+        let total_score = scores.iter().fold(0u128, |total, (_, score)| {
+            total.saturating_add(u128::from(*score))
+        });
+
+        if total_score == 0 {
+            return HashMap::new();
+        }
+
+        scores
+            .into_iter()
+            .map(|(verifier, score)| (verifier, total_rewards.multiply_ratio(score, total_score)))
+            .filter(|(_, rewards)| !rewards.is_zero())
+            .collect()
+    }
+
+    pub fn verifier_scores(&self) -> HashMap<Addr, u64> {
+        self.scores
+            .iter()
+            .map(|(verifier, score)| (Addr::unchecked(verifier), *score)) // Ok to convert unchecked here, since we only store valid addresses
+            .collect()
+    }
+
+    pub fn verifiers_to_reward(&self) -> Vec<Addr> {
         self.participation
             .iter()
             .filter_map(|(verifier, participated)| {
@@ -267,8 +394,27 @@ impl Epoch {
 #[cw_serde]
 pub struct RewardsPool {
     pub id: PoolId,
+    /// Balance in the rewards denom
     pub balance: Uint128,
     pub params: ParamsSnapshot,
+    /// Maps each additional denom of the pool to its balance and rate
+    #[serde(default)]
+    pub additional_denoms: BTreeMap<String, DenomRewards>,
+    #[serde(default)]
+    pub vesting: Option<Vesting>,
+    #[serde(default)]
+    pub scoring: Option<Scoring>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct DenomRewards {
+    pub balance: Uint128,
+    pub rewards_per_epoch: Uint128,
+    /// Last epoch distributed in this denom. Each denom keeps its own watermark, so epochs that the balance
+    /// didn't cover are distributed once the denom is funded again
+    #[serde(default)]
+    pub last_distributed_epoch: Option<u64>,
 }
 
 impl RewardsPool {
@@ -282,6 +428,109 @@ impl RewardsPool {
     }
 }
 
+#[cw_serde]
+pub struct EpochRewards {
+    pub pool_id: PoolId,
+    pub epoch_num: u64,
+    pub amount: Vec<Coin>,
+}
+
+impl From<EpochRewards> for msg::EpochRewards {
+    fn from(rewards: EpochRewards) -> Self {
+        Self {
+            pool_id: rewards.pool_id.into(),
+            epoch_num: rewards.epoch_num,
+            amount: rewards.amount,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct VestingRewards {
+    pub pool_id: PoolId,
+    pub start_height: u64,
+    pub end_height: u64,
+    pub total: Vec<Coin>,
+    pub released: Vec<Coin>,
+}
+
+impl VestingRewards {
+    /// Returns the rewards that have vested at the given block height but have not been released yet
+    pub fn releasable(&self, block_height: u64) -> Coins {
+        let released = to_coins(self.released.clone());
+
+        let mut releasable = Coins::default();
+        for coin in &self.total {
+            releasable
+                .add(Coin::new(
+                    self.vested(coin.amount, block_height)
+                        .saturating_sub(released.amount_of(&coin.denom)),
+                    coin.denom.clone(),
+                ))
+                .expect("denoms of the vesting rewards must be unique");
+        }
+
+        releasable
+    }
+
+    fn vested(&self, amount: Uint128, block_height: u64) -> Uint128 {
+        let duration = self.end_height.saturating_sub(self.start_height);
+        let elapsed = block_height
+            .min(self.end_height)
+            .saturating_sub(self.start_height);
+
+        if elapsed >= duration {
+            amount
+        } else {
+            amount.multiply_ratio(elapsed, duration)
+        }
+    }
+
+    pub fn is_fully_released(&self) -> bool {
+        to_coins(self.total.clone()) == to_coins(self.released.clone())
+    }
+
+    pub fn to_msg(&self, block_height: u64) -> msg::VestingRewards {
+        msg::VestingRewards {
+            pool_id: self.pool_id.clone().into(),
+            start_height: self.start_height,
+            end_height: self.end_height,
+            total: self.total.clone(),
+            released: self.released.clone(),
+            releasable: self.releasable(block_height).into_vec(),
+        }
+    }
+}
+
+/// Rewards are only stored with unique denoms, so the conversion never fails
+fn to_coins(coins: Vec<Coin>) -> Coins {
+    Coins::try_from(coins).expect("stored rewards must have unique denoms")
+}
+
+fn add_coins(
+    coins: Vec<Coin>,
+    other: impl IntoIterator<Item = Coin>,
+) -> Result<Vec<Coin>, ContractError> {
+    let mut coins = to_coins(coins);
+    other
+        .into_iter()
+        .try_for_each(|coin| coins.add(coin))
+        .map_err(ContractError::from)?;
+
+    Ok(coins.into_vec())
+}
+
+/// Determines how the rewards of a distribution reach the verifiers
+#[cw_serde]
+pub enum Payout {
+    /// The rewards must be sent to the verifiers
+    Send,
+    /// The rewards were credited to the verifiers and are sent once claimed
+    Credit,
+    /// The rewards were added to the vesting rewards of the verifiers and are sent once claimed
+    Vest,
+}
+
 #[cw_serde]
 #[derive(Eq, Hash, PartialOrd, Ord)]
 pub struct Verifier {
@@ -292,6 +541,9 @@ pub struct Verifier {
 pub struct RewardsDistribution {
     /// Amount of rewards denom each verifier received
     pub rewards: HashMap<Verifier, Uint128>,
+    /// Rewards in the additional denoms of the pool each verifier received
+    pub additional_rewards: HashMap<Verifier, Vec<Coin>>,
+    pub payout: Payout,
     /// List of epochs processed for this distribution
     pub epochs_processed: Vec<u64>,
     /// Epoch in which rewards were distributed
@@ -299,10 +551,36 @@ pub struct RewardsDistribution {
     /// True if there are more rewards to distribute (later epochs that have not yet been distributed but are ready for distribution at the time of calling)
     pub can_distribute_more: bool,
 }
+
+impl RewardsDistribution {
+    /// Returns the rewards of each verifier in all denoms, ordered by verifier
+    pub fn rewards_by_verifier(&self, rewards_denom: &str) -> BTreeMap<Verifier, Vec<Coin>> {
+        let mut rewards: BTreeMap<Verifier, Vec<Coin>> = self
+            .rewards
+            .iter()
+            .map(|(verifier, amount)| (verifier.clone(), vec![Coin::new(*amount, rewards_denom)]))
+            .collect();
+
+        for (verifier, amount) in &self.additional_rewards {
+            rewards
+                .entry(verifier.clone())
+                .or_default()
+                .extend(amount.iter().cloned());
+        }
+
+        rewards
+    }
+}
 pub fn load_config(storage: &dyn Storage) -> Config {
     CONFIG.load(storage).expect("couldn't load config")
 }
 
+pub fn save_config(storage: &mut dyn Storage, config: &Config) -> Result<(), ContractError> {
+    CONFIG
+        .save(storage, config)
+        .change_context(ContractError::SaveConfig)
+}
+
 pub fn load_rewards_watermark(
     storage: &dyn Storage,
     pool_id: PoolId,
@@ -411,8 +689,8 @@ pub fn update_pool_params(
             None => Err(ContractError::RewardsPoolNotFound),
             Some(pool) => Ok(RewardsPool {
                 id: pool_id.to_owned(),
-                balance: pool.balance,
                 params: updated_params.to_owned(),
+                ..pool
             }),
         })
         .change_context(ContractError::UpdateRewardsPool)
@@ -459,6 +737,273 @@ pub fn may_load_verifier_proxy(
         .change_context(ContractError::LoadProxyAddress)
 }
 
+pub fn may_load_missed_epochs_reporting(
+    storage: &dyn Storage,
+) -> Result<Option<MissedEpochsReporting>, ContractError> {
+    MISSED_EPOCHS_REPORTING
+        .may_load(storage)
+        .change_context(ContractError::LoadMissedEpochsReporting)
+}
+
+pub fn save_missed_epochs_reporting(
+    storage: &mut dyn Storage,
+    reporting: &MissedEpochsReporting,
+) -> Result<(), ContractError> {
+    MISSED_EPOCHS_REPORTING
+        .save(storage, reporting)
+        .change_context(ContractError::SaveMissedEpochsReporting)
+}
+
+/// Disables missed epochs reporting and resets all tracked misses
+pub fn remove_missed_epochs_reporting(storage: &mut dyn Storage) {
+    MISSED_EPOCHS_REPORTING.remove(storage);
+    CONSECUTIVE_MISSED_EPOCHS.clear(storage);
+}
+
+pub fn load_consecutive_missed_epochs(
+    storage: &dyn Storage,
+    pool_id: PoolId,
+) -> Result<BTreeMap<Addr, u64>, ContractError> {
+    CONSECUTIVE_MISSED_EPOCHS
+        .prefix(pool_id)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()
+        .change_context(ContractError::LoadConsecutiveMissedEpochs)
+}
+
+pub fn save_consecutive_missed_epochs(
+    storage: &mut dyn Storage,
+    pool_id: PoolId,
+    missed_epochs: &BTreeMap<Addr, u64>,
+) -> Result<(), ContractError> {
+    missed_epochs.iter().try_for_each(|(verifier, count)| {
+        CONSECUTIVE_MISSED_EPOCHS
+            .save(storage, (pool_id.clone(), verifier.clone()), count)
+            .change_context(ContractError::SaveConsecutiveMissedEpochs)
+    })
+}
+
+pub fn remove_consecutive_missed_epochs(
+    storage: &mut dyn Storage,
+    pool_id: PoolId,
+    verifiers: impl IntoIterator<Item = Addr>,
+) {
+    verifiers.into_iter().for_each(|verifier| {
+        CONSECUTIVE_MISSED_EPOCHS.remove(storage, (pool_id.clone(), verifier));
+    })
+}
+
+/// Adds the given rewards to the unclaimed rewards of each verifier for the pool
+pub fn add_unclaimed_rewards(
+    storage: &mut dyn Storage,
+    pool_id: &PoolId,
+    rewards: &HashMap<Addr, Coins>,
+) -> Result<(), ContractError> {
+    rewards.iter().try_for_each(|(verifier, amount)| {
+        let key = (verifier.clone(), pool_id.clone());
+        let unclaimed = UNCLAIMED_REWARDS
+            .may_load(storage, key.clone())
+            .change_context(ContractError::LoadUnclaimedRewards)?
+            .unwrap_or_default();
+
+        UNCLAIMED_REWARDS
+            .save(storage, key, &add_coins(unclaimed, amount.to_vec())?)
+            .change_context(ContractError::SaveUnclaimedRewards)
+    })
+}
+
+pub fn load_unclaimed_rewards(
+    storage: &dyn Storage,
+    verifier: &Addr,
+) -> Result<Vec<(PoolId, Vec<Coin>)>, ContractError> {
+    UNCLAIMED_REWARDS
+        .prefix(verifier.clone())
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()
+        .change_context(ContractError::LoadUnclaimedRewards)
+}
+
+/// Removes all unclaimed rewards of the verifier and returns their total
+pub fn take_unclaimed_rewards(
+    storage: &mut dyn Storage,
+    verifier: &Addr,
+) -> Result<Vec<Coin>, ContractError> {
+    let unclaimed_rewards = load_unclaimed_rewards(storage, verifier)?;
+
+    unclaimed_rewards
+        .into_iter()
+        .try_fold(vec![], |total, (pool_id, amount)| {
+            UNCLAIMED_REWARDS.remove(storage, (verifier.clone(), pool_id));
+            add_coins(total, amount)
+        })
+}
+
+/// Adds the given rewards to the vesting rewards of each verifier for the pool as a new tranche that vests from the block
+/// of the distribution. Earlier tranches keep their own schedule. Tranches that have fully vested by then are credited
+/// to the unclaimed rewards of the verifier, so the number of tranches per pool, and with it the cost of claims,
+/// is bounded by the number of distributions within one vesting duration
+pub fn add_vesting_rewards(
+    storage: &mut dyn Storage,
+    pool_id: &PoolId,
+    rewards: &HashMap<Addr, Coins>,
+    start_height: u64,
+    vesting: &Vesting,
+) -> Result<(), ContractError> {
+    let end_height = start_height.saturating_add(vesting.duration.into());
+    let mut vested = HashMap::new();
+
+    for (verifier, amount) in rewards {
+        let key = (verifier.clone(), pool_id.clone());
+        let (vested_tranches, mut tranches): (Vec<_>, Vec<_>) = VESTING_REWARDS
+            .may_load(storage, key.clone())
+            .change_context(ContractError::LoadVestingRewards)?
+            .unwrap_or_default()
+            .into_iter()
+            .partition(|tranche| tranche.end_height <= start_height);
+
+        vested.insert(
+            verifier.clone(),
+            to_coins(vested_tranches.iter().try_fold(vec![], |total, tranche| {
+                add_coins(total, tranche.releasable(start_height).into_vec())
+            })?),
+        );
+
+        match tranches.iter_mut().find(|tranche| {
+            tranche.start_height == start_height && tranche.end_height == end_height
+        }) {
+            Some(tranche) => tranche.total = add_coins(tranche.total.clone(), amount.to_vec())?,
+            None => tranches.push(VestingRewards {
+                pool_id: pool_id.clone(),
+                start_height,
+                end_height,
+                total: amount.to_vec(),
+                released: vec![],
+            }),
+        }
+
+        VESTING_REWARDS
+            .save(storage, key, &tranches)
+            .change_context(ContractError::SaveVestingRewards)?;
+    }
+
+    vested.retain(|_, amount| !amount.is_empty());
+    add_unclaimed_rewards(storage, pool_id, &vested)
+}
+
+/// Returns the vesting tranches of the verifier across all pools, ordered by pool and start height
+pub fn load_vesting_rewards(
+    storage: &dyn Storage,
+    verifier: &Addr,
+) -> Result<Vec<VestingRewards>, ContractError> {
+    Ok(load_vesting_tranches(storage, verifier)?
+        .into_iter()
+        .flat_map(|(_, tranches)| tranches)
+        .collect())
+}
+
+fn load_vesting_tranches(
+    storage: &dyn Storage,
+    verifier: &Addr,
+) -> Result<Vec<(PoolId, Vec<VestingRewards>)>, ContractError> {
+    VESTING_REWARDS
+        .prefix(verifier.clone())
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()
+        .change_context(ContractError::LoadVestingRewards)
+}
+
+/// Releases the vested rewards of the verifier across all pools and returns their total.
+/// Tranches are removed once fully released
+pub fn release_vesting_rewards(
+    storage: &mut dyn Storage,
+    verifier: &Addr,
+    block_height: u64,
+) -> Result<Vec<Coin>, ContractError> {
+    load_vesting_tranches(storage, verifier)?
+        .into_iter()
+        .try_fold(vec![], |total, (pool_id, tranches)| {
+            let mut released = vec![];
+            let mut remaining = vec![];
+
+            for tranche in tranches {
+                let releasable = tranche.releasable(block_height).into_vec();
+                let tranche = VestingRewards {
+                    released: add_coins(tranche.released.clone(), releasable.clone())?,
+                    ..tranche
+                };
+                released = add_coins(released, releasable)?;
+
+                if !tranche.is_fully_released() {
+                    remaining.push(tranche);
+                }
+            }
+
+            let key = (verifier.clone(), pool_id);
+            if remaining.is_empty() {
+                VESTING_REWARDS.remove(storage, key);
+            } else {
+                VESTING_REWARDS
+                    .save(storage, key, &remaining)
+                    .change_context(ContractError::SaveVestingRewards)?;
+            }
+
+            add_coins(total, released)
+        })
+}
+
+/// Records the rewards each verifier earned in the given denom for the epoch
+pub fn save_epoch_rewards(
+    storage: &mut dyn Storage,
+    pool_id: &PoolId,
+    epoch_num: u64,
+    denom: &str,
+    rewards: &HashMap<Addr, Uint128>,
+) -> Result<(), ContractError> {
+    rewards.iter().try_for_each(|(verifier, amount)| {
+        let key = (verifier.clone(), pool_id.clone(), epoch_num);
+        let epoch_rewards = REWARDS_HISTORY
+            .may_load(storage, key.clone())
+            .change_context(ContractError::LoadRewardsHistory)?
+            .unwrap_or(EpochRewards {
+                pool_id: pool_id.clone(),
+                epoch_num,
+                amount: vec![],
+            });
+
+        REWARDS_HISTORY
+            .save(
+                storage,
+                key,
+                &EpochRewards {
+                    amount: add_coins(epoch_rewards.amount.clone(), [Coin::new(*amount, denom)])?,
+                    ..epoch_rewards
+                },
+            )
+            .change_context(ContractError::SaveRewardsHistory)
+    })
+}
+
+pub fn load_rewards_history(
+    storage: &dyn Storage,
+    verifier: &Addr,
+    start_after: Option<(PoolId, u64)>,
+    limit: usize,
+) -> Result<Vec<EpochRewards>, ContractError> {
+    // the pool id and epoch number are part of the stored value, so the raw keys don't need to be deserialized
+    REWARDS_HISTORY
+        .sub_prefix(verifier.clone())
+        .range_raw(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|res| res.map(|(_, rewards)| rewards))
+        .collect::<StdResult<_>>()
+        .change_context(ContractError::LoadRewardsHistory)
+}
+
 pub fn load_verifier(
     storage: &dyn Storage,
     verifier_addr: &Addr,
@@ -554,6 +1099,8 @@ mod test {
                 epoch_num: 1u64,
                 block_height_started: 0u64,
             },
+            scoring: None,
+            scores: HashMap::new(),
         };
 
         let test_cases = vec![
@@ -592,6 +1139,102 @@ mod test {
         }
     }
 
+    /// Test that with scoring, rewards are split in proportion to the weighted participation of the verifiers that reach quorum
+    #[test]
+    fn rewards_by_verifier_with_scoring() {
+        let scoring = Scoring {
+            messages_poll_weight: 1u64.try_into().unwrap(),
+            verifier_set_poll_weight: 1u64.try_into().unwrap(),
+            signing_session_weight: 4u64.try_into().unwrap(),
+            default_weight: 1u64.try_into().unwrap(),
+            late_participation_factor: (1, 4).try_into().unwrap(),
+        };
+        let mut tally = EpochTally::new(
+            PoolId {
+                chain_name: chain_name!(MOCK_CHAIN),
+                contract: cosmos_addr!(POOL_CONTRACT),
+            },
+            Epoch {
+                epoch_num: 1u64,
+                block_height_started: 0u64,
+            },
+            Params {
+                epoch_duration: 100u64.try_into().unwrap(),
+                rewards_per_epoch: Uint128::new(1000).try_into().unwrap(),
+                participation_threshold: (1, 2).try_into().unwrap(),
+            },
+            Some(scoring),
+        );
+
+        let verifier1 = cosmos_addr!("verifier1");
+        let verifier2 = cosmos_addr!("verifier2");
+        let verifier3 = cosmos_addr!("verifier3");
+        for _ in 0..2 {
+            tally = tally.record_participation(
+                verifier1.clone(),
+                Some(EventKind::SigningSession),
+                false,
+            );
+        }
+        tally =
+            tally.record_participation(verifier2.clone(), Some(EventKind::SigningSession), false);
+        tally =
+            tally.record_participation(verifier2.clone(), Some(EventKind::SigningSession), true);
+        // below the participation threshold, so not rewarded despite the score
+        tally =
+            tally.record_participation(verifier3.clone(), Some(EventKind::SigningSession), true);
+        tally.event_count = 3;
+
+        assert_eq!(
+            tally.verifier_scores(),
+            HashMap::from([
+                (verifier1.clone(), 8),
+                (verifier2.clone(), 5),
+                (verifier3.clone(), 1)
+            ])
+        );
+        assert_eq!(
+            tally.rewards_by_verifier(),
+            HashMap::from([
+                (verifier1, Uint128::from(615u128)),
+                (verifier2, Uint128::from(384u128)),
+            ])
+        );
+    }
+
+    #[test]
+    fn vesting_rewards_release_linearly() {
+        let vesting_rewards = VestingRewards {
+            pool_id: PoolId {
+                chain_name: chain_name!(MOCK_CHAIN),
+                contract: cosmos_addr!(POOL_CONTRACT),
+            },
+            start_height: 100,
+            end_height: 200,
+            total: vec![Coin::new(1000u128, "uaxl"), Coin::new(10u128, "uusdc")],
+            released: vec![Coin::new(100u128, "uaxl")],
+        };
+
+        assert!(vesting_rewards.releasable(50).is_empty());
+        assert!(vesting_rewards.releasable(100).is_empty());
+        assert_eq!(
+            vesting_rewards.releasable(150).into_vec(),
+            vec![Coin::new(400u128, "uaxl"), Coin::new(5u128, "uusdc")]
+        );
+        assert_eq!(
+            vesting_rewards.releasable(300).into_vec(),
+            vec![Coin::new(900u128, "uaxl"), Coin::new(10u128, "uusdc")]
+        );
+        assert!(!vesting_rewards.is_fully_released());
+
+        let vesting_rewards = VestingRewards {
+            released: vesting_rewards.total.clone(),
+            ..vesting_rewards
+        };
+        assert!(vesting_rewards.releasable(300).is_empty());
+        assert!(vesting_rewards.is_fully_released());
+    }
+
     #[test]
     fn sub_reward_from_pool() {
         let params = ParamsSnapshot {
@@ -612,6 +1255,9 @@ mod test {
             },
             balance: Uint128::from(100u128),
             params,
+            additional_denoms: BTreeMap::new(),
+            vesting: None,
+            scoring: None,
         };
         let new_pool = pool.sub_reward(Uint128::from(50u128)).unwrap();
         assert_eq!(new_pool.balance, Uint128::from(50u128));
@@ -768,9 +1414,10 @@ mod test {
                 rewards_per_epoch: rewards_rate,
                 participation_threshold: (1, 2).try_into().unwrap(),
             },
+            None,
         );
 
-        tally = tally.record_participation(cosmos_addr!("verifier"));
+        tally = tally.record_participation(cosmos_addr!("verifier"), None, false);
 
         let res = save_epoch_tally(mock_deps.as_mut().storage, &tally);
         assert!(res.is_ok());
@@ -824,6 +1471,9 @@ mod test {
             id: PoolId::new(chain_name.clone(), cosmos_addr!(SOME_CONTRACT)),
             params,
             balance: Uint128::zero(),
+            additional_denoms: BTreeMap::new(),
+            vesting: None,
+            scoring: None,
         };
         let res = save_rewards_pool(mock_deps.as_mut().storage, &pool);
         assert!(res.is_ok());
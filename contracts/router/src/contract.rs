@@ -59,7 +59,7 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
@@ -90,11 +90,26 @@ pub fn execute(
         ExecuteMsg::RouteMessages(msgs) => Ok(execute::route_messages(
             deps.storage,
             deps.querier,
+            env.block.height,
             info.sender,
             msgs,
         )?),
         ExecuteMsg::DisableRouting => execute::disable_routing(deps.storage),
         ExecuteMsg::EnableRouting => execute::enable_routing(deps.storage),
+        ExecuteMsg::SetRateLimit {
+            chain,
+            direction,
+            limit,
+        } => Ok(execute::set_rate_limit(
+            deps.storage,
+            env.block.height,
+            chain,
+            direction,
+            limit,
+        )?),
+        ExecuteMsg::SetRoutingPolicy { chain, policy } => {
+            Ok(execute::set_routing_policy(deps.storage, chain, policy)?)
+        }
     }?
     .then(Ok)
 }
@@ -130,7 +145,7 @@ fn find_coordinator_address(storage: &dyn Storage) -> error_stack::Result<Addr,
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(
     deps: Deps,
-    _env: Env,
+    env: Env,
     msg: QueryMsg,
 ) -> Result<Binary, axelar_wasm_std::error::ContractError> {
     match msg {
@@ -139,6 +154,12 @@ pub fn query(
             to_json_binary(&query::chains(deps.storage, start_after, limit)?)
         }
         QueryMsg::IsEnabled => to_json_binary(&killswitch::is_contract_active(deps.storage)),
+        QueryMsg::RateLimits(chain) => {
+            to_json_binary(&query::rate_limits(deps.storage, chain, env.block.height)?)
+        }
+        QueryMsg::RoutingPolicy(chain) => {
+            to_json_binary(&query::routing_policy(deps.storage, chain)?)
+        }
     }
     .map_err(axelar_wasm_std::error::ContractError::from)
 }
@@ -155,9 +176,10 @@ mod test {
     use cosmwasm_std::testing::{
         message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
     };
-    use cosmwasm_std::{from_json, Addr, CosmosMsg, Empty, OwnedDeps, WasmMsg};
+    use cosmwasm_std::{from_json, Addr, CosmosMsg, Empty, Event, OwnedDeps, WasmMsg};
     use permission_control::Permission;
     use router_api::error::Error;
+    use router_api::msg::{ChainRateLimits, RateLimit, RateLimitUsage, RoutingPolicy};
     use router_api::{
         address, chain_name, chain_name_raw, cosmos_addr, cosmos_address, ChainEndpoint, ChainName,
         CrossChainId, GatewayDirection, Message, FIELD_DELIMITER,
@@ -1920,4 +1942,493 @@ mod test {
             .to_string()
             .contains(&axelar_wasm_std::permission_control::Error::Unauthorized.to_string()));
     }
+
+    fn set_rate_limit(
+        deps: DepsMut,
+        chain: &Chain,
+        direction: GatewayDirection,
+        limit: Option<RateLimit>,
+    ) -> Result<Response, ContractError> {
+        execute(
+            deps,
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::SetRateLimit {
+                chain: chain.chain_name.clone(),
+                direction,
+                limit,
+            }
+            .into(),
+        )
+    }
+
+    fn rate_limit(max_messages: u64, window_blocks: u64) -> RateLimit {
+        RateLimit {
+            max_messages: max_messages.try_into().unwrap(),
+            window_blocks: window_blocks.try_into().unwrap(),
+        }
+    }
+
+    #[test]
+    fn set_rate_limit_fails_if_not_governance() {
+        let mut deps = setup();
+        let eth = make_chain(ETHEREUM);
+        register_chain(deps.as_mut(), &eth);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(ADMIN_ADDRESS), &[]),
+            ExecuteMsg::SetRateLimit {
+                chain: eth.chain_name.clone(),
+                direction: GatewayDirection::Incoming,
+                limit: Some(rate_limit(10, 100)),
+            }
+            .into(),
+        )
+        .unwrap_err();
+
+        assert_contract_err_string_contains(
+            err,
+            permission_control::Error::GeneralPermissionDenied {
+                expected: Permission::Governance.into(),
+                actual: Permission::Admin.into(),
+            },
+        );
+    }
+
+    #[test]
+    fn set_rate_limit_fails_for_unregistered_chain_or_no_direction() {
+        let mut deps = setup();
+        let eth = make_chain(ETHEREUM);
+
+        let err = set_rate_limit(
+            deps.as_mut(),
+            &eth,
+            GatewayDirection::Incoming,
+            Some(rate_limit(10, 100)),
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(err, Error::ChainNotFound);
+
+        register_chain(deps.as_mut(), &eth);
+
+        let err = set_rate_limit(
+            deps.as_mut(),
+            &eth,
+            GatewayDirection::None,
+            Some(rate_limit(10, 100)),
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(err, Error::InvalidRateLimitDirection);
+    }
+
+    #[test]
+    fn rate_limits_are_set_and_removed_per_direction() {
+        let mut deps = setup();
+        let eth = make_chain(ETHEREUM);
+        register_chain(deps.as_mut(), &eth);
+
+        let query_rate_limits = |deps: Deps| {
+            from_json::<ChainRateLimits>(
+                query(
+                    deps,
+                    mock_env(),
+                    QueryMsg::RateLimits(eth.chain_name.clone()),
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+        assert_eq!(query_rate_limits(deps.as_ref()), ChainRateLimits::default());
+
+        let res = set_rate_limit(
+            deps.as_mut(),
+            &eth,
+            GatewayDirection::Bidirectional,
+            Some(rate_limit(10, 100)),
+        )
+        .unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::from(events::RateLimitSet {
+                chain: eth.chain_name.clone(),
+                direction: GatewayDirection::Bidirectional,
+                limit: Some(rate_limit(10, 100)),
+            })]
+        );
+
+        let usage = RateLimitUsage::new(rate_limit(10, 100), mock_env().block.height);
+        assert_eq!(
+            query_rate_limits(deps.as_ref()),
+            ChainRateLimits {
+                incoming: Some(usage.clone()),
+                outgoing: Some(usage.clone()),
+            }
+        );
+
+        set_rate_limit(deps.as_mut(), &eth, GatewayDirection::Incoming, None).unwrap();
+        assert_eq!(
+            query_rate_limits(deps.as_ref()),
+            ChainRateLimits {
+                incoming: None,
+                outgoing: Some(usage),
+            }
+        );
+
+        set_rate_limit(deps.as_mut(), &eth, GatewayDirection::Outgoing, None).unwrap();
+        assert_eq!(query_rate_limits(deps.as_ref()), ChainRateLimits::default());
+    }
+
+    #[test]
+    fn route_messages_within_incoming_rate_limit() {
+        let mut deps = setup();
+        let eth = make_chain(ETHEREUM);
+        let polygon = make_chain(POLYGON);
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+
+        set_rate_limit(
+            deps.as_mut(),
+            &eth,
+            GatewayDirection::Incoming,
+            Some(rate_limit(5, 100)),
+        )
+        .unwrap();
+
+        let mut nonce = 0;
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, &mut nonce, 3)).into(),
+        )
+        .unwrap();
+        assert!(!res.events.iter().any(|e| e.ty == "rate_limit_reached"));
+
+        // the remaining capacity is used up, which emits an event
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, &mut nonce, 2)).into(),
+        )
+        .unwrap();
+        let window_start = rate_limit(5, 100).window_start(mock_env().block.height);
+        assert!(res.events.contains(&Event::from(events::RateLimitReached {
+            chain: eth.chain_name.clone(),
+            direction: GatewayDirection::Incoming,
+            window_start,
+            max_messages: 5u64.try_into().unwrap(),
+        })));
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, &mut nonce, 1)).into(),
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            Error::RateLimitExceeded {
+                chain: eth.chain_name.clone(),
+                direction: GatewayDirection::Incoming,
+            },
+        );
+
+        // messages to the rate limited chain are not affected
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&polygon.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&polygon, &eth, &mut nonce, 10)).into(),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn route_messages_exceeding_outgoing_rate_limit_fails() {
+        let mut deps = setup();
+        let eth = make_chain(ETHEREUM);
+        let polygon = make_chain(POLYGON);
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+
+        set_rate_limit(
+            deps.as_mut(),
+            &polygon,
+            GatewayDirection::Outgoing,
+            Some(rate_limit(5, 100)),
+        )
+        .unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, &mut 0, 6)).into(),
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            Error::RateLimitExceeded {
+                chain: polygon.chain_name.clone(),
+                direction: GatewayDirection::Outgoing,
+            },
+        );
+
+        // a failed batch does not count against the limit
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, &mut 0, 5)).into(),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn rate_limit_resets_in_next_window() {
+        let mut deps = setup();
+        let eth = make_chain(ETHEREUM);
+        let polygon = make_chain(POLYGON);
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+
+        set_rate_limit(
+            deps.as_mut(),
+            &eth,
+            GatewayDirection::Incoming,
+            Some(rate_limit(5, 100)),
+        )
+        .unwrap();
+
+        let mut nonce = 0;
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, &mut nonce, 5)).into(),
+        )
+        .unwrap();
+
+        let mut env = mock_env();
+        env.block.height = env.block.height.saturating_add(100);
+
+        let usage = from_json::<ChainRateLimits>(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::RateLimits(eth.chain_name.clone()),
+            )
+            .unwrap(),
+        )
+        .unwrap()
+        .incoming
+        .unwrap();
+        assert_eq!(usage.messages_in_window, 0);
+        assert_eq!(usage.remaining(), 5);
+
+        assert!(execute(
+            deps.as_mut(),
+            env,
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, &mut nonce, 5)).into(),
+        )
+        .is_ok());
+    }
+
+    fn set_routing_policy(
+        deps: DepsMut,
+        chain: &Chain,
+        policy: RoutingPolicy,
+    ) -> Result<Response, ContractError> {
+        execute(
+            deps,
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::SetRoutingPolicy {
+                chain: chain.chain_name.clone(),
+                policy,
+            }
+            .into(),
+        )
+    }
+
+    #[test]
+    fn set_routing_policy_fails_if_not_governance() {
+        let mut deps = setup();
+        let eth = make_chain(ETHEREUM);
+        register_chain(deps.as_mut(), &eth);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(ADMIN_ADDRESS), &[]),
+            ExecuteMsg::SetRoutingPolicy {
+                chain: eth.chain_name.clone(),
+                policy: RoutingPolicy::Allowlist(vec![]),
+            }
+            .into(),
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            permission_control::Error::GeneralPermissionDenied {
+                expected: Permission::Governance.into(),
+                actual: Permission::Admin.into(),
+            },
+        );
+    }
+
+    #[test]
+    fn set_routing_policy_fails_for_unregistered_chain() {
+        let mut deps = setup();
+        let eth = make_chain(ETHEREUM);
+
+        let err =
+            set_routing_policy(deps.as_mut(), &eth, RoutingPolicy::Allowlist(vec![])).unwrap_err();
+        assert_contract_err_string_contains(err, Error::ChainNotFound);
+
+        assert!(query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::RoutingPolicy(eth.chain_name.clone()),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn routing_policy_can_be_set_and_queried() {
+        let mut deps = setup();
+        let eth = make_chain(ETHEREUM);
+        let polygon = make_chain(POLYGON);
+        register_chain(deps.as_mut(), &eth);
+
+        let query_routing_policy = |deps: Deps| {
+            from_json::<RoutingPolicy>(
+                query(
+                    deps,
+                    mock_env(),
+                    QueryMsg::RoutingPolicy(eth.chain_name.clone()),
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+        assert_eq!(query_routing_policy(deps.as_ref()), RoutingPolicy::AllowAll);
+
+        let policy = RoutingPolicy::Denylist(vec![polygon.chain_name.clone()]);
+        let res = set_routing_policy(deps.as_mut(), &eth, policy.clone()).unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::from(events::RoutingPolicySet {
+                chain: eth.chain_name.clone(),
+                policy: policy.clone(),
+            })]
+        );
+        assert_eq!(query_routing_policy(deps.as_ref()), policy);
+
+        set_routing_policy(deps.as_mut(), &eth, RoutingPolicy::AllowAll).unwrap();
+        assert_eq!(query_routing_policy(deps.as_ref()), RoutingPolicy::AllowAll);
+    }
+
+    #[test]
+    fn allowlist_restricts_destination_chains() {
+        let mut deps = setup();
+        let eth = make_chain(ETHEREUM);
+        let polygon = make_chain(POLYGON);
+        let avalanche = make_chain("avalanche");
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+        register_chain(deps.as_mut(), &avalanche);
+
+        set_routing_policy(
+            deps.as_mut(),
+            &eth,
+            RoutingPolicy::Allowlist(vec![polygon.chain_name.clone()]),
+        )
+        .unwrap();
+
+        let mut nonce = 0;
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, &mut nonce, 2)).into(),
+        )
+        .is_ok());
+
+        // a single disallowed message rejects the whole batch
+        let msgs = generate_messages(&eth, &polygon, &mut nonce, 1)
+            .into_iter()
+            .chain(generate_messages(&eth, &avalanche, &mut nonce, 1))
+            .collect();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(msgs).into(),
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            Error::RoutingNotAllowed {
+                source_chain: eth.chain_name.clone(),
+                destination_chain: avalanche.chain_name.clone(),
+            },
+        );
+
+        // the policy only applies to messages from the chain it is set for
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&avalanche.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&avalanche, &eth, &mut nonce, 1)).into(),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn denylist_rejects_listed_destination_chains() {
+        let mut deps = setup();
+        let eth = make_chain(ETHEREUM);
+        let polygon = make_chain(POLYGON);
+        let avalanche = make_chain("avalanche");
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+        register_chain(deps.as_mut(), &avalanche);
+
+        set_routing_policy(
+            deps.as_mut(),
+            &eth,
+            RoutingPolicy::Denylist(vec![avalanche.chain_name.clone()]),
+        )
+        .unwrap();
+
+        let mut nonce = 0;
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, &mut nonce, 1)).into(),
+        )
+        .is_ok());
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &avalanche, &mut nonce, 1)).into(),
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            Error::RoutingNotAllowed {
+                source_chain: eth.chain_name.clone(),
+                destination_chain: avalanche.chain_name.clone(),
+            },
+        );
+    }
 }
@@ -11,10 +11,12 @@ use cosmwasm_std::{
 use error_stack::{bail, ensure, report, Report, ResultExt};
 use itertools::Itertools;
 use router_api::error::Error;
+use router_api::msg::{RateLimit, RateLimitUsage, RoutingPolicy};
 use router_api::{ChainEndpoint, ChainName, Gateway, GatewayDirection, Message};
 
 use crate::events::{
     ChainFrozen, ChainRegistered, ChainUnfrozen, GatewayInfo, GatewayUpgraded, MessageRouted,
+    RateLimitReached, RateLimitSet, RoutingPolicySet,
 };
 use crate::state::{chain_endpoints, Config};
 use crate::{events, state};
@@ -162,6 +164,106 @@ pub fn enable_routing(storage: &mut dyn Storage) -> Result<Response, Error> {
     killswitch::disengage(storage, events::RoutingEnabled).map_err(|err| err.into())
 }
 
+pub fn set_rate_limit(
+    storage: &mut dyn Storage,
+    block_height: u64,
+    chain: ChainName,
+    direction: GatewayDirection,
+    limit: Option<RateLimit>,
+) -> error_stack::Result<Response, Error> {
+    ensure!(
+        direction != GatewayDirection::None,
+        Error::InvalidRateLimitDirection
+    );
+    ensure!(
+        state::load_chain_by_chain_name(storage, &chain)?.is_some(),
+        Error::ChainNotFound
+    );
+
+    let mut rate_limits = state::load_rate_limits(storage, &chain)?;
+    let usage = limit
+        .clone()
+        .map(|limit| RateLimitUsage::new(limit, block_height));
+    let directions = FlagSet::from(direction);
+
+    if directions.contains(GatewayDirection::Incoming) {
+        rate_limits.incoming = usage.clone();
+    }
+    if directions.contains(GatewayDirection::Outgoing) {
+        rate_limits.outgoing = usage;
+    }
+
+    state::save_rate_limits(storage, &chain, &rate_limits)?;
+
+    Ok(Response::new().add_event(RateLimitSet {
+        chain,
+        direction,
+        limit,
+    }))
+}
+
+pub fn set_routing_policy(
+    storage: &mut dyn Storage,
+    chain: ChainName,
+    policy: RoutingPolicy,
+) -> error_stack::Result<Response, Error> {
+    ensure!(
+        state::load_chain_by_chain_name(storage, &chain)?.is_some(),
+        Error::ChainNotFound
+    );
+
+    state::save_routing_policy(storage, &chain, &policy)?;
+
+    Ok(Response::new().add_event(RoutingPolicySet { chain, policy }))
+}
+
+/// Counts the messages against the rate limit of the chain in the given direction, if there is one.
+/// Fails if the messages don't all fit into the remaining capacity of the current window, so a batch is never routed
+/// partially. Returns an event if the messages use up the remaining capacity of the current window.
+fn consume_rate_limit(
+    storage: &mut dyn Storage,
+    chain: &ChainName,
+    direction: GatewayDirection,
+    msg_count: usize,
+    block_height: u64,
+) -> error_stack::Result<Option<RateLimitReached>, Error> {
+    let mut rate_limits = state::load_rate_limits(storage, chain)?;
+    let usage = match direction {
+        GatewayDirection::Incoming => &mut rate_limits.incoming,
+        GatewayDirection::Outgoing => &mut rate_limits.outgoing,
+        _ => unreachable!("rate limits are consumed in a single direction"),
+    };
+
+    let Some(current) = usage.take().map(|usage| usage.at(block_height)) else {
+        return Ok(None);
+    };
+
+    let msg_count = u64::try_from(msg_count).unwrap_or(u64::MAX);
+    ensure!(
+        msg_count <= current.remaining(),
+        Error::RateLimitExceeded {
+            chain: chain.clone(),
+            direction,
+        }
+    );
+
+    let current = RateLimitUsage {
+        messages_in_window: current.messages_in_window.saturating_add(msg_count),
+        ..current
+    };
+    let event = (current.remaining() == 0).then(|| RateLimitReached {
+        chain: chain.clone(),
+        direction,
+        window_start: current.window_start,
+        max_messages: current.limit.max_messages,
+    });
+
+    *usage = Some(current);
+    state::save_rate_limits(storage, chain, &rate_limits)?;
+
+    Ok(event)
+}
+
 fn verify_msg_ids(
     msgs: &[Message],
     expected_format: &MessageIdFormat,
@@ -176,14 +278,16 @@ fn validate_msgs(
     config: Config,
     sender: &Addr,
     msgs: Vec<Message>,
-) -> error_stack::Result<Vec<Message>, Error> {
+) -> error_stack::Result<(Option<ChainName>, Vec<Message>), Error> {
     // If sender is the nexus gateway, we cannot validate the source chain
     // because the source chain is registered in the core nexus module.
     // All messages received from the nexus gateway must adhere to the
     // HexTxHashAndEventIndex message ID format.
+    // For the same reason, routing policies and incoming rate limits,
+    // which only exist for chains registered with the router, don't apply.
     if sender == config.axelarnet_gateway {
         verify_msg_ids(&msgs, &MessageIdFormat::HexTxHashAndEventIndex)?;
-        return Ok(msgs);
+        return Ok((None, msgs));
     }
 
     let source_chain = state::load_chain_by_gateway(storage, sender)?;
@@ -202,12 +306,24 @@ fn validate_msgs(
 
     verify_msg_ids(&msgs, &source_chain.msg_id_format)?;
 
-    Ok(msgs)
+    let policy = state::load_routing_policy(storage, &source_chain.name)?;
+    if let Some(msg) = msgs
+        .iter()
+        .find(|msg| !policy.allows(&msg.destination_chain))
+    {
+        bail!(Error::RoutingNotAllowed {
+            source_chain: source_chain.name,
+            destination_chain: msg.destination_chain.clone(),
+        });
+    }
+
+    Ok((Some(source_chain.name), msgs))
 }
 
 pub fn route_messages(
-    storage: &dyn Storage,
+    storage: &mut dyn Storage,
     querier: QuerierWrapper,
+    block_height: u64,
     sender: Addr,
     msgs: Vec<Message>,
 ) -> error_stack::Result<Response, Error> {
@@ -219,46 +335,64 @@ pub fn route_messages(
     let config = state::load_config(storage)?;
     let client: nexus::Client = client::CosmosClient::new(querier).into();
 
-    let msgs = validate_msgs(storage, config.clone(), &sender, msgs)?;
+    let (source_chain, msgs) = validate_msgs(storage, config.clone(), &sender, msgs)?;
+
+    let mut rate_limit_events = vec![];
+    if let Some(source_chain) = source_chain {
+        rate_limit_events.extend(consume_rate_limit(
+            storage,
+            &source_chain,
+            GatewayDirection::Incoming,
+            msgs.len(),
+            block_height,
+        )?);
+    }
 
-    let wasm_msgs = msgs
-        .iter()
-        .chunk_by(|msg| msg.destination_chain.to_owned())
-        .into_iter()
-        .map(|(destination_chain, msgs)| {
-            let gateway = match state::load_chain_by_chain_name(storage, &destination_chain)? {
-                Some(destination_chain) if destination_chain.outgoing_frozen() => {
-                    return Err(report!(Error::ChainFrozen {
-                        chain: destination_chain.name,
-                    }));
-                }
-                Some(destination_chain) => destination_chain.gateway.address,
-                // messages with unknown destination chains are routed to
-                // the axelarnet gateway if the sender is not the nexus gateway
-                // itself
-                None if client
-                    .is_chain_registered(&destination_chain)
-                    .change_context(Error::Nexus)? =>
-                {
-                    config.axelarnet_gateway.clone()
-                }
-                _ => return Err(report!(Error::ChainNotFound)),
-            };
-
-            Ok(WasmMsg::Execute {
-                contract_addr: gateway.to_string(),
-                msg: to_json_binary(&gateway_api::msg::ExecuteMsg::RouteMessages(
-                    msgs.cloned().collect(),
-                ))
+    let mut wasm_msgs = vec![];
+    for (destination_chain, msgs) in &msgs.iter().chunk_by(|msg| msg.destination_chain.to_owned()) {
+        let msgs: Vec<Message> = msgs.cloned().collect();
+
+        let gateway = match state::load_chain_by_chain_name(storage, &destination_chain)? {
+            Some(destination_chain) if destination_chain.outgoing_frozen() => {
+                bail!(Error::ChainFrozen {
+                    chain: destination_chain.name,
+                });
+            }
+            Some(destination_chain) => {
+                rate_limit_events.extend(consume_rate_limit(
+                    storage,
+                    &destination_chain.name,
+                    GatewayDirection::Outgoing,
+                    msgs.len(),
+                    block_height,
+                )?);
+
+                destination_chain.gateway.address
+            }
+            // messages with unknown destination chains are routed to
+            // the axelarnet gateway if the sender is not the nexus gateway
+            // itself
+            None if client
+                .is_chain_registered(&destination_chain)
+                .change_context(Error::Nexus)? =>
+            {
+                config.axelarnet_gateway.clone()
+            }
+            _ => bail!(Error::ChainNotFound),
+        };
+
+        wasm_msgs.push(WasmMsg::Execute {
+            contract_addr: gateway.to_string(),
+            msg: to_json_binary(&gateway_api::msg::ExecuteMsg::RouteMessages(msgs))
                 .expect("must serialize message"),
-                funds: vec![],
-            })
-        })
-        .collect::<Result<Vec<_>, _>>()?;
+            funds: vec![],
+        });
+    }
 
     Ok(Response::new()
         .add_messages(wasm_msgs)
-        .add_events(msgs.into_iter().map(|msg| MessageRouted { msg })))
+        .add_events(msgs.into_iter().map(|msg| MessageRouted { msg }))
+        .add_events(rate_limit_events))
 }
 
 #[cfg(test)]
@@ -346,8 +480,9 @@ mod test {
         .unwrap();
 
         assert!(route_messages(
-            &deps.storage,
+            &mut deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![rand_message(source_chain, destination_chain)]
         )
@@ -387,8 +522,9 @@ mod test {
             .unwrap();
 
         assert!(route_messages(
-            &deps.storage,
+            &mut deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![rand_message(source_chain.clone(), destination_chain)]
         )
@@ -430,8 +566,9 @@ mod test {
             .unwrap();
 
         assert!(route_messages(
-            &deps.storage,
+            &mut deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![rand_message(chain_name!(POLYGON), destination_chain)]
         )
@@ -488,7 +625,7 @@ mod test {
             )
             .unwrap();
 
-        assert!(route_messages(&deps.storage, QuerierWrapper::new(&deps.querier), sender, vec![rand_message(source_chain, destination_chain.clone())])
+        assert!(route_messages(&mut deps.storage, QuerierWrapper::new(&deps.querier), mock_env().block.height, sender, vec![rand_message(source_chain, destination_chain.clone())])
             .is_err_and(move |err| {
                 matches!(err.current_context(), Error::ChainFrozen { chain } if *chain == destination_chain)
             }));
@@ -534,8 +671,9 @@ mod test {
         msg.cc_id = CrossChainId::new(source_chain, "foobar").unwrap();
         assert_err_contains!(
             route_messages(
-                &deps.storage,
+                &mut deps.storage,
                 QuerierWrapper::new(&deps.querier),
+                mock_env().block.height,
                 sender,
                 vec![msg]
             ),
@@ -568,8 +706,9 @@ mod test {
         msg.cc_id = CrossChainId::new(source_chain, "foobar").unwrap();
         assert_err_contains!(
             route_messages(
-                &deps.storage,
+                &mut deps.storage,
                 QuerierWrapper::new(&deps.querier),
+                mock_env().block.height,
                 sender,
                 vec![msg]
             ),
@@ -628,8 +767,9 @@ mod test {
 
         assert_err_contains!(
             route_messages(
-                &deps.storage,
+                &mut deps.storage,
                 QuerierWrapper::new(&deps.querier),
+                mock_env().block.height,
                 sender,
                 vec![msg]
             ),
@@ -706,8 +846,9 @@ mod test {
             .unwrap();
 
         assert!(route_messages(
-            &deps.storage,
+            &mut deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![
                 rand_message(source_chain.clone(), destination_chain_1.clone()),
@@ -772,8 +913,9 @@ mod test {
             .unwrap();
 
         assert!(route_messages(
-            &deps.storage,
+            &mut deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![
                 rand_message(source_chain.clone(), destination_chain_1.clone()),
@@ -810,8 +952,9 @@ mod test {
         .unwrap();
 
         assert!(route_messages(
-            &deps.storage,
+            &mut deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![rand_message(
                 source_chain.clone(),
@@ -861,8 +1004,9 @@ mod test {
             .unwrap();
 
         assert!(route_messages(
-            &deps.storage,
+            &mut deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![rand_message(
                 source_chain.clone(),
@@ -3,8 +3,10 @@ use cosmwasm_std::{Order, Storage};
 use cw_storage_plus::Bound;
 use error_stack::{Result, ResultExt};
 use router_api::error::Error;
+use router_api::msg::{ChainRateLimits, RoutingPolicy};
 use router_api::{ChainEndpoint, ChainName};
 
+use crate::state;
 use crate::state::chain_endpoints;
 
 pub fn chain_info(storage: &dyn Storage, chain: ChainName) -> Result<ChainEndpoint, Error> {
@@ -31,6 +33,27 @@ pub fn chains(
         .collect()
 }
 
+pub fn rate_limits(
+    storage: &dyn Storage,
+    chain: ChainName,
+    block_height: u64,
+) -> Result<ChainRateLimits, Error> {
+    chain_info(storage, chain.clone())?;
+
+    let rate_limits = state::load_rate_limits(storage, &chain)?;
+
+    Ok(ChainRateLimits {
+        incoming: rate_limits.incoming.map(|usage| usage.at(block_height)),
+        outgoing: rate_limits.outgoing.map(|usage| usage.at(block_height)),
+    })
+}
+
+pub fn routing_policy(storage: &dyn Storage, chain: ChainName) -> Result<RoutingPolicy, Error> {
+    chain_info(storage, chain.clone())?;
+
+    state::load_routing_policy(storage, &chain)
+}
+
 #[cfg(test)]
 mod test {
     use axelar_wasm_std::flagset::FlagSet;
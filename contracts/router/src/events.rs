@@ -1,4 +1,6 @@
+use axelar_wasm_std::nonempty;
 use cosmwasm_std::{Addr, Attribute, Event};
+use router_api::msg::{RateLimit, RoutingPolicy};
 use router_api::{ChainName, GatewayDirection, Message};
 
 pub struct RouterInstantiated {
@@ -32,6 +34,24 @@ pub struct ChainUnfrozen {
     pub direction: GatewayDirection,
 }
 
+pub struct RateLimitSet {
+    pub chain: ChainName,
+    pub direction: GatewayDirection,
+    pub limit: Option<RateLimit>,
+}
+
+pub struct RateLimitReached {
+    pub chain: ChainName,
+    pub direction: GatewayDirection,
+    pub window_start: u64,
+    pub max_messages: nonempty::Uint64,
+}
+
+pub struct RoutingPolicySet {
+    pub chain: ChainName,
+    pub policy: RoutingPolicy,
+}
+
 pub struct MessageRouted {
     pub msg: Message,
 }
@@ -105,6 +125,45 @@ impl From<ChainUnfrozen> for Event {
     }
 }
 
+impl From<RateLimitSet> for Event {
+    fn from(other: RateLimitSet) -> Self {
+        Event::new("rate_limit_set")
+            .add_attribute("chain", other.chain)
+            .add_attribute(
+                "direction",
+                serde_json::to_string(&other.direction).expect("failed to serialize direction"),
+            )
+            .add_attribute(
+                "limit",
+                serde_json::to_string(&other.limit).expect("failed to serialize rate limit"),
+            )
+    }
+}
+
+impl From<RateLimitReached> for Event {
+    fn from(other: RateLimitReached) -> Self {
+        Event::new("rate_limit_reached")
+            .add_attribute("chain", other.chain)
+            .add_attribute(
+                "direction",
+                serde_json::to_string(&other.direction).expect("failed to serialize direction"),
+            )
+            .add_attribute("window_start", other.window_start.to_string())
+            .add_attribute("max_messages", other.max_messages.to_string())
+    }
+}
+
+impl From<RoutingPolicySet> for Event {
+    fn from(other: RoutingPolicySet) -> Self {
+        Event::new("routing_policy_set")
+            .add_attribute("chain", other.chain)
+            .add_attribute(
+                "policy",
+                serde_json::to_string(&other.policy).expect("failed to serialize routing policy"),
+            )
+    }
+}
+
 impl From<MessageRouted> for Event {
     fn from(other: MessageRouted) -> Self {
         let attrs: Vec<Attribute> = other.msg.into();
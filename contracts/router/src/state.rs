@@ -1,8 +1,9 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Order, StdResult, Storage};
-use cw_storage_plus::{Index, IndexList, IndexedMap, Item, MultiIndex};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 use error_stack::{report, ResultExt};
 use router_api::error::Error;
+use router_api::msg::{ChainRateLimits, RoutingPolicy};
 use router_api::{ChainEndpoint, ChainName};
 
 pub fn save_config(storage: &mut dyn Storage, config: &Config) -> error_stack::Result<(), Error> {
@@ -35,6 +36,56 @@ pub fn load_chain_by_gateway(
         .ok_or(report!(Error::GatewayNotRegistered))
 }
 
+pub fn load_rate_limits(
+    storage: &dyn Storage,
+    chain_name: &ChainName,
+) -> error_stack::Result<ChainRateLimits, Error> {
+    RATE_LIMITS
+        .may_load(storage, chain_name.clone())
+        .change_context(Error::StoreFailure)
+        .map(Option::unwrap_or_default)
+}
+
+pub fn save_rate_limits(
+    storage: &mut dyn Storage,
+    chain_name: &ChainName,
+    rate_limits: &ChainRateLimits,
+) -> error_stack::Result<(), Error> {
+    if rate_limits.incoming.is_none() && rate_limits.outgoing.is_none() {
+        RATE_LIMITS.remove(storage, chain_name.clone());
+        return Ok(());
+    }
+
+    RATE_LIMITS
+        .save(storage, chain_name.clone(), rate_limits)
+        .change_context(Error::StoreFailure)
+}
+
+pub fn load_routing_policy(
+    storage: &dyn Storage,
+    chain_name: &ChainName,
+) -> error_stack::Result<RoutingPolicy, Error> {
+    ROUTING_POLICIES
+        .may_load(storage, chain_name.clone())
+        .change_context(Error::StoreFailure)
+        .map(Option::unwrap_or_default)
+}
+
+pub fn save_routing_policy(
+    storage: &mut dyn Storage,
+    chain_name: &ChainName,
+    policy: &RoutingPolicy,
+) -> error_stack::Result<(), Error> {
+    if *policy == RoutingPolicy::AllowAll {
+        ROUTING_POLICIES.remove(storage, chain_name.clone());
+        return Ok(());
+    }
+
+    ROUTING_POLICIES
+        .save(storage, chain_name.clone(), policy)
+        .change_context(Error::StoreFailure)
+}
+
 #[cw_serde]
 pub struct Config {
     pub axelarnet_gateway: Addr,
@@ -43,6 +94,10 @@ pub struct Config {
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
+const RATE_LIMITS: Map<ChainName, ChainRateLimits> = Map::new("rate_limits");
+
+const ROUTING_POLICIES: Map<ChainName, RoutingPolicy> = Map::new("routing_policies");
+
 pub struct ChainEndpointIndexes<'a> {
     pub gateway: GatewayIndex<'a>,
 }
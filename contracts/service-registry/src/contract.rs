@@ -6,7 +6,7 @@ use cosmwasm_std::{
 };
 use error_stack::{bail, Report, ResultExt};
 use service_registry_api::error::ContractError;
-use service_registry_api::{AuthorizationState, BondingState, Service};
+use service_registry_api::{AuthorizationState, BondingState, Service, SlashingConfig};
 
 use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
 use crate::state::{self, VERIFIERS};
@@ -42,7 +42,7 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
-    match msg.ensure_permissions(deps.storage, &info.sender, match_verifier)? {
+    match msg.ensure_permissions(deps.storage, &info.sender, match_reporter, match_verifier)? {
         ExecuteMsg::RegisterService {
             service_name,
             coordinator_contract,
@@ -52,6 +52,7 @@ pub fn execute(
             bond_denom,
             unbonding_period_days,
             description,
+            weighting_mode,
         } => {
             let coordinator_contract = deps.api.addr_validate(&coordinator_contract)?;
             execute::register_service(
@@ -64,6 +65,7 @@ pub fn execute(
                 bond_denom,
                 unbonding_period_days,
                 description,
+                weighting_mode,
             )
         }
         ExecuteMsg::UpdateService {
@@ -117,16 +119,70 @@ pub fn execute(
         ExecuteMsg::JailVerifiers {
             verifiers,
             service_name,
+            jail_duration_days,
         } => {
             let verifiers = verifiers
                 .into_iter()
                 .map(|verifier| address::validate_cosmwasm_address(deps.api, &verifier))
                 .collect::<Result<Vec<_>, _>>()?;
-            execute::update_verifier_authorization_status(
+            execute::jail_verifiers(deps, env, verifiers, service_name, jail_duration_days)
+        }
+        ExecuteMsg::UnjailVerifiers {
+            verifiers,
+            service_name,
+        } => {
+            let verifiers = verifiers
+                .into_iter()
+                .map(|verifier| address::validate_cosmwasm_address(deps.api, &verifier))
+                .collect::<Result<Vec<_>, _>>()?;
+            execute::unjail_verifiers(deps, verifiers, service_name)
+        }
+        ExecuteMsg::SetSlashingConfig {
+            service_name,
+            reporters,
+            slash_fraction,
+            slashed_funds_recipient,
+            jail_duration_days,
+            incorrect_votes_threshold,
+        } => {
+            let reporters = reporters
+                .into_iter()
+                .map(|reporter| address::validate_cosmwasm_address(deps.api, &reporter))
+                .collect::<Result<Vec<_>, _>>()?;
+            let slashed_funds_recipient =
+                address::validate_cosmwasm_address(deps.api, &slashed_funds_recipient)?;
+            execute::set_slashing_config(
                 deps,
-                verifiers,
                 service_name,
-                AuthorizationState::Jailed,
+                SlashingConfig {
+                    reporters,
+                    slash_fraction,
+                    slashed_funds_recipient,
+                    jail_duration_days,
+                    incorrect_votes_threshold,
+                },
+            )
+        }
+        ExecuteMsg::RemoveSlashingConfig { service_name } => {
+            execute::remove_slashing_config(deps, service_name)
+        }
+        ExecuteMsg::ReportMisbehaviour {
+            service_name,
+            chain_name,
+            verifiers,
+            misbehaviour,
+        } => {
+            let verifiers = verifiers
+                .into_iter()
+                .map(|verifier| address::validate_cosmwasm_address(deps.api, &verifier))
+                .collect::<Result<Vec<_>, _>>()?;
+            execute::report_misbehaviour(
+                deps,
+                env,
+                service_name,
+                chain_name,
+                verifiers,
+                misbehaviour,
             )
         }
         ExecuteMsg::RegisterChainSupport {
@@ -146,10 +202,29 @@ pub fn execute(
         ExecuteMsg::ClaimStake { service_name } => {
             execute::claim_stake(deps, env, info, service_name)
         }
+        ExecuteMsg::UnjailVerifier { service_name } => {
+            execute::unjail_verifier(deps, env, info, service_name)
+        }
     }?
     .then(Ok)
 }
 
+fn match_reporter(
+    storage: &dyn Storage,
+    sender_addr: &Addr,
+    msg: &ExecuteMsg,
+) -> Result<bool, Report<permission_control::Error>> {
+    let service_name = match msg {
+        ExecuteMsg::ReportMisbehaviour { service_name, .. } => service_name,
+        _ => bail!(permission_control::Error::WrongVariant),
+    };
+
+    let slashing_config = state::may_load_slashing_config(storage, service_name)
+        .change_context(permission_control::Error::Unauthorized)?;
+
+    Ok(slashing_config.is_some_and(|config| config.reporters.contains(sender_addr)))
+}
+
 fn match_verifier(
     storage: &dyn Storage,
     sender_addr: &Addr,
@@ -211,6 +286,47 @@ pub fn query(
             chain_name,
         )?)
         .map_err(|err| err.into()),
+        QueryMsg::SlashingConfig { service_name } => {
+            to_json_binary(&query::slashing_config(deps, service_name)?).map_err(|err| err.into())
+        }
+        QueryMsg::JailedVerifiers {
+            service_name,
+            start_after,
+            limit,
+        } => to_json_binary(&query::jailed_verifiers(
+            deps,
+            service_name,
+            start_after,
+            limit,
+        )?)
+        .map_err(|err| err.into()),
+        QueryMsg::Services { start_after, limit } => {
+            to_json_binary(&query::services(deps, start_after, limit)?).map_err(|err| err.into())
+        }
+        QueryMsg::Verifiers {
+            service_name,
+            filter,
+            start_after,
+            limit,
+        } => to_json_binary(&query::verifiers(
+            deps,
+            service_name,
+            filter,
+            start_after,
+            limit,
+        )?)
+        .map_err(|err| err.into()),
+        QueryMsg::SupportedChains {
+            service_name,
+            start_after,
+            limit,
+        } => to_json_binary(&query::supported_chains(
+            deps,
+            service_name,
+            start_after,
+            limit,
+        )?)
+        .map_err(|err| err.into()),
     }
 }
 
@@ -219,7 +335,7 @@ mod test {
     use std::collections::HashSet;
 
     use axelar_wasm_std::error::err_contains;
-    use axelar_wasm_std::nonempty;
+    use axelar_wasm_std::{nonempty, Threshold};
     use cosmwasm_std::testing::{
         message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
     };
@@ -227,16 +343,23 @@ mod test {
         coins, from_json, Api, CosmosMsg, Empty, OwnedDeps, StdResult, Uint128, WasmQuery,
     };
     use router_api::{chain_name, cosmos_addr, ChainName};
-    use service_registry_api::{Verifier, WeightedVerifier};
+    use service_registry_api::{
+        JailReason, JailedVerifier, Misbehaviour, Verifier, WeightedVerifier, WeightingMode,
+    };
 
     use super::*;
-    use crate::msg::{ServiceParamsOverride, UpdatedServiceParams, VerifierDetails};
+    use crate::msg::{
+        BondingStatus, ServiceParamsOverride, UpdatedServiceParams, VerifierDetails, VerifierFilter,
+    };
     use crate::state::VERIFIER_WEIGHT;
 
     const GOVERNANCE_ADDRESS: &str = "governance";
     const UNAUTHORIZED_ADDRESS: &str = "unauthorized";
     const COORDINATOR_ADDRESS: &str = "coordinator";
     const VERIFIER_ADDRESS: &str = "verifier";
+    const REPORTER_ADDRESS: &str = "reporter";
+    const TREASURY_ADDRESS: &str = "treasury";
+    const JAIL_DURATION_DAYS: u16 = 7;
     const AXL_DENOMINATION: &str = "uaxl";
     const SOLANA: &str = "solana";
     const ETHEREUM: &str = "ethereum";
@@ -308,6 +431,7 @@ mod test {
             bond_denom: AXL_DENOMINATION.into(),
             unbonding_period_days: 10,
             description: "amplifier service".into(),
+            weighting_mode: WeightingMode::Uniform,
         };
         let res = execute(
             deps,
@@ -322,6 +446,7 @@ mod test {
                 bond_denom: service.bond_denom.clone(),
                 unbonding_period_days: service.unbonding_period_days,
                 description: service.description.clone(),
+                weighting_mode: service.weighting_mode.clone(),
             },
         );
         assert!(res.is_ok());
@@ -379,6 +504,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(response.is_ok());
@@ -424,6 +550,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -441,6 +568,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         )
         .unwrap_err();
@@ -472,6 +600,7 @@ mod test {
                     .unwrap(),
             ),
             unbonding_period_days: Some(service.unbonding_period_days + 1),
+            weighting_mode: Some(WeightingMode::Sqrt),
         };
 
         let res = execute(
@@ -503,6 +632,7 @@ mod test {
             min_num_verifiers: updated_params.min_num_verifiers.unwrap(),
             min_verifier_bond: updated_params.min_verifier_bond.unwrap(),
             unbonding_period_days: updated_params.unbonding_period_days.unwrap(),
+            weighting_mode: updated_params.weighting_mode.unwrap(),
             ..service
         };
         assert_eq!(res, expected_service);
@@ -534,6 +664,7 @@ mod test {
                     max_num_verifiers: None,
                     min_verifier_bond: Some(new_min_bond),
                     unbonding_period_days: None,
+                    weighting_mode: None,
                 },
             },
         );
@@ -578,6 +709,7 @@ mod test {
                     max_num_verifiers: None,
                     min_verifier_bond: None,
                     unbonding_period_days: None,
+                    weighting_mode: None,
                 },
             },
         );
@@ -867,6 +999,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -918,6 +1051,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -966,6 +1100,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -1011,6 +1146,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -1093,6 +1229,131 @@ mod test {
         assert_eq!(verifiers, vec![]);
     }
 
+    #[test]
+    fn active_verifiers_should_be_weighted_according_to_weighting_mode() {
+        let service_name = "validators";
+        let chain_name = chain_name!(ETHEREUM);
+        let min_verifier_bond = Uint128::new(100);
+        let bonds = [
+            ("verifier1", 100u128),
+            ("verifier2", 400),
+            ("verifier3", 10000),
+        ];
+
+        let test_cases = [
+            (WeightingMode::Uniform, [1u128, 1, 1]),
+            (
+                WeightingMode::Linear {
+                    max_weight: Uint128::new(1000).try_into().unwrap(),
+                },
+                [100, 400, 1000],
+            ),
+            (WeightingMode::Sqrt, [10, 20, 100]),
+        ];
+
+        for (weighting_mode, expected_weights) in test_cases {
+            let mut deps = setup();
+            let api = deps.api;
+
+            let res = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+                ExecuteMsg::RegisterService {
+                    service_name: service_name.into(),
+                    coordinator_contract: cosmos_addr!(COORDINATOR_ADDRESS).to_string(),
+                    min_num_verifiers: 0,
+                    max_num_verifiers: Some(100),
+                    min_verifier_bond: min_verifier_bond.try_into().unwrap(),
+                    bond_denom: AXL_DENOMINATION.into(),
+                    unbonding_period_days: 10,
+                    description: "Some service".into(),
+                    weighting_mode: weighting_mode.clone(),
+                },
+            );
+            assert!(res.is_ok());
+
+            let res = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+                ExecuteMsg::AuthorizeVerifiers {
+                    verifiers: bonds
+                        .iter()
+                        .map(|(verifier, _)| api.addr_make(verifier).to_string())
+                        .collect(),
+                    service_name: service_name.into(),
+                },
+            );
+            assert!(res.is_ok());
+
+            for (verifier, bond) in bonds {
+                let res = execute(
+                    deps.as_mut(),
+                    mock_env(),
+                    message_info(&api.addr_make(verifier), &coins(bond, AXL_DENOMINATION)),
+                    ExecuteMsg::BondVerifier {
+                        service_name: service_name.into(),
+                    },
+                );
+                assert!(res.is_ok());
+
+                let res = execute(
+                    deps.as_mut(),
+                    mock_env(),
+                    message_info(&api.addr_make(verifier), &[]),
+                    ExecuteMsg::RegisterChainSupport {
+                        service_name: service_name.into(),
+                        chains: vec![chain_name.clone()],
+                    },
+                );
+                assert!(res.is_ok());
+            }
+
+            let verifiers: Vec<WeightedVerifier> = from_json(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::ActiveVerifiers {
+                        service_name: service_name.into(),
+                        chain_name: chain_name.clone(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+
+            for ((verifier, _), expected_weight) in bonds.iter().zip(expected_weights) {
+                let weight = verifiers
+                    .iter()
+                    .find(|weighted| weighted.verifier_info.address == api.addr_make(verifier))
+                    .unwrap()
+                    .weight;
+                assert_eq!(
+                    weight,
+                    Uint128::new(expected_weight).try_into().unwrap(),
+                    "unexpected weight for {} with {:?}",
+                    verifier,
+                    weighting_mode
+                );
+
+                let verifier_details: VerifierDetails = from_json(
+                    query(
+                        deps.as_ref(),
+                        mock_env(),
+                        QueryMsg::Verifier {
+                            service_name: service_name.into(),
+                            verifier: api.addr_make(verifier).to_string(),
+                        },
+                    )
+                    .unwrap(),
+                )
+                .unwrap();
+                assert_eq!(verifier_details.weight, weight);
+            }
+        }
+    }
+
     /// If a bonded and authorized verifier deregisters support for a chain they previously registered support for,
     /// that verifier should no longer be part of the active verifier set for that chain
     #[test]
@@ -1114,6 +1375,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -1201,6 +1463,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -1295,6 +1558,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -1420,6 +1684,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -1522,6 +1787,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -1634,6 +1900,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -1710,6 +1977,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -1836,6 +2104,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -1880,6 +2149,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -1936,6 +2206,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -2020,6 +2291,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -2063,6 +2335,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -2126,6 +2399,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -2200,6 +2474,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -2287,6 +2562,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -2396,6 +2672,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -2518,6 +2795,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         )
         .unwrap();
@@ -2629,6 +2907,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -2656,6 +2935,7 @@ mod test {
             ExecuteMsg::JailVerifiers {
                 verifiers: vec![verifier1.clone().into()],
                 service_name: service_name.into(),
+                jail_duration_days: None,
             },
         );
         assert!(res.is_ok());
@@ -2736,6 +3016,7 @@ mod test {
             ExecuteMsg::JailVerifiers {
                 verifiers: vec![verifier2.clone().into()],
                 service_name: service_name.into(),
+                jail_duration_days: None,
             },
         );
         assert!(res.is_ok());
@@ -2764,6 +3045,864 @@ mod test {
         ));
     }
 
+    fn execute_set_slashing_config(deps: DepsMut, service_name: String) {
+        let res = execute(
+            deps,
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::SetSlashingConfig {
+                service_name,
+                reporters: vec![cosmos_addr!(REPORTER_ADDRESS).to_string()],
+                slash_fraction: Threshold::try_from((1u64, 10u64)).unwrap(),
+                slashed_funds_recipient: cosmos_addr!(TREASURY_ADDRESS).to_string(),
+                jail_duration_days: Some(JAIL_DURATION_DAYS),
+                incorrect_votes_threshold: Some(1u64.try_into().unwrap()),
+            },
+        );
+        assert!(res.is_ok());
+    }
+
+    fn execute_bond_authorize_and_register_verifier(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+        service_name: String,
+        verifier: &Addr,
+        bond: u128,
+    ) {
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(verifier, &coins(bond, AXL_DENOMINATION)),
+            ExecuteMsg::BondVerifier {
+                service_name: service_name.clone(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::AuthorizeVerifiers {
+                verifiers: vec![verifier.to_string()],
+                service_name: service_name.clone(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(verifier, &[]),
+            ExecuteMsg::RegisterChainSupport {
+                service_name,
+                chains: vec![chain_name!(ETHEREUM)],
+            },
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn report_misbehaviour_should_slash_and_jail_verifiers() {
+        let mut deps = setup();
+        let service_name = "validators".to_string();
+        execute_register_service(deps.as_mut(), service_name.clone());
+        execute_set_slashing_config(deps.as_mut(), service_name.clone());
+
+        let verifier1 = cosmos_addr!("verifier-1");
+        let verifier2 = cosmos_addr!("verifier-2");
+        execute_bond_authorize_and_register_verifier(
+            &mut deps,
+            service_name.clone(),
+            &verifier1,
+            100,
+        );
+        execute_bond_authorize_and_register_verifier(
+            &mut deps,
+            service_name.clone(),
+            &verifier2,
+            100,
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(REPORTER_ADDRESS), &[]),
+            ExecuteMsg::ReportMisbehaviour {
+                service_name: service_name.clone(),
+                chain_name: chain_name!(ETHEREUM),
+                verifiers: vec![verifier1.to_string(), verifier1.to_string()],
+                misbehaviour: Misbehaviour::IncorrectVote,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: cosmos_addr!(TREASURY_ADDRESS).to_string(),
+                amount: coins(10, AXL_DENOMINATION)
+            })
+        );
+        assert_eq!(res.events.len(), 2);
+
+        let verifier1_details: VerifierDetails = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Verifier {
+                    service_name: service_name.clone(),
+                    verifier: verifier1.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            verifier1_details.verifier.bonding_state,
+            BondingState::Bonded {
+                amount: Uint128::new(90).try_into().unwrap()
+            }
+        );
+        assert_eq!(
+            verifier1_details.verifier.authorization_state,
+            AuthorizationState::Jailed
+        );
+        assert_auth_verifier_count_is_valid(&deps, &service_name, 1);
+
+        // already jailed verifiers are not slashed again
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(REPORTER_ADDRESS), &[]),
+            ExecuteMsg::ReportMisbehaviour {
+                service_name: service_name.clone(),
+                chain_name: chain_name!(ETHEREUM),
+                verifiers: vec![verifier1.to_string()],
+                misbehaviour: Misbehaviour::MissedEpochs { count: 3 },
+            },
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+        assert!(res.events.is_empty());
+    }
+
+    #[test]
+    fn report_misbehaviour_should_skip_verifiers_that_are_not_active() {
+        let mut deps = setup();
+        let service_name = "validators".to_string();
+        execute_register_service(deps.as_mut(), service_name.clone());
+        execute_set_slashing_config(deps.as_mut(), service_name.clone());
+
+        let unauthorized = cosmos_addr!("unauthorized-verifier");
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&unauthorized, &coins(100, AXL_DENOMINATION)),
+            ExecuteMsg::BondVerifier {
+                service_name: service_name.clone(),
+            },
+        );
+        assert!(res.is_ok());
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&unauthorized, &[]),
+            ExecuteMsg::RegisterChainSupport {
+                service_name: service_name.clone(),
+                chains: vec![chain_name!(ETHEREUM)],
+            },
+        );
+        assert!(res.is_ok());
+
+        let underbonded = cosmos_addr!("underbonded-verifier");
+        execute_bond_authorize_and_register_verifier(
+            &mut deps,
+            service_name.clone(),
+            &underbonded,
+            100,
+        );
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::UpdateService {
+                service_name: service_name.clone(),
+                updated_service_params: UpdatedServiceParams {
+                    min_num_verifiers: None,
+                    max_num_verifiers: None,
+                    min_verifier_bond: Some(Uint128::new(200).try_into().unwrap()),
+                    unbonding_period_days: None,
+                    weighting_mode: None,
+                },
+            },
+        );
+        assert!(res.is_ok());
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(REPORTER_ADDRESS), &[]),
+            ExecuteMsg::ReportMisbehaviour {
+                service_name: service_name.clone(),
+                chain_name: chain_name!(ETHEREUM),
+                verifiers: vec![unauthorized.to_string(), underbonded.to_string()],
+                misbehaviour: Misbehaviour::MissedEpochs { count: 3 },
+            },
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+        assert!(res.events.is_empty());
+    }
+
+    #[test]
+    fn report_misbehaviour_should_only_slash_incorrect_votes_once_threshold_is_reached() {
+        let mut deps = setup();
+        let service_name = "validators".to_string();
+        execute_register_service(deps.as_mut(), service_name.clone());
+
+        let verifier = cosmos_addr!(VERIFIER_ADDRESS);
+        execute_bond_authorize_and_register_verifier(
+            &mut deps,
+            service_name.clone(),
+            &verifier,
+            100,
+        );
+
+        let set_incorrect_votes_threshold =
+            |deps: DepsMut, incorrect_votes_threshold: Option<u64>| {
+                let res = execute(
+                    deps,
+                    mock_env(),
+                    message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+                    ExecuteMsg::SetSlashingConfig {
+                        service_name: service_name.clone(),
+                        reporters: vec![cosmos_addr!(REPORTER_ADDRESS).to_string()],
+                        slash_fraction: Threshold::try_from((1u64, 10u64)).unwrap(),
+                        slashed_funds_recipient: cosmos_addr!(TREASURY_ADDRESS).to_string(),
+                        jail_duration_days: None,
+                        incorrect_votes_threshold: incorrect_votes_threshold
+                            .map(|threshold| threshold.try_into().unwrap()),
+                    },
+                );
+                assert!(res.is_ok());
+            };
+        let report_incorrect_vote = |deps: DepsMut| {
+            execute(
+                deps,
+                mock_env(),
+                message_info(&cosmos_addr!(REPORTER_ADDRESS), &[]),
+                ExecuteMsg::ReportMisbehaviour {
+                    service_name: service_name.clone(),
+                    chain_name: chain_name!(ETHEREUM),
+                    verifiers: vec![verifier.to_string()],
+                    misbehaviour: Misbehaviour::IncorrectVote,
+                },
+            )
+            .unwrap()
+        };
+
+        // slashing of incorrect votes is opt-in
+        set_incorrect_votes_threshold(deps.as_mut(), None);
+        let res = report_incorrect_vote(deps.as_mut());
+        assert!(res.messages.is_empty());
+        assert!(res.events.is_empty());
+
+        set_incorrect_votes_threshold(deps.as_mut(), Some(2));
+        let res = report_incorrect_vote(deps.as_mut());
+        assert!(res.messages.is_empty());
+        assert!(res.events.is_empty());
+
+        let res = report_incorrect_vote(deps.as_mut());
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.events.len(), 2);
+    }
+
+    #[test]
+    fn report_misbehaviour_should_fail_if_sender_is_not_reporter() {
+        let mut deps = setup();
+        let service_name = "validators".to_string();
+        execute_register_service(deps.as_mut(), service_name.clone());
+
+        let verifier = cosmos_addr!(VERIFIER_ADDRESS);
+        execute_bond_authorize_and_register_verifier(
+            &mut deps,
+            service_name.clone(),
+            &verifier,
+            100,
+        );
+
+        let report_msg = ExecuteMsg::ReportMisbehaviour {
+            service_name: service_name.clone(),
+            chain_name: chain_name!(ETHEREUM),
+            verifiers: vec![verifier.to_string()],
+            misbehaviour: Misbehaviour::IncorrectVote,
+        };
+
+        // slashing is not enabled yet
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(REPORTER_ADDRESS), &[]),
+            report_msg.clone(),
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            permission_control::Error,
+            permission_control::Error::SpecificPermissionDenied { .. }
+        ));
+
+        execute_set_slashing_config(deps.as_mut(), service_name.clone());
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(UNAUTHORIZED_ADDRESS), &[]),
+            report_msg.clone(),
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            permission_control::Error,
+            permission_control::Error::SpecificPermissionDenied { .. }
+        ));
+
+        // slashing is disabled again
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RemoveSlashingConfig {
+                service_name: service_name.clone(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(REPORTER_ADDRESS), &[]),
+            report_msg,
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            permission_control::Error,
+            permission_control::Error::SpecificPermissionDenied { .. }
+        ));
+    }
+
+    #[test]
+    fn query_slashing_config_should_return_configured_slashing_config() {
+        let mut deps = setup();
+        let service_name = "validators".to_string();
+        execute_register_service(deps.as_mut(), service_name.clone());
+
+        let query_slashing_config = |deps: Deps| -> Option<SlashingConfig> {
+            from_json(
+                query(
+                    deps,
+                    mock_env(),
+                    QueryMsg::SlashingConfig {
+                        service_name: service_name.clone(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(query_slashing_config(deps.as_ref()), None);
+
+        execute_set_slashing_config(deps.as_mut(), service_name.clone());
+
+        assert_eq!(
+            query_slashing_config(deps.as_ref()),
+            Some(SlashingConfig {
+                reporters: vec![cosmos_addr!(REPORTER_ADDRESS)],
+                slash_fraction: Threshold::try_from((1u64, 10u64)).unwrap(),
+                slashed_funds_recipient: cosmos_addr!(TREASURY_ADDRESS),
+                jail_duration_days: Some(JAIL_DURATION_DAYS),
+                incorrect_votes_threshold: Some(1u64.try_into().unwrap()),
+            })
+        );
+    }
+
+    #[test]
+    fn query_services_should_return_paginated_services() {
+        let mut deps = setup();
+        for service_name in ["validators-b", "validators-a", "validators-c"] {
+            execute_register_service(deps.as_mut(), service_name.to_string());
+        }
+
+        let query_services = |start_after: Option<String>, limit: u32| -> Vec<String> {
+            from_json::<Vec<Service>>(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::Services {
+                        start_after,
+                        limit: limit.try_into().unwrap(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+            .into_iter()
+            .map(|service| service.name)
+            .collect()
+        };
+
+        assert_eq!(
+            query_services(None, 10),
+            vec!["validators-a", "validators-b", "validators-c"]
+        );
+        assert_eq!(
+            query_services(None, 2),
+            vec!["validators-a", "validators-b"]
+        );
+        assert_eq!(
+            query_services(Some("validators-b".to_string()), 10),
+            vec!["validators-c"]
+        );
+    }
+
+    #[test]
+    fn query_verifiers_should_return_verifiers_matching_filter() {
+        let mut deps = setup();
+        let service_name = "validators".to_string();
+        execute_register_service(deps.as_mut(), service_name.clone());
+
+        let mut verifiers = vec![
+            cosmos_addr!("verifier-1"),
+            cosmos_addr!("verifier-2"),
+            cosmos_addr!("verifier-3"),
+        ];
+        verifiers.sort();
+        let (bonded_and_authorized, bonded, authorized) =
+            (&verifiers[0], &verifiers[1], &verifiers[2]);
+
+        execute_bond_authorize_and_register_verifier(
+            &mut deps,
+            service_name.clone(),
+            bonded_and_authorized,
+            100,
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(bonded, &coins(100, AXL_DENOMINATION)),
+            ExecuteMsg::BondVerifier {
+                service_name: service_name.clone(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::AuthorizeVerifiers {
+                verifiers: vec![authorized.to_string()],
+                service_name: service_name.clone(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let query_verifiers = |filter: Option<VerifierFilter>,
+                               start_after: Option<String>,
+                               limit: u32|
+         -> Vec<VerifierDetails> {
+            from_json(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::Verifiers {
+                        service_name: service_name.clone(),
+                        filter,
+                        start_after,
+                        limit: limit.try_into().unwrap(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+        let addresses = |verifiers: Vec<VerifierDetails>| -> Vec<Addr> {
+            verifiers
+                .into_iter()
+                .map(|details| details.verifier.address)
+                .collect()
+        };
+
+        let all_verifiers = query_verifiers(None, None, 10);
+        assert_eq!(addresses(all_verifiers.clone()), verifiers);
+        assert_eq!(
+            all_verifiers[0].supported_chains,
+            vec![chain_name!(ETHEREUM)]
+        );
+
+        assert_eq!(
+            addresses(query_verifiers(
+                Some(VerifierFilter {
+                    bonding_status: Some(BondingStatus::Bonded),
+                    authorization_state: None,
+                }),
+                None,
+                10
+            )),
+            vec![bonded_and_authorized.clone(), bonded.clone()]
+        );
+        assert_eq!(
+            addresses(query_verifiers(
+                Some(VerifierFilter {
+                    bonding_status: None,
+                    authorization_state: Some(AuthorizationState::Authorized),
+                }),
+                None,
+                10
+            )),
+            vec![bonded_and_authorized.clone(), authorized.clone()]
+        );
+        assert_eq!(
+            addresses(query_verifiers(
+                Some(VerifierFilter {
+                    bonding_status: Some(BondingStatus::Bonded),
+                    authorization_state: Some(AuthorizationState::Authorized),
+                }),
+                None,
+                10
+            )),
+            vec![bonded_and_authorized.clone()]
+        );
+        assert_eq!(
+            addresses(query_verifiers(
+                None,
+                Some(bonded_and_authorized.to_string()),
+                1
+            )),
+            vec![bonded.clone()]
+        );
+    }
+
+    #[test]
+    fn query_supported_chains_should_return_distinct_chains_sorted_by_name() {
+        let mut deps = setup();
+        let service_name = "validators".to_string();
+        execute_register_service(deps.as_mut(), service_name.clone());
+
+        for (verifier, chains) in [
+            (
+                cosmos_addr!("verifier-1"),
+                vec![chain_name!(POLYGON), chain_name!(ETHEREUM)],
+            ),
+            (
+                cosmos_addr!("verifier-2"),
+                vec![chain_name!(ETHEREUM), chain_name!(AVALANCHE)],
+            ),
+        ] {
+            let res = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&verifier, &coins(100, AXL_DENOMINATION)),
+                ExecuteMsg::BondVerifier {
+                    service_name: service_name.clone(),
+                },
+            );
+            assert!(res.is_ok());
+
+            let res = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&verifier, &[]),
+                ExecuteMsg::RegisterChainSupport {
+                    service_name: service_name.clone(),
+                    chains,
+                },
+            );
+            assert!(res.is_ok());
+        }
+
+        let query_supported_chains =
+            |start_after: Option<ChainName>, limit: u32| -> Vec<ChainName> {
+                from_json(
+                    query(
+                        deps.as_ref(),
+                        mock_env(),
+                        QueryMsg::SupportedChains {
+                            service_name: service_name.clone(),
+                            start_after,
+                            limit: limit.try_into().unwrap(),
+                        },
+                    )
+                    .unwrap(),
+                )
+                .unwrap()
+            };
+
+        assert_eq!(
+            query_supported_chains(None, 10),
+            vec![
+                chain_name!(AVALANCHE),
+                chain_name!(ETHEREUM),
+                chain_name!(POLYGON)
+            ]
+        );
+        assert_eq!(
+            query_supported_chains(None, 1),
+            vec![chain_name!(AVALANCHE)]
+        );
+        assert_eq!(
+            query_supported_chains(Some(chain_name!(ETHEREUM)), 10),
+            vec![chain_name!(POLYGON)]
+        );
+    }
+
+    #[test]
+    fn unjail_verifiers_should_restore_authorization_state() {
+        let mut deps = setup();
+        let service_name = "validators".to_string();
+        execute_register_service(deps.as_mut(), service_name.clone());
+
+        let verifier = cosmos_addr!(VERIFIER_ADDRESS);
+        execute_bond_authorize_and_register_verifier(
+            &mut deps,
+            service_name.clone(),
+            &verifier,
+            100,
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::JailVerifiers {
+                verifiers: vec![verifier.to_string()],
+                service_name: service_name.clone(),
+                jail_duration_days: None,
+            },
+        );
+        assert!(res.is_ok());
+        assert_auth_verifier_count_is_valid(&deps, &service_name, 0);
+
+        // without a jail duration, the verifier cannot unjail itself
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&verifier, &[]),
+            ExecuteMsg::UnjailVerifier {
+                service_name: service_name.clone(),
+            },
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            ContractError,
+            ContractError::JailNotExpired
+        ));
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::UnjailVerifiers {
+                verifiers: vec![verifier.to_string()],
+                service_name: service_name.clone(),
+            },
+        )
+        .unwrap();
+        assert_eq!(res.events.len(), 1);
+
+        let verifier_details: VerifierDetails = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Verifier {
+                    service_name: service_name.clone(),
+                    verifier: verifier.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            verifier_details.verifier.authorization_state,
+            AuthorizationState::Authorized
+        );
+        assert_eq!(
+            verifier_details.verifier.bonding_state,
+            BondingState::Bonded {
+                amount: Uint128::new(100).try_into().unwrap()
+            }
+        );
+        assert_auth_verifier_count_is_valid(&deps, &service_name, 1);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::UnjailVerifiers {
+                verifiers: vec![verifier.to_string()],
+                service_name,
+            },
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            ContractError,
+            ContractError::VerifierNotJailed
+        ));
+    }
+
+    #[test]
+    fn unjail_verifier_should_only_succeed_after_jail_duration_passed() {
+        let mut deps = setup();
+        let service_name = "validators".to_string();
+        execute_register_service(deps.as_mut(), service_name.clone());
+        execute_set_slashing_config(deps.as_mut(), service_name.clone());
+
+        let verifier = cosmos_addr!(VERIFIER_ADDRESS);
+        execute_bond_authorize_and_register_verifier(
+            &mut deps,
+            service_name.clone(),
+            &verifier,
+            100,
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(REPORTER_ADDRESS), &[]),
+            ExecuteMsg::ReportMisbehaviour {
+                service_name: service_name.clone(),
+                chain_name: chain_name!(ETHEREUM),
+                verifiers: vec![verifier.to_string()],
+                misbehaviour: Misbehaviour::IncorrectVote,
+            },
+        );
+        assert!(res.is_ok());
+
+        let unjail_msg = ExecuteMsg::UnjailVerifier {
+            service_name: service_name.clone(),
+        };
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&verifier, &[]),
+            unjail_msg.clone(),
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            ContractError,
+            ContractError::JailNotExpired
+        ));
+
+        let mut after_jail_env = mock_env();
+        after_jail_env.block.time = after_jail_env
+            .block
+            .time
+            .plus_days(JAIL_DURATION_DAYS.into());
+
+        let res = execute(
+            deps.as_mut(),
+            after_jail_env,
+            message_info(&verifier, &[]),
+            unjail_msg,
+        );
+        assert!(res.is_ok());
+        assert_auth_verifier_count_is_valid(&deps, &service_name, 1);
+    }
+
+    #[test]
+    fn query_jailed_verifiers_should_return_jail_details() {
+        let mut deps = setup();
+        let service_name = "validators".to_string();
+        execute_register_service(deps.as_mut(), service_name.clone());
+        execute_set_slashing_config(deps.as_mut(), service_name.clone());
+
+        let verifier1 = cosmos_addr!("verifier-1");
+        let verifier2 = cosmos_addr!("verifier-2");
+        let verifier3 = cosmos_addr!("verifier-3");
+        for verifier in [&verifier1, &verifier2, &verifier3] {
+            execute_bond_authorize_and_register_verifier(
+                &mut deps,
+                service_name.clone(),
+                verifier,
+                100,
+            );
+        }
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(REPORTER_ADDRESS), &[]),
+            ExecuteMsg::ReportMisbehaviour {
+                service_name: service_name.clone(),
+                chain_name: chain_name!(ETHEREUM),
+                verifiers: vec![verifier1.to_string()],
+                misbehaviour: Misbehaviour::IncorrectVote,
+            },
+        );
+        assert!(res.is_ok());
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::JailVerifiers {
+                verifiers: vec![verifier2.to_string()],
+                service_name: service_name.clone(),
+                jail_duration_days: None,
+            },
+        );
+        assert!(res.is_ok());
+
+        let query_jailed_verifiers =
+            |start_after: Option<String>, limit: u32| -> Vec<JailedVerifier> {
+                from_json(
+                    query(
+                        deps.as_ref(),
+                        mock_env(),
+                        QueryMsg::JailedVerifiers {
+                            service_name: service_name.clone(),
+                            start_after,
+                            limit: limit.try_into().unwrap(),
+                        },
+                    )
+                    .unwrap(),
+                )
+                .unwrap()
+            };
+
+        let now = mock_env().block.time;
+        let mut expected = vec![
+            JailedVerifier {
+                verifier: verifier1,
+                reason: JailReason::Misbehaviour(Misbehaviour::IncorrectVote),
+                jailed_at: Some(now),
+                expires_at: Some(now.plus_days(JAIL_DURATION_DAYS.into())),
+            },
+            JailedVerifier {
+                verifier: verifier2,
+                reason: JailReason::Governance,
+                jailed_at: Some(now),
+                expires_at: None,
+            },
+        ];
+        expected.sort_by(|a, b| a.verifier.cmp(&b.verifier));
+
+        assert_eq!(query_jailed_verifiers(None, 10), expected);
+        assert_eq!(query_jailed_verifiers(None, 1), expected[..1]);
+        assert_eq!(
+            query_jailed_verifiers(Some(expected[0].verifier.to_string()), 10),
+            expected[1..]
+        );
+    }
+
     #[test]
     fn get_single_verifier_details() {
         let mut deps = setup();
@@ -2783,6 +3922,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -2880,6 +4020,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -2945,6 +4086,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -2977,6 +4119,7 @@ mod test {
                     max_num_verifiers: Some(Some(2)),
                     min_verifier_bond: None,
                     unbonding_period_days: None,
+                    weighting_mode: None,
                 },
             },
         );
@@ -2999,6 +4142,7 @@ mod test {
                     max_num_verifiers: Some(Some(4)),
                     min_verifier_bond: None,
                     unbonding_period_days: None,
+                    weighting_mode: None,
                 },
             },
         );
@@ -3023,6 +4167,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                weighting_mode: WeightingMode::Uniform,
             },
         );
         assert!(res.is_ok());
@@ -3082,6 +4227,7 @@ mod test {
             ExecuteMsg::JailVerifiers {
                 verifiers: vec![cosmos_addr!("verifier2").to_string()],
                 service_name: service_name.clone(),
+                jail_duration_days: None,
             },
         );
         assert!(res.is_ok());
@@ -3112,6 +4258,7 @@ mod test {
             ExecuteMsg::JailVerifiers {
                 verifiers: vec![new_verifier],
                 service_name: service_name.clone(),
+                jail_duration_days: None,
             },
         );
         assert!(res.is_ok());
@@ -3130,6 +4277,7 @@ mod test {
             ExecuteMsg::JailVerifiers {
                 verifiers: vec![new_verifier],
                 service_name: service_name.clone(),
+                jail_duration_days: None,
             },
         );
         assert!(res.is_ok());
@@ -1,7 +1,11 @@
 use axelar_wasm_std::nonempty;
+use cosmwasm_std::{Timestamp, Uint128};
 use error_stack::{ensure, Result};
+use itertools::Itertools;
 use router_api::ChainName;
-use service_registry_api::{self, AuthorizationState, Verifier};
+use service_registry_api::{
+    self, AuthorizationState, JailReason, Misbehaviour, SlashingConfig, Verifier, WeightingMode,
+};
 use state::VERIFIERS;
 
 use super::*;
@@ -19,6 +23,7 @@ pub fn register_service(
     bond_denom: String,
     unbonding_period_days: u16,
     description: String,
+    weighting_mode: WeightingMode,
 ) -> Result<Response, ContractError> {
     state::save_new_service(
         deps.storage,
@@ -32,6 +37,7 @@ pub fn register_service(
             bond_denom,
             unbonding_period_days,
             description,
+            weighting_mode,
         },
     )?;
 
@@ -60,6 +66,96 @@ pub fn update_verifier_authorization_status(
     Ok(Response::new())
 }
 
+pub fn jail_verifiers(
+    deps: DepsMut,
+    env: Env,
+    verifiers: Vec<Addr>,
+    service_name: String,
+    jail_duration_days: Option<u16>,
+) -> Result<Response, ContractError> {
+    ensure_service_exists(deps.storage, &service_name)?;
+
+    let verifiers = verifiers.into_iter().unique().collect::<Vec<_>>();
+    let expires_at = jail_expiry(&env, jail_duration_days);
+
+    state::jail_verifiers(
+        deps.storage,
+        &service_name,
+        verifiers.clone(),
+        JailReason::Governance,
+        env.block.time,
+        expires_at,
+    )?;
+
+    Ok(
+        Response::new().add_events(verifiers.into_iter().map(|verifier| Event::VerifierJailed {
+            verifier,
+            service_name: service_name.clone(),
+            reason: JailReason::Governance,
+            expires_at,
+        })),
+    )
+}
+
+pub fn unjail_verifiers(
+    deps: DepsMut,
+    verifiers: Vec<Addr>,
+    service_name: String,
+) -> Result<Response, ContractError> {
+    ensure_service_exists(deps.storage, &service_name)?;
+
+    let verifiers = verifiers.into_iter().unique().collect::<Vec<_>>();
+
+    for verifier in verifiers.iter() {
+        state::unjail_verifier(deps.storage, &service_name, verifier.clone())?;
+    }
+
+    ensure_authorization_max_limit_respected(deps.storage, &service_name)?;
+
+    Ok(Response::new().add_events(
+        verifiers
+            .into_iter()
+            .map(|verifier| Event::VerifierUnjailed {
+                verifier,
+                service_name: service_name.clone(),
+            }),
+    ))
+}
+
+pub fn unjail_verifier(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    service_name: String,
+) -> Result<Response, ContractError> {
+    ensure_service_exists(deps.storage, &service_name)?;
+
+    let verifier = VERIFIERS
+        .may_load(deps.storage, (&service_name, &info.sender))
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::VerifierNotFound)?;
+
+    ensure!(
+        verifier.authorization_state == AuthorizationState::Jailed,
+        ContractError::VerifierNotJailed
+    );
+
+    let jail_expired = state::may_load_jail_record(deps.storage, &service_name, &info.sender)?
+        .and_then(|record| record.expires_at)
+        .is_some_and(|expires_at| expires_at <= env.block.time);
+
+    ensure!(jail_expired, ContractError::JailNotExpired);
+
+    state::unjail_verifier(deps.storage, &service_name, info.sender.clone())?;
+
+    ensure_authorization_max_limit_respected(deps.storage, &service_name)?;
+
+    Ok(Response::new().add_event(Event::VerifierUnjailed {
+        verifier: info.sender,
+        service_name,
+    }))
+}
+
 pub fn update_service(
     deps: DepsMut,
     service_name: String,
@@ -95,6 +191,150 @@ pub fn remove_service_params_override(
     Ok(Response::new())
 }
 
+pub fn set_slashing_config(
+    deps: DepsMut,
+    service_name: String,
+    slashing_config: SlashingConfig,
+) -> Result<Response, ContractError> {
+    state::save_slashing_config(deps.storage, &service_name, &slashing_config)?;
+
+    Ok(Response::new())
+}
+
+pub fn remove_slashing_config(
+    deps: DepsMut,
+    service_name: String,
+) -> Result<Response, ContractError> {
+    state::remove_slashing_config(deps.storage, &service_name)?;
+
+    Ok(Response::new())
+}
+
+/// Slashes and jails the reported verifiers. Only verifiers that are active for the service and chain are slashed, i.e.
+/// authorized verifiers bonded with at least the minimum bond that support the given chain. All other verifiers are skipped,
+/// so that a report never fails the transaction of the reporting contract. Incorrect votes are only slashed if the slashing
+/// config sets a threshold, and only once a verifier reaches it.
+pub fn report_misbehaviour(
+    deps: DepsMut,
+    env: Env,
+    service_name: String,
+    chain_name: ChainName,
+    verifiers: Vec<Addr>,
+    misbehaviour: Misbehaviour,
+) -> Result<Response, ContractError> {
+    let service = state::service(deps.storage, &service_name, Some(&chain_name))?;
+    let slashing_config = state::may_load_slashing_config(deps.storage, &service_name)?
+        .ok_or(ContractError::SlashingNotEnabled)?;
+
+    let incorrect_votes_threshold = match misbehaviour {
+        Misbehaviour::IncorrectVote => match slashing_config.incorrect_votes_threshold {
+            Some(threshold) => Some(threshold),
+            None => return Ok(Response::new()),
+        },
+        Misbehaviour::MissedEpochs { .. } => None,
+    };
+
+    let reason = JailReason::Misbehaviour(misbehaviour.clone());
+    let expires_at = jail_expiry(&env, slashing_config.jail_duration_days);
+
+    let mut events = vec![];
+    let mut jailed_verifiers = vec![];
+    let mut total_slashed = Uint128::zero();
+
+    for verifier_addr in verifiers.into_iter().unique() {
+        let Some(verifier) = VERIFIERS
+            .may_load(deps.storage, (&service_name, &verifier_addr))
+            .change_context(ContractError::StorageError)?
+        else {
+            continue;
+        };
+
+        let supports_chain = state::VERIFIERS_PER_CHAIN
+            .may_load(
+                deps.storage,
+                (
+                    service_name.clone(),
+                    chain_name.clone(),
+                    verifier_addr.clone(),
+                ),
+            )
+            .change_context(ContractError::StorageError)?
+            .is_some();
+
+        let sufficiently_bonded = matches!(
+            verifier.bonding_state,
+            BondingState::Bonded { amount } if amount >= service.min_verifier_bond
+        );
+
+        if verifier.authorization_state != AuthorizationState::Authorized
+            || !sufficiently_bonded
+            || !supports_chain
+        {
+            continue;
+        }
+
+        if let Some(threshold) = incorrect_votes_threshold {
+            if !state::record_incorrect_vote(
+                deps.storage,
+                &service_name,
+                &verifier_addr,
+                threshold,
+            )? {
+                continue;
+            }
+        }
+
+        let (verifier, slashed) = state::slash_verifier(verifier, slashing_config.slash_fraction)?;
+
+        VERIFIERS
+            .save(deps.storage, (&service_name, &verifier_addr), &verifier)
+            .change_context(ContractError::StorageError)?;
+
+        total_slashed = total_slashed
+            .checked_add(slashed)
+            .map_err(ContractError::Overflow)?;
+
+        events.push(Event::VerifierSlashed {
+            verifier: verifier_addr.clone(),
+            service_name: service_name.clone(),
+            chain_name: chain_name.clone(),
+            misbehaviour: misbehaviour.clone(),
+            amount: slashed,
+        });
+        events.push(Event::VerifierJailed {
+            verifier: verifier_addr.clone(),
+            service_name: service_name.clone(),
+            reason: reason.clone(),
+            expires_at,
+        });
+        jailed_verifiers.push(verifier_addr);
+    }
+
+    state::jail_verifiers(
+        deps.storage,
+        &service_name,
+        jailed_verifiers,
+        reason,
+        env.block.time,
+        expires_at,
+    )?;
+
+    let response = Response::new().add_events(events);
+
+    if total_slashed.is_zero() {
+        return Ok(response);
+    }
+
+    Ok(response.add_message(BankMsg::Send {
+        to_address: slashing_config.slashed_funds_recipient.into(),
+        amount: [Coin {
+            denom: service.bond_denom,
+            amount: total_slashed,
+        }]
+        .to_vec(),
+    }))
+}
+
 pub fn bond_verifier(
     deps: DepsMut,
     info: MessageInfo,
@@ -243,6 +483,10 @@ pub fn claim_stake(
     }))
 }
 
+fn jail_expiry(env: &Env, jail_duration_days: Option<u16>) -> Option<Timestamp> {
+    jail_duration_days.map(|days| env.block.time.plus_days(days.into()))
+}
+
 fn ensure_service_exists(
     storage: &dyn Storage,
     service_name: &String,
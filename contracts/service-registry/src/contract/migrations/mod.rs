@@ -3,6 +3,8 @@ use axelar_wasm_std::migrate_from_version;
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{DepsMut, Empty, Env, Response};
 
+use crate::state;
+
 pub type MigrateMsg = Empty;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -12,5 +14,58 @@ pub fn migrate(
     _env: Env,
     _msg: MigrateMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+    state::backfill_supported_chains(deps.storage)?;
+
     Ok(Response::default())
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::Empty;
+    use router_api::{chain_name, cosmos_addr};
+
+    use super::migrate;
+    use crate::state::{self, VERIFIERS_PER_CHAIN};
+
+    #[test]
+    fn migrate_backfills_supported_chains() {
+        let mut deps = mock_dependencies();
+        cw2::set_contract_version(deps.as_mut().storage, env!("CARGO_PKG_NAME"), "1.1.0").unwrap();
+
+        let service_name = "validators".to_string();
+        for (chain, verifier) in [
+            (chain_name!("polygon"), cosmos_addr!("verifier-1")),
+            (chain_name!("ethereum"), cosmos_addr!("verifier-1")),
+            (chain_name!("ethereum"), cosmos_addr!("verifier-2")),
+        ] {
+            VERIFIERS_PER_CHAIN
+                .save(
+                    deps.as_mut().storage,
+                    (service_name.clone(), chain, verifier),
+                    &(),
+                )
+                .unwrap();
+        }
+
+        migrate(deps.as_mut(), mock_env(), Empty {}).unwrap();
+
+        assert_eq!(
+            state::supported_chains(deps.as_ref().storage, &service_name, None, 10).unwrap(),
+            vec![chain_name!("ethereum"), chain_name!("polygon")]
+        );
+
+        // a chain is only removed once no verifier supports it anymore
+        state::deregister_chains_support(
+            deps.as_mut().storage,
+            service_name.clone(),
+            vec![chain_name!("ethereum")],
+            cosmos_addr!("verifier-1"),
+        )
+        .unwrap();
+        assert_eq!(
+            state::supported_chains(deps.as_ref().storage, &service_name, None, 10).unwrap(),
+            vec![chain_name!("ethereum"), chain_name!("polygon")]
+        );
+    }
+}
@@ -1,7 +1,8 @@
 use std::cmp::Ordering;
 
-use axelar_wasm_std::address;
-use cosmwasm_std::{Addr, Deps, Env, Order};
+use axelar_wasm_std::{address, nonempty};
+use cosmwasm_std::{Addr, Deps, Env, Order, Uint128};
+use cw_storage_plus::Bound;
 use error_stack::report;
 use itertools::Itertools;
 use report::ResultExt;
@@ -10,7 +11,7 @@ use service_registry_api::error::ContractError;
 use service_registry_api::*;
 use sha3::{Digest, Keccak256};
 
-use crate::msg::{ServiceParamsOverride, VerifierDetails};
+use crate::msg::{ServiceParamsOverride, VerifierDetails, VerifierFilter};
 use crate::state::{self, VERIFIERS, VERIFIERS_PER_CHAIN, VERIFIER_WEIGHT};
 
 pub fn active_verifiers(
@@ -30,16 +31,16 @@ pub fn active_verifiers(
                 .ok()
                 .flatten()
         })
-        .filter_ok(|verifier| {
-            matches!(
-                verifier.bonding_state,
-                BondingState::Bonded { amount } if amount >= service.min_verifier_bond
-            )
+        .filter_map_ok(|verifier| match verifier.bonding_state {
+            BondingState::Bonded { amount } if amount >= service.min_verifier_bond => {
+                Some((verifier, amount))
+            }
+            _ => None,
         })
-        .filter_ok(|verifier| verifier.authorization_state == AuthorizationState::Authorized)
-        .map_ok(|verifier| WeightedVerifier {
+        .filter_ok(|(verifier, _)| verifier.authorization_state == AuthorizationState::Authorized)
+        .map_ok(|(verifier, bond)| WeightedVerifier {
             verifier_info: verifier,
-            weight: VERIFIER_WEIGHT, // all verifiers have an identical const weight for now
+            weight: verifier_weight(&service.weighting_mode, bond),
         })
         .try_collect()
         .into_report()?;
@@ -54,6 +55,16 @@ pub fn active_verifiers(
     })
 }
 
+/// Derives the weight of a verifier from its bond according to the service's weighting mode
+fn verifier_weight(weighting_mode: &WeightingMode, bond: nonempty::Uint128) -> nonempty::Uint128 {
+    match weighting_mode {
+        WeightingMode::Uniform => VERIFIER_WEIGHT,
+        WeightingMode::Linear { max_weight } => bond.min(*max_weight),
+        WeightingMode::Sqrt => nonempty::Uint128::try_from(Uint128::from(bond).u128().isqrt())
+            .expect("square root of a non-zero bond must be non-zero"),
+    }
+}
+
 fn select_top_verifiers(
     mut verifiers: Vec<WeightedVerifier>,
     max_verifiers: u16,
@@ -105,21 +116,85 @@ pub fn verifier(
         .may_load(deps.storage, (&service_name, &verifier_addr))?
         .ok_or(ContractError::VerifierNotFound)?;
 
+    let service = state::service(deps.storage, &service_name, None)?;
+
+    verifier_details(deps, &service.weighting_mode, verifier)
+}
+
+pub fn verifiers(
+    deps: Deps,
+    service_name: String,
+    filter: Option<VerifierFilter>,
+    start_after: Option<String>,
+    limit: nonempty::Uint32,
+) -> Result<Vec<VerifierDetails>, axelar_wasm_std::error::ContractError> {
+    let service = state::service(deps.storage, &service_name, None)?;
+    let filter = filter.unwrap_or_default();
+
+    let start_after = start_after
+        .map(|addr| address::validate_cosmwasm_address(deps.api, &addr))
+        .transpose()?;
+
+    VERIFIERS
+        .prefix(&service_name)
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .filter_ok(|(_, verifier)| filter.matches(verifier))
+        .take(limit.into())
+        .map(|res| {
+            let (_, verifier) = res?;
+            verifier_details(deps, &service.weighting_mode, verifier)
+        })
+        .collect()
+}
+
+fn verifier_details(
+    deps: Deps,
+    weighting_mode: &WeightingMode,
+    verifier: Verifier,
+) -> Result<VerifierDetails, axelar_wasm_std::error::ContractError> {
+    let weight = match verifier.bonding_state {
+        BondingState::Bonded { amount } => verifier_weight(weighting_mode, amount),
+        _ => VERIFIER_WEIGHT,
+    };
+
     let supported_chains = VERIFIERS_PER_CHAIN
         .idx
         .verifier_address
-        .prefix((service_name, verifier_addr.clone()))
+        .prefix((verifier.service_name.clone(), verifier.address.clone()))
         .keys(deps.storage, None, None, Order::Ascending)
         .map_ok(|(_, chain, _)| chain)
         .try_collect()?;
 
     Ok(VerifierDetails {
         verifier,
-        weight: VERIFIER_WEIGHT,
+        weight,
         supported_chains,
     })
 }
 
+pub fn services(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: nonempty::Uint32,
+) -> error_stack::Result<Vec<Service>, ContractError> {
+    state::services(deps.storage, start_after.as_ref(), limit.into())
+}
+
+pub fn supported_chains(
+    deps: Deps,
+    service_name: String,
+    start_after: Option<ChainName>,
+    limit: nonempty::Uint32,
+) -> error_stack::Result<Vec<ChainName>, ContractError> {
+    state::service(deps.storage, &service_name, None)?;
+    state::supported_chains(deps.storage, &service_name, start_after, limit.into())
+}
+
 pub fn service(
     deps: Deps,
     service_name: String,
@@ -137,6 +212,59 @@ pub fn service_params_override(
         .map(|o| o.map(Into::into))
 }
 
+pub fn slashing_config(
+    deps: Deps,
+    service_name: String,
+) -> error_stack::Result<Option<SlashingConfig>, ContractError> {
+    state::may_load_slashing_config(deps.storage, &service_name)
+}
+
+pub fn jailed_verifiers(
+    deps: Deps,
+    service_name: String,
+    start_after: Option<String>,
+    limit: nonempty::Uint32,
+) -> Result<Vec<JailedVerifier>, axelar_wasm_std::error::ContractError> {
+    state::service(deps.storage, &service_name, None)?;
+
+    let start_after = start_after
+        .map(|addr| address::validate_cosmwasm_address(deps.api, &addr))
+        .transpose()?;
+
+    VERIFIERS
+        .prefix(&service_name)
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .filter_ok(|(_, verifier)| verifier.authorization_state == AuthorizationState::Jailed)
+        .take(limit.into())
+        .map(|res| {
+            let (verifier, _) = res?;
+
+            let jailed_verifier =
+                match state::may_load_jail_record(deps.storage, &service_name, &verifier)? {
+                    Some(record) => JailedVerifier {
+                        verifier,
+                        reason: record.reason,
+                        jailed_at: Some(record.jailed_at),
+                        expires_at: record.expires_at,
+                    },
+                    None => JailedVerifier {
+                        verifier,
+                        reason: JailReason::Governance,
+                        jailed_at: None,
+                        expires_at: None,
+                    },
+                };
+
+            Ok(jailed_verifier)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use axelar_wasm_std::nonempty;
@@ -1,6 +1,7 @@
 use axelar_wasm_std::IntoEvent;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Timestamp, Uint128};
 use router_api::ChainName;
+use service_registry_api::{JailReason, Misbehaviour};
 
 #[derive(IntoEvent)]
 pub enum Event {
@@ -14,12 +15,30 @@ pub enum Event {
         service_name: String,
         chains: Vec<ChainName>,
     },
+    VerifierSlashed {
+        verifier: Addr,
+        service_name: String,
+        chain_name: ChainName,
+        misbehaviour: Misbehaviour,
+        amount: Uint128,
+    },
+    VerifierJailed {
+        verifier: Addr,
+        service_name: String,
+        reason: JailReason,
+        expires_at: Option<Timestamp>,
+    },
+    VerifierUnjailed {
+        verifier: Addr,
+        service_name: String,
+    },
 }
 
 #[cfg(test)]
 mod test {
-    use cosmwasm_std::{Addr, Event as CosmwasmEvent};
+    use cosmwasm_std::{Addr, Event as CosmwasmEvent, Timestamp, Uint128};
     use router_api::chain_name;
+    use service_registry_api::{JailReason, Misbehaviour};
 
     use super::Event;
 
@@ -47,4 +66,39 @@ mod test {
         let cosmwasm_event: CosmwasmEvent = event.into();
         goldie::assert_json!(cosmwasm_event);
     }
+
+    #[test]
+    fn verifier_slashed_is_serializable() {
+        let event = Event::VerifierSlashed {
+            verifier: Addr::unchecked("verifier"),
+            service_name: "test_service".to_string(),
+            chain_name: chain_name!(ETHEREUM),
+            misbehaviour: Misbehaviour::MissedEpochs { count: 3 },
+            amount: Uint128::from(100u128),
+        };
+        let cosmwasm_event: CosmwasmEvent = event.into();
+        goldie::assert_json!(cosmwasm_event);
+    }
+
+    #[test]
+    fn verifier_jailed_is_serializable() {
+        let event = Event::VerifierJailed {
+            verifier: Addr::unchecked("verifier"),
+            service_name: "test_service".to_string(),
+            reason: JailReason::Misbehaviour(Misbehaviour::IncorrectVote),
+            expires_at: Some(Timestamp::from_seconds(604800)),
+        };
+        let cosmwasm_event: CosmwasmEvent = event.into();
+        goldie::assert_json!(cosmwasm_event);
+    }
+
+    #[test]
+    fn verifier_unjailed_is_serializable() {
+        let event = Event::VerifierUnjailed {
+            verifier: Addr::unchecked("verifier"),
+            service_name: "test_service".to_string(),
+        };
+        let cosmwasm_event: CosmwasmEvent = event.into();
+        goldie::assert_json!(cosmwasm_event);
+    }
 }
@@ -1,7 +1,8 @@
 use cosmwasm_schema::cw_serde;
 // these messages and structs are extracted into a separate package to avoid circular dependencies
 pub use service_registry_api::msg::{
-    ExecuteMsg, QueryMsg, ServiceParamsOverride, UpdatedServiceParams, VerifierDetails,
+    BondingStatus, ExecuteMsg, QueryMsg, ServiceParamsOverride, UpdatedServiceParams,
+    VerifierDetails, VerifierFilter,
 };
 
 pub use crate::contract::MigrateMsg;
@@ -1,13 +1,16 @@
-use axelar_wasm_std::nonempty;
+use axelar_wasm_std::{nonempty, Threshold};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Storage, Timestamp, Uint128};
-use cw_storage_plus::{Index, IndexList, IndexedMap, KeyDeserialize, Map, MultiIndex};
+use cosmwasm_std::{Addr, Order, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, KeyDeserialize, Map, MultiIndex};
 use error_stack::{bail, report, ResultExt as _};
+use itertools::Itertools;
 use report::ResultExt;
 use router_api::ChainName;
 use service_registry_api::error::ContractError;
 use service_registry_api::AuthorizationState::Authorized;
-use service_registry_api::{AuthorizationState, BondingState, Service, Verifier};
+use service_registry_api::{
+    AuthorizationState, BondingState, JailReason, Service, SlashingConfig, Verifier, WeightingMode,
+};
 type ServiceName = String;
 type VerifierAddress = Addr;
 use error_stack::ensure;
@@ -18,6 +21,7 @@ pub struct UpdatedServiceParams {
     pub max_num_verifiers: Option<Option<u16>>,
     pub min_verifier_bond: Option<nonempty::Uint128>,
     pub unbonding_period_days: Option<u16>,
+    pub weighting_mode: Option<WeightingMode>,
 }
 
 impl From<crate::msg::UpdatedServiceParams> for UpdatedServiceParams {
@@ -27,6 +31,7 @@ impl From<crate::msg::UpdatedServiceParams> for UpdatedServiceParams {
             max_num_verifiers: params.max_num_verifiers,
             min_verifier_bond: params.min_verifier_bond,
             unbonding_period_days: params.unbonding_period_days,
+            weighting_mode: params.weighting_mode,
         }
     }
 }
@@ -55,6 +60,15 @@ impl From<ServiceParamsOverride> for crate::msg::ServiceParamsOverride {
     }
 }
 
+#[cw_serde]
+pub struct JailRecord {
+    pub reason: JailReason,
+    pub jailed_at: Timestamp,
+    pub expires_at: Option<Timestamp>,
+    /// Authorization state the verifier is restored to when it gets unjailed
+    pub authorization_state_before_jail: AuthorizationState,
+}
+
 pub struct VerifierPerChainIndexes<'a> {
     pub verifier_address: MultiIndex<
         'a,
@@ -91,7 +105,7 @@ pub const VERIFIERS_PER_CHAIN: IndexedMap<
     },
 );
 
-/// For now, all verifiers have equal weight, regardless of amount bonded
+/// Weight of every active verifier of a service with uniform weighting
 pub const VERIFIER_WEIGHT: nonempty::Uint128 = nonempty::Uint128::one();
 pub const VERIFIERS: Map<(&ServiceName, &VerifierAddress), Verifier> = Map::new("verifiers");
 
@@ -101,6 +115,16 @@ const SERVICE_OVERRIDES: Map<(&ServiceName, &ChainName), ServiceParamsOverride>
 
 const AUTHORIZED_VERIFIER_COUNT: Map<&ServiceName, u16> = Map::new("authorized_verifier_count");
 
+/// Number of verifiers supporting each chain of a service. The chain name is the last key element,
+/// so chains are ordered by name and can be paginated without iterating over all verifiers.
+pub const SUPPORTED_CHAINS: Map<(&ServiceName, ChainName), u64> = Map::new("supported_chains");
+
+const SLASHING_CONFIGS: Map<&ServiceName, SlashingConfig> = Map::new("slashing_configs");
+
+const JAIL_RECORDS: Map<(&ServiceName, &VerifierAddress), JailRecord> = Map::new("jail_records");
+
+const INCORRECT_VOTES: Map<(&ServiceName, &VerifierAddress), u64> = Map::new("incorrect_votes");
+
 pub fn service(
     storage: &dyn Storage,
     service_name: &ServiceName,
@@ -134,6 +158,24 @@ pub fn service(
     }
 }
 
+pub fn services(
+    storage: &dyn Storage,
+    start_after: Option<&ServiceName>,
+    limit: usize,
+) -> error_stack::Result<Vec<Service>, ContractError> {
+    SERVICES
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|res| res.map(|(_, service)| service))
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(ContractError::StorageError)
+}
+
 pub fn save_new_service(
     storage: &mut dyn Storage,
     service_name: &ServiceName,
@@ -188,6 +230,9 @@ pub fn update_service(
                 unbonding_period_days: updated_service_params
                     .unbonding_period_days
                     .unwrap_or(service.unbonding_period_days),
+                weighting_mode: updated_service_params
+                    .weighting_mode
+                    .unwrap_or(service.weighting_mode),
                 ..service
             }),
         })
@@ -233,6 +278,67 @@ pub fn may_load_service_params_override(
         .change_context(ContractError::StorageError)
 }
 
+pub fn save_slashing_config(
+    storage: &mut dyn Storage,
+    service_name: &ServiceName,
+    slashing_config: &SlashingConfig,
+) -> error_stack::Result<(), ContractError> {
+    if !has_service(storage, service_name) {
+        bail!(ContractError::ServiceNotFound);
+    }
+
+    SLASHING_CONFIGS
+        .save(storage, service_name, slashing_config)
+        .into_report()
+}
+
+pub fn remove_slashing_config(
+    storage: &mut dyn Storage,
+    service_name: &ServiceName,
+) -> error_stack::Result<(), ContractError> {
+    if !SLASHING_CONFIGS.has(storage, service_name) {
+        bail!(ContractError::SlashingConfigNotFound);
+    }
+
+    SLASHING_CONFIGS.remove(storage, service_name);
+
+    Ok(())
+}
+
+pub fn may_load_slashing_config(
+    storage: &dyn Storage,
+    service_name: &ServiceName,
+) -> error_stack::Result<Option<SlashingConfig>, ContractError> {
+    SLASHING_CONFIGS
+        .may_load(storage, service_name)
+        .change_context(ContractError::StorageError)
+}
+
+/// Counts an incorrect vote of the verifier. Returns true and resets the count once the threshold is reached.
+pub fn record_incorrect_vote(
+    storage: &mut dyn Storage,
+    service_name: &ServiceName,
+    verifier_addr: &VerifierAddress,
+    threshold: nonempty::Uint64,
+) -> error_stack::Result<bool, ContractError> {
+    let count = INCORRECT_VOTES
+        .may_load(storage, (service_name, verifier_addr))
+        .change_context(ContractError::StorageError)?
+        .unwrap_or_default()
+        .saturating_add(1);
+
+    if count >= u64::from(threshold) {
+        INCORRECT_VOTES.remove(storage, (service_name, verifier_addr));
+        return Ok(true);
+    }
+
+    INCORRECT_VOTES
+        .save(storage, (service_name, verifier_addr), &count)
+        .change_context(ContractError::StorageError)?;
+
+    Ok(false)
+}
+
 pub fn update_verifier_authorization_status(
     storage: &mut dyn Storage,
     service_name: ServiceName,
@@ -242,6 +348,10 @@ pub fn update_verifier_authorization_status(
     let mut authorized_count_change = 0i16;
 
     for verifier_addr in verifiers {
+        if auth_state != AuthorizationState::Jailed {
+            JAIL_RECORDS.remove(storage, (&service_name, &verifier_addr));
+        }
+
         VERIFIERS
             .update(
                 storage,
@@ -282,6 +392,91 @@ pub fn update_verifier_authorization_status(
     Ok(())
 }
 
+/// Jails the given verifiers and records why and until when they are jailed. Verifiers that are already jailed
+/// keep the authorization state they had before they were first jailed, so unjailing restores it.
+pub fn jail_verifiers(
+    storage: &mut dyn Storage,
+    service_name: &ServiceName,
+    verifiers: Vec<Addr>,
+    reason: JailReason,
+    jailed_at: Timestamp,
+    expires_at: Option<Timestamp>,
+) -> error_stack::Result<(), ContractError> {
+    for verifier_addr in &verifiers {
+        let authorization_state_before_jail =
+            match may_load_jail_record(storage, service_name, verifier_addr)? {
+                Some(record) => record.authorization_state_before_jail,
+                None => VERIFIERS
+                    .may_load(storage, (service_name, verifier_addr))
+                    .change_context(ContractError::StorageError)?
+                    .map(|verifier| verifier.authorization_state)
+                    .filter(|state| *state != AuthorizationState::Jailed)
+                    .unwrap_or(AuthorizationState::NotAuthorized),
+            };
+
+        JAIL_RECORDS
+            .save(
+                storage,
+                (service_name, verifier_addr),
+                &JailRecord {
+                    reason: reason.clone(),
+                    jailed_at,
+                    expires_at,
+                    authorization_state_before_jail,
+                },
+            )
+            .change_context(ContractError::StorageError)?;
+    }
+
+    update_verifier_authorization_status(
+        storage,
+        service_name.clone(),
+        AuthorizationState::Jailed,
+        verifiers,
+    )
+}
+
+/// Restores the authorization state the verifier had before it was jailed and returns it.
+/// Verifiers that were jailed before jail records existed are restored to authorized.
+pub fn unjail_verifier(
+    storage: &mut dyn Storage,
+    service_name: &ServiceName,
+    verifier_addr: Addr,
+) -> error_stack::Result<AuthorizationState, ContractError> {
+    let verifier = VERIFIERS
+        .may_load(storage, (service_name, &verifier_addr))
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::VerifierNotFound)?;
+
+    ensure!(
+        verifier.authorization_state == AuthorizationState::Jailed,
+        ContractError::VerifierNotJailed
+    );
+
+    let auth_state = may_load_jail_record(storage, service_name, &verifier_addr)?
+        .map(|record| record.authorization_state_before_jail)
+        .unwrap_or(AuthorizationState::Authorized);
+
+    update_verifier_authorization_status(
+        storage,
+        service_name.clone(),
+        auth_state.clone(),
+        vec![verifier_addr],
+    )?;
+
+    Ok(auth_state)
+}
+
+pub fn may_load_jail_record(
+    storage: &dyn Storage,
+    service_name: &ServiceName,
+    verifier_addr: &VerifierAddress,
+) -> error_stack::Result<Option<JailRecord>, ContractError> {
+    JAIL_RECORDS
+        .may_load(storage, (service_name, verifier_addr))
+        .change_context(ContractError::StorageError)
+}
+
 pub fn number_of_authorized_verifiers(
     storage: &dyn Storage,
     service_name: &ServiceName,
@@ -370,18 +565,61 @@ pub fn claim_verifier_stake(
     }
 }
 
+/// Slashes the given fraction of the verifier's bond, regardless of whether the bond is currently unbonding.
+/// Returns the updated verifier and the slashed amount. If nothing remains of the bond, the verifier becomes unbonded.
+pub fn slash_verifier(
+    verifier: Verifier,
+    slash_fraction: Threshold,
+) -> Result<(Verifier, Uint128), ContractError> {
+    let bonded_amount = match verifier.bonding_state {
+        BondingState::Bonded { amount }
+        | BondingState::RequestedUnbonding { amount }
+        | BondingState::Unbonding { amount, .. } => Uint128::from(amount),
+        BondingState::Unbonded => return Ok((verifier, Uint128::zero())),
+    };
+
+    let slashed = bonded_amount.mul_floor(slash_fraction);
+    let remaining = bonded_amount.checked_sub(slashed)?;
+
+    let bonding_state = match nonempty::Uint128::try_from(remaining) {
+        Err(_) => BondingState::Unbonded,
+        Ok(amount) => match verifier.bonding_state {
+            BondingState::Bonded { .. } => BondingState::Bonded { amount },
+            BondingState::RequestedUnbonding { .. } => BondingState::RequestedUnbonding { amount },
+            BondingState::Unbonding { unbonded_at, .. } => BondingState::Unbonding {
+                amount,
+                unbonded_at,
+            },
+            BondingState::Unbonded => BondingState::Unbonded,
+        },
+    };
+
+    Ok((
+        Verifier {
+            bonding_state,
+            ..verifier
+        },
+        slashed,
+    ))
+}
+
 pub fn register_chains_support(
     storage: &mut dyn Storage,
     service_name: String,
     chains: Vec<ChainName>,
     verifier: VerifierAddress,
 ) -> Result<(), ContractError> {
-    for chain in chains.iter() {
-        VERIFIERS_PER_CHAIN.save(
-            storage,
-            (service_name.clone(), chain.clone(), verifier.clone()),
-            &(),
-        )?;
+    for chain in chains {
+        let key = (service_name.clone(), chain.clone(), verifier.clone());
+        if VERIFIERS_PER_CHAIN.has(storage, key.clone()) {
+            continue;
+        }
+
+        VERIFIERS_PER_CHAIN.save(storage, key, &())?;
+        let count = SUPPORTED_CHAINS
+            .may_load(storage, (&service_name, chain.clone()))?
+            .unwrap_or_default();
+        SUPPORTED_CHAINS.save(storage, (&service_name, chain), &count.saturating_add(1))?;
     }
     Ok(())
 }
@@ -393,11 +631,65 @@ pub fn deregister_chains_support(
     verifier: VerifierAddress,
 ) -> Result<(), ContractError> {
     for chain in chains {
-        VERIFIERS_PER_CHAIN.remove(storage, (service_name.clone(), chain, verifier.clone()))?;
+        let key = (service_name.clone(), chain.clone(), verifier.clone());
+        if !VERIFIERS_PER_CHAIN.has(storage, key.clone()) {
+            continue;
+        }
+
+        VERIFIERS_PER_CHAIN.remove(storage, key)?;
+        match SUPPORTED_CHAINS
+            .may_load(storage, (&service_name, chain.clone()))?
+            .unwrap_or_default()
+        {
+            0 | 1 => SUPPORTED_CHAINS.remove(storage, (&service_name, chain)),
+            count => {
+                SUPPORTED_CHAINS.save(storage, (&service_name, chain), &count.saturating_sub(1))?
+            }
+        }
     }
     Ok(())
 }
 
+/// Counts the verifiers supporting each chain of each service. Needed for chain support registered before the counts were stored.
+pub fn backfill_supported_chains(
+    storage: &mut dyn Storage,
+) -> error_stack::Result<(), ContractError> {
+    let counts = VERIFIERS_PER_CHAIN
+        .keys(storage, None, None, Order::Ascending)
+        .map_ok(|(service_name, chain, _)| (service_name, chain))
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(ContractError::StorageError)?
+        .into_iter()
+        .counts();
+
+    for ((service_name, chain), count) in counts {
+        SUPPORTED_CHAINS
+            .save(storage, (&service_name, chain), &(count as u64))
+            .change_context(ContractError::StorageError)?;
+    }
+
+    Ok(())
+}
+
+pub fn supported_chains(
+    storage: &dyn Storage,
+    service_name: &ServiceName,
+    start_after: Option<ChainName>,
+    limit: usize,
+) -> error_stack::Result<Vec<ChainName>, ContractError> {
+    SUPPORTED_CHAINS
+        .prefix(service_name)
+        .keys(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(ContractError::StorageError)
+}
+
 fn calculate_auth_verifier_count_change(
     previous_state: Option<&AuthorizationState>,
     auth_state: &AuthorizationState,
@@ -1211,6 +1503,215 @@ mod tests {
         assert_eq!(res.unwrap_err(), ContractError::VerifierJailed);
     }
 
+    #[test]
+    fn slash_verifier_reduces_bond_in_every_bonding_state() {
+        let slash_fraction = Threshold::try_from((1u64, 10u64)).unwrap();
+        let amount: nonempty::Uint128 = Uint128::from(100u32).try_into().unwrap();
+        let remaining: nonempty::Uint128 = Uint128::from(90u32).try_into().unwrap();
+
+        for (bonding_state, expected_bonding_state) in [
+            (
+                BondingState::Bonded { amount },
+                BondingState::Bonded { amount: remaining },
+            ),
+            (
+                BondingState::RequestedUnbonding { amount },
+                BondingState::RequestedUnbonding { amount: remaining },
+            ),
+            (
+                BondingState::Unbonding {
+                    amount,
+                    unbonded_at: Timestamp::from_nanos(1),
+                },
+                BondingState::Unbonding {
+                    amount: remaining,
+                    unbonded_at: Timestamp::from_nanos(1),
+                },
+            ),
+        ] {
+            let verifier = Verifier {
+                address: cosmos_addr!(VERIFIER),
+                bonding_state,
+                authorization_state: AuthorizationState::Authorized,
+                service_name: "validators".to_string(),
+            };
+
+            let (verifier, slashed) = slash_verifier(verifier, slash_fraction).unwrap();
+            assert_eq!(verifier.bonding_state, expected_bonding_state);
+            assert_eq!(slashed, Uint128::from(10u32));
+        }
+    }
+
+    #[test]
+    fn slash_verifier_unbonds_verifier_if_nothing_remains() {
+        let verifier = Verifier {
+            address: cosmos_addr!(VERIFIER),
+            bonding_state: BondingState::Bonded {
+                amount: Uint128::from(100u32).try_into().unwrap(),
+            },
+            authorization_state: AuthorizationState::Authorized,
+            service_name: "validators".to_string(),
+        };
+
+        let (verifier, slashed) =
+            slash_verifier(verifier, Threshold::try_from((1u64, 1u64)).unwrap()).unwrap();
+        assert_eq!(verifier.bonding_state, BondingState::Unbonded);
+        assert_eq!(slashed, Uint128::from(100u32));
+    }
+
+    #[test]
+    fn slash_unbonded_verifier_slashes_nothing() {
+        let verifier = Verifier {
+            address: cosmos_addr!(VERIFIER),
+            bonding_state: BondingState::Unbonded,
+            authorization_state: AuthorizationState::Authorized,
+            service_name: "validators".to_string(),
+        };
+
+        let (slashed_verifier, slashed) = slash_verifier(
+            verifier.clone(),
+            Threshold::try_from((1u64, 10u64)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(slashed_verifier, verifier);
+        assert_eq!(slashed, Uint128::zero());
+    }
+
+    #[test]
+    fn remove_slashing_config_fails_if_slashing_config_does_not_exist() {
+        let mut deps = mock_dependencies();
+        save_mock_service(deps.as_mut().storage);
+
+        assert_err_contains!(
+            remove_slashing_config(deps.as_mut().storage, &"amplifier".to_string()),
+            ContractError,
+            ContractError::SlashingConfigNotFound
+        );
+    }
+
+    #[test]
+    fn unjail_verifier_restores_authorization_state_before_jail() {
+        let mut deps = mock_dependencies();
+        let service = save_mock_service(deps.as_mut().storage);
+        let verifier = cosmos_addr!(VERIFIER);
+
+        update_verifier_authorization_status(
+            deps.as_mut().storage,
+            service.name.clone(),
+            AuthorizationState::Authorized,
+            vec![verifier.clone()],
+        )
+        .unwrap();
+
+        jail_verifiers(
+            deps.as_mut().storage,
+            &service.name,
+            vec![verifier.clone()],
+            JailReason::Governance,
+            Timestamp::from_seconds(1),
+            None,
+        )
+        .unwrap();
+
+        // jailing again must not overwrite the state to restore
+        jail_verifiers(
+            deps.as_mut().storage,
+            &service.name,
+            vec![verifier.clone()],
+            JailReason::Governance,
+            Timestamp::from_seconds(2),
+            Some(Timestamp::from_seconds(3)),
+        )
+        .unwrap();
+        assert_eq!(
+            number_of_authorized_verifiers(deps.as_ref().storage, &service.name).unwrap(),
+            0
+        );
+
+        let record = may_load_jail_record(deps.as_ref().storage, &service.name, &verifier)
+            .unwrap()
+            .unwrap();
+        assert_eq!(record.jailed_at, Timestamp::from_seconds(2));
+        assert_eq!(record.expires_at, Some(Timestamp::from_seconds(3)));
+        assert_eq!(
+            record.authorization_state_before_jail,
+            AuthorizationState::Authorized
+        );
+
+        let auth_state =
+            unjail_verifier(deps.as_mut().storage, &service.name, verifier.clone()).unwrap();
+
+        assert_eq!(auth_state, AuthorizationState::Authorized);
+        assert_eq!(
+            VERIFIERS
+                .load(deps.as_ref().storage, (&service.name, &verifier))
+                .unwrap()
+                .authorization_state,
+            AuthorizationState::Authorized
+        );
+        assert_eq!(
+            number_of_authorized_verifiers(deps.as_ref().storage, &service.name).unwrap(),
+            1
+        );
+        assert!(
+            may_load_jail_record(deps.as_ref().storage, &service.name, &verifier)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn unjail_verifier_fails_if_verifier_is_not_jailed() {
+        let mut deps = mock_dependencies();
+        let service = save_mock_service(deps.as_mut().storage);
+        let verifier = cosmos_addr!(VERIFIER);
+
+        update_verifier_authorization_status(
+            deps.as_mut().storage,
+            service.name.clone(),
+            AuthorizationState::Authorized,
+            vec![verifier.clone()],
+        )
+        .unwrap();
+
+        assert_err_contains!(
+            unjail_verifier(deps.as_mut().storage, &service.name, verifier),
+            ContractError,
+            ContractError::VerifierNotJailed
+        );
+    }
+
+    #[test]
+    fn authorizing_jailed_verifier_removes_jail_record() {
+        let mut deps = mock_dependencies();
+        let service = save_mock_service(deps.as_mut().storage);
+        let verifier = cosmos_addr!(VERIFIER);
+
+        jail_verifiers(
+            deps.as_mut().storage,
+            &service.name,
+            vec![verifier.clone()],
+            JailReason::Governance,
+            Timestamp::from_seconds(1),
+            None,
+        )
+        .unwrap();
+
+        update_verifier_authorization_status(
+            deps.as_mut().storage,
+            service.name.clone(),
+            AuthorizationState::Authorized,
+            vec![verifier.clone()],
+        )
+        .unwrap();
+
+        assert!(
+            may_load_jail_record(deps.as_ref().storage, &service.name, &verifier)
+                .unwrap()
+                .is_none()
+        );
+    }
+
     fn mock_service() -> Service {
         Service {
             name: "amplifier".to_string(),
@@ -1221,6 +1722,7 @@ mod tests {
             bond_denom: "uaxl".to_string(),
             unbonding_period_days: 1,
             description: "description".to_string(),
+            weighting_mode: WeightingMode::Uniform,
         }
     }
 
@@ -1282,6 +1784,7 @@ mod tests {
             min_num_verifiers: None,
             min_verifier_bond: None,
             unbonding_period_days: None,
+            weighting_mode: None,
         };
 
         let result = update_service(deps.as_mut().storage, &service.name, params);
@@ -4,7 +4,7 @@ use axelar_wasm_std::{nonempty, MajorityThreshold, VerificationStatus};
 use cosmwasm_std::CosmosMsg;
 use error_stack::ResultExt;
 use multisig::verifier_set::VerifierSet;
-use router_api::Message;
+use router_api::{CrossChainId, Message};
 
 use crate::msg::{ExecuteMsg, MessageStatus, PollResponse, QueryMsg, VotingParameters};
 
@@ -18,6 +18,8 @@ pub enum Error {
     VotingParameters,
     #[error("failed to query voting verifier for messages status. messages: {0:?}")]
     MessagesStatus(Vec<Message>),
+    #[error("failed to query voting verifier for message status. cc_id: {0}")]
+    MessageStatusById(CrossChainId),
     #[error("failed to query voting verifier for poll. poll_id: {0}")]
     Poll(PollId),
 }
@@ -26,6 +28,7 @@ impl Error {
     fn for_query(value: QueryMsg) -> Self {
         match value {
             QueryMsg::MessagesStatus(messages) => Error::MessagesStatus(messages),
+            QueryMsg::MessageStatusById { cc_id } => Error::MessageStatusById(cc_id),
             QueryMsg::VerifierSetStatus(verifier_set) => Error::VerifierSetStatus(verifier_set),
             QueryMsg::Poll { poll_id } => Error::Poll(poll_id),
             QueryMsg::VotingParameters => Error::VotingParameters,
@@ -101,6 +104,13 @@ impl Client<'_> {
         }
     }
 
+    pub fn message_status_by_id(&self, cc_id: CrossChainId) -> Result<Option<MessageStatus>> {
+        let msg = QueryMsg::MessageStatusById { cc_id };
+        self.client
+            .query(&msg)
+            .change_context_lazy(|| Error::for_query(msg))
+    }
+
     pub fn verifier_set_status(&self, new_verifier_set: VerifierSet) -> Result<VerificationStatus> {
         let msg = QueryMsg::VerifierSetStatus(new_verifier_set);
         self.client
@@ -174,6 +184,10 @@ mod test {
         };
 
         assert!(client.messages_status(vec![]).unwrap().is_empty());
+        assert_eq!(
+            client.message_status_by_id(msg_1.cc_id.clone()).unwrap(),
+            None
+        );
         assert_eq!(
             client
                 .messages_status(vec![msg_1.clone(), msg_2.clone()])
@@ -261,6 +275,17 @@ mod test {
         goldie::assert!(res.unwrap_err().to_string());
     }
 
+    #[test]
+    fn query_message_status_by_id_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.message_status_by_id(CrossChainId::new("eth", "0x1234-0").unwrap());
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
     #[test]
     fn query_poll_returns_error_when_query_fails() {
         let (querier, addr) = setup_queries_to_fail();
@@ -102,6 +102,11 @@ pub fn query(
         QueryMsg::MessagesStatus(messages) => {
             to_json_binary(&query::messages_status(deps, &messages, env.block.height)?)
         }
+        QueryMsg::MessageStatusById { cc_id } => to_json_binary(&query::message_status_by_id(
+            deps,
+            &cc_id,
+            env.block.height,
+        )?),
         QueryMsg::VerifierSetStatus(new_verifier_set) => to_json_binary(
             &query::verifier_set_status(deps, &new_verifier_set, env.block.height)?,
         ),
@@ -127,11 +132,14 @@ mod test {
     use cosmwasm_std::testing::{
         message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
     };
-    use cosmwasm_std::{from_json, Empty, Fraction, OwnedDeps, Uint128, Uint64, WasmQuery};
+    use cosmwasm_std::{
+        from_json, Addr, CosmosMsg, Empty, Fraction, OwnedDeps, Uint128, Uint64, WasmMsg, WasmQuery,
+    };
     use multisig::key::KeyType;
     use multisig::test::common::{build_verifier_set, ecdsa_test_data};
     use router_api::{address, chain_name, cosmos_addr, ChainName, CrossChainId, Message};
     use service_registry::{AuthorizationState, BondingState, Verifier, WeightedVerifier};
+    use service_registry_api::{Misbehaviour, SlashingConfig};
     use sha3::{Digest, Keccak256, Keccak512};
     use starknet_checked_felt::CheckedFelt;
 
@@ -207,27 +215,42 @@ mod test {
         )
         .unwrap();
 
+        mock_service_registry(&mut deps, verifiers, None);
+
+        deps
+    }
+
+    fn mock_service_registry(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+        verifiers: Vec<Verifier>,
+        slashing_config: Option<SlashingConfig>,
+    ) {
+        let service_registry = cosmos_addr!(SERVICE_REGISTRY_ADDRESS);
+
         deps.querier.update_wasm(move |wq| match wq {
-            WasmQuery::Smart { contract_addr, .. }
+            WasmQuery::Smart { contract_addr, msg }
                 if contract_addr == service_registry.as_str() =>
             {
-                Ok(to_json_binary(
-                    &verifiers
-                        .clone()
-                        .into_iter()
-                        .map(|v| WeightedVerifier {
-                            verifier_info: v,
-                            weight: nonempty::Uint128::one(),
-                        })
-                        .collect::<Vec<WeightedVerifier>>(),
-                )
-                .into())
-                .into()
+                match from_json::<service_registry_api::msg::QueryMsg>(msg).unwrap() {
+                    service_registry_api::msg::QueryMsg::SlashingConfig { .. } => {
+                        Ok(to_json_binary(&slashing_config).into()).into()
+                    }
+                    _ => Ok(to_json_binary(
+                        &verifiers
+                            .clone()
+                            .into_iter()
+                            .map(|v| WeightedVerifier {
+                                verifier_info: v,
+                                weight: nonempty::Uint128::one(),
+                            })
+                            .collect::<Vec<WeightedVerifier>>(),
+                    )
+                    .into())
+                    .into(),
+                }
             }
             _ => panic!("no mock for this query"),
         });
-
-        deps
     }
 
     fn message_id(id: &str, index: u64, msg_id_format: &MessageIdFormat) -> nonempty::String {
@@ -715,6 +738,36 @@ mod test {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn should_look_up_message_status_by_id() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(2);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let messages = messages(2, &msg_id_format);
+
+        let query_by_id = |deps: Deps, cc_id: CrossChainId| -> Option<MessageStatus> {
+            from_json(query(deps, mock_env(), QueryMsg::MessageStatusById { cc_id }).unwrap())
+                .unwrap()
+        };
+
+        assert_eq!(query_by_id(deps.as_ref(), messages[0].cc_id.clone()), None);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages.clone()),
+        )
+        .unwrap();
+
+        for message in messages {
+            assert_eq!(
+                query_by_id(deps.as_ref(), message.cc_id.clone()),
+                Some(MessageStatus::new(message, VerificationStatus::InProgress))
+            );
+        }
+    }
+
     #[test]
     fn should_retry_if_status_not_final() {
         let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
@@ -1136,6 +1189,114 @@ mod test {
         assert_eq!(res, VerificationStatus::NotFoundOnSourceChain);
     }
 
+    fn end_verifier_set_poll_with_one_incorrect_vote(
+        slashing_config: Option<SlashingConfig>,
+    ) -> (Response, Addr) {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(3);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        mock_service_registry(&mut deps, verifiers.clone(), slashing_config);
+
+        let verifier_set = build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers());
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&cosmos_addr!(SENDER), &[]),
+            ExecuteMsg::VerifyVerifierSet {
+                message_id: message_id("id", 0, &msg_id_format),
+                new_verifier_set: verifier_set.clone(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let incorrect_voter = verifiers[2].address.clone();
+        for verifier in verifiers {
+            let vote = if verifier.address == incorrect_voter {
+                Vote::NotFound
+            } else {
+                Vote::SucceededOnChain
+            };
+            let res = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&verifier.address, &[]),
+                ExecuteMsg::Vote {
+                    poll_id: 1u64.into(),
+                    votes: vec![vote],
+                },
+            );
+            assert!(res.is_ok());
+        }
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env_expired(),
+            message_info(&cosmos_addr!(SENDER), &[]),
+            ExecuteMsg::EndPoll {
+                poll_id: 1u64.into(),
+            },
+        )
+        .unwrap();
+
+        (res, incorrect_voter)
+    }
+
+    #[test]
+    fn end_poll_should_report_incorrect_voters_if_contract_is_reporter() {
+        let (res, incorrect_voter) =
+            end_verifier_set_poll_with_one_incorrect_vote(Some(SlashingConfig {
+                reporters: vec![mock_env().contract.address],
+                slash_fraction: Threshold::try_from((1, 10)).unwrap(),
+                slashed_funds_recipient: cosmos_addr!("treasury"),
+                jail_duration_days: None,
+                incorrect_votes_threshold: Some(1u64.try_into().unwrap()),
+            }));
+
+        // two rewards messages and one misbehaviour report
+        assert_eq!(res.messages.len(), 3);
+        assert_eq!(
+            res.messages[2].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: cosmos_addr!(SERVICE_REGISTRY_ADDRESS).to_string(),
+                msg: to_json_binary(&service_registry_api::msg::ExecuteMsg::ReportMisbehaviour {
+                    service_name: SERVICE_NAME.to_string(),
+                    chain_name: source_chain(),
+                    verifiers: vec![incorrect_voter.to_string()],
+                    misbehaviour: Misbehaviour::IncorrectVote,
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn end_poll_should_not_report_incorrect_voters_if_contract_is_not_reporter() {
+        let (res, _) = end_verifier_set_poll_with_one_incorrect_vote(None);
+        assert_eq!(res.messages.len(), 2);
+
+        let (res, _) = end_verifier_set_poll_with_one_incorrect_vote(Some(SlashingConfig {
+            reporters: vec![cosmos_addr!(REWARDS_ADDRESS)],
+            slash_fraction: Threshold::try_from((1, 10)).unwrap(),
+            slashed_funds_recipient: cosmos_addr!("treasury"),
+            jail_duration_days: None,
+            incorrect_votes_threshold: Some(1u64.try_into().unwrap()),
+        }));
+        assert_eq!(res.messages.len(), 2);
+    }
+
+    #[test]
+    fn end_poll_should_not_report_incorrect_voters_if_slashing_of_incorrect_votes_is_disabled() {
+        let (res, _) = end_verifier_set_poll_with_one_incorrect_vote(Some(SlashingConfig {
+            reporters: vec![mock_env().contract.address],
+            slash_fraction: Threshold::try_from((1, 10)).unwrap(),
+            slashed_funds_recipient: cosmos_addr!("treasury"),
+            jail_duration_days: None,
+            incorrect_votes_threshold: None,
+        }));
+        assert_eq!(res.messages.len(), 2);
+    }
+
     #[test]
     fn should_confirm_verifier_set_after_failed() {
         let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
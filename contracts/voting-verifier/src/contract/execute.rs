@@ -5,14 +5,15 @@ use axelar_wasm_std::utils::TryMapExt;
 use axelar_wasm_std::voting::{PollId, PollResults, Vote, WeightedPoll};
 use axelar_wasm_std::{nonempty, snapshot, MajorityThreshold, VerificationStatus};
 use cosmwasm_std::{
-    to_json_binary, Deps, DepsMut, Env, Event, MessageInfo, OverflowError, OverflowOperation,
-    Response, Storage, WasmMsg,
+    to_json_binary, CosmosMsg, Deps, DepsMut, Env, Event, MessageInfo, OverflowError,
+    OverflowOperation, Response, Storage, WasmMsg,
 };
 use error_stack::{report, Report, Result, ResultExt};
 use itertools::Itertools;
 use multisig::verifier_set::VerifierSet;
 use router_api::{ChainName, Message};
 use service_registry::WeightedVerifier;
+use service_registry_api::Misbehaviour;
 
 use crate::contract::query::{message_status, verifier_set_status};
 use crate::error::ContractError;
@@ -21,7 +22,8 @@ use crate::events::{
     VerifierSetConfirmation, Voted,
 };
 use crate::state::{
-    self, poll_messages, poll_verifier_sets, Poll, PollContent, CONFIG, POLLS, POLL_ID, VOTES,
+    self, poll_messages, poll_verifier_sets, Config, Poll, PollContent, CONFIG, MESSAGE_HASHES,
+    POLLS, POLL_ID, VOTES,
 };
 
 pub fn update_voting_parameters(
@@ -140,6 +142,9 @@ pub fn verify_messages(
                 &state::PollContent::<Message>::new(message.clone(), id, idx),
             )
             .change_context(ContractError::StorageError)?;
+        MESSAGE_HASHES
+            .save(deps.storage, &message.cc_id, &message.hash())
+            .change_context(ContractError::StorageError)?;
     }
 
     let messages = msgs_to_verify
@@ -288,12 +293,27 @@ pub fn end_poll(deps: DepsMut, env: Env, poll_id: PollId) -> Result<Response, Co
         .try_collect()
         .change_context(ContractError::StorageError)?;
 
+    let voters = votes
+        .iter()
+        .map(|(voter, _)| voter.clone())
+        .collect::<Vec<_>>();
+
     let poll_result = match &poll {
         Poll::Messages(poll) | Poll::ConfirmVerifierSet(poll) => {
             poll.state(HashMap::from_iter(votes))
         }
     };
 
+    let incorrect_voters = voters
+        .into_iter()
+        .filter(|voter| !poll_result.consensus_participants.contains(voter))
+        .collect::<Vec<_>>();
+
+    let event_kind = match &poll {
+        Poll::Messages(_) => rewards::msg::EventKind::MessagesPoll,
+        Poll::ConfirmVerifierSet(_) => rewards::msg::EventKind::VerifierSetPoll,
+    };
+
     // TODO: change rewards contract interface to accept a list of addresses to avoid creating multiple wasm messages
     let rewards_msgs = poll_result
         .consensus_participants
@@ -307,13 +327,19 @@ pub fn end_poll(deps: DepsMut, env: Env, poll_id: PollId) -> Result<Response, Co
                     .try_into()
                     .expect("couldn't convert poll id to nonempty string"),
                 verifier_address: address.to_string(),
+                event_kind: Some(event_kind),
+                late: false,
             })
             .expect("failed to serialize message for rewards contract"),
             funds: vec![],
         });
 
+    let misbehaviour_report_msg =
+        report_incorrect_voters(deps.as_ref(), &env, &config, incorrect_voters)?;
+
     Ok(Response::new()
         .add_messages(rewards_msgs)
+        .add_messages(misbehaviour_report_msg)
         .add_event(PollEnded {
             poll_id: poll_result.poll_id,
             results: poll_result.results.0.clone(),
@@ -321,6 +347,39 @@ pub fn end_poll(deps: DepsMut, env: Env, poll_id: PollId) -> Result<Response, Co
         }))
 }
 
+/// Reports verifiers that voted against the consensus to the service registry,
+/// if slashing is enabled for the service and this contract is one of its reporters.
+fn report_incorrect_voters(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    incorrect_voters: Vec<String>,
+) -> Result<Option<CosmosMsg>, ContractError> {
+    if incorrect_voters.is_empty() {
+        return Ok(None);
+    }
+
+    let service_registry: service_registry_api::Client =
+        client::ContractClient::new(deps.querier, &config.service_registry_contract).into();
+
+    let reports_incorrect_votes = service_registry
+        .slashing_config(config.service_name.clone().into())
+        .change_context(ContractError::FailedToQuerySlashingConfig)?
+        .is_some_and(|slashing_config| {
+            slashing_config.incorrect_votes_threshold.is_some()
+                && slashing_config.reporters.contains(&env.contract.address)
+        });
+
+    Ok(reports_incorrect_votes.then(|| {
+        service_registry.report_misbehaviour(
+            config.service_name.clone().into(),
+            config.source_chain.clone(),
+            incorrect_voters,
+            Misbehaviour::IncorrectVote,
+        )
+    }))
+}
+
 fn take_snapshot(deps: Deps, chain: &ChainName) -> Result<snapshot::Snapshot, ContractError> {
     let config = CONFIG.load(deps.storage).expect("failed to load config");
 
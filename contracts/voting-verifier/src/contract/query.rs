@@ -3,11 +3,13 @@ use axelar_wasm_std::VerificationStatus;
 use cosmwasm_std::Deps;
 use error_stack::{Result, ResultExt};
 use multisig::verifier_set::VerifierSet;
-use router_api::Message;
+use router_api::{CrossChainId, Message};
 
 use crate::error::ContractError;
 use crate::msg::{MessageStatus, PollData, PollResponse, VotingParameters};
-use crate::state::{poll_messages, poll_verifier_sets, Poll, PollContent, CONFIG, POLLS};
+use crate::state::{
+    poll_messages, poll_verifier_sets, Poll, PollContent, CONFIG, MESSAGE_HASHES, POLLS,
+};
 
 pub fn voting_parameters(deps: Deps) -> Result<VotingParameters, ContractError> {
     let config = CONFIG
@@ -52,6 +54,27 @@ pub fn message_status(
     ))
 }
 
+pub fn message_status_by_id(
+    deps: Deps,
+    cc_id: &CrossChainId,
+    cur_block_height: u64,
+) -> Result<Option<MessageStatus>, ContractError> {
+    let Some(hash) = MESSAGE_HASHES
+        .may_load(deps.storage, cc_id)
+        .change_context(ContractError::StorageError)?
+    else {
+        return Ok(None);
+    };
+
+    let message = poll_messages()
+        .load(deps.storage, &hash)
+        .change_context(ContractError::StorageError)?
+        .content;
+
+    message_status(deps, &message, cur_block_height)
+        .map(|status| Some(MessageStatus::new(message, status)))
+}
+
 pub fn poll_response(
     deps: Deps,
     current_block_height: u64,
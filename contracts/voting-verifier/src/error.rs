@@ -20,6 +20,9 @@ pub enum ContractError {
     #[error("failed to build verifier snapshot")]
     FailedToBuildSnapshot,
 
+    #[error("failed to query slashing config")]
+    FailedToQuerySlashingConfig,
+
     #[error("empty batch of messages")]
     EmptyMessages,
 
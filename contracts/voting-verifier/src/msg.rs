@@ -3,7 +3,7 @@ use axelar_wasm_std::{nonempty, MajorityThreshold, VerificationStatus};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use msgs_derive::Permissions;
 use multisig::verifier_set::VerifierSet;
-use router_api::Message;
+use router_api::{CrossChainId, Message};
 pub use voting_verifier_api::msg::InstantiateMsg;
 
 pub use crate::contract::MigrateMsg;
@@ -72,6 +72,11 @@ pub enum QueryMsg {
     #[returns(Vec<MessageStatus>)]
     MessagesStatus(Vec<Message>),
 
+    /// Returns the status of the latest message with the given ID that was put to a poll, or None if there is none.
+    /// Unlike MessagesStatus, this doesn't require the message content
+    #[returns(Option<MessageStatus>)]
+    MessageStatusById { cc_id: CrossChainId },
+
     #[returns(VerificationStatus)]
     VerifierSetStatus(VerifierSet),
 
@@ -7,7 +7,7 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Order, StdResult, Storage};
 use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 use multisig::verifier_set::VerifierSet;
-use router_api::{ChainName, Message};
+use router_api::{ChainName, CrossChainId, Message};
 
 use crate::error::ContractError;
 
@@ -89,6 +89,11 @@ pub const VOTES: Map<(PollId, VerifierAddr), Vec<Vote>> = Map::new("votes");
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
+/// Maps a message ID to the hash of the latest message with that ID that was put to a poll,
+/// so the message can be looked up without knowing its content.
+/// Messages that were last put to a poll before this map was introduced are not included
+pub const MESSAGE_HASHES: Map<&CrossChainId, Hash> = Map::new("message_hashes");
+
 /// A multi-index that indexes a message by (PollID, index in poll) pair. The primary key of the underlying
 /// map is the hash of the message (typed as Hash). This allows looking up a Message by it's hash,
 /// or by a (PollID, index in poll) pair. The PollID is stored as a String
@@ -6,6 +6,7 @@ use multisig::key::KeyType;
 use router_api::cosmos_addr;
 use solana_multisig_prover::contract::{execute, instantiate, query};
 use solana_multisig_prover_api::encoding::Encoder;
+use solana_multisig_prover_api::msg::PayloadLimits;
 
 use crate::contract::Contract;
 use crate::protocol::{emptying_deps_mut, Protocol};
@@ -52,6 +53,7 @@ impl MultisigProverContract {
                     encoder: Encoder::Abi,
                     key_type: KeyType::Ecdsa,
                     domain_separator: [0; 32],
+                    payload_limits: PayloadLimits::default(),
                 },
                 &[],
                 "multisig_prover",
@@ -290,6 +290,7 @@ fn claim_stake_when_jailed_fails() {
                 cosmos_addr!("verifier1").to_string(),
                 cosmos_addr!("verifier2").to_string(),
             ],
+            jail_duration_days: None,
         },
     );
     assert!(response.is_ok());
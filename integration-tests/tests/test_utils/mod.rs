@@ -28,6 +28,7 @@ use router_api::{
     chain_name, cosmos_addr, Address, ChainName, CrossChainId, GatewayDirection, Message,
 };
 use service_registry_api::msg::ExecuteMsg;
+use service_registry_api::WeightingMode;
 use sha3::{Digest, Keccak256};
 use solana_multisig_prover::msg::VerifierSetResponse;
 use tofn::ecdsa::KeyPair;
@@ -274,6 +275,7 @@ pub fn register_service(
             bond_denom: AXL_DENOMINATION.into(),
             unbonding_period_days,
             description: "Some service".into(),
+            weighting_mode: WeightingMode::Uniform,
         },
     );
     assert!(response.is_ok());
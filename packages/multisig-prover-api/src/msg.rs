@@ -1,5 +1,5 @@
 use axelar_wasm_std::hash::Hash;
-use axelar_wasm_std::MajorityThreshold;
+use axelar_wasm_std::{nonempty, MajorityThreshold};
 use cosmwasm_schema::cw_serde;
 use multisig::key::KeyType;
 
@@ -52,4 +52,20 @@ pub struct InstantiateMsg {
     #[serde(with = "axelar_wasm_std::hex")] // (de)serialization with hex module
     #[schemars(with = "String")] // necessary attribute in conjunction with #[serde(with ...)]
     pub domain_separator: Hash,
+    /// Limits on the payloads of message proofs, so that each proof fits into a single transaction on the destination chain.
+    /// If not set, all messages passed to ConstructProof are put into a single payload.
+    #[serde(default)]
+    pub payload_limits: PayloadLimits,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct PayloadLimits {
+    /// Maximum number of messages in a single payload. Unlimited if not set.
+    pub max_messages: Option<nonempty::Uint32>,
+    /// Maximum size in bytes of a single payload, as encoded by the prover's encoder. Unlimited if not set.
+    pub max_payload_size: Option<nonempty::Uint32>,
+    /// If true, ConstructProof splits messages that exceed the limits into multiple payloads, each signed in its own signing session.
+    /// If false, ConstructProof fails for messages that exceed the limits.
+    pub auto_chunk: bool,
 }
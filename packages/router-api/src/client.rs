@@ -1,11 +1,20 @@
 use axelar_wasm_std::msg_id::MessageIdFormat;
 use axelar_wasm_std::vec::VecExt;
 use cosmwasm_std::{Addr, CosmosMsg};
+use error_stack::ResultExt;
 
 use crate::msg::{ExecuteMsg, QueryMsg};
-use crate::primitives::{Address, ChainName};
+use crate::primitives::{Address, ChainEndpoint, ChainName};
 use crate::Message;
 
+type Result<T> = error_stack::Result<T, Error>;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("failed to query router for chain info. chain: {0}")]
+    ChainInfo(ChainName),
+}
+
 pub struct Client<'a> {
     pub client: client::ContractClient<'a, ExecuteMsg, QueryMsg>,
 }
@@ -22,6 +31,12 @@ impl Client<'_> {
             .map(|msgs| self.client.execute(&ExecuteMsg::RouteMessages(msgs)))
     }
 
+    pub fn chain_info(&self, chain: ChainName) -> Result<ChainEndpoint> {
+        self.client
+            .query(&QueryMsg::ChainInfo(chain.clone()))
+            .change_context(Error::ChainInfo(chain))
+    }
+
     pub fn register_chain(
         &self,
         original_sender: Addr,
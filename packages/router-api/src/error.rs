@@ -2,7 +2,7 @@ use axelar_wasm_std::{nonempty, IntoContractError};
 use cosmwasm_std::StdError;
 use thiserror::Error;
 
-use crate::ChainName;
+use crate::{ChainName, GatewayDirection};
 
 /// A chain name must adhere to the following rules:
 /// 1. it can optionally start with an uppercase letter, followed by one or more lowercase letters
@@ -41,6 +41,21 @@ pub enum Error {
     #[error("chain is frozen")]
     ChainFrozen { chain: ChainName },
 
+    #[error("rate limit of chain {chain} exceeded in direction {direction:?}")]
+    RateLimitExceeded {
+        chain: ChainName,
+        direction: GatewayDirection,
+    },
+
+    #[error("rate limit direction must not be none")]
+    InvalidRateLimitDirection,
+
+    #[error("routing from chain {source_chain} to chain {destination_chain} is not allowed")]
+    RoutingNotAllowed {
+        source_chain: ChainName,
+        destination_chain: ChainName,
+    },
+
     #[error("address is invalid")]
     InvalidAddress,
 
@@ -50,8 +50,32 @@ pub enum ExecuteMsg {
     #[permission(Elevated)]
     EnableRouting,
 
+    /// Sets the rate limit of the specified chain in the specified directions, or removes it if `limit` is None.
+    /// Limits are enforced per RouteMessages batch: a batch that would exceed the limit in the current window is rejected
+    /// as a whole, and none of its messages are routed. Relayers must therefore keep batches to a chain at or below
+    /// `max_messages`, since a larger batch can never be routed, and retry rejected messages in smaller batches or in a
+    /// later window. The remaining capacity of the current window can be queried with RateLimits.
+    /// Setting a limit resets the usage of the current window.
+    /// Messages received from the axelarnet gateway originate from chains registered with nexus, so they are only
+    /// counted against the outgoing limit of their destination chain.
+    #[permission(Governance)]
+    SetRateLimit {
+        chain: ChainName,
+        direction: GatewayDirection,
+        limit: Option<RateLimit>,
+    },
+
+    /// Sets the policy that restricts the destination chains messages from the specified chain can be routed to.
+    /// Policies can only be set for chains registered with the router, so they don't apply to messages received from
+    /// the axelarnet gateway.
+    #[permission(Governance)]
+    SetRoutingPolicy {
+        chain: ChainName,
+        policy: RoutingPolicy,
+    },
+
     /// Routes a message to all outgoing gateways registered to the destination domain.
-    /// Called by an incoming gateway
+    /// Called by an incoming gateway. Fails as a whole if any of the messages would exceed a rate limit.
     #[permission(Specific(gateway))]
     RouteMessages(Vec<Message>),
 }
@@ -74,4 +98,96 @@ pub enum QueryMsg {
     },
     #[returns(bool)]
     IsEnabled,
+
+    /// Returns the rate limits of a chain and their usage in the current window
+    #[returns(ChainRateLimits)]
+    RateLimits(ChainName),
+
+    /// Returns the routing policy for messages from a chain
+    #[returns(RoutingPolicy)]
+    RoutingPolicy(ChainName),
+}
+
+/// Restricts the destination chains that messages from a source chain can be routed to
+#[cw_serde]
+#[derive(Default)]
+pub enum RoutingPolicy {
+    #[default]
+    AllowAll,
+    /// Messages can only be routed to the listed chains
+    Allowlist(Vec<ChainName>),
+    /// Messages can be routed to any chain except the listed ones
+    Denylist(Vec<ChainName>),
+}
+
+impl RoutingPolicy {
+    pub fn allows(&self, destination_chain: &ChainName) -> bool {
+        match self {
+            RoutingPolicy::AllowAll => true,
+            RoutingPolicy::Allowlist(chains) => chains.contains(destination_chain),
+            RoutingPolicy::Denylist(chains) => !chains.contains(destination_chain),
+        }
+    }
+}
+
+/// Limits the number of messages routed from or to a chain within a window of blocks
+#[cw_serde]
+pub struct RateLimit {
+    pub max_messages: nonempty::Uint64,
+    /// Length of a window in blocks. Windows start at block heights that are a multiple of the length.
+    pub window_blocks: nonempty::Uint64,
+}
+
+impl RateLimit {
+    /// Returns the first block of the window that contains the given block height
+    pub fn window_start(&self, block_height: u64) -> u64 {
+        let window_blocks: u64 = self.window_blocks.into();
+
+        block_height.saturating_sub(
+            block_height
+                .checked_rem(window_blocks)
+                .expect("window length must not be zero"),
+        )
+    }
+}
+
+#[cw_serde]
+pub struct RateLimitUsage {
+    pub limit: RateLimit,
+    /// First block of the window the usage was recorded in
+    pub window_start: u64,
+    pub messages_in_window: u64,
+}
+
+impl RateLimitUsage {
+    pub fn new(limit: RateLimit, block_height: u64) -> Self {
+        Self {
+            window_start: limit.window_start(block_height),
+            limit,
+            messages_in_window: 0,
+        }
+    }
+
+    /// Returns the usage in the window that contains the given block height.
+    /// The usage is reset if the window has changed since it was recorded.
+    pub fn at(self, block_height: u64) -> Self {
+        let window_start = self.limit.window_start(block_height);
+
+        if window_start == self.window_start {
+            self
+        } else {
+            Self::new(self.limit, block_height)
+        }
+    }
+
+    pub fn remaining(&self) -> u64 {
+        u64::from(self.limit.max_messages).saturating_sub(self.messages_in_window)
+    }
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct ChainRateLimits {
+    pub incoming: Option<RateLimitUsage>,
+    pub outgoing: Option<RateLimitUsage>,
 }
@@ -1,8 +1,10 @@
+use axelar_wasm_std::nonempty;
+use cosmwasm_std::CosmosMsg;
 use error_stack::ResultExt;
 use router_api::ChainName;
 
-use crate::msg::{ExecuteMsg, QueryMsg, ServiceParamsOverride, VerifierDetails};
-use crate::{Service, WeightedVerifier};
+use crate::msg::{ExecuteMsg, QueryMsg, ServiceParamsOverride, VerifierDetails, VerifierFilter};
+use crate::{JailedVerifier, Misbehaviour, Service, SlashingConfig, WeightedVerifier};
 
 type Result<T> = error_stack::Result<T, Error>;
 
@@ -28,6 +30,21 @@ pub enum Error {
         service_name: String,
         verifier: String,
     },
+
+    #[error("failed to query service registry for slashing config of service {0}")]
+    SlashingConfig(String),
+
+    #[error("failed to query service registry for jailed verifiers of service {0}")]
+    JailedVerifiers(String),
+
+    #[error("failed to query service registry for services")]
+    Services,
+
+    #[error("failed to query service registry for verifiers of service {0}")]
+    Verifiers(String),
+
+    #[error("failed to query service registry for supported chains of service {0}")]
+    SupportedChains(String),
 }
 
 impl Error {
@@ -58,6 +75,27 @@ impl Error {
                 service_name,
                 verifier,
             },
+            QueryMsg::SlashingConfig { service_name } => Error::SlashingConfig(service_name),
+            QueryMsg::JailedVerifiers {
+                service_name,
+                start_after: _,
+                limit: _,
+            } => Error::JailedVerifiers(service_name),
+            QueryMsg::Services {
+                start_after: _,
+                limit: _,
+            } => Error::Services,
+            QueryMsg::Verifiers {
+                service_name,
+                filter: _,
+                start_after: _,
+                limit: _,
+            } => Error::Verifiers(service_name),
+            QueryMsg::SupportedChains {
+                service_name,
+                start_after: _,
+                limit: _,
+            } => Error::SupportedChains(service_name),
         }
     }
 }
@@ -73,7 +111,20 @@ pub struct Client<'a> {
 }
 
 impl Client<'_> {
-    // TODO: add execute methods
+    pub fn report_misbehaviour(
+        &self,
+        service_name: String,
+        chain_name: ChainName,
+        verifiers: Vec<String>,
+        misbehaviour: Misbehaviour,
+    ) -> CosmosMsg {
+        self.client.execute(&ExecuteMsg::ReportMisbehaviour {
+            service_name,
+            chain_name,
+            verifiers,
+            misbehaviour,
+        })
+    }
 
     pub fn active_verifiers(
         &self,
@@ -123,19 +174,95 @@ impl Client<'_> {
             .query(&msg)
             .change_context_lazy(|| Error::from_msg(msg))
     }
+
+    pub fn slashing_config(&self, service_name: String) -> Result<Option<SlashingConfig>> {
+        let msg = QueryMsg::SlashingConfig { service_name };
+        self.client
+            .query(&msg)
+            .change_context_lazy(|| Error::from_msg(msg))
+    }
+
+    pub fn jailed_verifiers(
+        &self,
+        service_name: String,
+        start_after: Option<String>,
+        limit: nonempty::Uint32,
+    ) -> Result<Vec<JailedVerifier>> {
+        let msg = QueryMsg::JailedVerifiers {
+            service_name,
+            start_after,
+            limit,
+        };
+        self.client
+            .query(&msg)
+            .change_context_lazy(|| Error::from_msg(msg))
+    }
+
+    pub fn services(
+        &self,
+        start_after: Option<String>,
+        limit: nonempty::Uint32,
+    ) -> Result<Vec<Service>> {
+        let msg = QueryMsg::Services { start_after, limit };
+        self.client
+            .query(&msg)
+            .change_context_lazy(|| Error::from_msg(msg))
+    }
+
+    pub fn verifiers(
+        &self,
+        service_name: String,
+        filter: Option<VerifierFilter>,
+        start_after: Option<String>,
+        limit: nonempty::Uint32,
+    ) -> Result<Vec<VerifierDetails>> {
+        let msg = QueryMsg::Verifiers {
+            service_name,
+            filter,
+            start_after,
+            limit,
+        };
+        self.client
+            .query(&msg)
+            .change_context_lazy(|| Error::from_msg(msg))
+    }
+
+    pub fn supported_chains(
+        &self,
+        service_name: String,
+        start_after: Option<ChainName>,
+        limit: nonempty::Uint32,
+    ) -> Result<Vec<ChainName>> {
+        let msg = QueryMsg::SupportedChains {
+            service_name,
+            start_after,
+            limit,
+        };
+        self.client
+            .query(&msg)
+            .change_context_lazy(|| Error::from_msg(msg))
+    }
 }
 
 #[cfg(test)]
 mod test {
 
     use axelar_wasm_std::nonempty::Uint128;
+    use axelar_wasm_std::Threshold;
     use cosmwasm_std::testing::{MockApi, MockQuerier};
-    use cosmwasm_std::{from_json, to_json_binary, Addr, QuerierWrapper, SystemError, WasmQuery};
+    use cosmwasm_std::{
+        from_json, to_json_binary, Addr, QuerierWrapper, SystemError, Timestamp, WasmQuery,
+    };
     use router_api::{chain_name, cosmos_addr, ChainName};
 
     use crate::client::Client;
-    use crate::msg::{QueryMsg, ServiceParamsOverride, VerifierDetails};
-    use crate::{Service, Verifier, WeightedVerifier};
+    use crate::msg::{
+        BondingStatus, QueryMsg, ServiceParamsOverride, VerifierDetails, VerifierFilter,
+    };
+    use crate::{
+        JailReason, JailedVerifier, Misbehaviour, Service, SlashingConfig, Verifier,
+        WeightedVerifier, WeightingMode,
+    };
 
     const ETHEREUM: &str = "ethereum";
 
@@ -267,6 +394,129 @@ mod test {
         assert_eq!(res.unwrap(), None);
     }
 
+    #[test]
+    fn query_slashing_config_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.slashing_config("verifiers".to_string());
+
+        assert!(res.is_err(), "{:?}", res.unwrap());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_slashing_config_returns_slashing_config() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.slashing_config("verifiers".to_string());
+
+        assert!(res.is_ok(), "{}", res.unwrap_err().to_string());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_jailed_verifiers_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.jailed_verifiers("verifiers".to_string(), None, 10u32.try_into().unwrap());
+
+        assert!(res.is_err(), "{:?}", res.unwrap());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_jailed_verifiers_returns_jailed_verifiers() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.jailed_verifiers("verifiers".to_string(), None, 10u32.try_into().unwrap());
+
+        assert!(res.is_ok(), "{}", res.unwrap_err().to_string());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_services_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.services(None, 10u32.try_into().unwrap());
+
+        assert!(res.is_err(), "{:?}", res.unwrap());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_services_returns_services() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.services(None, 10u32.try_into().unwrap());
+
+        assert!(res.is_ok(), "{}", res.unwrap_err().to_string());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_verifiers_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.verifiers(
+            "verifiers".to_string(),
+            None,
+            None,
+            10u32.try_into().unwrap(),
+        );
+
+        assert!(res.is_err(), "{:?}", res.unwrap());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_verifiers_returns_verifier_details() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.verifiers(
+            "verifiers".to_string(),
+            Some(VerifierFilter {
+                bonding_status: Some(BondingStatus::Bonded),
+                authorization_state: None,
+            }),
+            None,
+            10u32.try_into().unwrap(),
+        );
+
+        assert!(res.is_ok(), "{}", res.unwrap_err().to_string());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_supported_chains_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.supported_chains("verifiers".to_string(), None, 10u32.try_into().unwrap());
+
+        assert!(res.is_err(), "{:?}", res.unwrap());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_supported_chains_returns_supported_chains() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.supported_chains("verifiers".to_string(), None, 10u32.try_into().unwrap());
+
+        assert!(res.is_ok(), "{}", res.unwrap_err().to_string());
+        goldie::assert_json!(res.unwrap());
+    }
+
     fn setup_queries_to_fail() -> (MockQuerier, Addr) {
         let addr = cosmos_addr!("service-registry");
         let addr_clone = addr.clone();
@@ -295,6 +545,7 @@ mod test {
             bond_denom: "uaxl".into(),
             unbonding_period_days: 10,
             description: "some service".into(),
+            weighting_mode: WeightingMode::Uniform,
         }
     }
 
@@ -360,6 +611,60 @@ mod test {
                     })
                     .into())
                     .into(),
+                    QueryMsg::SlashingConfig { service_name: _ } => {
+                        Ok(to_json_binary(&Some(SlashingConfig {
+                            reporters: vec![cosmos_addr!("rewards")],
+                            slash_fraction: Threshold::try_from((1u64, 10u64)).unwrap(),
+                            slashed_funds_recipient: cosmos_addr!("treasury"),
+                            jail_duration_days: Some(7),
+                            incorrect_votes_threshold: Some(3u64.try_into().unwrap()),
+                        }))
+                        .into())
+                        .into()
+                    }
+                    QueryMsg::JailedVerifiers {
+                        service_name: _,
+                        start_after: _,
+                        limit: _,
+                    } => Ok(to_json_binary(&vec![JailedVerifier {
+                        verifier: cosmos_addr!("verifier"),
+                        reason: JailReason::Misbehaviour(Misbehaviour::IncorrectVote),
+                        jailed_at: Some(Timestamp::from_seconds(1)),
+                        expires_at: Some(Timestamp::from_seconds(604801)),
+                    }])
+                    .into())
+                    .into(),
+                    QueryMsg::Services {
+                        start_after: _,
+                        limit: _,
+                    } => Ok(
+                        to_json_binary(&vec![mock_service("verifiers".to_string(), None)]).into(),
+                    )
+                    .into(),
+                    QueryMsg::Verifiers {
+                        service_name,
+                        filter: _,
+                        start_after: _,
+                        limit: _,
+                    } => Ok(to_json_binary(&vec![VerifierDetails {
+                        verifier: Verifier {
+                            address: cosmos_addr!("verifier"),
+                            bonding_state: crate::BondingState::Bonded {
+                                amount: Uint128::one(),
+                            },
+                            authorization_state: crate::AuthorizationState::Authorized,
+                            service_name,
+                        },
+                        weight: Uint128::one(),
+                        supported_chains: vec![chain_name!(ETHEREUM)],
+                    }])
+                    .into())
+                    .into(),
+                    QueryMsg::SupportedChains {
+                        service_name: _,
+                        start_after: _,
+                        limit: _,
+                    } => Ok(to_json_binary(&vec![chain_name!(ETHEREUM)]).into()).into(),
                 }
             }
             _ => panic!("unexpected query: {:?}", msg),
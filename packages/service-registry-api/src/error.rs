@@ -43,6 +43,14 @@ pub enum ContractError {
     VerifierJailed,
     #[error("failed to unbond verifier")]
     FailedToUnbondVerifier,
+    #[error("slashing is not enabled for this service")]
+    SlashingNotEnabled,
+    #[error("slashing config not found")]
+    SlashingConfigNotFound,
+    #[error("verifier is not jailed")]
+    VerifierNotJailed,
+    #[error("jail duration of verifier has not passed yet")]
+    JailNotExpired,
 
     // Generic error to wrap cw_storage_plus errors
     // This should only be used for things that shouldn't happen, such as encountering
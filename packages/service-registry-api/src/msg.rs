@@ -1,4 +1,4 @@
-use axelar_wasm_std::nonempty;
+use axelar_wasm_std::{nonempty, Threshold};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use msgs_derive::Permissions;
 use router_api::ChainName;
@@ -7,6 +7,14 @@ use serde::{Deserialize, Serialize};
 
 use crate::primitives::*;
 
+// Pagination limits
+const DEFAULT_PAGINATION_LIMIT: u32 = u32::MAX;
+
+fn default_pagination_limit() -> nonempty::Uint32 {
+    nonempty::Uint32::try_from(DEFAULT_PAGINATION_LIMIT)
+        .expect("default pagination limit must be a u32")
+}
+
 #[cw_serde]
 #[derive(Permissions)]
 pub enum ExecuteMsg {
@@ -21,6 +29,9 @@ pub enum ExecuteMsg {
         bond_denom: String,
         unbonding_period_days: u16, // number of days to wait after starting unbonding before allowed to claim stake
         description: String,
+        /// How verifier weights are derived from their bonds. Defaults to uniform weights.
+        #[serde(default)]
+        weighting_mode: WeightingMode,
     },
     /// Updates modifiable fields of the service. Note, not all fields are modifiable.
     #[permission(Governance)]
@@ -54,10 +65,45 @@ pub enum ExecuteMsg {
         service_name: String,
     },
     /// Jail verifiers. Can only be called by governance account. Jailed verifiers are not allowed to unbond or claim stake.
+    /// If a jail duration is given, jailed verifiers can unjail themselves after it has passed.
     #[permission(Governance)]
     JailVerifiers {
         verifiers: Vec<String>,
         service_name: String,
+        #[serde(default)]
+        jail_duration_days: Option<u16>,
+    },
+    /// Unjail verifiers. Can only be called by governance account. Unjailed verifiers regain the authorization state they had before being jailed.
+    #[permission(Governance)]
+    UnjailVerifiers {
+        verifiers: Vec<String>,
+        service_name: String,
+    },
+    /// Sets the slashing parameters of a service, which allows the given reporters to report misbehaving verifiers.
+    #[permission(Governance)]
+    SetSlashingConfig {
+        service_name: String,
+        reporters: Vec<String>,
+        slash_fraction: Threshold,
+        slashed_funds_recipient: String,
+        #[serde(default)]
+        jail_duration_days: Option<u16>,
+        /// Slashing for incorrect votes is opt-in. If None, reports of incorrect votes are ignored
+        #[serde(default)]
+        incorrect_votes_threshold: Option<nonempty::Uint64>,
+    },
+    /// Removes the slashing parameters of a service. Verifiers of the service can no longer be reported.
+    #[permission(Governance)]
+    RemoveSlashingConfig { service_name: String },
+    /// Reports misbehaving verifiers. Can only be called by the reporters in the slashing parameters of the service.
+    /// Reported verifiers are jailed and a fraction of their bond is sent to the slashed funds recipient.
+    /// Jailed verifiers can appeal to governance to be unjailed, or unjail themselves once their jail duration has passed.
+    #[permission(Specific(reporter))]
+    ReportMisbehaviour {
+        service_name: String,
+        chain_name: ChainName,
+        verifiers: Vec<String>,
+        misbehaviour: Misbehaviour,
     },
 
     /// Register support for the specified chains. Called by the verifier.
@@ -82,6 +128,9 @@ pub enum ExecuteMsg {
     /// Claim previously staked funds that have finished unbonding for the sender.
     #[permission(Any)]
     ClaimStake { service_name: String },
+    /// Unjails the sender once its jail duration has passed.
+    #[permission(Any)]
+    UnjailVerifier { service_name: String },
 }
 
 #[cw_serde]
@@ -110,6 +159,101 @@ pub enum QueryMsg {
         service_name: String,
         verifier: String,
     },
+
+    #[returns(Option<SlashingConfig>)]
+    SlashingConfig { service_name: String },
+
+    // Returns the jailed verifiers of a service, ordered by address
+    // The list is paginated by:
+    // - start_after: the verifier address to start after, which the next page of results should start.
+    // - limit: limit the number of verifiers returned, default is u32::MAX.
+    #[returns(Vec<JailedVerifier>)]
+    JailedVerifiers {
+        service_name: String,
+        start_after: Option<String>,
+        #[serde(default = "default_pagination_limit")]
+        limit: nonempty::Uint32,
+    },
+
+    // Returns all registered services, ordered by name
+    // The list is paginated by:
+    // - start_after: the service name to start after, which the next page of results should start.
+    // - limit: limit the number of services returned, default is u32::MAX.
+    #[returns(Vec<Service>)]
+    Services {
+        start_after: Option<String>,
+        #[serde(default = "default_pagination_limit")]
+        limit: nonempty::Uint32,
+    },
+
+    // Returns the verifiers of a service that match the given filter, ordered by address
+    // The list is paginated by:
+    // - start_after: the verifier address to start after, which the next page of results should start.
+    // - limit: limit the number of verifiers returned, default is u32::MAX.
+    #[returns(Vec<VerifierDetails>)]
+    Verifiers {
+        service_name: String,
+        filter: Option<VerifierFilter>,
+        start_after: Option<String>,
+        #[serde(default = "default_pagination_limit")]
+        limit: nonempty::Uint32,
+    },
+
+    // Returns all chains at least one verifier of the service has registered support for, ordered by name
+    // The list is paginated by:
+    // - start_after: the chain name to start after, which the next page of results should start.
+    // - limit: limit the number of chains returned, default is u32::MAX.
+    #[returns(Vec<ChainName>)]
+    SupportedChains {
+        service_name: String,
+        start_after: Option<ChainName>,
+        #[serde(default = "default_pagination_limit")]
+        limit: nonempty::Uint32,
+    },
+}
+
+/// Bonding state of a verifier, regardless of the bonded amount
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub enum BondingStatus {
+    Bonded,
+    RequestedUnbonding,
+    Unbonding,
+    Unbonded,
+}
+
+impl BondingStatus {
+    pub fn matches(&self, bonding_state: &BondingState) -> bool {
+        matches!(
+            (self, bonding_state),
+            (BondingStatus::Bonded, BondingState::Bonded { .. })
+                | (
+                    BondingStatus::RequestedUnbonding,
+                    BondingState::RequestedUnbonding { .. }
+                )
+                | (BondingStatus::Unbonding, BondingState::Unbonding { .. })
+                | (BondingStatus::Unbonded, BondingState::Unbonded)
+        )
+    }
+}
+
+// Any non-None field must match for a verifier to be returned
+#[cw_serde]
+#[derive(Default)]
+pub struct VerifierFilter {
+    pub bonding_status: Option<BondingStatus>,
+    pub authorization_state: Option<AuthorizationState>,
+}
+
+impl VerifierFilter {
+    pub fn matches(&self, verifier: &Verifier) -> bool {
+        self.bonding_status
+            .as_ref()
+            .is_none_or(|status| status.matches(&verifier.bonding_state))
+            && self
+                .authorization_state
+                .as_ref()
+                .is_none_or(|state| *state == verifier.authorization_state)
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -127,6 +271,8 @@ pub struct UpdatedServiceParams {
     pub max_num_verifiers: Option<Option<u16>>,
     pub min_verifier_bond: Option<nonempty::Uint128>,
     pub unbonding_period_days: Option<u16>,
+    #[serde(default)]
+    pub weighting_mode: Option<WeightingMode>,
 }
 
 // Represents any overrideable fields of the Service struct
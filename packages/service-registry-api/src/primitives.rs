@@ -1,4 +1,4 @@
-use axelar_wasm_std::{nonempty, Participant};
+use axelar_wasm_std::{nonempty, Participant, Threshold};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Timestamp};
 use schemars::JsonSchema;
@@ -16,6 +16,67 @@ pub struct Service {
     // otherwise a verifier could bail before they get penalized
     pub unbonding_period_days: u16,
     pub description: String,
+    // services registered before weighting modes were introduced use uniform weights
+    #[serde(default)]
+    pub weighting_mode: WeightingMode,
+}
+
+/// Determines how the weight of an active verifier is derived from its bond
+#[cw_serde]
+#[derive(Default, Eq)]
+pub enum WeightingMode {
+    /// All verifiers have the same weight, regardless of the amount bonded
+    #[default]
+    Uniform,
+    /// The weight is the bonded amount, capped at `max_weight`
+    Linear { max_weight: nonempty::Uint128 },
+    /// The weight is the integer square root of the bonded amount
+    Sqrt,
+}
+
+/// Slashing parameters of a service. Verifiers of services without slashing parameters cannot be reported for misbehaviour.
+#[cw_serde]
+pub struct SlashingConfig {
+    /// Contracts that are allowed to report misbehaving verifiers, e.g. the rewards contract or voting verifiers
+    pub reporters: Vec<Addr>,
+    /// Fraction of the bond that gets slashed each time a verifier is reported
+    pub slash_fraction: Threshold,
+    /// Address that receives the slashed funds
+    pub slashed_funds_recipient: Addr,
+    /// Number of days after which reported verifiers can unjail themselves. If None, only governance can unjail them
+    #[serde(default)]
+    pub jail_duration_days: Option<u16>,
+    /// Number of incorrect votes after which a verifier gets slashed and jailed. If None, incorrect votes are not reported
+    #[serde(default)]
+    pub incorrect_votes_threshold: Option<nonempty::Uint64>,
+}
+
+/// Misbehaviour of a verifier that leads to slashing and jailing
+#[cw_serde]
+pub enum Misbehaviour {
+    /// The verifier voted against the consensus of a poll
+    IncorrectVote,
+    /// The verifier did not meet the participation threshold for the given number of consecutive epochs
+    MissedEpochs { count: u64 },
+}
+
+/// Reason a verifier was jailed
+#[cw_serde]
+pub enum JailReason {
+    /// Jailed by governance
+    Governance,
+    /// Jailed after being reported for misbehaviour
+    Misbehaviour(Misbehaviour),
+}
+
+#[cw_serde]
+pub struct JailedVerifier {
+    pub verifier: Addr,
+    pub reason: JailReason,
+    /// None for verifiers that were jailed before jail details were recorded
+    pub jailed_at: Option<Timestamp>,
+    /// Time after which the verifier can unjail itself. If None, only governance can unjail the verifier
+    pub expires_at: Option<Timestamp>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]